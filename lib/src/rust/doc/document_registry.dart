@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'document_types.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>
+abstract class DocumentRegistry implements RustOpaqueInterface {
+  Future<Uint8List> applyAction({
+    required String docId,
+    required List<BlockActionDoc> actions,
+  });
+
+  Future<AppliedBlockChanges> applyUpdates({
+    required String docId,
+    required List<Uint8List> updates,
+  });
+
+  /// 문서를 닫고 Doc을 해제
+  ///
+  /// [doc_id] 문서 id
+  ///
+  /// 반환: 열려 있던 문서였는지 여부
+  Future<bool> close({required String docId});
+
+  Future<Uint8List> encodeFullState({required String docId});
+
+  Future<DocumentState> getDocumentState({required String docId});
+
+  Future<Uint8List> initEmptyDoc({required String docId});
+
+  /// 열려 있는 문서 id 목록 (정렬됨)
+  Future<List<String>> listOpen();
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+  static Future<DocumentRegistry> newInstance() =>
+      RustLib.instance.api.crateDocDocumentRegistryDocumentRegistryNew();
+
+  /// 문서를 열기 (이미 열려 있으면 그대로 둠)
+  ///
+  /// [doc_id] 문서 id
+  Future<void> open({required String docId});
+}
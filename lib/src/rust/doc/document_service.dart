@@ -7,32 +7,767 @@ import '../frb_generated.dart';
 import 'document_types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `yrs_any_to_json`, `yrs_value_to_json`
+// These functions are ignored because they are not marked as `pub`: `apply_action_in`, `apply_meta_op`, `apply_updates_and_diff`, `block_changes_from_events`, `block_texts_in_order`, `build_undo_manager`, `check_actions`, `check_block_types`, `cycle_breakers`, `decode_state_vector`, `expire_stale_awareness`, `extract_state`, `find_subdoc`, `is_empty_in`, `meta_changes_from_events`, `meta_json_as_stored`, `meta_json_scalar`, `move_block_to`, `observe_for_cache`, `prune_meta_fields`, `push_meta_json`, `read_comments`, `read_meta_value`, `reconstruct_snapshot`, `register_change_observer`, `run_undo_step`, `set_block_listener`, `set_change_listener`, `set_emptiness_listener`, `set_meta_listener`, `statistics`, `write_comment`, `write_imported_blocks`, `write_meta_json`, `yrs_any_to_json`, `yrs_value_to_json`
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>
 abstract class DocumentService implements RustOpaqueInterface {
+  /// 최근 window_ms 동안 awareness 상태를 갱신한 편집자 수 ("3명 편집 중" 표시용)
+  ///
+  /// [window_ms] 활동으로 인정할 기간 (밀리초)
+  ///
+  /// AWARENESS_TIMEOUT_MS 동안 소식이 없는 원격 클라이언트는 먼저 오프라인 처리됨
+  Future<int> activeEditorCount({required BigInt windowMs});
+
+  /// 블록에 댓글 추가
+  ///
+  /// [block_id] 댓글을 붙일 블록 id
+  /// [comment_id] 댓글 id (Dart에서 생성, 문서 안에서 고유해야 함)
+  /// [author] 작성자
+  /// [text] 댓글 내용
+  /// [anchor_offset] 블록 텍스트 안의 위치 (None이면 블록 전체에 대한 댓글).
+  /// 상대 위치로 저장되므로 앞쪽 텍스트가 편집되어도 같은 글자를 따라감
+  ///
+  /// 블록이 없거나, 같은 id의 댓글이 있거나, 위치가 텍스트 범위를 벗어나면 에러
+  Future<Uint8List> addComment({
+    required String blockId,
+    required String commentId,
+    required String author,
+    required String text,
+    int? anchorOffset,
+  });
+
+  /// 피어에게 없는 내용만 담고 삭제는 모두 뺀 업데이트 생성 (병합 전용 동기화용)
+  ///
+  /// [peer_state_vector] 피어의 state vector (v1 인코딩)
+  ///
+  /// 주의: 로컬에서 삭제한 내용이 피어에는 남으므로, 삭제가 실제로 필요한 경우 두 문서가 갈라질 수 있음.
+  /// 이미 삭제된 상태로 전달되는 새 항목은 피어에서도 삭제된 상태로 들어감 (피어의 기존 내용만 보존됨).
+  Future<Uint8List> additiveUpdateFor({required Uint8List peerStateVector});
+
   Future<Uint8List> applyAction({required List<BlockActionDoc> actions});
 
-  Future<void> applyUpdates({required List<Uint8List> updates});
+  /// apply_action과 같지만 id 없이 보낸 Insert 블록에 Rust에서 만든 id도 함께 반환 (새 블록에 바로 포커스 주기 위함)
+  ///
+  /// [actions] 적용할 블록 작업 목록, Insert의 block.id가 빈 문자열이면 UUID를 새로 생성
+  ///
+  /// 반환: 업데이트와 생성된 id 목록 (작업 순서대로, id를 지정한 Insert는 포함되지 않음).
+  /// apply_action도 빈 id에 id를 생성하지만 기존 호출과의 호환을 위해 업데이트만 반환함
+  Future<ActionResult> applyActionWithIds({
+    required List<BlockActionDoc> actions,
+  });
+
+  /// apply_action과 같지만 트랜잭션에 origin을 붙여 실행
+  ///
+  /// [actions] 적용할 블록 작업 목록
+  /// [origin] 트랜잭션 origin (subscribe_changes 스트림에 그대로 전달됨)
+  ///
+  /// origin 없는 apply_action은 기본(origin 없음) 트랜잭션을 쓰므로 기존 undo 추적에 영향 없음
+  Future<Uint8List> applyActionWithOrigin({
+    required List<BlockActionDoc> actions,
+    required String origin,
+  });
+
+  /// apply_action과 같지만 Insert/Update 블록에 생성/수정 시각을 기록 ("2분 전 편집" 표시용)
+  ///
+  /// [actions] 적용할 블록 작업 목록
+  /// [timestamp_ms] Dart에서 넘겨주는 현재 시각 (밀리초), 협업자마다 시계가 다르므로 Rust에서 시스템 시간을 읽지 않음
+  ///
+  /// Insert는 createdAt과 updatedAt을, Update는 updatedAt만 기록.
+  /// updatedAt은 클라이언트별로 저장하고 가장 큰 값을 보여주므로, 동시 편집이나 시계 차이로 뒤로 가지 않음.
+  /// 기록된 시각은 get_block/get_document_state의 created_at/updated_at으로 확인
+  Future<Uint8List> applyActionWithTimestamp({
+    required List<BlockActionDoc> actions,
+    required PlatformInt64 timestampMs,
+  });
+
+  /// 다른 클라이언트의 awareness 업데이트 적용
+  ///
+  /// [update] encode_awareness_update로 만든 바이트 (v1)
+  ///
+  /// 반환: 바뀐 클라이언트 id JSON (예: {"added":[12],"updated":[34],"removed":[]})
+  Future<String> applyAwarenessUpdate({required Uint8List update});
+
+  /// 블록의 기존 텍스트에 Quill delta를 적용 (글자 단위 CRDT 병합 유지)
+  ///
+  /// [block_id] 대상 블록 id
+  /// [delta_json] retain/insert/delete 연산 목록 (attributes 포함 가능)
+  ///
+  /// 블록이 없거나 텍스트 필드가 없으면 에러 반환 (텍스트를 새로 만들지 않음)
+  Future<Uint8List> applyDelta({
+    required String blockId,
+    required String deltaJson,
+  });
+
+  /// encode_meta_only_update로 만든 업데이트의 메타데이터를 이 문서의 META 맵에 반영
+  ///
+  /// [update] encode_meta_only_update 결과 (encode_full_state 같은 전체 상태도 가능)
+  ///
+  /// 업데이트에 블록 데이터가 있어도 무시하고 META만 반영. 업데이트에 없는 키는 제거되고 값이 다른 키만 다시 씀.
+  /// 업데이트에 META 맵이 없으면 (일부만 담긴 diff 등) 메타를 지우지 않도록 에러 반환
+  ///
+  /// 주의: CRDT 병합이 아니라 마지막에 적용한 쪽이 이기는 교체임. 업데이트를 만든 뒤에 이 문서에서 바꾼 메타도
+  /// 업데이트 시점의 값으로 덮어쓰거나 지우므로, 오래된 스냅샷을 적용하면 더 최신의 로컬 메타 변경이 사라짐.
+  /// 노트 목록처럼 메타를 읽기만 하는 복사본을 최신으로 맞추는 용도로만 쓰고, 편집 중인 문서의 메타 동기화는
+  /// encode_diff/apply_updates로 해야 함
+  ///
+  /// 반환: 이 문서에 적용된 변경의 업데이트 (로컬 저장용)
+  Future<Uint8List> applyMetaOnlyUpdate({required Uint8List update});
+
+  /// 원격 하위 문서 업데이트(v2)를 적용 (부모 문서 변경 스트림에는 나타나지 않음)
+  ///
+  /// [guid] 하위 문서 guid
+  /// [update] 적용할 업데이트
+  Future<void> applySubdocUpdate({
+    required String guid,
+    required Uint8List update,
+  });
+
+  /// 블록 텍스트에 여러 편집(retain/insert/delete)을 하나의 트랜잭션으로 적용
+  ///
+  /// [block_id] 대상 블록 id
+  /// [ops_json] Quill delta 형식의 연산 목록 (예: [{"retain": 5}, {"insert": "!"}, {"delete": 2}])
+  ///
+  /// 모든 연산의 범위를 먼저 검사하므로, 하나라도 범위를 벗어나면 아무것도 적용하지 않고 에러 반환
+  Future<Uint8List> applyTextOps({
+    required String blockId,
+    required String opsJson,
+  });
+
+  /// 원격 업데이트 하나를 적용하고 새 내용이 들어왔는지 반환 (이미 받은 업데이트를 다시 브로드캐스트하지 않기 위함)
+  ///
+  /// [update] 적용할 업데이트 (v2)
+  ///
+  /// 반환: 적용 전후 state vector를 비교해 앞으로 나아갔으면 true, 이미 가진 내용뿐이면 false.
+  /// 삭제만 담긴 업데이트는 state vector를 바꾸지 않으므로 false가 반환됨. 디코딩에 실패하면 에러
+  Future<bool> applyUpdate({required Uint8List update});
+
+  /// 블록 id를 매핑에 따라 바꿔서 업데이트를 현재 문서에 적용 (템플릿 인스턴스화 용도)
+  ///
+  /// [update] 적용할 업데이트 (v2 인코딩)
+  /// [id_map_json] 원래 id -> 새 id 매핑 JSON (예: {"tpl-heading": "a8Kd2x"})
+  ///
+  /// 매핑에 없는 id는 그대로 유지되며, 결과 id가 기존 블록과 겹치면 아무것도 적용하지 않고 에러 반환
+  Future<Uint8List> applyUpdateRemapped({
+    required Uint8List update,
+    required String idMapJson,
+  });
+
+  /// 원격 업데이트를 적용하고 추가/수정/삭제된 블록 id를 반환
+  ///
+  /// [updates] 적용할 업데이트 목록
+  ///
+  /// 메타만 바뀐 경우 세 목록 모두 비어 있음
+  Future<AppliedBlockChanges> applyUpdates({required List<Uint8List> updates});
+
+  /// v1 인코딩 업데이트를 적용하고 추가/수정/삭제된 블록 id를 반환
+  ///
+  /// [updates] 적용할 업데이트 목록 (v1 인코딩)
+  ///
+  /// v1과 v2는 호환되지 않으므로 v1으로 디코딩한 뒤 apply_updates와 같은 경로로 적용함.
+  /// 디코딩에 실패한 업데이트는 로그를 남기고 건너뜀
+  Future<AppliedBlockChanges> applyUpdatesV1({
+    required List<Uint8List> updates,
+  });
+
+  /// 원격 업데이트를 적용하고 블록별 변경 종류를 JSON으로 반환
+  ///
+  /// [updates] 적용할 업데이트 목록
+  ///
+  /// 반환: {"blockA": "inserted", "blockB": "text_changed", "blockC": "deleted"}
+  /// 변경 종류: inserted, deleted, text_changed, data_changed, text_and_data_changed
+  /// 같은 업데이트 안에서 추가 후 삭제된 블록은 포함되지 않음
+  Future<String> applyUpdatesWithChanges({required List<Uint8List> updates});
+
+  /// 블록 데이터에 저장된 모든 바이너리 첨부의 총 바이트 수
+  Future<BigInt> attachmentTotalBytes();
+
+  /// 업데이트 묶음 시작. 이후 commit_batch까지 이 문서에서 일어난 로컬 변경의 업데이트를 모아둠
+  ///
+  /// 각 편집 함수는 여전히 자신의 업데이트를 반환하지만, 묶음 중에는 전송하지 않고 commit_batch 결과만 보내면 됨.
+  /// apply_updates로 적용된 원격 업데이트(REMOTE_ORIGIN)는 다시 보낼 필요가 없으므로 모으지 않음.
+  /// 이미 묶음이 진행 중이면 에러
+  Future<void> beginBatch();
+
+  /// 두 블록을 합칠 수 있는지 여부 ("이전 블록과 합치기" 메뉴 활성화용)
+  ///
+  /// [first_id] 남게 되는 앞 블록 id
+  /// [second_id] 앞 블록에 합쳐질 블록 id
+  ///
+  /// 두 블록이 모두 존재하고, 둘 다 텍스트를 가지며, 타입이 호환되고(서식 있는 텍스트 블록끼리, code는 code끼리),
+  /// second가 first의 조상이 아니어야 함. 판정은 merge_blocks가 쓰는 BlockOperations::check_merge 그대로
+  Future<bool> canMergeBlocks({
+    required String firstId,
+    required String secondId,
+  });
+
+  /// 다시 실행 가능 여부 (툴바 버튼 활성화용, UndoManager가 없으면 false)
+  Future<bool> canRedo();
+
+  /// 실행 취소 가능 여부 (툴바 버튼 활성화용, UndoManager가 없으면 false)
+  Future<bool> canUndo();
+
+  /// 부모의 자식 순서를 SHA-256 해시로 반환 (피어 간 형제 순서가 갈라진 곳을 빠르게 찾기 위함)
+  ///
+  /// [parent_id] 자식 순서를 확인할 부모 블록 id (부모가 없는 최상위 블록은 "root")
+  ///
+  /// 문서 상태와 같은 prevId 체인 정렬을 사용하며, 체인이 끊기거나 순환하는 자식이 있으면 에러 반환
+  Future<String> childrenOrderHash({required String parentId});
+
+  /// 같은 Doc을 유지한 채 모든 블록, 메타, 댓글을 지움 ("모든 내용 삭제"용)
+  ///
+  /// BLOCKS/META 맵은 비운 상태로 남아 있어 다시 초기화하지 않고 apply_action 가능
+  /// 반환: 피어가 같은 상태가 되도록 적용할 업데이트
+  Future<Uint8List> clearDocument();
+
+  /// 묶음을 끝내고 모아둔 업데이트를 merge_updates_v2로 합친 업데이트 하나를 반환
+  ///
+  /// 묶음 중 변경이 없었으면 빈 업데이트 반환. begin_batch 없이 호출하면 에러
+  Future<Uint8List> commitBatch();
+
+  /// 삭제 기록(툼스톤) 없이 현재 내용만 담은 새 문서의 전체 상태 업데이트 생성 (서버 스냅샷 교체용)
+  ///
+  /// 블록, 메타데이터, 댓글, 루트 포인터를 새 Doc에 다시 써서 인코딩하므로 결과는 기존 기록과 이어지지 않음.
+  /// 주의: 예전 기록을 가진 피어의 업데이트는 압축된 문서와 병합되지 않고 내용이 중복/유실될 수 있으므로,
+  /// 모든 클라이언트가 압축본으로 다시 시작하도록 맞춘 뒤에만 사용해야 함.
+  /// 이 인스턴스는 바뀌지 않으며, 압축본은 새 DocumentService에 apply_updates로 불러와야 함
+  Future<Uint8List> compact();
+
+  /// 업데이트 목록을 중복 없이 하나로 합침 (서버의 업데이트 로그 정리용)
+  ///
+  /// [updates] v2 인코딩 업데이트 목록 (같은 업데이트가 여러 번 들어 있어도 됨)
+  ///
+  /// 새 Doc에 모두 적용한 뒤 전체 상태를 다시 인코딩하므로 겹치는 작업은 한 번만 남고,
+  /// merge_updates와 달리 삭제된 내용은 GC되어 길이만 남음.
+  /// compact와 달리 기존 기록과 이어지므로 예전 업데이트를 가진 피어와도 그대로 병합됨.
+  /// 선행 업데이트가 빠진 업데이트가 있으면 에러 (빈 구간이 생긴 결과는 나중에 빠진 업데이트를 받아도 적용되지 않음)
+  Future<Uint8List> compactUpdates({required List<Uint8List> updates});
+
+  /// 문서 내용(블록 트리와 메타데이터)의 SHA-256 해시 (복제본이 수렴했는지 빠르게 확인하기 위함)
+  ///
+  /// 업데이트 바이트가 아니라 문서 상태를 해시하므로 편집 순서나 툼스톤이 달라도 보이는 내용이 같으면 같은 값.
+  /// 댓글이 있으면 댓글도 포함하고, doc_id는 내용이 아니므로 제외
+  Future<String> contentHash();
+
+  /// 선택한 블록과 모든 하위 블록을 다른 문서에도 붙여넣을 수 있는 클립보드 JSON으로 복사
+  ///
+  /// [block_ids] 복사할 블록 id 목록 (순서와 상관없이 문서 순서로 담김)
+  ///
+  /// 반환: {"format", "version", "blocks": [AppFlowy 노드]} 형식의 JSON. 텍스트 서식과 블록 데이터가 그대로 담기며
+  /// yrs client id 같은 문서 내부 정보는 포함하지 않음. 다른 선택 블록의 하위 블록은 상위 블록에 포함되어 한 번만 담김
+  Future<String> copyBlocks({required List<String> blockIds});
+
+  /// 블록에 yrs 하위 문서를 붙이고 guid 반환 (임베드된 보드가 페이지 텍스트와 따로 동기화되도록 함)
+  ///
+  /// [block_id] 하위 문서를 붙일 블록 id
+  ///
+  /// 이미 하위 문서가 있으면 새로 만들지 않고 기존 guid 반환.
+  /// 블록에 하위 문서를 연결하는 부모 문서 변경은 subscribe_changes 스트림이나 encode_diff로 전파해야 하며,
+  /// 하위 문서 내용은 부모 문서 업데이트에 포함되지 않으므로 encode_subdoc_state/apply_subdoc_update로 따로 주고받음
+  Future<String> createSubdoc({required String blockId});
+
+  /// encode_relative_position으로 만든 상대 위치를 현재 문서 기준의 블록 id와 위치로 변환
+  ///
+  /// [relative_position] 인코딩된 상대 위치
+  ///
+  /// 블록이 삭제되었거나 아직 받지 못한 내용을 가리키면 에러
+  Future<RelativePos> decodeRelativePosition({
+    required Uint8List relativePosition,
+  });
+
+  /// 블록과 그 아래 모든 하위 블록을 한 트랜잭션으로 삭제 ("섹션 삭제" 용)
+  ///
+  /// [block_id] 삭제할 블록 id
+  ///
+  /// 하위 블록 전체를 먼저 모은 뒤 BLOCKS에서 제거하고, 다음 형제의 prev_id는 삭제된 블록의 prev_id로 다시 연결.
+  /// 루트 페이지 블록은 삭제할 수 없음
+  Future<Uint8List> deleteBlockRecursive({required String blockId});
+
+  /// 문서 텍스트를 샘플링해서 주 언어를 추정 (맞춤법 검사/현지화 힌트용)
+  ///
+  /// 문자 체계와 흔한 단어를 보는 간단한 휴리스틱이며, BCP-47 코드(예: "en", "ko", "ja")를 반환
+  /// 텍스트가 너무 적어 판단할 수 없으면 "und" 반환
+  Future<String> detectLanguage();
+
+  /// 두 문서 상태의 차이를 apply_action으로 다시 적용할 수 있는 블록 액션으로 반환 (감사 로그용, DocumentService 인스턴스 불필요)
+  ///
+  /// [old_update] 이전 상태의 전체 업데이트 (v2)
+  /// [new_update] 이후 상태의 전체 업데이트 (v2)
+  ///
+  /// Insert/Move는 새 문서 순서대로, Update는 바뀐 속성과 텍스트 전체 교체 delta만, Delete는 삭제된 최상위 블록만 포함
+  static Future<List<BlockActionDoc>> diffStates({
+    required Uint8List oldUpdate,
+    required Uint8List newUpdate,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceDiffStates(
+    oldUpdate: oldUpdate,
+    newUpdate: newUpdate,
+  );
+
+  /// 문서 id 반환
+  Future<String> docId();
+
+  /// 블록과 모든 하위 블록을 새 id로 복제해서 원본 바로 뒤에 삽입
+  ///
+  /// [block_id] 복제할 블록 id
+  ///
+  /// 반환: 업데이트와 원래 id -> 새 id 매핑. 텍스트는 서식까지 복사되며 복제본은 원본과 독립적으로 편집 가능
+  Future<DuplicateResult> duplicateBlock({required String blockId});
+
+  /// BLOCKS 맵을 대상으로 UndoManager 활성화 (이미 활성화되어 있으면 스택을 비우고 새로 생성)
+  ///
+  /// [origins] 추적할 트랜잭션 origin 목록, 비어 있으면 origin 없는 로컬 편집만 추적
+  ///
+  /// apply_updates로 적용된 원격 업데이트는 REMOTE_ORIGIN으로 적용되므로 추적되지 않음
+  Future<void> enableUndoManager({required List<String> origins});
+
+  /// 현재 알고 있는 모든 편집자의 awareness 상태를 인코딩 (v1, 다른 클라이언트로 전송용)
+  Future<Uint8List> encodeAwarenessUpdate();
+
+  /// 상대의 state vector 이후 변경분만 인코딩하여 반환 (동기화 2단계)
+  ///
+  /// [remote_state_vector] 상대의 state vector (v1 인코딩)
+  Future<Uint8List> encodeDiffSince({required Uint8List remoteStateVector});
 
   /// 현재 문서의 전체 상태를 인코딩하여 반환
   Future<Uint8List> encodeFullState();
 
+  /// 현재 문서의 전체 상태를 v1 인코딩으로 반환 (v1만 쓰는 서버/저장소와 호환용)
+  Future<Uint8List> encodeFullStateV1();
+
+  /// 블록 없이 META 맵 내용만 담은 업데이트 생성 (노트 목록 화면의 제목/색상 동기화용)
+  ///
+  /// 반환: 현재 메타데이터만 새 Doc에 기록한 전체 상태. 원본 문서의 변경 기록이 아니므로
+  /// apply_updates가 아닌 apply_meta_only_update로 적용해야 하며, 적용하면 CRDT 병합이 아니라
+  /// 받는 쪽 META를 이 시점의 값으로 통째로 교체함 (apply_meta_only_update 주석 참고)
+  Future<Uint8List> encodeMetaOnlyUpdate();
+
+  /// 블록 텍스트 안의 위치를 원격 편집에도 유지되는 상대 위치로 인코딩 (협업 커서 저장용)
+  ///
+  /// [block_id] 대상 블록 id
+  /// [offset] 텍스트 안의 위치 (delta retain과 같은 단위)
+  ///
+  /// 텍스트 필드가 없는 블록이거나 위치가 텍스트 길이를 넘으면 에러
+  Future<Uint8List> encodeRelativePosition({
+    required String blockId,
+    required int offset,
+  });
+
+  /// 현재 문서의 state vector를 인코딩하여 반환 (동기화 1단계, v1 인코딩)
+  Future<Uint8List> encodeStateVector();
+
+  /// 하위 문서의 전체 상태를 업데이트(v2)로 인코딩
+  ///
+  /// [guid] create_subdoc 또는 get_subdoc_guids로 얻은 하위 문서 guid
+  Future<Uint8List> encodeSubdocState({required String guid});
+
+  /// 최상위 블록이 하나만 있도록 정규화 (잘못된 병합으로 최상위 블록이 여러 개가 된 경우)
+  ///
+  /// 루트 포인터(ROOT_ID)가 가리키는 최상위 블록이 있으면 나머지 최상위 블록을 그 아래로 옮기고,
+  /// 없으면 새 페이지 블록을 만들어 모두 그 아래로 옮긴 뒤 루트 포인터를 갱신함.
+  /// 이미 최상위 블록이 하나 이하이면 아무것도 변경하지 않음.
+  Future<Uint8List> enforceSingleRoot();
+
+  /// encode_diff_since로 보낼 변경분의 크기(바이트)를 미리 계산
+  ///
+  /// [remote_state_vector] 상대의 state vector (v1 인코딩)
+  ///
+  /// 상대가 이미 모든 삽입을 가지고 있으면 0 반환.
+  /// state vector에는 삭제 정보가 없으므로 상대에게 삭제만 빠진 경우도 0이 반환됨 (apply_update와 같은 한계).
+  /// 그 밖의 경우 크기는 encode_diff_since와 같은 변경분을 실제로 인코딩해서 잰 값
+  Future<BigInt> estimateDiffSize({required Uint8List remoteStateVector});
+
+  /// AppFlowy 문서 JSON 형식으로 내보내기 (.json 파일로 저장해서 데스크톱 AppFlowy에서 열기 위함)
+  ///
+  /// 반환: {"document": {"id": "page", "type": "page", "data": {}, "children": [...]}} 형식의 JSON
+  /// 텍스트가 없는 블록은 delta 필드를 생략
+  Future<String> exportAppflowyJson();
+
+  /// 문서를 Markdown으로 내보내기 (노트를 Markdown으로 복사하기 위함)
+  ///
+  /// 제목/문단/글머리 기호·번호·할 일 목록/인용/코드 블록을 블록 type으로 구분하고,
+  /// 목록 들여쓰기는 부모를 따라 올라간 깊이로 정함. bold/italic/code 속성은 **, *, `로 변환.
+  /// 알 수 없는 type은 텍스트만 출력
+  Future<String> exportMarkdown();
+
+  /// 문서 구조만 중첩 JSON으로 반환 (delta, 데이터 제외 - 사이드바/아웃라인용)
+  ///
+  /// 반환: {"id": "...", "type": "page", "children": [{"id": "...", "type": "heading", "children": []}]}
+  Future<String> exportStructure();
+
+  /// 루트 블록부터 시작하는 중첩 트리 JSON으로 내보내기 (트리 구조를 선호하는 웹 렌더러용)
+  ///
+  /// 반환: {"id", "type", "data", "delta", "children": [...]} 노드를 문서 순서로 중첩한 JSON.
+  /// delta는 op 배열이고 텍스트가 없는 블록은 null. 이미 방문한 블록을 다시 만나면 (순환)
+  /// 더 내려가지 않고 {"id": "...", "cycle": true} 표시 노드로 대체
+  Future<String> exportTreeJson();
+
+  /// 업데이트의 삭제 집합만 JSON으로 반환 (삭제/툼스톤 충돌 분석용, DocumentService 인스턴스 불필요)
+  ///
+  /// [update] 분석할 업데이트 (v2 또는 v1 인코딩)
+  ///
+  /// 반환: {"encoding": "v2", "has_deletions": true, "clients": {"123": [[0, 5], [8, 9]]}}
+  /// 범위는 [시작 clock, 끝 clock) 형식이며, 삭제가 없으면 has_deletions가 false이고 clients가 비어 있음
+  static Future<String> extractDeleteSet({required Uint8List update}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceExtractDeleteSet(update: update);
+
+  /// 텍스트가 너무 긴 블록의 id 목록 반환 (렌더링 성능 경고용)
+  ///
+  /// [max_chars] 허용하는 최대 글자 수 (바이트가 아닌 유니코드 문자 기준)
+  Future<List<String>> findOversizedBlocks({required int maxChars});
+
+  /// 현재 내용을 그대로 복사한 독립 문서 생성 (노트 복제용)
+  ///
+  /// [new_doc_id] 복제된 문서의 id
+  ///
+  /// 전체 상태를 새 Doc에 적용하므로 이후 편집은 서로 영향을 주지 않음.
+  /// 복제본은 원본 기록에 없는 client id를 사용하므로 나중에 두 문서를 병합해도 항목이 겹치지 않음.
+  /// GC 설정은 원본을 따르고, 구독/undo/awareness 상태는 복사되지 않음
+  Future<DocumentService> fork({required String newDocId});
+
+  /// 여러 블록의 전체 텍스트에 서식 속성을 한 트랜잭션으로 적용
+  ///
+  /// [block_ids] 서식을 적용할 블록 id 목록
+  /// [attributes_json] 적용할 속성 JSON 객체 (예: {"bold": true, "italic": null})
+  ///
+  /// 값이 null인 속성은 제거되며, 존재하지 않거나 텍스트가 없는 블록은 로그만 남기고 건너뜀
+  Future<Uint8List> formatBlocks({
+    required List<String> blockIds,
+    required String attributesJson,
+  });
+
+  /// GC 사용 여부 반환 (false일 때만 스냅샷 사용 가능)
+  Future<bool> gcEnabled();
+
+  /// BLOCKS 맵의 모든 블록 id (루트 페이지 블록 포함, 내용은 읽지 않음)
+  ///
+  /// 순서는 맵 순회 순서이며 문서 순서가 보장되지 않음. 문서 순서가 필요하면 get_children_ids를 사용
+  Future<List<String>> getAllBlockIds();
+
   /// 모든 메타데이터를 JSON 문자열로 반환
   ///
   /// 반환: JSON 형식의 메타데이터 (예: {"title": "노트", "color": 123, "status": "active"})
   Future<String> getAllMeta();
 
+  /// 전체 상태를 추출하지 않고 블록 하나만 읽기
+  ///
+  /// [block_id] 읽을 블록 id
+  ///
+  /// 블록이 없으면 None 반환
+  Future<BlockDoc?> getBlock({required String blockId});
+
+  /// BLOCKS 맵에 있는 블록 수 (루트 페이지 블록 포함)
+  Future<BigInt> getBlockCount();
+
+  /// 블록의 조상 id 목록을 최상위부터 순서대로 반환 (대상 블록은 제외)
+  ///
+  /// [block_id] 경로를 찾을 블록 id
+  ///
+  /// 부모가 없는 최상위 블록이면 빈 목록, 블록이 없으면 에러 반환
+  Future<List<String>> getBlockPath({required String blockId});
+
+  /// 블록 텍스트를 서식 없이 순수 문자열로 반환 (검색 색인, 글자 수 계산용)
+  ///
+  /// [block_id] 대상 블록 id
+  ///
+  /// 속성은 버리고 블록 안의 줄바꿈은 유지, 텍스트가 없는 블록은 빈 문자열 반환
+  /// 인라인 임베드(이미지, 수식 등)는 \u{FFFC} 한 글자로 표시해 위치가 텍스트 안의 위치와 맞도록 함
+  Future<String> getBlockPlainText({required String blockId});
+
+  /// 블록을 문서 순서(깊이 우선, 자식 순서대로)로 깊이와 함께 반환 (목차, 내보내기 등 트리 순회용)
+  ///
+  /// 깊이는 루트 페이지 블록이 0, 그 자식이 1부터 시작.
+  /// 루트에서 닿지 않는 블록(고아)은 id 순으로 맨 뒤에 붙고 깊이가 -1
+  Future<List<OrderedBlock>> getBlocksInOrder();
+
+  /// 블록을 get_blocks_in_order와 같은 문서 순서로 나눠서 반환 (아주 큰 노트를 조금씩 화면에 불러오기 위함)
+  ///
+  /// [offset] 건너뛸 블록 수
+  /// [limit] 최대 반환 블록 수
+  ///
+  /// 반환: 해당 구간의 블록, 전체 블록 수, 뒤에 블록이 더 있는지 여부.
+  /// 순서는 문서 상태에서 결정적으로 정해지므로 그 사이에 문서가 바뀌지 않으면 페이지가 겹치거나 빠지지 않음.
+  /// 문서 상태 캐시를 쓰므로 페이지마다 전체 상태를 다시 만들지 않음
+  Future<BlockPage> getBlocksPage({
+    required BigInt offset,
+    required BigInt limit,
+  });
+
+  /// 전체 문서를 직렬화하지 않고 부모의 자식 id를 문서 순서대로 반환 (접힌 섹션을 펼칠 때 사용)
+  ///
+  /// [parent_id] 부모 블록 id (부모가 없는 최상위 블록은 "root")
+  ///
+  /// 자식이 없으면 빈 목록, 부모 블록이 없을 때만 에러 반환
+  Future<List<String>> getChildrenIds({required String parentId});
+
+  /// 블록에 붙은 댓글 목록 (해결된 댓글 포함)
+  ///
+  /// [block_id] 블록 id
+  ///
+  /// 텍스트 위치 순으로 정렬되고 블록 전체 댓글은 맨 뒤, 같은 위치는 댓글 id 순.
+  /// 블록이 삭제되어도 에러 없이 남은 댓글을 반환하며, 이런 댓글은 validate_document의 orphaned_comment_ids로 확인
+  Future<List<CommentDoc>> getCommentsForBlock({required String blockId});
+
+  /// 문서 상태 전체를 JSON 문자열로 반환 (디버깅, 로그, 웹 뷰 전달용)
+  ///
+  /// DocumentState와 같은 필드(doc_id, blocks, children_map, root_id)를 쓰되,
+  /// 블록의 delta는 문자열이 아니라 delta 배열 그대로 넣음 (예: "delta": [{"insert": "Hello"}])
+  Future<String> getDocumentJson();
+
+  /// 문서 전체 텍스트를 문서 순서대로 줄바꿈으로 이어서 반환 (미리보기, 검색 색인용)
+  ///
+  /// get_document_state와 같은 순서로 순회하며, 텍스트 필드가 없는 블록(구분선, 이미지 등)은 건너뜀
+  /// 인라인 임베드는 get_block_plain_text처럼 \u{FFFC} 한 글자로 표시
+  Future<String> getDocumentPlainText();
+
+  /// 문서 저장 용량 (노트별 용량 표시, 할당량 경고용)
+  ///
+  /// 반환: full_state_bytes는 encode_full_state 크기 (삭제 기록 포함), content_bytes는 현재 내용만 담은
+  /// compact 결과 크기. 삭제가 많이 쌓였거나 GC가 돌면 둘의 차이가 커짐. 문서는 변경하지 않음
+  Future<DocSize> getDocumentSizeBytes();
+
   Future<DocumentState> getDocumentState();
 
+  /// 메타데이터 불리언 값 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 불리언이 아니면 None
+  Future<bool?> getMetaBool({required String key});
+
+  /// 메타데이터 정수 값 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 정수가 아니면 None (소수점이 있는 숫자도 None)
+  Future<PlatformInt64?> getMetaInt({required String key});
+
+  /// 메타데이터 키 목록만 반환 (값은 직렬화하지 않음)
+  ///
+  /// 반환: 정렬된 키 목록, META 맵이 없으면 빈 목록
+  Future<List<String>> getMetaKeys();
+
+  /// 메타데이터 문자열 값 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 문자열이 아니면 None
+  Future<String?> getMetaString({required String key});
+
+  /// 메타데이터 문자열 배열 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 배열이 아니면 None, 문자열이 아닌 항목이 섞여 있어도 None
+  Future<List<String>?> getMetaStringArray({required String key});
+
+  /// set_root_node_id로 저장된 루트 블록 id 읽기 (apply_updates로 불러온 문서의 루트 확인용)
+  ///
+  /// 반환: 루트 블록 id, 한 번도 설정되지 않았으면 None
+  Future<String?> getRootNodeId();
+
+  /// 문서 전체 단어 수, 글자 수(공백 포함/제외), 블록 수 (글쓰기 목표 기능용)
+  ///
+  /// 단어는 공백으로 나눈 단위로 세지만, 한자와 가나는 띄어쓰기를 하지 않으므로 글자 하나를 한 단어로 셈 (한글은 띄어쓰기 기준).
+  /// 블록 경계도 단어 경계로 취급하고, 블록 수는 루트 페이지 블록을 뺀 블록 수.
+  /// 인라인 임베드(이미지, 수식 등)는 글자나 단어로 세지 않음 (search_text 위치와 달리 \u{FFFC}를 넣지 않음).
+  /// 빈 문서나 초기화 전 문서는 에러 없이 모두 0 반환
+  Future<DocStats> getStatistics();
+
+  /// get_statistics와 같지만 블록별 수치도 문서 순서대로 함께 반환 (여백에 블록별 글자 수 표시용)
+  ///
+  /// 텍스트 필드가 없는 블록(구분선, 이미지 등)은 블록별 목록에서 빠짐
+  Future<DocStats> getStatisticsWithBlocks();
+
+  /// 문서에 연결된 모든 하위 문서 guid (정렬됨, 원격 업데이트로 받은 하위 문서 포함)
+  Future<List<String>> getSubdocGuids();
+
+  /// AppFlowy 문서 JSON을 빈 문서로 가져오기 (기존 AppFlowy 노트 이전용)
+  ///
+  /// [json] {"document": {"type": "page", "data": {}, "delta": [], "children": [...]}} 형식의 JSON
+  ///
+  /// 노드 id는 유지하고 없으면 새로 만들며, 루트 노드를 루트 포인터(ROOT_ID)로 설정.
+  /// 루트 노드가 없거나 구조가 잘못되었거나 이미 블록이 있는 문서면 에러 반환
+  Future<Uint8List> importAppflowyJson({required String json});
+
+  /// Markdown을 블록으로 변환해서 루트 블록의 마지막 자식 뒤에 추가 ("Markdown으로 붙여넣기"용)
+  ///
+  /// [markdown] 붙여넣을 Markdown 텍스트
+  ///
+  /// 제목은 heading, 목록은 들여쓰기에 따라 중첩된 bulleted_list/numbered_list/todo_list, 코드 펜스는 code 블록으로 변환.
+  /// **굵게**, *기울임*, `코드`는 delta 속성으로 변환. 루트 포인터가 없으면 새 페이지 블록을 만들어 루트로 설정
+  Future<Uint8List> importMarkdown({required String markdown});
+
+  /// export_tree_json 형식의 중첩 트리 JSON을 빈 문서로 가져오기
+  ///
+  /// [json] {"id", "type", "data", "delta", "children": [...]} 형식의 루트 노드 JSON
+  ///
+  /// 노드 id는 유지하고 없으면 새로 만들며, 루트 노드를 루트 포인터(ROOT_ID)로 설정.
+  /// delta가 null이면 텍스트 없는 블록이고, 순환 표시 노드({"cycle": true})는 건너뜀.
+  /// set_max_tree_depth로 정한 깊이보다 깊게 중첩된 트리, 구조가 잘못된 트리, 이미 블록이 있는 문서는 에러 반환
+  Future<Uint8List> importTreeJson({required String json});
+
+  /// 블록을 바로 앞 형제의 마지막 자식으로 들여쓰기 (Tab)
+  ///
+  /// [block_id] 들여쓸 블록 id
+  ///
+  /// 앞 형제가 없는 첫 번째 자식이면 아무것도 하지 않고 빈 업데이트 반환
+  Future<Uint8List> indentBlock({required String blockId});
+
   Future<Uint8List> initEmptyDoc();
 
+  /// 많은 블록을 한 부모 아래에 한 번에 삽입 (큰 문서 가져오기용)
+  ///
+  /// [blocks] 삽입할 블록 목록, 입력 순서대로 형제 순서가 정해짐 (각 블록의 parent_id/prev_id/next_id는 무시)
+  /// [parent_id] 부모 블록 id, "root"면 최상위
+  ///
+  /// 부모의 기존 마지막 자식 뒤에 붙이며, apply_action과 달리 블록마다 형제 체인을 다시 찾지 않음.
+  /// 부모가 없거나 id가 중복되거나 delta가 잘못되면 아무것도 적용하지 않고 에러
+  Future<Uint8List> insertBlocksBulk({
+    required List<BlockDoc> blocks,
+    required String parentId,
+  });
+
+  /// 묶음이 진행 중인지 여부
+  Future<bool> isBatching();
+
+  /// 문서에 내용 블록이 하나도 없는지 확인
+  ///
+  /// "비어 있음" = BLOCKS 맵에 루트 포인터(ROOT_ID)가 가리키는 루트 페이지 블록 외의 블록이 없음.
+  /// 메타데이터는 보지 않으며, 텍스트가 없는 빈 문단도 내용 블록으로 셈
+  Future<bool> isEmpty();
+
+  /// 마지막 replace_all에서 바꾼 개수 ("12개 바꿈" 표시용)
+  Future<int> lastReplaceCount();
+
+  /// 문서를 처음 열 때 업데이트 적용과 상태 추출을 한 번에 처리 (큰 노트 열기용)
+  ///
+  /// [updates] 적용할 업데이트 목록 (v2)
+  ///
+  /// apply_updates 후 get_document_state를 부른 것과 같은 상태를 반환하지만, apply_updates가 변경 블록을 구하려고
+  /// 하는 적용 전후 전체 순회를 건너뛰고 같은 트랜잭션 안에서 상태를 한 번만 추출함.
+  /// 결과는 상태 캐시에 저장되므로 바로 이어지는 get_document_state는 다시 추출하지 않음.
+  /// 디코딩에 실패한 업데이트는 apply_updates처럼 로그만 남기고 건너뜀
+  Future<DocumentState> loadAndGetState({required List<Uint8List> updates});
+
+  /// save_to_file로 저장한 파일을 읽어서 apply_updates로 적용
+  ///
+  /// [path] 읽을 파일 경로
+  ///
+  /// 파일이 올바른 업데이트가 아니면 빈 문서로 넘어가지 않고 디코딩 에러 반환
+  Future<void> loadFromFile({required String path});
+
+  /// 뒤 블록을 앞 블록에 합침 ("이전 블록과 합치기", 블록 맨 앞에서 Backspace)
+  ///
+  /// [first_id] 남게 되는 앞 블록 id
+  /// [second_id] 앞 블록에 합쳐질 블록 id
+  ///
+  /// 같은 쓰기 트랜잭션 안에서 can_merge_blocks와 같은 BlockOperations::check_merge로 먼저 검사하고, 합칠 수 없으면 아무것도 바꾸지 않고 에러.
+  /// second의 텍스트는 서식과 임베드까지 first 끝에 이어 붙이고, second의 자식은 순서대로 first의 마지막 자식 뒤로 옮긴 뒤 second를 삭제
+  Future<Uint8List> mergeBlocks({
+    required String firstId,
+    required String secondId,
+  });
+
+  /// 같은 기준 상태에서 오프라인으로 갈라진 두 편집본을 하나의 상태로 병합 (DocumentService 인스턴스 불필요)
+  ///
+  /// [base] 두 편집본이 공통으로 시작한 상태의 전체 업데이트 (v2)
+  /// [left] 한쪽 클라이언트의 기준 상태 이후 전체 업데이트 (v2)
+  /// [right] 다른 쪽 클라이언트의 기준 상태 이후 전체 업데이트 (v2)
+  ///
+  /// left/right의 state vector가 base의 state vector를 포함하지 않으면 base에서 갈라진 것이 아니므로 에러 반환.
+  /// 반환: 병합된 문서의 전체 상태 업데이트 (v2)
+  static Future<Uint8List> mergeThreeWay({
+    required Uint8List base,
+    required Uint8List left,
+    required Uint8List right,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceMergeThreeWay(
+        base: base,
+        left: left,
+        right: right,
+      );
+
   Future<Uint8List> mergeUpdates({required List<Uint8List> updates});
 
+  /// v1 인코딩 업데이트 병합 (v1만 쓰는 서버/저장소와 호환용)
+  Future<Uint8List> mergeUpdatesV1({required List<Uint8List> updates});
+
+  /// JSON을 set_meta_from_json으로 적용했을 때 바뀔 메타데이터 미리보기
+  ///
+  /// [json] 비교할 메타데이터 JSON (set_meta_from_json 입력과 같은 형식)
+  ///
+  /// 반환: {"added": {"key": 값}, "removed": ["key"], "changed": {"key": {"from": 이전값, "to": 새값}}}
+  /// null 값은 키 제거를 의미하고, JSON에 없는 키는 변경되지 않음. 타입이 다르면 변경으로 취급.
+  Future<String> metaDiffAgainstJson({required String json});
+
+  /// 로컬 복제본에 없는 클라이언트별 clock 목록 ("3개 클라이언트 동기화 안 됨" 표시용, DocumentService 인스턴스 불필요)
+  ///
+  /// [local_sv] 로컬 state vector (v1 인코딩)
+  /// [remote_sv] 원격 state vector (v1 인코딩)
+  ///
+  /// 반환: 원격이 로컬보다 앞선 클라이언트마다 (client id, 원격 clock), client id 순으로 정렬.
+  /// 로컬은 해당 클라이언트의 로컬 clock부터 원격 clock 전까지의 내용이 없음. 비어 있으면 로컬이 원격을 모두 가짐
+  static Future<List<(BigInt, int)>> missingFrom({
+    required Uint8List localSv,
+    required Uint8List remoteSv,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceMissingFrom(
+    localSv: localSv,
+    remoteSv: remoteSv,
+  );
+
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
   static Future<DocumentService> newInstance() =>
       RustLib.instance.api.crateDocDocumentServiceDocumentServiceNew();
 
+  /// GC를 끈 문서로 서비스 생성 (스냅샷/버전 기록용)
+  ///
+  /// 삭제된 항목도 툼스톤으로 계속 남기 때문에 편집이 쌓일수록 메모리와 업데이트 크기가 커짐.
+  /// take_snapshot, restore_snapshot, state_as_of는 GC가 꺼진 문서에서만 동작함
+  static Future<DocumentService> newWithGcDisabled() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceNewWithGcDisabled();
+
+  /// 지정한 문서 id로 서비스 생성 (로그와 문서별 라우팅에 사용)
+  ///
+  /// [doc_id] 문서 id
+  static Future<DocumentService> newWithId({required String docId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceNewWithId(
+        docId: docId,
+      );
+
+  /// 문서 id와 GC 사용 여부를 지정해서 서비스 생성
+  ///
+  /// [doc_id] 문서 id
+  /// [gc] true면 삭제된 내용을 정리해서 메모리를 아끼고, false면 스냅샷/버전 기록을 위해 남겨둠
+  static Future<DocumentService> newWithOptions({
+    required String docId,
+    required bool gc,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceNewWithOptions(
+        docId: docId,
+        gc: gc,
+      );
+
+  /// 블록을 부모의 형제로 내어쓰기 (Shift-Tab), 부모 바로 뒤에 삽입
+  ///
+  /// [block_id] 내어쓸 블록 id
+  ///
+  /// 최상위 블록이나 루트 페이지의 자식처럼 더 나갈 곳이 없으면 아무것도 하지 않고 빈 업데이트 반환
+  Future<Uint8List> outdentBlock({required String blockId});
+
+  /// copy_blocks로 만든 클립보드 JSON을 새 id로 지정한 위치에 붙여넣기
+  ///
+  /// [payload] copy_blocks가 반환한 JSON (다른 DocumentService에서 복사한 것도 가능)
+  /// [target_parent_id] 붙여넣을 부모 블록 id (부모가 없는 최상위는 "root")
+  /// [after_block_id] 이 형제 블록 바로 뒤에 붙여넣음, None이면 부모의 첫 자식으로 붙여넣음
+  ///
+  /// 같은 클립보드를 여러 번 붙여넣어도 매번 새 id를 사용. 엄격 type 모드에서는 허용되지 않은 type이 있으면 에러
+  Future<Uint8List> pasteBlocks({
+    required String payload,
+    required String targetParentId,
+    String? afterBlockId,
+  });
+
   /// 메타데이터 배열에 문자열 항목 추가 (중복 체크)
   ///
   /// [key] 메타데이터 키
@@ -42,6 +777,23 @@ abstract class DocumentService implements RustOpaqueInterface {
     required String value,
   });
 
+  /// 문서를 전혀 바꾸지 않고 현재 상태를 읽기 (get_document_state와 달리 캐시를 쓰지 않음)
+  ///
+  /// root 맵이 아직 없는 문서(초기화 전)는 root 맵을 만들지 않고 빈 DocumentState 반환 (root_id는 빈 문자열)
+  Future<DocumentState> readDocumentState();
+
+  /// 마지막으로 실행 취소한 편집을 다시 실행하고 그 결과 업데이트를 반환
+  ///
+  /// 다시 실행할 항목이 없으면 빈 변경의 업데이트 반환
+  Future<Uint8List> redo();
+
+  /// 원격 클라이언트의 awareness 상태 제거 (Dart 쪽 타임아웃으로 연결이 끊긴 편집자 정리용)
+  ///
+  /// [client_id] 제거할 클라이언트 id
+  ///
+  /// 반환: 상태가 있던 클라이언트였는지 여부
+  Future<bool> removeAwarenessClient({required BigInt clientId});
+
   /// 메타데이터 배열에서 문자열 항목 제거
   ///
   /// [key] 메타데이터 키
@@ -56,6 +808,131 @@ abstract class DocumentService implements RustOpaqueInterface {
   /// [key] 제거할 메타데이터 키
   Future<Uint8List> removeMetaKey({required String key});
 
+  /// validate_document가 찾는 구조 문제를 고쳐서 피어에 보낼 업데이트로 반환
+  ///
+  /// 없는 블록을 가리키는 prevId/nextId는 지우고, 부모가 없는 블록과 순환마다 id가 가장 작은 블록은
+  /// 최상위(DEFAULT_PARENT)로 옮겨 기존 최상위 블록 뒤에 이어 붙임.
+  /// 고칠 것이 없으면 아무것도 변경하지 않으므로 두 번 실행해도 결과가 같음.
+  Future<Uint8List> repairDocument();
+
+  /// 문서 전체에서 찾아 바꾸기를 한 트랜잭션으로 적용
+  ///
+  /// [query] 찾을 문자열
+  /// [replacement] 바꿀 문자열
+  /// [case_sensitive] 대소문자 구분 여부
+  ///
+  /// 겹치는 일치는 앞쪽 것만 바꾸고, 바뀐 부분은 원래 첫 글자의 서식을 유지
+  /// 바꾼 개수는 last_replace_count로 확인
+  Future<Uint8List> replaceAll({
+    required String query,
+    required String replacement,
+    required bool caseSensitive,
+  });
+
+  /// 댓글을 해결됨으로 표시 (댓글은 남아 있고 get_comments_for_block의 resolved가 true가 됨)
+  ///
+  /// [comment_id] 댓글 id
+  ///
+  /// 댓글이 없으면 에러
+  Future<Uint8List> resolveComment({required String commentId});
+
+  /// 스냅샷 시점의 내용으로 문서를 되돌리고, 피어도 같은 상태로 옮길 업데이트를 반환
+  ///
+  /// [snapshot] take_snapshot으로 얻은 스냅샷
+  ///
+  /// 되돌리기는 새 편집으로 기록되므로 이후 변경 기록은 그대로 남음.
+  /// 현재 문서에 없는 항목을 가리키는 스냅샷이면 아무것도 바꾸지 않고 에러 반환
+  Future<Uint8List> restoreSnapshot({required Uint8List snapshot});
+
+  /// 전체 상태(encode_full_state)를 파일에 저장 (오프라인 우선 앱에서 Rust가 직접 디스크에 저장)
+  ///
+  /// [path] 저장할 파일 경로 (이미 있으면 덮어씀)
+  Future<void> saveToFile({required String path});
+
+  /// 문서 전체에서 텍스트 검색 (문서 내 찾기용)
+  ///
+  /// [query] 찾을 문자열
+  /// [case_sensitive] 대소문자 구분 여부
+  ///
+  /// 문서 순서대로 블록 id, 블록 텍스트 안의 글자 위치, 일치 길이를 반환
+  /// 위치는 인라인 임베드를 한 글자로 세므로 get_block_plain_text, YText 위치와 같음
+  /// 겹치는 일치도 각각 보고하며, 빈 검색어는 빈 목록 반환
+  Future<List<TextMatch>> searchText({
+    required String query,
+    required bool caseSensitive,
+  });
+
+  /// 엄격 모드에서 허용할 블록 type 목록 설정 (커스텀 블록을 쓰는 앱용)
+  ///
+  /// [types] 허용할 type 전체 목록, 기본 목록을 대체하므로 계속 쓸 기본 type(page, paragraph 등)도 포함해야 함
+  Future<void> setAllowedBlockTypes({required List<String> types});
+
+  /// 문서 전체 첨부 용량 상한 설정 (None이면 제한 없음)
+  ///
+  /// [budget] 최대 바이트 수
+  Future<void> setAttachmentBudget({BigInt? budget});
+
+  /// 블록 데이터에 바이너리 첨부 저장
+  ///
+  /// [block_id] 대상 블록 id
+  /// [key] 블록 데이터 키
+  /// [bytes] 저장할 바이너리
+  ///
+  /// 첨부 용량 상한이 설정되어 있으면 (현재 총량 - 같은 키의 기존 첨부 + 새 첨부)가 상한을 넘을 때 에러 반환
+  Future<Uint8List> setBlockAttachment({
+    required String blockId,
+    required String key,
+    required Uint8List bytes,
+  });
+
+  /// 블록 데이터의 필드 하나만 설정 (체크박스 checked 토글 등)
+  ///
+  /// [block_id] 대상 블록 id
+  /// [field] 필드 키, 점으로 구분하면 중첩 맵 경로 (예: "style.color")
+  /// [value_json] 저장할 값의 JSON 문자열 (apply_action의 attributes와 같은 형식으로 저장)
+  ///
+  /// 해당 키만 쓰므로 다른 클라이언트가 같은 블록의 다른 필드를 동시에 바꿔도 둘 다 유지됨.
+  /// 경로 중간에 맵이 아닌 값이 있으면 덮어쓰지 않고 에러 반환
+  Future<Uint8List> setBlockDataField({
+    required String blockId,
+    required String field,
+    required String valueJson,
+  });
+
+  /// 블록 id 생성을 시드 기반의 결정적 순서로 전환 (테스트 전용)
+  ///
+  /// [seed] 시드 (같은 시드면 duplicate, paste, import 등이 같은 id를 같은 순서로 생성)
+  ///
+  /// 시드 id는 다른 문서/피어와 겹칠 수 있으므로 프로덕션에서는 호출하지 말고 기본 UUID v4를 사용
+  Future<void> setIdGeneratorSeed({required BigInt seed});
+
+  /// 로컬 편집자의 awareness 상태(이름, 커서, 색상 등) 설정
+  ///
+  /// [state_json] 상태 JSON (예: {"user":"taeuk","color":"#ff0000","cursor":{"blockId":"a","offset":3}})
+  ///
+  /// 문서 내용과 무관하므로 문서 업데이트를 만들지 않음
+  Future<void> setLocalAwareness({required String stateJson});
+
+  /// 로그 출력 수준 설정 (모든 문서에 적용, 재컴파일 없이 릴리스 빌드 로그를 줄이기 위함)
+  ///
+  /// [level] "off", "error", "warn", "info", "debug", "trace" 중 하나 (대소문자 무관)
+  ///
+  /// 기본값은 디버그 빌드 info, 릴리스 빌드 warn. 메타데이터 값처럼 노트 내용이 담긴 로그는 debug 이상에서만 출력됨
+  static Future<void> setLogLevel({required String level}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceSetLogLevel(
+        level: level,
+      );
+
+  /// import_tree_json이 받아들이는 최대 중첩 깊이 설정 (루트 아래 단계 수, 기본값 DEFAULT_MAX_TREE_DEPTH)
+  ///
+  /// [depth] 최대 깊이
+  Future<void> setMaxTreeDepth({required int depth});
+
+  /// 여러 메타데이터 변경을 한 트랜잭션으로 적용 (apply_action처럼 업데이트 하나만 생성)
+  ///
+  /// [operations] 순서대로 적용할 메타 변경 목록
+  Future<Uint8List> setMetaBatch({required List<MetaOp> operations});
+
   /// 메타데이터에 불리언 값 설정
   ///
   /// [key] 메타데이터 키
@@ -66,7 +943,8 @@ abstract class DocumentService implements RustOpaqueInterface {
   ///
   /// [json_str] 설정할 메타데이터 JSON (예: {"title": "노트", "status": "active"})
   ///
-  /// 지원 타입: string, number (int/double), boolean, string array
+  /// 지원 타입: string, number (int/double), boolean, array, object
+  /// 중첩 객체는 YMap으로 저장되어 필드 단위로 병합되고 (null 필드는 제거), 배열은 YArray로 통째로 교체됨
   Future<Uint8List> setMetaFromJson({required String jsonStr});
 
   /// 메타데이터에 정수 값 설정
@@ -95,4 +973,118 @@ abstract class DocumentService implements RustOpaqueInterface {
 
   /// Setting a root node id in the root map
   Future<Uint8List> setRootNodeId({required String id});
+
+  /// 블록 type 검사 사용 여부 설정 (기본값 false, 기존 호출과의 호환을 위해 켜야만 검사함)
+  ///
+  /// [strict] true면 허용 목록에 없는 type의 Insert/Update를 에러로 거부 ("paragrah" 같은 오타 방지).
+  /// 가져오기(import_*)와 apply_update_remapped로 들어오는 블록도 같은 목록으로 검사함
+  Future<void> setStrictTypes({required bool strict});
+
+  /// 기록해 둔 스냅샷 시점의 문서 상태를 재구성 (문서는 바꾸지 않음)
+  ///
+  /// [snapshot] take_snapshot으로 기록한 스냅샷 (v1 인코딩)
+  ///
+  /// state vector만으로는 언제 삭제되었는지 알 수 없으므로 state vector가 아니라 delete set까지 담긴 스냅샷을 받음
+  /// (encode_state_vector로 저장해 둔 값은 쓸 수 없으며, 넘기면 디코딩 에러).
+  /// 기록 이후에 추가된 블록은 빠지고, 기록 이후에 삭제된 블록은 그대로 보임.
+  /// GC가 꺼진 문서에서만 동작하며, 현재 문서에 없는 항목을 가리키는 스냅샷이면 에러 반환
+  Future<DocumentState> stateAsOf({required Uint8List snapshot});
+
+  /// 두 state vector가 같은지 비교 (내용을 주고받지 않고 두 복제본이 동기화되었는지 확인, DocumentService 인스턴스 불필요)
+  ///
+  /// [a] 첫 번째 state vector (v1 인코딩)
+  /// [b] 두 번째 state vector (v1 인코딩)
+  ///
+  /// state vector에는 삭제 정보가 없으므로 삭제만 다른 경우는 같다고 판단함
+  static Future<bool> stateVectorsEqual({
+    required Uint8List a,
+    required Uint8List b,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceStateVectorsEqual(a: a, b: b);
+
+  /// 문서 순서대로 블록 JSON을 하나씩 콜백으로 전달 (큰 문서의 점진적 렌더링용)
+  ///
+  /// [callback] 블록 JSON(BlockDoc 형식)을 받는 Dart 콜백, 각 호출이 끝난 뒤 다음 블록 전달
+  ///
+  /// 마지막 블록 전달이 끝나야 완료됨
+  Future<void> streamBlocks({
+    required FutureOr<void> Function(String) callback,
+  });
+
+  /// 블록 하나의 변경만 JSON으로 Dart 스트림에 전달 (집중 편집 화면용, apply_updates로 받은 원격 변경 포함)
+  ///
+  /// [block_id] 관찰할 블록 id
+  /// [sink] 변경 JSON을 받을 스트림, 같은 블록으로 다시 호출하면 이전 스트림을 대체
+  ///
+  /// 변경: {"blockId":"a","event":"updated","fields":["attributes.level","type"],"delta":[...],"childrenReordered":false}
+  /// (delta는 텍스트가 바뀐 경우에만 포함)
+  /// 블록이 삭제되면 {"blockId":"a","event":"deleted"}를 마지막으로 보내고 구독이 끝남
+  Stream<String> subscribeBlockChanges({required String blockId});
+
+  /// 문서에 커밋되는 모든 업데이트(v2)를 트랜잭션 origin과 함께 Dart 스트림으로 전달 (apply_updates로 받은 원격 변경 포함)
+  ///
+  /// [sink] 업데이트와 origin을 받을 스트림, 다시 호출하면 이전 스트림을 대체
+  ///
+  /// origin: apply_action_with_origin으로 준 값, apply_updates로 받은 원격 변경은 "remote", 그 외 로컬 편집은 None
+  /// (Dart 쪽에서 자기가 보낸 변경을 서버로 다시 보내지 않도록 걸러낼 때 사용)
+  Stream<OriginUpdate> subscribeChanges();
+
+  /// 문서가 비어 있음/내용 있음 사이를 오갈 때만 Dart 스트림에 전달 (빈 문서 안내 문구 표시용)
+  ///
+  /// [sink] is_empty 결과를 받을 스트림, 다시 호출하면 이전 스트림을 대체
+  ///
+  /// 구독 즉시 현재 값을 한 번 보내고, 이후에는 값이 바뀐 트랜잭션에서만 보냄 (apply_updates로 받은 원격 변경 포함)
+  Stream<bool> subscribeEmptiness();
+
+  /// 메타데이터가 바뀔 때마다 바뀐 키만 JSON으로 Dart 스트림에 전달 (apply_updates로 받은 원격 변경 포함)
+  ///
+  /// [sink] {"title": "새 제목", "status": null} 형식의 JSON을 받을 스트림 (null은 키 삭제)
+  ///
+  /// 중첩 메타가 바뀌면 해당 최상위 키의 현재 값 전체를 보냄. 다시 호출하면 이전 스트림을 대체
+  Stream<String> subscribeMetaChanges();
+
+  /// 하위 문서에 커밋되는 업데이트(v2)를 Dart 스트림으로 전달 (부모 문서의 subscribe_changes와 별개)
+  ///
+  /// [guid] 하위 문서 guid
+  /// [sink] 업데이트 바이트를 받을 스트림, 같은 guid로 다시 호출하면 이전 스트림을 대체
+  Stream<Uint8List> subscribeSubdocChanges({required String guid});
+
+  /// 현재 시점의 스냅샷을 인코딩하여 반환 (버전 기록용, v1 인코딩)
+  ///
+  /// GC가 꺼진 문서(new_with_gc_disabled)에서만 동작함
+  Future<Uint8List> takeSnapshot();
+
+  /// 마지막 로컬 편집을 실행 취소하고 그 결과 업데이트를 반환
+  ///
+  /// 취소할 항목이 없으면 빈 변경의 업데이트 반환
+  Future<Uint8List> undo();
+
+  /// subscribe_block_changes 구독 해제
+  ///
+  /// 반환: 구독 중이던 블록이었는지 여부
+  Future<bool> unsubscribeBlockChanges({required String blockId});
+
+  /// apply_action을 실제로 적용하지 않고 성공할지만 검사 (드래그/붙여넣기 확정 전 미리 확인용)
+  ///
+  /// [actions] 검사할 블록 작업 목록
+  ///
+  /// apply_action이 적용 전에 하는 검사(move 필수 필드, 부모 존재, 자기 하위로 이동 금지, 엄격 모드 type 등)를
+  /// 읽기 트랜잭션에서 그대로 실행하고 첫 번째 에러를 반환. 문서는 변경하지 않음
+  Future<void> validateAction({required List<BlockActionDoc> actions});
+
+  /// AppFlowy 문서 JSON을 가져오기 전에 구조 검사 (DocumentService 인스턴스 불필요)
+  ///
+  /// [json] {"document": {"type": "page", "data": {}, "delta": [], "children": [...]}} 형식의 JSON
+  ///
+  /// 반환: 발견된 문제 목록 (노드 경로 포함), 비어 있으면 가져오기 가능
+  static Future<List<String>> validateAppflowyJson({required String json}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceValidateAppflowyJson(json: json);
+
+  /// 블록 트리 구조 검사 (렌더링 전에 깨진 구조를 찾기 위한 읽기 전용 진단)
+  ///
+  /// 반환: 부모가 없는 블록, 부모-자식 순환에 속한 블록, 없는 블록을 prevId/nextId로 가리키는 블록,
+  /// 블록이 삭제되어 남은 댓글 목록
+  Future<DocumentValidation> validateDocument();
 }
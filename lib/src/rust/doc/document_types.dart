@@ -8,7 +8,25 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'document_types.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `default`, `default`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+@freezed
+sealed class ActionResult with _$ActionResult {
+  const factory ActionResult({
+    required Uint8List update,
+    required List<String> generatedIds,
+  }) = _ActionResult;
+}
+
+@freezed
+sealed class AppliedBlockChanges with _$AppliedBlockChanges {
+  const factory AppliedBlockChanges({
+    required List<String> insertedBlockIds,
+    required List<String> updatedBlockIds,
+    required List<String> deletedBlockIds,
+    required FailedToDecodeUpdates failedUpdates,
+  }) = _AppliedBlockChanges;
+}
 
 @freezed
 sealed class BlockActionDoc with _$BlockActionDoc {
@@ -33,13 +51,48 @@ sealed class BlockDoc with _$BlockDoc {
     String? prevId,
     String? nextId,
     String? oldParentId,
+    PlatformInt64? createdAt,
+    PlatformInt64? updatedAt,
   }) = _BlockDoc;
 }
 
+@freezed
+sealed class BlockPage with _$BlockPage {
+  const factory BlockPage({
+    required List<BlockDoc> blocks,
+    required BigInt total,
+    required bool hasMore,
+  }) = _BlockPage;
+}
+
+@freezed
+sealed class BlockStats with _$BlockStats {
+  const factory BlockStats({
+    required String blockId,
+    required int wordCount,
+    required int charCount,
+    required int charCountNoSpaces,
+  }) = _BlockStats;
+}
+
+@freezed
+sealed class CommentDoc with _$CommentDoc {
+  const factory CommentDoc({
+    required String commentId,
+    required String blockId,
+    required String author,
+    required String text,
+    required bool resolved,
+    int? anchorOffset,
+  }) = _CommentDoc;
+}
+
 class CustomRustError implements FrbException {
+  /// 에러 종류 코드 (DocError에서 변환된 경우 변형별 코드, 그 외 "unknown")
+  final String code;
   final String message;
 
-  const CustomRustError({required this.message});
+  const CustomRustError({required this.code, required this.message});
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
   static Future<CustomRustError> newInstance({required String message}) =>
@@ -48,16 +101,36 @@ class CustomRustError implements FrbException {
       );
 
   @override
-  int get hashCode => message.hashCode;
+  int get hashCode => code.hashCode ^ message.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is CustomRustError &&
           runtimeType == other.runtimeType &&
+          code == other.code &&
           message == other.message;
 }
 
+@freezed
+sealed class DocSize with _$DocSize {
+  const factory DocSize({
+    required BigInt fullStateBytes,
+    required BigInt contentBytes,
+  }) = _DocSize;
+}
+
+@freezed
+sealed class DocStats with _$DocStats {
+  const factory DocStats({
+    required int wordCount,
+    required int charCount,
+    required int charCountNoSpaces,
+    required int blockCount,
+    List<BlockStats>? blocks,
+  }) = _DocStats;
+}
+
 @freezed
 sealed class DocumentState with _$DocumentState {
   const factory DocumentState({
@@ -68,9 +141,98 @@ sealed class DocumentState with _$DocumentState {
   }) = _DocumentState;
 }
 
+@freezed
+sealed class DocumentValidation with _$DocumentValidation {
+  const factory DocumentValidation({
+    required List<String> orphanedBlockIds,
+    required List<String> cyclicBlockIds,
+    required List<String> danglingReferenceIds,
+    required List<String> orphanedCommentIds,
+  }) = _DocumentValidation;
+}
+
+@freezed
+sealed class DuplicateResult with _$DuplicateResult {
+  const factory DuplicateResult({
+    required Uint8List update,
+    required Map<String, String> idMap,
+  }) = _DuplicateResult;
+}
+
 @freezed
 sealed class FailedToDecodeUpdates with _$FailedToDecodeUpdates {
   const factory FailedToDecodeUpdates({
     required List<String> failedUpdatesIds,
+    required List<String> errors,
   }) = _FailedToDecodeUpdates;
 }
+
+@freezed
+sealed class MetaOp with _$MetaOp {
+  const MetaOp._();
+
+  const factory MetaOp.setString({
+    required String key,
+    required String value,
+  }) = MetaOp_SetString;
+
+  const factory MetaOp.setInt({
+    required String key,
+    required PlatformInt64 value,
+  }) = MetaOp_SetInt;
+
+  const factory MetaOp.setBool({
+    required String key,
+    required bool value,
+  }) = MetaOp_SetBool;
+
+  const factory MetaOp.setStringArray({
+    required String key,
+    required List<String> values,
+  }) = MetaOp_SetStringArray;
+
+  const factory MetaOp.remove({required String key}) = MetaOp_Remove;
+
+  const factory MetaOp.pushArrayItem({
+    required String key,
+    required String value,
+  }) = MetaOp_PushArrayItem;
+
+  const factory MetaOp.removeArrayItem({
+    required String key,
+    required String value,
+  }) = MetaOp_RemoveArrayItem;
+}
+
+@freezed
+sealed class OrderedBlock with _$OrderedBlock {
+  const factory OrderedBlock({
+    required BlockDoc block,
+    required int depth,
+  }) = _OrderedBlock;
+}
+
+@freezed
+sealed class OriginUpdate with _$OriginUpdate {
+  const factory OriginUpdate({
+    required Uint8List update,
+    String? origin,
+  }) = _OriginUpdate;
+}
+
+@freezed
+sealed class RelativePos with _$RelativePos {
+  const factory RelativePos({
+    required String blockId,
+    required int offset,
+  }) = _RelativePos;
+}
+
+@freezed
+sealed class TextMatch with _$TextMatch {
+  const factory TextMatch({
+    required String blockId,
+    required int offset,
+    required int length,
+  }) = _TextMatch;
+}
@@ -11,41 +11,4256 @@ part of 'document_types.dart';
 
 // dart format off
 T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ActionResult {
+
+ Uint8List get update; List<String> get generatedIds;
+/// Create a copy of ActionResult
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ActionResultCopyWith<ActionResult> get copyWith => _$ActionResultCopyWithImpl<ActionResult>(this as ActionResult, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ActionResult&&const DeepCollectionEquality().equals(other.update, update)&&const DeepCollectionEquality().equals(other.generatedIds, generatedIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(update),const DeepCollectionEquality().hash(generatedIds));
+
+@override
+String toString() {
+  return 'ActionResult(update: $update, generatedIds: $generatedIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ActionResultCopyWith<$Res>  {
+  factory $ActionResultCopyWith(ActionResult value, $Res Function(ActionResult) _then) = _$ActionResultCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List update, List<String> generatedIds
+});
+
+
+
+
+}
+/// @nodoc
+class _$ActionResultCopyWithImpl<$Res>
+    implements $ActionResultCopyWith<$Res> {
+  _$ActionResultCopyWithImpl(this._self, this._then);
+
+  final ActionResult _self;
+  final $Res Function(ActionResult) _then;
+
+/// Create a copy of ActionResult
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? update = null,Object? generatedIds = null,}) {
+  return _then(_self.copyWith(
+update: null == update ? _self.update : update // ignore: cast_nullable_to_non_nullable
+as Uint8List,generatedIds: null == generatedIds ? _self.generatedIds : generatedIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ActionResult].
+extension ActionResultPatterns on ActionResult {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ActionResult value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ActionResult() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ActionResult value)  $default,){
+final _that = this;
+switch (_that) {
+case _ActionResult():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ActionResult value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ActionResult() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( Uint8List update,  List<String> generatedIds)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ActionResult() when $default != null:
+return $default(_that.update,_that.generatedIds);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( Uint8List update,  List<String> generatedIds)  $default,) {final _that = this;
+switch (_that) {
+case _ActionResult():
+return $default(_that.update,_that.generatedIds);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( Uint8List update,  List<String> generatedIds)?  $default,) {final _that = this;
+switch (_that) {
+case _ActionResult() when $default != null:
+return $default(_that.update,_that.generatedIds);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ActionResult implements ActionResult {
+  const _ActionResult({required this.update, required final  List<String> generatedIds}): _generatedIds = generatedIds;
+  
+
+@override final  Uint8List update;
+ final  List<String> _generatedIds;
+@override List<String> get generatedIds {
+  if (_generatedIds is EqualUnmodifiableListView) return _generatedIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_generatedIds);
+}
+
+
+/// Create a copy of ActionResult
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ActionResultCopyWith<_ActionResult> get copyWith => __$ActionResultCopyWithImpl<_ActionResult>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ActionResult&&const DeepCollectionEquality().equals(other.update, update)&&const DeepCollectionEquality().equals(other._generatedIds, _generatedIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(update),const DeepCollectionEquality().hash(_generatedIds));
+
+@override
+String toString() {
+  return 'ActionResult(update: $update, generatedIds: $generatedIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ActionResultCopyWith<$Res> implements $ActionResultCopyWith<$Res> {
+  factory _$ActionResultCopyWith(_ActionResult value, $Res Function(_ActionResult) _then) = __$ActionResultCopyWithImpl;
+@override @useResult
+$Res call({
+ Uint8List update, List<String> generatedIds
+});
+
+
+
+
+}
+/// @nodoc
+class __$ActionResultCopyWithImpl<$Res>
+    implements _$ActionResultCopyWith<$Res> {
+  __$ActionResultCopyWithImpl(this._self, this._then);
+
+  final _ActionResult _self;
+  final $Res Function(_ActionResult) _then;
+
+/// Create a copy of ActionResult
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? update = null,Object? generatedIds = null,}) {
+  return _then(_ActionResult(
+update: null == update ? _self.update : update // ignore: cast_nullable_to_non_nullable
+as Uint8List,generatedIds: null == generatedIds ? _self._generatedIds : generatedIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$AppliedBlockChanges {
+
+ List<String> get insertedBlockIds; List<String> get updatedBlockIds; List<String> get deletedBlockIds; FailedToDecodeUpdates get failedUpdates;
+/// Create a copy of AppliedBlockChanges
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AppliedBlockChangesCopyWith<AppliedBlockChanges> get copyWith => _$AppliedBlockChangesCopyWithImpl<AppliedBlockChanges>(this as AppliedBlockChanges, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AppliedBlockChanges&&const DeepCollectionEquality().equals(other.insertedBlockIds, insertedBlockIds)&&const DeepCollectionEquality().equals(other.updatedBlockIds, updatedBlockIds)&&const DeepCollectionEquality().equals(other.deletedBlockIds, deletedBlockIds)&&(identical(other.failedUpdates, failedUpdates) || other.failedUpdates == failedUpdates));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(insertedBlockIds),const DeepCollectionEquality().hash(updatedBlockIds),const DeepCollectionEquality().hash(deletedBlockIds),failedUpdates);
+
+@override
+String toString() {
+  return 'AppliedBlockChanges(insertedBlockIds: $insertedBlockIds, updatedBlockIds: $updatedBlockIds, deletedBlockIds: $deletedBlockIds, failedUpdates: $failedUpdates)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AppliedBlockChangesCopyWith<$Res>  {
+  factory $AppliedBlockChangesCopyWith(AppliedBlockChanges value, $Res Function(AppliedBlockChanges) _then) = _$AppliedBlockChangesCopyWithImpl;
+@useResult
+$Res call({
+ List<String> insertedBlockIds, List<String> updatedBlockIds, List<String> deletedBlockIds, FailedToDecodeUpdates failedUpdates
+});
+
+
+$FailedToDecodeUpdatesCopyWith<$Res> get failedUpdates;
+
+}
+/// @nodoc
+class _$AppliedBlockChangesCopyWithImpl<$Res>
+    implements $AppliedBlockChangesCopyWith<$Res> {
+  _$AppliedBlockChangesCopyWithImpl(this._self, this._then);
+
+  final AppliedBlockChanges _self;
+  final $Res Function(AppliedBlockChanges) _then;
+
+/// Create a copy of AppliedBlockChanges
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? insertedBlockIds = null,Object? updatedBlockIds = null,Object? deletedBlockIds = null,Object? failedUpdates = null,}) {
+  return _then(_self.copyWith(
+insertedBlockIds: null == insertedBlockIds ? _self.insertedBlockIds : insertedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,updatedBlockIds: null == updatedBlockIds ? _self.updatedBlockIds : updatedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,deletedBlockIds: null == deletedBlockIds ? _self.deletedBlockIds : deletedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,failedUpdates: null == failedUpdates ? _self.failedUpdates : failedUpdates // ignore: cast_nullable_to_non_nullable
+as FailedToDecodeUpdates,
+  ));
+}
+/// Create a copy of AppliedBlockChanges
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$FailedToDecodeUpdatesCopyWith<$Res> get failedUpdates {
+  
+  return $FailedToDecodeUpdatesCopyWith<$Res>(_self.failedUpdates, (value) {
+    return _then(_self.copyWith(failedUpdates: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [AppliedBlockChanges].
+extension AppliedBlockChangesPatterns on AppliedBlockChanges {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _AppliedBlockChanges value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _AppliedBlockChanges() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _AppliedBlockChanges value)  $default,){
+final _that = this;
+switch (_that) {
+case _AppliedBlockChanges():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _AppliedBlockChanges value)?  $default,){
+final _that = this;
+switch (_that) {
+case _AppliedBlockChanges() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> insertedBlockIds,  List<String> updatedBlockIds,  List<String> deletedBlockIds,  FailedToDecodeUpdates failedUpdates)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _AppliedBlockChanges() when $default != null:
+return $default(_that.insertedBlockIds,_that.updatedBlockIds,_that.deletedBlockIds,_that.failedUpdates);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> insertedBlockIds,  List<String> updatedBlockIds,  List<String> deletedBlockIds,  FailedToDecodeUpdates failedUpdates)  $default,) {final _that = this;
+switch (_that) {
+case _AppliedBlockChanges():
+return $default(_that.insertedBlockIds,_that.updatedBlockIds,_that.deletedBlockIds,_that.failedUpdates);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> insertedBlockIds,  List<String> updatedBlockIds,  List<String> deletedBlockIds,  FailedToDecodeUpdates failedUpdates)?  $default,) {final _that = this;
+switch (_that) {
+case _AppliedBlockChanges() when $default != null:
+return $default(_that.insertedBlockIds,_that.updatedBlockIds,_that.deletedBlockIds,_that.failedUpdates);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _AppliedBlockChanges implements AppliedBlockChanges {
+  const _AppliedBlockChanges({required final  List<String> insertedBlockIds, required final  List<String> updatedBlockIds, required final  List<String> deletedBlockIds, required this.failedUpdates}): _insertedBlockIds = insertedBlockIds,_updatedBlockIds = updatedBlockIds,_deletedBlockIds = deletedBlockIds;
+  
+
+ final  List<String> _insertedBlockIds;
+@override List<String> get insertedBlockIds {
+  if (_insertedBlockIds is EqualUnmodifiableListView) return _insertedBlockIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_insertedBlockIds);
+}
+
+ final  List<String> _updatedBlockIds;
+@override List<String> get updatedBlockIds {
+  if (_updatedBlockIds is EqualUnmodifiableListView) return _updatedBlockIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_updatedBlockIds);
+}
+
+ final  List<String> _deletedBlockIds;
+@override List<String> get deletedBlockIds {
+  if (_deletedBlockIds is EqualUnmodifiableListView) return _deletedBlockIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_deletedBlockIds);
+}
+
+@override final  FailedToDecodeUpdates failedUpdates;
+
+/// Create a copy of AppliedBlockChanges
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$AppliedBlockChangesCopyWith<_AppliedBlockChanges> get copyWith => __$AppliedBlockChangesCopyWithImpl<_AppliedBlockChanges>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _AppliedBlockChanges&&const DeepCollectionEquality().equals(other._insertedBlockIds, _insertedBlockIds)&&const DeepCollectionEquality().equals(other._updatedBlockIds, _updatedBlockIds)&&const DeepCollectionEquality().equals(other._deletedBlockIds, _deletedBlockIds)&&(identical(other.failedUpdates, failedUpdates) || other.failedUpdates == failedUpdates));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_insertedBlockIds),const DeepCollectionEquality().hash(_updatedBlockIds),const DeepCollectionEquality().hash(_deletedBlockIds),failedUpdates);
+
+@override
+String toString() {
+  return 'AppliedBlockChanges(insertedBlockIds: $insertedBlockIds, updatedBlockIds: $updatedBlockIds, deletedBlockIds: $deletedBlockIds, failedUpdates: $failedUpdates)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$AppliedBlockChangesCopyWith<$Res> implements $AppliedBlockChangesCopyWith<$Res> {
+  factory _$AppliedBlockChangesCopyWith(_AppliedBlockChanges value, $Res Function(_AppliedBlockChanges) _then) = __$AppliedBlockChangesCopyWithImpl;
+@override @useResult
+$Res call({
+ List<String> insertedBlockIds, List<String> updatedBlockIds, List<String> deletedBlockIds, FailedToDecodeUpdates failedUpdates
+});
+
+
+@override $FailedToDecodeUpdatesCopyWith<$Res> get failedUpdates;
+
+}
+/// @nodoc
+class __$AppliedBlockChangesCopyWithImpl<$Res>
+    implements _$AppliedBlockChangesCopyWith<$Res> {
+  __$AppliedBlockChangesCopyWithImpl(this._self, this._then);
+
+  final _AppliedBlockChanges _self;
+  final $Res Function(_AppliedBlockChanges) _then;
+
+/// Create a copy of AppliedBlockChanges
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? insertedBlockIds = null,Object? updatedBlockIds = null,Object? deletedBlockIds = null,Object? failedUpdates = null,}) {
+  return _then(_AppliedBlockChanges(
+insertedBlockIds: null == insertedBlockIds ? _self._insertedBlockIds : insertedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,updatedBlockIds: null == updatedBlockIds ? _self._updatedBlockIds : updatedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,deletedBlockIds: null == deletedBlockIds ? _self._deletedBlockIds : deletedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,failedUpdates: null == failedUpdates ? _self.failedUpdates : failedUpdates // ignore: cast_nullable_to_non_nullable
+as FailedToDecodeUpdates,
+  ));
+}
+
+/// Create a copy of AppliedBlockChanges
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$FailedToDecodeUpdatesCopyWith<$Res> get failedUpdates {
+  
+  return $FailedToDecodeUpdatesCopyWith<$Res>(_self.failedUpdates, (value) {
+    return _then(_self.copyWith(failedUpdates: value));
+  });
+}
+}
+
 /// @nodoc
 mixin _$BlockActionDoc {
 
- BlockActionTypeDoc get action; BlockDoc get block; Uint32List get path; Uint32List? get oldPath;
-/// Create a copy of BlockActionDoc
+ BlockActionTypeDoc get action; BlockDoc get block; Uint32List get path; Uint32List? get oldPath;
+/// Create a copy of BlockActionDoc
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BlockActionDocCopyWith<BlockActionDoc> get copyWith => _$BlockActionDocCopyWithImpl<BlockActionDoc>(this as BlockActionDoc, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BlockActionDoc&&(identical(other.action, action) || other.action == action)&&(identical(other.block, block) || other.block == block)&&const DeepCollectionEquality().equals(other.path, path)&&const DeepCollectionEquality().equals(other.oldPath, oldPath));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,action,block,const DeepCollectionEquality().hash(path),const DeepCollectionEquality().hash(oldPath));
+
+@override
+String toString() {
+  return 'BlockActionDoc(action: $action, block: $block, path: $path, oldPath: $oldPath)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BlockActionDocCopyWith<$Res>  {
+  factory $BlockActionDocCopyWith(BlockActionDoc value, $Res Function(BlockActionDoc) _then) = _$BlockActionDocCopyWithImpl;
+@useResult
+$Res call({
+ BlockActionTypeDoc action, BlockDoc block, Uint32List path, Uint32List? oldPath
+});
+
+
+$BlockDocCopyWith<$Res> get block;
+
+}
+/// @nodoc
+class _$BlockActionDocCopyWithImpl<$Res>
+    implements $BlockActionDocCopyWith<$Res> {
+  _$BlockActionDocCopyWithImpl(this._self, this._then);
+
+  final BlockActionDoc _self;
+  final $Res Function(BlockActionDoc) _then;
+
+/// Create a copy of BlockActionDoc
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? action = null,Object? block = null,Object? path = null,Object? oldPath = freezed,}) {
+  return _then(_self.copyWith(
+action: null == action ? _self.action : action // ignore: cast_nullable_to_non_nullable
+as BlockActionTypeDoc,block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
+as BlockDoc,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as Uint32List,oldPath: freezed == oldPath ? _self.oldPath : oldPath // ignore: cast_nullable_to_non_nullable
+as Uint32List?,
+  ));
+}
+/// Create a copy of BlockActionDoc
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$BlockDocCopyWith<$Res> get block {
+  
+  return $BlockDocCopyWith<$Res>(_self.block, (value) {
+    return _then(_self.copyWith(block: value));
+  });
+}
+}
+
+
+/// Adds pattern-matching-related methods to [BlockActionDoc].
+extension BlockActionDocPatterns on BlockActionDoc {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BlockActionDoc value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _BlockActionDoc() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BlockActionDoc value)  $default,){
+final _that = this;
+switch (_that) {
+case _BlockActionDoc():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BlockActionDoc value)?  $default,){
+final _that = this;
+switch (_that) {
+case _BlockActionDoc() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BlockActionTypeDoc action,  BlockDoc block,  Uint32List path,  Uint32List? oldPath)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _BlockActionDoc() when $default != null:
+return $default(_that.action,_that.block,_that.path,_that.oldPath);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BlockActionTypeDoc action,  BlockDoc block,  Uint32List path,  Uint32List? oldPath)  $default,) {final _that = this;
+switch (_that) {
+case _BlockActionDoc():
+return $default(_that.action,_that.block,_that.path,_that.oldPath);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BlockActionTypeDoc action,  BlockDoc block,  Uint32List path,  Uint32List? oldPath)?  $default,) {final _that = this;
+switch (_that) {
+case _BlockActionDoc() when $default != null:
+return $default(_that.action,_that.block,_that.path,_that.oldPath);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _BlockActionDoc implements BlockActionDoc {
+  const _BlockActionDoc({required this.action, required this.block, required this.path, this.oldPath});
+  
+
+@override final  BlockActionTypeDoc action;
+@override final  BlockDoc block;
+@override final  Uint32List path;
+@override final  Uint32List? oldPath;
+
+/// Create a copy of BlockActionDoc
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$BlockActionDocCopyWith<_BlockActionDoc> get copyWith => __$BlockActionDocCopyWithImpl<_BlockActionDoc>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BlockActionDoc&&(identical(other.action, action) || other.action == action)&&(identical(other.block, block) || other.block == block)&&const DeepCollectionEquality().equals(other.path, path)&&const DeepCollectionEquality().equals(other.oldPath, oldPath));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,action,block,const DeepCollectionEquality().hash(path),const DeepCollectionEquality().hash(oldPath));
+
+@override
+String toString() {
+  return 'BlockActionDoc(action: $action, block: $block, path: $path, oldPath: $oldPath)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$BlockActionDocCopyWith<$Res> implements $BlockActionDocCopyWith<$Res> {
+  factory _$BlockActionDocCopyWith(_BlockActionDoc value, $Res Function(_BlockActionDoc) _then) = __$BlockActionDocCopyWithImpl;
+@override @useResult
+$Res call({
+ BlockActionTypeDoc action, BlockDoc block, Uint32List path, Uint32List? oldPath
+});
+
+
+@override $BlockDocCopyWith<$Res> get block;
+
+}
+/// @nodoc
+class __$BlockActionDocCopyWithImpl<$Res>
+    implements _$BlockActionDocCopyWith<$Res> {
+  __$BlockActionDocCopyWithImpl(this._self, this._then);
+
+  final _BlockActionDoc _self;
+  final $Res Function(_BlockActionDoc) _then;
+
+/// Create a copy of BlockActionDoc
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? action = null,Object? block = null,Object? path = null,Object? oldPath = freezed,}) {
+  return _then(_BlockActionDoc(
+action: null == action ? _self.action : action // ignore: cast_nullable_to_non_nullable
+as BlockActionTypeDoc,block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
+as BlockDoc,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as Uint32List,oldPath: freezed == oldPath ? _self.oldPath : oldPath // ignore: cast_nullable_to_non_nullable
+as Uint32List?,
+  ));
+}
+
+/// Create a copy of BlockActionDoc
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$BlockDocCopyWith<$Res> get block {
+  
+  return $BlockDocCopyWith<$Res>(_self.block, (value) {
+    return _then(_self.copyWith(block: value));
+  });
+}
+}
+
+/// @nodoc
+mixin _$BlockDoc {
+
+ String get id; String get ty; Map<String, String> get attributes; String? get delta; String? get parentId; String? get prevId; String? get nextId; String? get oldParentId; PlatformInt64? get createdAt; PlatformInt64? get updatedAt;
+/// Create a copy of BlockDoc
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BlockDocCopyWith<BlockDoc> get copyWith => _$BlockDocCopyWithImpl<BlockDoc>(this as BlockDoc, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BlockDoc&&(identical(other.id, id) || other.id == id)&&(identical(other.ty, ty) || other.ty == ty)&&const DeepCollectionEquality().equals(other.attributes, attributes)&&(identical(other.delta, delta) || other.delta == delta)&&(identical(other.parentId, parentId) || other.parentId == parentId)&&(identical(other.prevId, prevId) || other.prevId == prevId)&&(identical(other.nextId, nextId) || other.nextId == nextId)&&(identical(other.oldParentId, oldParentId) || other.oldParentId == oldParentId)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.updatedAt, updatedAt) || other.updatedAt == updatedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,ty,const DeepCollectionEquality().hash(attributes),delta,parentId,prevId,nextId,oldParentId,createdAt,updatedAt);
+
+@override
+String toString() {
+  return 'BlockDoc(id: $id, ty: $ty, attributes: $attributes, delta: $delta, parentId: $parentId, prevId: $prevId, nextId: $nextId, oldParentId: $oldParentId, createdAt: $createdAt, updatedAt: $updatedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BlockDocCopyWith<$Res>  {
+  factory $BlockDocCopyWith(BlockDoc value, $Res Function(BlockDoc) _then) = _$BlockDocCopyWithImpl;
+@useResult
+$Res call({
+ String id, String ty, Map<String, String> attributes, String? delta, String? parentId, String? prevId, String? nextId, String? oldParentId, PlatformInt64? createdAt, PlatformInt64? updatedAt
+});
+
+
+
+
+}
+/// @nodoc
+class _$BlockDocCopyWithImpl<$Res>
+    implements $BlockDocCopyWith<$Res> {
+  _$BlockDocCopyWithImpl(this._self, this._then);
+
+  final BlockDoc _self;
+  final $Res Function(BlockDoc) _then;
+
+/// Create a copy of BlockDoc
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? ty = null,Object? attributes = null,Object? delta = freezed,Object? parentId = freezed,Object? prevId = freezed,Object? nextId = freezed,Object? oldParentId = freezed,Object? createdAt = freezed,Object? updatedAt = freezed,}) {
+  return _then(_self.copyWith(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,ty: null == ty ? _self.ty : ty // ignore: cast_nullable_to_non_nullable
+as String,attributes: null == attributes ? _self.attributes : attributes // ignore: cast_nullable_to_non_nullable
+as Map<String, String>,delta: freezed == delta ? _self.delta : delta // ignore: cast_nullable_to_non_nullable
+as String?,parentId: freezed == parentId ? _self.parentId : parentId // ignore: cast_nullable_to_non_nullable
+as String?,prevId: freezed == prevId ? _self.prevId : prevId // ignore: cast_nullable_to_non_nullable
+as String?,nextId: freezed == nextId ? _self.nextId : nextId // ignore: cast_nullable_to_non_nullable
+as String?,oldParentId: freezed == oldParentId ? _self.oldParentId : oldParentId // ignore: cast_nullable_to_non_nullable
+as String?,createdAt: freezed == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,updatedAt: freezed == updatedAt ? _self.updatedAt : updatedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [BlockDoc].
+extension BlockDocPatterns on BlockDoc {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BlockDoc value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _BlockDoc() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BlockDoc value)  $default,){
+final _that = this;
+switch (_that) {
+case _BlockDoc():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BlockDoc value)?  $default,){
+final _that = this;
+switch (_that) {
+case _BlockDoc() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String ty,  Map<String, String> attributes,  String? delta,  String? parentId,  String? prevId,  String? nextId,  String? oldParentId,  PlatformInt64? createdAt,  PlatformInt64? updatedAt)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _BlockDoc() when $default != null:
+return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_that.prevId,_that.nextId,_that.oldParentId,_that.createdAt,_that.updatedAt);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String ty,  Map<String, String> attributes,  String? delta,  String? parentId,  String? prevId,  String? nextId,  String? oldParentId,  PlatformInt64? createdAt,  PlatformInt64? updatedAt)  $default,) {final _that = this;
+switch (_that) {
+case _BlockDoc():
+return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_that.prevId,_that.nextId,_that.oldParentId,_that.createdAt,_that.updatedAt);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String ty,  Map<String, String> attributes,  String? delta,  String? parentId,  String? prevId,  String? nextId,  String? oldParentId,  PlatformInt64? createdAt,  PlatformInt64? updatedAt)?  $default,) {final _that = this;
+switch (_that) {
+case _BlockDoc() when $default != null:
+return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_that.prevId,_that.nextId,_that.oldParentId,_that.createdAt,_that.updatedAt);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _BlockDoc implements BlockDoc {
+  const _BlockDoc({required this.id, required this.ty, required final  Map<String, String> attributes, this.delta, this.parentId, this.prevId, this.nextId, this.oldParentId, this.createdAt, this.updatedAt}): _attributes = attributes;
+  
+
+@override final  String id;
+@override final  String ty;
+ final  Map<String, String> _attributes;
+@override Map<String, String> get attributes {
+  if (_attributes is EqualUnmodifiableMapView) return _attributes;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableMapView(_attributes);
+}
+
+@override final  String? delta;
+@override final  String? parentId;
+@override final  String? prevId;
+@override final  String? nextId;
+@override final  String? oldParentId;
+@override final  PlatformInt64? createdAt;
+@override final  PlatformInt64? updatedAt;
+
+/// Create a copy of BlockDoc
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$BlockDocCopyWith<_BlockDoc> get copyWith => __$BlockDocCopyWithImpl<_BlockDoc>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BlockDoc&&(identical(other.id, id) || other.id == id)&&(identical(other.ty, ty) || other.ty == ty)&&const DeepCollectionEquality().equals(other._attributes, _attributes)&&(identical(other.delta, delta) || other.delta == delta)&&(identical(other.parentId, parentId) || other.parentId == parentId)&&(identical(other.prevId, prevId) || other.prevId == prevId)&&(identical(other.nextId, nextId) || other.nextId == nextId)&&(identical(other.oldParentId, oldParentId) || other.oldParentId == oldParentId)&&(identical(other.createdAt, createdAt) || other.createdAt == createdAt)&&(identical(other.updatedAt, updatedAt) || other.updatedAt == updatedAt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,ty,const DeepCollectionEquality().hash(_attributes),delta,parentId,prevId,nextId,oldParentId,createdAt,updatedAt);
+
+@override
+String toString() {
+  return 'BlockDoc(id: $id, ty: $ty, attributes: $attributes, delta: $delta, parentId: $parentId, prevId: $prevId, nextId: $nextId, oldParentId: $oldParentId, createdAt: $createdAt, updatedAt: $updatedAt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$BlockDocCopyWith<$Res> implements $BlockDocCopyWith<$Res> {
+  factory _$BlockDocCopyWith(_BlockDoc value, $Res Function(_BlockDoc) _then) = __$BlockDocCopyWithImpl;
+@override @useResult
+$Res call({
+ String id, String ty, Map<String, String> attributes, String? delta, String? parentId, String? prevId, String? nextId, String? oldParentId, PlatformInt64? createdAt, PlatformInt64? updatedAt
+});
+
+
+
+
+}
+/// @nodoc
+class __$BlockDocCopyWithImpl<$Res>
+    implements _$BlockDocCopyWith<$Res> {
+  __$BlockDocCopyWithImpl(this._self, this._then);
+
+  final _BlockDoc _self;
+  final $Res Function(_BlockDoc) _then;
+
+/// Create a copy of BlockDoc
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? ty = null,Object? attributes = null,Object? delta = freezed,Object? parentId = freezed,Object? prevId = freezed,Object? nextId = freezed,Object? oldParentId = freezed,Object? createdAt = freezed,Object? updatedAt = freezed,}) {
+  return _then(_BlockDoc(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,ty: null == ty ? _self.ty : ty // ignore: cast_nullable_to_non_nullable
+as String,attributes: null == attributes ? _self._attributes : attributes // ignore: cast_nullable_to_non_nullable
+as Map<String, String>,delta: freezed == delta ? _self.delta : delta // ignore: cast_nullable_to_non_nullable
+as String?,parentId: freezed == parentId ? _self.parentId : parentId // ignore: cast_nullable_to_non_nullable
+as String?,prevId: freezed == prevId ? _self.prevId : prevId // ignore: cast_nullable_to_non_nullable
+as String?,nextId: freezed == nextId ? _self.nextId : nextId // ignore: cast_nullable_to_non_nullable
+as String?,oldParentId: freezed == oldParentId ? _self.oldParentId : oldParentId // ignore: cast_nullable_to_non_nullable
+as String?,createdAt: freezed == createdAt ? _self.createdAt : createdAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,updatedAt: freezed == updatedAt ? _self.updatedAt : updatedAt // ignore: cast_nullable_to_non_nullable
+as PlatformInt64?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$BlockPage {
+
+ List<BlockDoc> get blocks; BigInt get total; bool get hasMore;
+/// Create a copy of BlockPage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BlockPageCopyWith<BlockPage> get copyWith => _$BlockPageCopyWithImpl<BlockPage>(this as BlockPage, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BlockPage&&const DeepCollectionEquality().equals(other.blocks, blocks)&&(identical(other.total, total) || other.total == total)&&(identical(other.hasMore, hasMore) || other.hasMore == hasMore));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(blocks),total,hasMore);
+
+@override
+String toString() {
+  return 'BlockPage(blocks: $blocks, total: $total, hasMore: $hasMore)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BlockPageCopyWith<$Res>  {
+  factory $BlockPageCopyWith(BlockPage value, $Res Function(BlockPage) _then) = _$BlockPageCopyWithImpl;
+@useResult
+$Res call({
+ List<BlockDoc> blocks, BigInt total, bool hasMore
+});
+
+
+
+
+}
+/// @nodoc
+class _$BlockPageCopyWithImpl<$Res>
+    implements $BlockPageCopyWith<$Res> {
+  _$BlockPageCopyWithImpl(this._self, this._then);
+
+  final BlockPage _self;
+  final $Res Function(BlockPage) _then;
+
+/// Create a copy of BlockPage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? blocks = null,Object? total = null,Object? hasMore = null,}) {
+  return _then(_self.copyWith(
+blocks: null == blocks ? _self.blocks : blocks // ignore: cast_nullable_to_non_nullable
+as List<BlockDoc>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as BigInt,hasMore: null == hasMore ? _self.hasMore : hasMore // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [BlockPage].
+extension BlockPagePatterns on BlockPage {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BlockPage value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _BlockPage() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BlockPage value)  $default,){
+final _that = this;
+switch (_that) {
+case _BlockPage():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BlockPage value)?  $default,){
+final _that = this;
+switch (_that) {
+case _BlockPage() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<BlockDoc> blocks,  BigInt total,  bool hasMore)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _BlockPage() when $default != null:
+return $default(_that.blocks,_that.total,_that.hasMore);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<BlockDoc> blocks,  BigInt total,  bool hasMore)  $default,) {final _that = this;
+switch (_that) {
+case _BlockPage():
+return $default(_that.blocks,_that.total,_that.hasMore);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<BlockDoc> blocks,  BigInt total,  bool hasMore)?  $default,) {final _that = this;
+switch (_that) {
+case _BlockPage() when $default != null:
+return $default(_that.blocks,_that.total,_that.hasMore);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _BlockPage implements BlockPage {
+  const _BlockPage({required final  List<BlockDoc> blocks, required this.total, required this.hasMore}): _blocks = blocks;
+  
+
+ final  List<BlockDoc> _blocks;
+@override List<BlockDoc> get blocks {
+  if (_blocks is EqualUnmodifiableListView) return _blocks;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_blocks);
+}
+
+@override final  BigInt total;
+@override final  bool hasMore;
+
+/// Create a copy of BlockPage
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$BlockPageCopyWith<_BlockPage> get copyWith => __$BlockPageCopyWithImpl<_BlockPage>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BlockPage&&const DeepCollectionEquality().equals(other._blocks, _blocks)&&(identical(other.total, total) || other.total == total)&&(identical(other.hasMore, hasMore) || other.hasMore == hasMore));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_blocks),total,hasMore);
+
+@override
+String toString() {
+  return 'BlockPage(blocks: $blocks, total: $total, hasMore: $hasMore)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$BlockPageCopyWith<$Res> implements $BlockPageCopyWith<$Res> {
+  factory _$BlockPageCopyWith(_BlockPage value, $Res Function(_BlockPage) _then) = __$BlockPageCopyWithImpl;
+@override @useResult
+$Res call({
+ List<BlockDoc> blocks, BigInt total, bool hasMore
+});
+
+
+
+
+}
+/// @nodoc
+class __$BlockPageCopyWithImpl<$Res>
+    implements _$BlockPageCopyWith<$Res> {
+  __$BlockPageCopyWithImpl(this._self, this._then);
+
+  final _BlockPage _self;
+  final $Res Function(_BlockPage) _then;
+
+/// Create a copy of BlockPage
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? blocks = null,Object? total = null,Object? hasMore = null,}) {
+  return _then(_BlockPage(
+blocks: null == blocks ? _self._blocks : blocks // ignore: cast_nullable_to_non_nullable
+as List<BlockDoc>,total: null == total ? _self.total : total // ignore: cast_nullable_to_non_nullable
+as BigInt,hasMore: null == hasMore ? _self.hasMore : hasMore // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$BlockStats {
+
+ String get blockId; int get wordCount; int get charCount; int get charCountNoSpaces;
+/// Create a copy of BlockStats
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BlockStatsCopyWith<BlockStats> get copyWith => _$BlockStatsCopyWithImpl<BlockStats>(this as BlockStats, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BlockStats&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.wordCount, wordCount) || other.wordCount == wordCount)&&(identical(other.charCount, charCount) || other.charCount == charCount)&&(identical(other.charCountNoSpaces, charCountNoSpaces) || other.charCountNoSpaces == charCountNoSpaces));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,blockId,wordCount,charCount,charCountNoSpaces);
+
+@override
+String toString() {
+  return 'BlockStats(blockId: $blockId, wordCount: $wordCount, charCount: $charCount, charCountNoSpaces: $charCountNoSpaces)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BlockStatsCopyWith<$Res>  {
+  factory $BlockStatsCopyWith(BlockStats value, $Res Function(BlockStats) _then) = _$BlockStatsCopyWithImpl;
+@useResult
+$Res call({
+ String blockId, int wordCount, int charCount, int charCountNoSpaces
+});
+
+
+
+
+}
+/// @nodoc
+class _$BlockStatsCopyWithImpl<$Res>
+    implements $BlockStatsCopyWith<$Res> {
+  _$BlockStatsCopyWithImpl(this._self, this._then);
+
+  final BlockStats _self;
+  final $Res Function(BlockStats) _then;
+
+/// Create a copy of BlockStats
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? blockId = null,Object? wordCount = null,Object? charCount = null,Object? charCountNoSpaces = null,}) {
+  return _then(_self.copyWith(
+blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,wordCount: null == wordCount ? _self.wordCount : wordCount // ignore: cast_nullable_to_non_nullable
+as int,charCount: null == charCount ? _self.charCount : charCount // ignore: cast_nullable_to_non_nullable
+as int,charCountNoSpaces: null == charCountNoSpaces ? _self.charCountNoSpaces : charCountNoSpaces // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [BlockStats].
+extension BlockStatsPatterns on BlockStats {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BlockStats value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _BlockStats() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BlockStats value)  $default,){
+final _that = this;
+switch (_that) {
+case _BlockStats():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BlockStats value)?  $default,){
+final _that = this;
+switch (_that) {
+case _BlockStats() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String blockId,  int wordCount,  int charCount,  int charCountNoSpaces)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _BlockStats() when $default != null:
+return $default(_that.blockId,_that.wordCount,_that.charCount,_that.charCountNoSpaces);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String blockId,  int wordCount,  int charCount,  int charCountNoSpaces)  $default,) {final _that = this;
+switch (_that) {
+case _BlockStats():
+return $default(_that.blockId,_that.wordCount,_that.charCount,_that.charCountNoSpaces);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String blockId,  int wordCount,  int charCount,  int charCountNoSpaces)?  $default,) {final _that = this;
+switch (_that) {
+case _BlockStats() when $default != null:
+return $default(_that.blockId,_that.wordCount,_that.charCount,_that.charCountNoSpaces);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _BlockStats implements BlockStats {
+  const _BlockStats({required this.blockId, required this.wordCount, required this.charCount, required this.charCountNoSpaces});
+  
+
+@override final  String blockId;
+@override final  int wordCount;
+@override final  int charCount;
+@override final  int charCountNoSpaces;
+
+/// Create a copy of BlockStats
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$BlockStatsCopyWith<_BlockStats> get copyWith => __$BlockStatsCopyWithImpl<_BlockStats>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BlockStats&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.wordCount, wordCount) || other.wordCount == wordCount)&&(identical(other.charCount, charCount) || other.charCount == charCount)&&(identical(other.charCountNoSpaces, charCountNoSpaces) || other.charCountNoSpaces == charCountNoSpaces));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,blockId,wordCount,charCount,charCountNoSpaces);
+
+@override
+String toString() {
+  return 'BlockStats(blockId: $blockId, wordCount: $wordCount, charCount: $charCount, charCountNoSpaces: $charCountNoSpaces)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$BlockStatsCopyWith<$Res> implements $BlockStatsCopyWith<$Res> {
+  factory _$BlockStatsCopyWith(_BlockStats value, $Res Function(_BlockStats) _then) = __$BlockStatsCopyWithImpl;
+@override @useResult
+$Res call({
+ String blockId, int wordCount, int charCount, int charCountNoSpaces
+});
+
+
+
+
+}
+/// @nodoc
+class __$BlockStatsCopyWithImpl<$Res>
+    implements _$BlockStatsCopyWith<$Res> {
+  __$BlockStatsCopyWithImpl(this._self, this._then);
+
+  final _BlockStats _self;
+  final $Res Function(_BlockStats) _then;
+
+/// Create a copy of BlockStats
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? blockId = null,Object? wordCount = null,Object? charCount = null,Object? charCountNoSpaces = null,}) {
+  return _then(_BlockStats(
+blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,wordCount: null == wordCount ? _self.wordCount : wordCount // ignore: cast_nullable_to_non_nullable
+as int,charCount: null == charCount ? _self.charCount : charCount // ignore: cast_nullable_to_non_nullable
+as int,charCountNoSpaces: null == charCountNoSpaces ? _self.charCountNoSpaces : charCountNoSpaces // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$CommentDoc {
+
+ String get commentId; String get blockId; String get author; String get text; bool get resolved; int? get anchorOffset;
+/// Create a copy of CommentDoc
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CommentDocCopyWith<CommentDoc> get copyWith => _$CommentDocCopyWithImpl<CommentDoc>(this as CommentDoc, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CommentDoc&&(identical(other.commentId, commentId) || other.commentId == commentId)&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.author, author) || other.author == author)&&(identical(other.text, text) || other.text == text)&&(identical(other.resolved, resolved) || other.resolved == resolved)&&(identical(other.anchorOffset, anchorOffset) || other.anchorOffset == anchorOffset));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,commentId,blockId,author,text,resolved,anchorOffset);
+
+@override
+String toString() {
+  return 'CommentDoc(commentId: $commentId, blockId: $blockId, author: $author, text: $text, resolved: $resolved, anchorOffset: $anchorOffset)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CommentDocCopyWith<$Res>  {
+  factory $CommentDocCopyWith(CommentDoc value, $Res Function(CommentDoc) _then) = _$CommentDocCopyWithImpl;
+@useResult
+$Res call({
+ String commentId, String blockId, String author, String text, bool resolved, int? anchorOffset
+});
+
+
+
+
+}
+/// @nodoc
+class _$CommentDocCopyWithImpl<$Res>
+    implements $CommentDocCopyWith<$Res> {
+  _$CommentDocCopyWithImpl(this._self, this._then);
+
+  final CommentDoc _self;
+  final $Res Function(CommentDoc) _then;
+
+/// Create a copy of CommentDoc
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? commentId = null,Object? blockId = null,Object? author = null,Object? text = null,Object? resolved = null,Object? anchorOffset = freezed,}) {
+  return _then(_self.copyWith(
+commentId: null == commentId ? _self.commentId : commentId // ignore: cast_nullable_to_non_nullable
+as String,blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,author: null == author ? _self.author : author // ignore: cast_nullable_to_non_nullable
+as String,text: null == text ? _self.text : text // ignore: cast_nullable_to_non_nullable
+as String,resolved: null == resolved ? _self.resolved : resolved // ignore: cast_nullable_to_non_nullable
+as bool,anchorOffset: freezed == anchorOffset ? _self.anchorOffset : anchorOffset // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [CommentDoc].
+extension CommentDocPatterns on CommentDoc {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _CommentDoc value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _CommentDoc() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _CommentDoc value)  $default,){
+final _that = this;
+switch (_that) {
+case _CommentDoc():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _CommentDoc value)?  $default,){
+final _that = this;
+switch (_that) {
+case _CommentDoc() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String commentId,  String blockId,  String author,  String text,  bool resolved,  int? anchorOffset)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _CommentDoc() when $default != null:
+return $default(_that.commentId,_that.blockId,_that.author,_that.text,_that.resolved,_that.anchorOffset);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String commentId,  String blockId,  String author,  String text,  bool resolved,  int? anchorOffset)  $default,) {final _that = this;
+switch (_that) {
+case _CommentDoc():
+return $default(_that.commentId,_that.blockId,_that.author,_that.text,_that.resolved,_that.anchorOffset);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String commentId,  String blockId,  String author,  String text,  bool resolved,  int? anchorOffset)?  $default,) {final _that = this;
+switch (_that) {
+case _CommentDoc() when $default != null:
+return $default(_that.commentId,_that.blockId,_that.author,_that.text,_that.resolved,_that.anchorOffset);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _CommentDoc implements CommentDoc {
+  const _CommentDoc({required this.commentId, required this.blockId, required this.author, required this.text, required this.resolved, this.anchorOffset});
+  
+
+@override final  String commentId;
+@override final  String blockId;
+@override final  String author;
+@override final  String text;
+@override final  bool resolved;
+@override final  int? anchorOffset;
+
+/// Create a copy of CommentDoc
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$CommentDocCopyWith<_CommentDoc> get copyWith => __$CommentDocCopyWithImpl<_CommentDoc>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _CommentDoc&&(identical(other.commentId, commentId) || other.commentId == commentId)&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.author, author) || other.author == author)&&(identical(other.text, text) || other.text == text)&&(identical(other.resolved, resolved) || other.resolved == resolved)&&(identical(other.anchorOffset, anchorOffset) || other.anchorOffset == anchorOffset));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,commentId,blockId,author,text,resolved,anchorOffset);
+
+@override
+String toString() {
+  return 'CommentDoc(commentId: $commentId, blockId: $blockId, author: $author, text: $text, resolved: $resolved, anchorOffset: $anchorOffset)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$CommentDocCopyWith<$Res> implements $CommentDocCopyWith<$Res> {
+  factory _$CommentDocCopyWith(_CommentDoc value, $Res Function(_CommentDoc) _then) = __$CommentDocCopyWithImpl;
+@override @useResult
+$Res call({
+ String commentId, String blockId, String author, String text, bool resolved, int? anchorOffset
+});
+
+
+
+
+}
+/// @nodoc
+class __$CommentDocCopyWithImpl<$Res>
+    implements _$CommentDocCopyWith<$Res> {
+  __$CommentDocCopyWithImpl(this._self, this._then);
+
+  final _CommentDoc _self;
+  final $Res Function(_CommentDoc) _then;
+
+/// Create a copy of CommentDoc
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? commentId = null,Object? blockId = null,Object? author = null,Object? text = null,Object? resolved = null,Object? anchorOffset = freezed,}) {
+  return _then(_CommentDoc(
+commentId: null == commentId ? _self.commentId : commentId // ignore: cast_nullable_to_non_nullable
+as String,blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,author: null == author ? _self.author : author // ignore: cast_nullable_to_non_nullable
+as String,text: null == text ? _self.text : text // ignore: cast_nullable_to_non_nullable
+as String,resolved: null == resolved ? _self.resolved : resolved // ignore: cast_nullable_to_non_nullable
+as bool,anchorOffset: freezed == anchorOffset ? _self.anchorOffset : anchorOffset // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$DocSize {
+
+ BigInt get fullStateBytes; BigInt get contentBytes;
+/// Create a copy of DocSize
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DocSizeCopyWith<DocSize> get copyWith => _$DocSizeCopyWithImpl<DocSize>(this as DocSize, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DocSize&&(identical(other.fullStateBytes, fullStateBytes) || other.fullStateBytes == fullStateBytes)&&(identical(other.contentBytes, contentBytes) || other.contentBytes == contentBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,fullStateBytes,contentBytes);
+
+@override
+String toString() {
+  return 'DocSize(fullStateBytes: $fullStateBytes, contentBytes: $contentBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DocSizeCopyWith<$Res>  {
+  factory $DocSizeCopyWith(DocSize value, $Res Function(DocSize) _then) = _$DocSizeCopyWithImpl;
+@useResult
+$Res call({
+ BigInt fullStateBytes, BigInt contentBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$DocSizeCopyWithImpl<$Res>
+    implements $DocSizeCopyWith<$Res> {
+  _$DocSizeCopyWithImpl(this._self, this._then);
+
+  final DocSize _self;
+  final $Res Function(DocSize) _then;
+
+/// Create a copy of DocSize
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? fullStateBytes = null,Object? contentBytes = null,}) {
+  return _then(_self.copyWith(
+fullStateBytes: null == fullStateBytes ? _self.fullStateBytes : fullStateBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,contentBytes: null == contentBytes ? _self.contentBytes : contentBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [DocSize].
+extension DocSizePatterns on DocSize {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _DocSize value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _DocSize() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _DocSize value)  $default,){
+final _that = this;
+switch (_that) {
+case _DocSize():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _DocSize value)?  $default,){
+final _that = this;
+switch (_that) {
+case _DocSize() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BigInt fullStateBytes,  BigInt contentBytes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _DocSize() when $default != null:
+return $default(_that.fullStateBytes,_that.contentBytes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BigInt fullStateBytes,  BigInt contentBytes)  $default,) {final _that = this;
+switch (_that) {
+case _DocSize():
+return $default(_that.fullStateBytes,_that.contentBytes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BigInt fullStateBytes,  BigInt contentBytes)?  $default,) {final _that = this;
+switch (_that) {
+case _DocSize() when $default != null:
+return $default(_that.fullStateBytes,_that.contentBytes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _DocSize implements DocSize {
+  const _DocSize({required this.fullStateBytes, required this.contentBytes});
+  
+
+@override final  BigInt fullStateBytes;
+@override final  BigInt contentBytes;
+
+/// Create a copy of DocSize
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$DocSizeCopyWith<_DocSize> get copyWith => __$DocSizeCopyWithImpl<_DocSize>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _DocSize&&(identical(other.fullStateBytes, fullStateBytes) || other.fullStateBytes == fullStateBytes)&&(identical(other.contentBytes, contentBytes) || other.contentBytes == contentBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,fullStateBytes,contentBytes);
+
+@override
+String toString() {
+  return 'DocSize(fullStateBytes: $fullStateBytes, contentBytes: $contentBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$DocSizeCopyWith<$Res> implements $DocSizeCopyWith<$Res> {
+  factory _$DocSizeCopyWith(_DocSize value, $Res Function(_DocSize) _then) = __$DocSizeCopyWithImpl;
+@override @useResult
+$Res call({
+ BigInt fullStateBytes, BigInt contentBytes
+});
+
+
+
+
+}
+/// @nodoc
+class __$DocSizeCopyWithImpl<$Res>
+    implements _$DocSizeCopyWith<$Res> {
+  __$DocSizeCopyWithImpl(this._self, this._then);
+
+  final _DocSize _self;
+  final $Res Function(_DocSize) _then;
+
+/// Create a copy of DocSize
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? fullStateBytes = null,Object? contentBytes = null,}) {
+  return _then(_DocSize(
+fullStateBytes: null == fullStateBytes ? _self.fullStateBytes : fullStateBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,contentBytes: null == contentBytes ? _self.contentBytes : contentBytes // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$DocStats {
+
+ int get wordCount; int get charCount; int get charCountNoSpaces; int get blockCount; List<BlockStats>? get blocks;
+/// Create a copy of DocStats
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DocStatsCopyWith<DocStats> get copyWith => _$DocStatsCopyWithImpl<DocStats>(this as DocStats, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DocStats&&(identical(other.wordCount, wordCount) || other.wordCount == wordCount)&&(identical(other.charCount, charCount) || other.charCount == charCount)&&(identical(other.charCountNoSpaces, charCountNoSpaces) || other.charCountNoSpaces == charCountNoSpaces)&&(identical(other.blockCount, blockCount) || other.blockCount == blockCount)&&const DeepCollectionEquality().equals(other.blocks, blocks));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,wordCount,charCount,charCountNoSpaces,blockCount,const DeepCollectionEquality().hash(blocks));
+
+@override
+String toString() {
+  return 'DocStats(wordCount: $wordCount, charCount: $charCount, charCountNoSpaces: $charCountNoSpaces, blockCount: $blockCount, blocks: $blocks)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DocStatsCopyWith<$Res>  {
+  factory $DocStatsCopyWith(DocStats value, $Res Function(DocStats) _then) = _$DocStatsCopyWithImpl;
+@useResult
+$Res call({
+ int wordCount, int charCount, int charCountNoSpaces, int blockCount, List<BlockStats>? blocks
+});
+
+
+
+
+}
+/// @nodoc
+class _$DocStatsCopyWithImpl<$Res>
+    implements $DocStatsCopyWith<$Res> {
+  _$DocStatsCopyWithImpl(this._self, this._then);
+
+  final DocStats _self;
+  final $Res Function(DocStats) _then;
+
+/// Create a copy of DocStats
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? wordCount = null,Object? charCount = null,Object? charCountNoSpaces = null,Object? blockCount = null,Object? blocks = freezed,}) {
+  return _then(_self.copyWith(
+wordCount: null == wordCount ? _self.wordCount : wordCount // ignore: cast_nullable_to_non_nullable
+as int,charCount: null == charCount ? _self.charCount : charCount // ignore: cast_nullable_to_non_nullable
+as int,charCountNoSpaces: null == charCountNoSpaces ? _self.charCountNoSpaces : charCountNoSpaces // ignore: cast_nullable_to_non_nullable
+as int,blockCount: null == blockCount ? _self.blockCount : blockCount // ignore: cast_nullable_to_non_nullable
+as int,blocks: freezed == blocks ? _self.blocks : blocks // ignore: cast_nullable_to_non_nullable
+as List<BlockStats>?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [DocStats].
+extension DocStatsPatterns on DocStats {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _DocStats value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _DocStats() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _DocStats value)  $default,){
+final _that = this;
+switch (_that) {
+case _DocStats():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _DocStats value)?  $default,){
+final _that = this;
+switch (_that) {
+case _DocStats() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int wordCount,  int charCount,  int charCountNoSpaces,  int blockCount,  List<BlockStats>? blocks)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _DocStats() when $default != null:
+return $default(_that.wordCount,_that.charCount,_that.charCountNoSpaces,_that.blockCount,_that.blocks);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int wordCount,  int charCount,  int charCountNoSpaces,  int blockCount,  List<BlockStats>? blocks)  $default,) {final _that = this;
+switch (_that) {
+case _DocStats():
+return $default(_that.wordCount,_that.charCount,_that.charCountNoSpaces,_that.blockCount,_that.blocks);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int wordCount,  int charCount,  int charCountNoSpaces,  int blockCount,  List<BlockStats>? blocks)?  $default,) {final _that = this;
+switch (_that) {
+case _DocStats() when $default != null:
+return $default(_that.wordCount,_that.charCount,_that.charCountNoSpaces,_that.blockCount,_that.blocks);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _DocStats implements DocStats {
+  const _DocStats({required this.wordCount, required this.charCount, required this.charCountNoSpaces, required this.blockCount, final  List<BlockStats>? blocks}): _blocks = blocks;
+  
+
+@override final  int wordCount;
+@override final  int charCount;
+@override final  int charCountNoSpaces;
+@override final  int blockCount;
+ final  List<BlockStats>? _blocks;
+@override List<BlockStats>? get blocks {
+  final value = _blocks;
+  if (value == null) return null;
+  if (_blocks is EqualUnmodifiableListView) return _blocks;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(value);
+}
+
+
+/// Create a copy of DocStats
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$DocStatsCopyWith<_DocStats> get copyWith => __$DocStatsCopyWithImpl<_DocStats>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _DocStats&&(identical(other.wordCount, wordCount) || other.wordCount == wordCount)&&(identical(other.charCount, charCount) || other.charCount == charCount)&&(identical(other.charCountNoSpaces, charCountNoSpaces) || other.charCountNoSpaces == charCountNoSpaces)&&(identical(other.blockCount, blockCount) || other.blockCount == blockCount)&&const DeepCollectionEquality().equals(other._blocks, _blocks));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,wordCount,charCount,charCountNoSpaces,blockCount,const DeepCollectionEquality().hash(_blocks));
+
+@override
+String toString() {
+  return 'DocStats(wordCount: $wordCount, charCount: $charCount, charCountNoSpaces: $charCountNoSpaces, blockCount: $blockCount, blocks: $blocks)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$DocStatsCopyWith<$Res> implements $DocStatsCopyWith<$Res> {
+  factory _$DocStatsCopyWith(_DocStats value, $Res Function(_DocStats) _then) = __$DocStatsCopyWithImpl;
+@override @useResult
+$Res call({
+ int wordCount, int charCount, int charCountNoSpaces, int blockCount, List<BlockStats>? blocks
+});
+
+
+
+
+}
+/// @nodoc
+class __$DocStatsCopyWithImpl<$Res>
+    implements _$DocStatsCopyWith<$Res> {
+  __$DocStatsCopyWithImpl(this._self, this._then);
+
+  final _DocStats _self;
+  final $Res Function(_DocStats) _then;
+
+/// Create a copy of DocStats
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? wordCount = null,Object? charCount = null,Object? charCountNoSpaces = null,Object? blockCount = null,Object? blocks = freezed,}) {
+  return _then(_DocStats(
+wordCount: null == wordCount ? _self.wordCount : wordCount // ignore: cast_nullable_to_non_nullable
+as int,charCount: null == charCount ? _self.charCount : charCount // ignore: cast_nullable_to_non_nullable
+as int,charCountNoSpaces: null == charCountNoSpaces ? _self.charCountNoSpaces : charCountNoSpaces // ignore: cast_nullable_to_non_nullable
+as int,blockCount: null == blockCount ? _self.blockCount : blockCount // ignore: cast_nullable_to_non_nullable
+as int,blocks: freezed == blocks ? _self._blocks : blocks // ignore: cast_nullable_to_non_nullable
+as List<BlockStats>?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$DocumentState {
+
+ String get docId; Map<String, BlockDoc> get blocks; Map<String, List<String>> get childrenMap; String get rootId;
+/// Create a copy of DocumentState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DocumentStateCopyWith<DocumentState> get copyWith => _$DocumentStateCopyWithImpl<DocumentState>(this as DocumentState, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DocumentState&&(identical(other.docId, docId) || other.docId == docId)&&const DeepCollectionEquality().equals(other.blocks, blocks)&&const DeepCollectionEquality().equals(other.childrenMap, childrenMap)&&(identical(other.rootId, rootId) || other.rootId == rootId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,docId,const DeepCollectionEquality().hash(blocks),const DeepCollectionEquality().hash(childrenMap),rootId);
+
+@override
+String toString() {
+  return 'DocumentState(docId: $docId, blocks: $blocks, childrenMap: $childrenMap, rootId: $rootId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DocumentStateCopyWith<$Res>  {
+  factory $DocumentStateCopyWith(DocumentState value, $Res Function(DocumentState) _then) = _$DocumentStateCopyWithImpl;
+@useResult
+$Res call({
+ String docId, Map<String, BlockDoc> blocks, Map<String, List<String>> childrenMap, String rootId
+});
+
+
+
+
+}
+/// @nodoc
+class _$DocumentStateCopyWithImpl<$Res>
+    implements $DocumentStateCopyWith<$Res> {
+  _$DocumentStateCopyWithImpl(this._self, this._then);
+
+  final DocumentState _self;
+  final $Res Function(DocumentState) _then;
+
+/// Create a copy of DocumentState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? docId = null,Object? blocks = null,Object? childrenMap = null,Object? rootId = null,}) {
+  return _then(_self.copyWith(
+docId: null == docId ? _self.docId : docId // ignore: cast_nullable_to_non_nullable
+as String,blocks: null == blocks ? _self.blocks : blocks // ignore: cast_nullable_to_non_nullable
+as Map<String, BlockDoc>,childrenMap: null == childrenMap ? _self.childrenMap : childrenMap // ignore: cast_nullable_to_non_nullable
+as Map<String, List<String>>,rootId: null == rootId ? _self.rootId : rootId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [DocumentState].
+extension DocumentStatePatterns on DocumentState {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _DocumentState value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _DocumentState() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _DocumentState value)  $default,){
+final _that = this;
+switch (_that) {
+case _DocumentState():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _DocumentState value)?  $default,){
+final _that = this;
+switch (_that) {
+case _DocumentState() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String docId,  Map<String, BlockDoc> blocks,  Map<String, List<String>> childrenMap,  String rootId)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _DocumentState() when $default != null:
+return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String docId,  Map<String, BlockDoc> blocks,  Map<String, List<String>> childrenMap,  String rootId)  $default,) {final _that = this;
+switch (_that) {
+case _DocumentState():
+return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String docId,  Map<String, BlockDoc> blocks,  Map<String, List<String>> childrenMap,  String rootId)?  $default,) {final _that = this;
+switch (_that) {
+case _DocumentState() when $default != null:
+return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _DocumentState implements DocumentState {
+  const _DocumentState({required this.docId, required final  Map<String, BlockDoc> blocks, required final  Map<String, List<String>> childrenMap, required this.rootId}): _blocks = blocks,_childrenMap = childrenMap;
+  
+
+@override final  String docId;
+ final  Map<String, BlockDoc> _blocks;
+@override Map<String, BlockDoc> get blocks {
+  if (_blocks is EqualUnmodifiableMapView) return _blocks;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableMapView(_blocks);
+}
+
+ final  Map<String, List<String>> _childrenMap;
+@override Map<String, List<String>> get childrenMap {
+  if (_childrenMap is EqualUnmodifiableMapView) return _childrenMap;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableMapView(_childrenMap);
+}
+
+@override final  String rootId;
+
+/// Create a copy of DocumentState
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$DocumentStateCopyWith<_DocumentState> get copyWith => __$DocumentStateCopyWithImpl<_DocumentState>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _DocumentState&&(identical(other.docId, docId) || other.docId == docId)&&const DeepCollectionEquality().equals(other._blocks, _blocks)&&const DeepCollectionEquality().equals(other._childrenMap, _childrenMap)&&(identical(other.rootId, rootId) || other.rootId == rootId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,docId,const DeepCollectionEquality().hash(_blocks),const DeepCollectionEquality().hash(_childrenMap),rootId);
+
+@override
+String toString() {
+  return 'DocumentState(docId: $docId, blocks: $blocks, childrenMap: $childrenMap, rootId: $rootId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$DocumentStateCopyWith<$Res> implements $DocumentStateCopyWith<$Res> {
+  factory _$DocumentStateCopyWith(_DocumentState value, $Res Function(_DocumentState) _then) = __$DocumentStateCopyWithImpl;
+@override @useResult
+$Res call({
+ String docId, Map<String, BlockDoc> blocks, Map<String, List<String>> childrenMap, String rootId
+});
+
+
+
+
+}
+/// @nodoc
+class __$DocumentStateCopyWithImpl<$Res>
+    implements _$DocumentStateCopyWith<$Res> {
+  __$DocumentStateCopyWithImpl(this._self, this._then);
+
+  final _DocumentState _self;
+  final $Res Function(_DocumentState) _then;
+
+/// Create a copy of DocumentState
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? docId = null,Object? blocks = null,Object? childrenMap = null,Object? rootId = null,}) {
+  return _then(_DocumentState(
+docId: null == docId ? _self.docId : docId // ignore: cast_nullable_to_non_nullable
+as String,blocks: null == blocks ? _self._blocks : blocks // ignore: cast_nullable_to_non_nullable
+as Map<String, BlockDoc>,childrenMap: null == childrenMap ? _self._childrenMap : childrenMap // ignore: cast_nullable_to_non_nullable
+as Map<String, List<String>>,rootId: null == rootId ? _self.rootId : rootId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$DocumentValidation {
+
+ List<String> get orphanedBlockIds; List<String> get cyclicBlockIds; List<String> get danglingReferenceIds; List<String> get orphanedCommentIds;
+/// Create a copy of DocumentValidation
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DocumentValidationCopyWith<DocumentValidation> get copyWith => _$DocumentValidationCopyWithImpl<DocumentValidation>(this as DocumentValidation, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DocumentValidation&&const DeepCollectionEquality().equals(other.orphanedBlockIds, orphanedBlockIds)&&const DeepCollectionEquality().equals(other.cyclicBlockIds, cyclicBlockIds)&&const DeepCollectionEquality().equals(other.danglingReferenceIds, danglingReferenceIds)&&const DeepCollectionEquality().equals(other.orphanedCommentIds, orphanedCommentIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(orphanedBlockIds),const DeepCollectionEquality().hash(cyclicBlockIds),const DeepCollectionEquality().hash(danglingReferenceIds),const DeepCollectionEquality().hash(orphanedCommentIds));
+
+@override
+String toString() {
+  return 'DocumentValidation(orphanedBlockIds: $orphanedBlockIds, cyclicBlockIds: $cyclicBlockIds, danglingReferenceIds: $danglingReferenceIds, orphanedCommentIds: $orphanedCommentIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DocumentValidationCopyWith<$Res>  {
+  factory $DocumentValidationCopyWith(DocumentValidation value, $Res Function(DocumentValidation) _then) = _$DocumentValidationCopyWithImpl;
+@useResult
+$Res call({
+ List<String> orphanedBlockIds, List<String> cyclicBlockIds, List<String> danglingReferenceIds, List<String> orphanedCommentIds
+});
+
+
+
+
+}
+/// @nodoc
+class _$DocumentValidationCopyWithImpl<$Res>
+    implements $DocumentValidationCopyWith<$Res> {
+  _$DocumentValidationCopyWithImpl(this._self, this._then);
+
+  final DocumentValidation _self;
+  final $Res Function(DocumentValidation) _then;
+
+/// Create a copy of DocumentValidation
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? orphanedBlockIds = null,Object? cyclicBlockIds = null,Object? danglingReferenceIds = null,Object? orphanedCommentIds = null,}) {
+  return _then(_self.copyWith(
+orphanedBlockIds: null == orphanedBlockIds ? _self.orphanedBlockIds : orphanedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,cyclicBlockIds: null == cyclicBlockIds ? _self.cyclicBlockIds : cyclicBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,danglingReferenceIds: null == danglingReferenceIds ? _self.danglingReferenceIds : danglingReferenceIds // ignore: cast_nullable_to_non_nullable
+as List<String>,orphanedCommentIds: null == orphanedCommentIds ? _self.orphanedCommentIds : orphanedCommentIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [DocumentValidation].
+extension DocumentValidationPatterns on DocumentValidation {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _DocumentValidation value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _DocumentValidation() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _DocumentValidation value)  $default,){
+final _that = this;
+switch (_that) {
+case _DocumentValidation():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _DocumentValidation value)?  $default,){
+final _that = this;
+switch (_that) {
+case _DocumentValidation() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> orphanedBlockIds,  List<String> cyclicBlockIds,  List<String> danglingReferenceIds,  List<String> orphanedCommentIds)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _DocumentValidation() when $default != null:
+return $default(_that.orphanedBlockIds,_that.cyclicBlockIds,_that.danglingReferenceIds,_that.orphanedCommentIds);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> orphanedBlockIds,  List<String> cyclicBlockIds,  List<String> danglingReferenceIds,  List<String> orphanedCommentIds)  $default,) {final _that = this;
+switch (_that) {
+case _DocumentValidation():
+return $default(_that.orphanedBlockIds,_that.cyclicBlockIds,_that.danglingReferenceIds,_that.orphanedCommentIds);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> orphanedBlockIds,  List<String> cyclicBlockIds,  List<String> danglingReferenceIds,  List<String> orphanedCommentIds)?  $default,) {final _that = this;
+switch (_that) {
+case _DocumentValidation() when $default != null:
+return $default(_that.orphanedBlockIds,_that.cyclicBlockIds,_that.danglingReferenceIds,_that.orphanedCommentIds);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _DocumentValidation implements DocumentValidation {
+  const _DocumentValidation({required final  List<String> orphanedBlockIds, required final  List<String> cyclicBlockIds, required final  List<String> danglingReferenceIds, required final  List<String> orphanedCommentIds}): _orphanedBlockIds = orphanedBlockIds,_cyclicBlockIds = cyclicBlockIds,_danglingReferenceIds = danglingReferenceIds,_orphanedCommentIds = orphanedCommentIds;
+  
+
+ final  List<String> _orphanedBlockIds;
+@override List<String> get orphanedBlockIds {
+  if (_orphanedBlockIds is EqualUnmodifiableListView) return _orphanedBlockIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_orphanedBlockIds);
+}
+
+ final  List<String> _cyclicBlockIds;
+@override List<String> get cyclicBlockIds {
+  if (_cyclicBlockIds is EqualUnmodifiableListView) return _cyclicBlockIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_cyclicBlockIds);
+}
+
+ final  List<String> _danglingReferenceIds;
+@override List<String> get danglingReferenceIds {
+  if (_danglingReferenceIds is EqualUnmodifiableListView) return _danglingReferenceIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_danglingReferenceIds);
+}
+
+ final  List<String> _orphanedCommentIds;
+@override List<String> get orphanedCommentIds {
+  if (_orphanedCommentIds is EqualUnmodifiableListView) return _orphanedCommentIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_orphanedCommentIds);
+}
+
+
+/// Create a copy of DocumentValidation
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$DocumentValidationCopyWith<_DocumentValidation> get copyWith => __$DocumentValidationCopyWithImpl<_DocumentValidation>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _DocumentValidation&&const DeepCollectionEquality().equals(other._orphanedBlockIds, _orphanedBlockIds)&&const DeepCollectionEquality().equals(other._cyclicBlockIds, _cyclicBlockIds)&&const DeepCollectionEquality().equals(other._danglingReferenceIds, _danglingReferenceIds)&&const DeepCollectionEquality().equals(other._orphanedCommentIds, _orphanedCommentIds));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_orphanedBlockIds),const DeepCollectionEquality().hash(_cyclicBlockIds),const DeepCollectionEquality().hash(_danglingReferenceIds),const DeepCollectionEquality().hash(_orphanedCommentIds));
+
+@override
+String toString() {
+  return 'DocumentValidation(orphanedBlockIds: $orphanedBlockIds, cyclicBlockIds: $cyclicBlockIds, danglingReferenceIds: $danglingReferenceIds, orphanedCommentIds: $orphanedCommentIds)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$DocumentValidationCopyWith<$Res> implements $DocumentValidationCopyWith<$Res> {
+  factory _$DocumentValidationCopyWith(_DocumentValidation value, $Res Function(_DocumentValidation) _then) = __$DocumentValidationCopyWithImpl;
+@override @useResult
+$Res call({
+ List<String> orphanedBlockIds, List<String> cyclicBlockIds, List<String> danglingReferenceIds, List<String> orphanedCommentIds
+});
+
+
+
+
+}
+/// @nodoc
+class __$DocumentValidationCopyWithImpl<$Res>
+    implements _$DocumentValidationCopyWith<$Res> {
+  __$DocumentValidationCopyWithImpl(this._self, this._then);
+
+  final _DocumentValidation _self;
+  final $Res Function(_DocumentValidation) _then;
+
+/// Create a copy of DocumentValidation
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? orphanedBlockIds = null,Object? cyclicBlockIds = null,Object? danglingReferenceIds = null,Object? orphanedCommentIds = null,}) {
+  return _then(_DocumentValidation(
+orphanedBlockIds: null == orphanedBlockIds ? _self._orphanedBlockIds : orphanedBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,cyclicBlockIds: null == cyclicBlockIds ? _self._cyclicBlockIds : cyclicBlockIds // ignore: cast_nullable_to_non_nullable
+as List<String>,danglingReferenceIds: null == danglingReferenceIds ? _self._danglingReferenceIds : danglingReferenceIds // ignore: cast_nullable_to_non_nullable
+as List<String>,orphanedCommentIds: null == orphanedCommentIds ? _self._orphanedCommentIds : orphanedCommentIds // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$DuplicateResult {
+
+ Uint8List get update; Map<String, String> get idMap;
+/// Create a copy of DuplicateResult
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DuplicateResultCopyWith<DuplicateResult> get copyWith => _$DuplicateResultCopyWithImpl<DuplicateResult>(this as DuplicateResult, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DuplicateResult&&const DeepCollectionEquality().equals(other.update, update)&&const DeepCollectionEquality().equals(other.idMap, idMap));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(update),const DeepCollectionEquality().hash(idMap));
+
+@override
+String toString() {
+  return 'DuplicateResult(update: $update, idMap: $idMap)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DuplicateResultCopyWith<$Res>  {
+  factory $DuplicateResultCopyWith(DuplicateResult value, $Res Function(DuplicateResult) _then) = _$DuplicateResultCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List update, Map<String, String> idMap
+});
+
+
+
+
+}
+/// @nodoc
+class _$DuplicateResultCopyWithImpl<$Res>
+    implements $DuplicateResultCopyWith<$Res> {
+  _$DuplicateResultCopyWithImpl(this._self, this._then);
+
+  final DuplicateResult _self;
+  final $Res Function(DuplicateResult) _then;
+
+/// Create a copy of DuplicateResult
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? update = null,Object? idMap = null,}) {
+  return _then(_self.copyWith(
+update: null == update ? _self.update : update // ignore: cast_nullable_to_non_nullable
+as Uint8List,idMap: null == idMap ? _self.idMap : idMap // ignore: cast_nullable_to_non_nullable
+as Map<String, String>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [DuplicateResult].
+extension DuplicateResultPatterns on DuplicateResult {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _DuplicateResult value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _DuplicateResult() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _DuplicateResult value)  $default,){
+final _that = this;
+switch (_that) {
+case _DuplicateResult():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _DuplicateResult value)?  $default,){
+final _that = this;
+switch (_that) {
+case _DuplicateResult() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( Uint8List update,  Map<String, String> idMap)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _DuplicateResult() when $default != null:
+return $default(_that.update,_that.idMap);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( Uint8List update,  Map<String, String> idMap)  $default,) {final _that = this;
+switch (_that) {
+case _DuplicateResult():
+return $default(_that.update,_that.idMap);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( Uint8List update,  Map<String, String> idMap)?  $default,) {final _that = this;
+switch (_that) {
+case _DuplicateResult() when $default != null:
+return $default(_that.update,_that.idMap);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _DuplicateResult implements DuplicateResult {
+  const _DuplicateResult({required this.update, required final  Map<String, String> idMap}): _idMap = idMap;
+  
+
+@override final  Uint8List update;
+ final  Map<String, String> _idMap;
+@override Map<String, String> get idMap {
+  if (_idMap is EqualUnmodifiableMapView) return _idMap;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableMapView(_idMap);
+}
+
+
+/// Create a copy of DuplicateResult
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$DuplicateResultCopyWith<_DuplicateResult> get copyWith => __$DuplicateResultCopyWithImpl<_DuplicateResult>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _DuplicateResult&&const DeepCollectionEquality().equals(other.update, update)&&const DeepCollectionEquality().equals(other._idMap, _idMap));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(update),const DeepCollectionEquality().hash(_idMap));
+
+@override
+String toString() {
+  return 'DuplicateResult(update: $update, idMap: $idMap)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$DuplicateResultCopyWith<$Res> implements $DuplicateResultCopyWith<$Res> {
+  factory _$DuplicateResultCopyWith(_DuplicateResult value, $Res Function(_DuplicateResult) _then) = __$DuplicateResultCopyWithImpl;
+@override @useResult
+$Res call({
+ Uint8List update, Map<String, String> idMap
+});
+
+
+
+
+}
+/// @nodoc
+class __$DuplicateResultCopyWithImpl<$Res>
+    implements _$DuplicateResultCopyWith<$Res> {
+  __$DuplicateResultCopyWithImpl(this._self, this._then);
+
+  final _DuplicateResult _self;
+  final $Res Function(_DuplicateResult) _then;
+
+/// Create a copy of DuplicateResult
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? update = null,Object? idMap = null,}) {
+  return _then(_DuplicateResult(
+update: null == update ? _self.update : update // ignore: cast_nullable_to_non_nullable
+as Uint8List,idMap: null == idMap ? _self._idMap : idMap // ignore: cast_nullable_to_non_nullable
+as Map<String, String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$FailedToDecodeUpdates {
+
+ List<String> get failedUpdatesIds; List<String> get errors;
+/// Create a copy of FailedToDecodeUpdates
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$FailedToDecodeUpdatesCopyWith<FailedToDecodeUpdates> get copyWith => _$FailedToDecodeUpdatesCopyWithImpl<FailedToDecodeUpdates>(this as FailedToDecodeUpdates, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FailedToDecodeUpdates&&const DeepCollectionEquality().equals(other.failedUpdatesIds, failedUpdatesIds)&&const DeepCollectionEquality().equals(other.errors, errors));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(failedUpdatesIds),const DeepCollectionEquality().hash(errors));
+
+@override
+String toString() {
+  return 'FailedToDecodeUpdates(failedUpdatesIds: $failedUpdatesIds, errors: $errors)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $FailedToDecodeUpdatesCopyWith<$Res>  {
+  factory $FailedToDecodeUpdatesCopyWith(FailedToDecodeUpdates value, $Res Function(FailedToDecodeUpdates) _then) = _$FailedToDecodeUpdatesCopyWithImpl;
+@useResult
+$Res call({
+ List<String> failedUpdatesIds, List<String> errors
+});
+
+
+
+
+}
+/// @nodoc
+class _$FailedToDecodeUpdatesCopyWithImpl<$Res>
+    implements $FailedToDecodeUpdatesCopyWith<$Res> {
+  _$FailedToDecodeUpdatesCopyWithImpl(this._self, this._then);
+
+  final FailedToDecodeUpdates _self;
+  final $Res Function(FailedToDecodeUpdates) _then;
+
+/// Create a copy of FailedToDecodeUpdates
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? failedUpdatesIds = null,Object? errors = null,}) {
+  return _then(_self.copyWith(
+failedUpdatesIds: null == failedUpdatesIds ? _self.failedUpdatesIds : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
+as List<String>,errors: null == errors ? _self.errors : errors // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [FailedToDecodeUpdates].
+extension FailedToDecodeUpdatesPatterns on FailedToDecodeUpdates {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _FailedToDecodeUpdates value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _FailedToDecodeUpdates() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _FailedToDecodeUpdates value)  $default,){
+final _that = this;
+switch (_that) {
+case _FailedToDecodeUpdates():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _FailedToDecodeUpdates value)?  $default,){
+final _that = this;
+switch (_that) {
+case _FailedToDecodeUpdates() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> failedUpdatesIds,  List<String> errors)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _FailedToDecodeUpdates() when $default != null:
+return $default(_that.failedUpdatesIds,_that.errors);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> failedUpdatesIds,  List<String> errors)  $default,) {final _that = this;
+switch (_that) {
+case _FailedToDecodeUpdates():
+return $default(_that.failedUpdatesIds,_that.errors);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> failedUpdatesIds,  List<String> errors)?  $default,) {final _that = this;
+switch (_that) {
+case _FailedToDecodeUpdates() when $default != null:
+return $default(_that.failedUpdatesIds,_that.errors);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _FailedToDecodeUpdates implements FailedToDecodeUpdates {
+  const _FailedToDecodeUpdates({required final  List<String> failedUpdatesIds, required final  List<String> errors}): _failedUpdatesIds = failedUpdatesIds,_errors = errors;
+  
+
+ final  List<String> _failedUpdatesIds;
+@override List<String> get failedUpdatesIds {
+  if (_failedUpdatesIds is EqualUnmodifiableListView) return _failedUpdatesIds;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_failedUpdatesIds);
+}
+
+ final  List<String> _errors;
+@override List<String> get errors {
+  if (_errors is EqualUnmodifiableListView) return _errors;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_errors);
+}
+
+
+/// Create a copy of FailedToDecodeUpdates
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$FailedToDecodeUpdatesCopyWith<_FailedToDecodeUpdates> get copyWith => __$FailedToDecodeUpdatesCopyWithImpl<_FailedToDecodeUpdates>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _FailedToDecodeUpdates&&const DeepCollectionEquality().equals(other._failedUpdatesIds, _failedUpdatesIds)&&const DeepCollectionEquality().equals(other._errors, _errors));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_failedUpdatesIds),const DeepCollectionEquality().hash(_errors));
+
+@override
+String toString() {
+  return 'FailedToDecodeUpdates(failedUpdatesIds: $failedUpdatesIds, errors: $errors)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$FailedToDecodeUpdatesCopyWith<$Res> implements $FailedToDecodeUpdatesCopyWith<$Res> {
+  factory _$FailedToDecodeUpdatesCopyWith(_FailedToDecodeUpdates value, $Res Function(_FailedToDecodeUpdates) _then) = __$FailedToDecodeUpdatesCopyWithImpl;
+@override @useResult
+$Res call({
+ List<String> failedUpdatesIds, List<String> errors
+});
+
+
+
+
+}
+/// @nodoc
+class __$FailedToDecodeUpdatesCopyWithImpl<$Res>
+    implements _$FailedToDecodeUpdatesCopyWith<$Res> {
+  __$FailedToDecodeUpdatesCopyWithImpl(this._self, this._then);
+
+  final _FailedToDecodeUpdates _self;
+  final $Res Function(_FailedToDecodeUpdates) _then;
+
+/// Create a copy of FailedToDecodeUpdates
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? failedUpdatesIds = null,Object? errors = null,}) {
+  return _then(_FailedToDecodeUpdates(
+failedUpdatesIds: null == failedUpdatesIds ? _self._failedUpdatesIds : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
+as List<String>,errors: null == errors ? _self._errors : errors // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$MetaOp {
+
+ String get key;
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOpCopyWith<MetaOp> get copyWith => _$MetaOpCopyWithImpl<MetaOp>(this as MetaOp, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp&&(identical(other.key, key) || other.key == key));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key);
+
+@override
+String toString() {
+  return 'MetaOp(key: $key)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOpCopyWith<$Res>  {
+  factory $MetaOpCopyWith(MetaOp value, $Res Function(MetaOp) _then) = _$MetaOpCopyWithImpl;
+@useResult
+$Res call({
+ String key
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOpCopyWithImpl<$Res>
+    implements $MetaOpCopyWith<$Res> {
+  _$MetaOpCopyWithImpl(this._self, this._then);
+
+  final MetaOp _self;
+  final $Res Function(MetaOp) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? key = null,}) {
+  return _then(_self.copyWith(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [MetaOp].
+extension MetaOpPatterns on MetaOp {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MetaOp_SetString value)?  setString,TResult Function( MetaOp_SetInt value)?  setInt,TResult Function( MetaOp_SetBool value)?  setBool,TResult Function( MetaOp_SetStringArray value)?  setStringArray,TResult Function( MetaOp_Remove value)?  remove,TResult Function( MetaOp_PushArrayItem value)?  pushArrayItem,TResult Function( MetaOp_RemoveArrayItem value)?  removeArrayItem,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case MetaOp_SetString() when setString != null:
+return setString(_that);case MetaOp_SetInt() when setInt != null:
+return setInt(_that);case MetaOp_SetBool() when setBool != null:
+return setBool(_that);case MetaOp_SetStringArray() when setStringArray != null:
+return setStringArray(_that);case MetaOp_Remove() when remove != null:
+return remove(_that);case MetaOp_PushArrayItem() when pushArrayItem != null:
+return pushArrayItem(_that);case MetaOp_RemoveArrayItem() when removeArrayItem != null:
+return removeArrayItem(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MetaOp_SetString value)  setString,required TResult Function( MetaOp_SetInt value)  setInt,required TResult Function( MetaOp_SetBool value)  setBool,required TResult Function( MetaOp_SetStringArray value)  setStringArray,required TResult Function( MetaOp_Remove value)  remove,required TResult Function( MetaOp_PushArrayItem value)  pushArrayItem,required TResult Function( MetaOp_RemoveArrayItem value)  removeArrayItem,}){
+final _that = this;
+switch (_that) {
+case MetaOp_SetString():
+return setString(_that);case MetaOp_SetInt():
+return setInt(_that);case MetaOp_SetBool():
+return setBool(_that);case MetaOp_SetStringArray():
+return setStringArray(_that);case MetaOp_Remove():
+return remove(_that);case MetaOp_PushArrayItem():
+return pushArrayItem(_that);case MetaOp_RemoveArrayItem():
+return removeArrayItem(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MetaOp_SetString value)?  setString,TResult? Function( MetaOp_SetInt value)?  setInt,TResult? Function( MetaOp_SetBool value)?  setBool,TResult? Function( MetaOp_SetStringArray value)?  setStringArray,TResult? Function( MetaOp_Remove value)?  remove,TResult? Function( MetaOp_PushArrayItem value)?  pushArrayItem,TResult? Function( MetaOp_RemoveArrayItem value)?  removeArrayItem,}){
+final _that = this;
+switch (_that) {
+case MetaOp_SetString() when setString != null:
+return setString(_that);case MetaOp_SetInt() when setInt != null:
+return setInt(_that);case MetaOp_SetBool() when setBool != null:
+return setBool(_that);case MetaOp_SetStringArray() when setStringArray != null:
+return setStringArray(_that);case MetaOp_Remove() when remove != null:
+return remove(_that);case MetaOp_PushArrayItem() when pushArrayItem != null:
+return pushArrayItem(_that);case MetaOp_RemoveArrayItem() when removeArrayItem != null:
+return removeArrayItem(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String key,  String value)?  setString,TResult Function( String key,  PlatformInt64 value)?  setInt,TResult Function( String key,  bool value)?  setBool,TResult Function( String key,  List<String> values)?  setStringArray,TResult Function( String key)?  remove,TResult Function( String key,  String value)?  pushArrayItem,TResult Function( String key,  String value)?  removeArrayItem,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case MetaOp_SetString() when setString != null:
+return setString(_that.key,_that.value);case MetaOp_SetInt() when setInt != null:
+return setInt(_that.key,_that.value);case MetaOp_SetBool() when setBool != null:
+return setBool(_that.key,_that.value);case MetaOp_SetStringArray() when setStringArray != null:
+return setStringArray(_that.key,_that.values);case MetaOp_Remove() when remove != null:
+return remove(_that.key);case MetaOp_PushArrayItem() when pushArrayItem != null:
+return pushArrayItem(_that.key,_that.value);case MetaOp_RemoveArrayItem() when removeArrayItem != null:
+return removeArrayItem(_that.key,_that.value);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String key,  String value)  setString,required TResult Function( String key,  PlatformInt64 value)  setInt,required TResult Function( String key,  bool value)  setBool,required TResult Function( String key,  List<String> values)  setStringArray,required TResult Function( String key)  remove,required TResult Function( String key,  String value)  pushArrayItem,required TResult Function( String key,  String value)  removeArrayItem,}) {final _that = this;
+switch (_that) {
+case MetaOp_SetString():
+return setString(_that.key,_that.value);case MetaOp_SetInt():
+return setInt(_that.key,_that.value);case MetaOp_SetBool():
+return setBool(_that.key,_that.value);case MetaOp_SetStringArray():
+return setStringArray(_that.key,_that.values);case MetaOp_Remove():
+return remove(_that.key);case MetaOp_PushArrayItem():
+return pushArrayItem(_that.key,_that.value);case MetaOp_RemoveArrayItem():
+return removeArrayItem(_that.key,_that.value);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String key,  String value)?  setString,TResult? Function( String key,  PlatformInt64 value)?  setInt,TResult? Function( String key,  bool value)?  setBool,TResult? Function( String key,  List<String> values)?  setStringArray,TResult? Function( String key)?  remove,TResult? Function( String key,  String value)?  pushArrayItem,TResult? Function( String key,  String value)?  removeArrayItem,}) {final _that = this;
+switch (_that) {
+case MetaOp_SetString() when setString != null:
+return setString(_that.key,_that.value);case MetaOp_SetInt() when setInt != null:
+return setInt(_that.key,_that.value);case MetaOp_SetBool() when setBool != null:
+return setBool(_that.key,_that.value);case MetaOp_SetStringArray() when setStringArray != null:
+return setStringArray(_that.key,_that.values);case MetaOp_Remove() when remove != null:
+return remove(_that.key);case MetaOp_PushArrayItem() when pushArrayItem != null:
+return pushArrayItem(_that.key,_that.value);case MetaOp_RemoveArrayItem() when removeArrayItem != null:
+return removeArrayItem(_that.key,_that.value);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class MetaOp_SetString extends MetaOp {
+  const MetaOp_SetString({required this.key, required this.value}): super._();
+  
+
+@override final  String key;
+ final  String value;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_SetStringCopyWith<MetaOp_SetString> get copyWith => _$MetaOp_SetStringCopyWithImpl<MetaOp_SetString>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_SetString&&(identical(other.key, key) || other.key == key)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,value);
+
+@override
+String toString() {
+  return 'MetaOp.setString(key: $key, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_SetStringCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_SetStringCopyWith(MetaOp_SetString value, $Res Function(MetaOp_SetString) _then) = _$MetaOp_SetStringCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, String value
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_SetStringCopyWithImpl<$Res>
+    implements $MetaOp_SetStringCopyWith<$Res> {
+  _$MetaOp_SetStringCopyWithImpl(this._self, this._then);
+
+  final MetaOp_SetString _self;
+  final $Res Function(MetaOp_SetString) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? value = null,}) {
+  return _then(MetaOp_SetString(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetaOp_SetInt extends MetaOp {
+  const MetaOp_SetInt({required this.key, required this.value}): super._();
+  
+
+@override final  String key;
+ final  PlatformInt64 value;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_SetIntCopyWith<MetaOp_SetInt> get copyWith => _$MetaOp_SetIntCopyWithImpl<MetaOp_SetInt>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_SetInt&&(identical(other.key, key) || other.key == key)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,value);
+
+@override
+String toString() {
+  return 'MetaOp.setInt(key: $key, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_SetIntCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_SetIntCopyWith(MetaOp_SetInt value, $Res Function(MetaOp_SetInt) _then) = _$MetaOp_SetIntCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, PlatformInt64 value
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_SetIntCopyWithImpl<$Res>
+    implements $MetaOp_SetIntCopyWith<$Res> {
+  _$MetaOp_SetIntCopyWithImpl(this._self, this._then);
+
+  final MetaOp_SetInt _self;
+  final $Res Function(MetaOp_SetInt) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? value = null,}) {
+  return _then(MetaOp_SetInt(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetaOp_SetBool extends MetaOp {
+  const MetaOp_SetBool({required this.key, required this.value}): super._();
+  
+
+@override final  String key;
+ final  bool value;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_SetBoolCopyWith<MetaOp_SetBool> get copyWith => _$MetaOp_SetBoolCopyWithImpl<MetaOp_SetBool>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_SetBool&&(identical(other.key, key) || other.key == key)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,value);
+
+@override
+String toString() {
+  return 'MetaOp.setBool(key: $key, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_SetBoolCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_SetBoolCopyWith(MetaOp_SetBool value, $Res Function(MetaOp_SetBool) _then) = _$MetaOp_SetBoolCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, bool value
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_SetBoolCopyWithImpl<$Res>
+    implements $MetaOp_SetBoolCopyWith<$Res> {
+  _$MetaOp_SetBoolCopyWithImpl(this._self, this._then);
+
+  final MetaOp_SetBool _self;
+  final $Res Function(MetaOp_SetBool) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? value = null,}) {
+  return _then(MetaOp_SetBool(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetaOp_SetStringArray extends MetaOp {
+  const MetaOp_SetStringArray({required this.key, required final  List<String> values}): _values = values,super._();
+  
+
+@override final  String key;
+ final  List<String> _values;
+ List<String> get values {
+  if (_values is EqualUnmodifiableListView) return _values;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_values);
+}
+
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_SetStringArrayCopyWith<MetaOp_SetStringArray> get copyWith => _$MetaOp_SetStringArrayCopyWithImpl<MetaOp_SetStringArray>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_SetStringArray&&(identical(other.key, key) || other.key == key)&&const DeepCollectionEquality().equals(other._values, _values));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,const DeepCollectionEquality().hash(_values));
+
+@override
+String toString() {
+  return 'MetaOp.setStringArray(key: $key, values: $values)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_SetStringArrayCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_SetStringArrayCopyWith(MetaOp_SetStringArray value, $Res Function(MetaOp_SetStringArray) _then) = _$MetaOp_SetStringArrayCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, List<String> values
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_SetStringArrayCopyWithImpl<$Res>
+    implements $MetaOp_SetStringArrayCopyWith<$Res> {
+  _$MetaOp_SetStringArrayCopyWithImpl(this._self, this._then);
+
+  final MetaOp_SetStringArray _self;
+  final $Res Function(MetaOp_SetStringArray) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? values = null,}) {
+  return _then(MetaOp_SetStringArray(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,values: null == values ? _self._values : values // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetaOp_Remove extends MetaOp {
+  const MetaOp_Remove({required this.key}): super._();
+  
+
+@override final  String key;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_RemoveCopyWith<MetaOp_Remove> get copyWith => _$MetaOp_RemoveCopyWithImpl<MetaOp_Remove>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_Remove&&(identical(other.key, key) || other.key == key));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key);
+
+@override
+String toString() {
+  return 'MetaOp.remove(key: $key)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_RemoveCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_RemoveCopyWith(MetaOp_Remove value, $Res Function(MetaOp_Remove) _then) = _$MetaOp_RemoveCopyWithImpl;
+@override @useResult
+$Res call({
+ String key
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_RemoveCopyWithImpl<$Res>
+    implements $MetaOp_RemoveCopyWith<$Res> {
+  _$MetaOp_RemoveCopyWithImpl(this._self, this._then);
+
+  final MetaOp_Remove _self;
+  final $Res Function(MetaOp_Remove) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,}) {
+  return _then(MetaOp_Remove(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetaOp_PushArrayItem extends MetaOp {
+  const MetaOp_PushArrayItem({required this.key, required this.value}): super._();
+  
+
+@override final  String key;
+ final  String value;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_PushArrayItemCopyWith<MetaOp_PushArrayItem> get copyWith => _$MetaOp_PushArrayItemCopyWithImpl<MetaOp_PushArrayItem>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_PushArrayItem&&(identical(other.key, key) || other.key == key)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,value);
+
+@override
+String toString() {
+  return 'MetaOp.pushArrayItem(key: $key, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_PushArrayItemCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_PushArrayItemCopyWith(MetaOp_PushArrayItem value, $Res Function(MetaOp_PushArrayItem) _then) = _$MetaOp_PushArrayItemCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, String value
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_PushArrayItemCopyWithImpl<$Res>
+    implements $MetaOp_PushArrayItemCopyWith<$Res> {
+  _$MetaOp_PushArrayItemCopyWithImpl(this._self, this._then);
+
+  final MetaOp_PushArrayItem _self;
+  final $Res Function(MetaOp_PushArrayItem) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? value = null,}) {
+  return _then(MetaOp_PushArrayItem(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetaOp_RemoveArrayItem extends MetaOp {
+  const MetaOp_RemoveArrayItem({required this.key, required this.value}): super._();
+  
+
+@override final  String key;
+ final  String value;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetaOp_RemoveArrayItemCopyWith<MetaOp_RemoveArrayItem> get copyWith => _$MetaOp_RemoveArrayItemCopyWithImpl<MetaOp_RemoveArrayItem>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetaOp_RemoveArrayItem&&(identical(other.key, key) || other.key == key)&&(identical(other.value, value) || other.value == value));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,key,value);
+
+@override
+String toString() {
+  return 'MetaOp.removeArrayItem(key: $key, value: $value)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetaOp_RemoveArrayItemCopyWith<$Res> implements $MetaOpCopyWith<$Res> {
+  factory $MetaOp_RemoveArrayItemCopyWith(MetaOp_RemoveArrayItem value, $Res Function(MetaOp_RemoveArrayItem) _then) = _$MetaOp_RemoveArrayItemCopyWithImpl;
+@override @useResult
+$Res call({
+ String key, String value
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetaOp_RemoveArrayItemCopyWithImpl<$Res>
+    implements $MetaOp_RemoveArrayItemCopyWith<$Res> {
+  _$MetaOp_RemoveArrayItemCopyWithImpl(this._self, this._then);
+
+  final MetaOp_RemoveArrayItem _self;
+  final $Res Function(MetaOp_RemoveArrayItem) _then;
+
+/// Create a copy of MetaOp
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? key = null,Object? value = null,}) {
+  return _then(MetaOp_RemoveArrayItem(
+key: null == key ? _self.key : key // ignore: cast_nullable_to_non_nullable
+as String,value: null == value ? _self.value : value // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$OrderedBlock {
+
+ BlockDoc get block; int get depth;
+/// Create a copy of OrderedBlock
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$BlockActionDocCopyWith<BlockActionDoc> get copyWith => _$BlockActionDocCopyWithImpl<BlockActionDoc>(this as BlockActionDoc, _$identity);
+$OrderedBlockCopyWith<OrderedBlock> get copyWith => _$OrderedBlockCopyWithImpl<OrderedBlock>(this as OrderedBlock, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is BlockActionDoc&&(identical(other.action, action) || other.action == action)&&(identical(other.block, block) || other.block == block)&&const DeepCollectionEquality().equals(other.path, path)&&const DeepCollectionEquality().equals(other.oldPath, oldPath));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OrderedBlock&&(identical(other.block, block) || other.block == block)&&(identical(other.depth, depth) || other.depth == depth));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,action,block,const DeepCollectionEquality().hash(path),const DeepCollectionEquality().hash(oldPath));
+int get hashCode => Object.hash(runtimeType,block,depth);
 
 @override
 String toString() {
-  return 'BlockActionDoc(action: $action, block: $block, path: $path, oldPath: $oldPath)';
+  return 'OrderedBlock(block: $block, depth: $depth)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $BlockActionDocCopyWith<$Res>  {
-  factory $BlockActionDocCopyWith(BlockActionDoc value, $Res Function(BlockActionDoc) _then) = _$BlockActionDocCopyWithImpl;
+abstract mixin class $OrderedBlockCopyWith<$Res>  {
+  factory $OrderedBlockCopyWith(OrderedBlock value, $Res Function(OrderedBlock) _then) = _$OrderedBlockCopyWithImpl;
 @useResult
 $Res call({
- BlockActionTypeDoc action, BlockDoc block, Uint32List path, Uint32List? oldPath
+ BlockDoc block, int depth
 });
 
 
@@ -53,25 +4268,23 @@ $BlockDocCopyWith<$Res> get block;
 
 }
 /// @nodoc
-class _$BlockActionDocCopyWithImpl<$Res>
-    implements $BlockActionDocCopyWith<$Res> {
-  _$BlockActionDocCopyWithImpl(this._self, this._then);
+class _$OrderedBlockCopyWithImpl<$Res>
+    implements $OrderedBlockCopyWith<$Res> {
+  _$OrderedBlockCopyWithImpl(this._self, this._then);
 
-  final BlockActionDoc _self;
-  final $Res Function(BlockActionDoc) _then;
+  final OrderedBlock _self;
+  final $Res Function(OrderedBlock) _then;
 
-/// Create a copy of BlockActionDoc
+/// Create a copy of OrderedBlock
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? action = null,Object? block = null,Object? path = null,Object? oldPath = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? block = null,Object? depth = null,}) {
   return _then(_self.copyWith(
-action: null == action ? _self.action : action // ignore: cast_nullable_to_non_nullable
-as BlockActionTypeDoc,block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
-as BlockDoc,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
-as Uint32List,oldPath: freezed == oldPath ? _self.oldPath : oldPath // ignore: cast_nullable_to_non_nullable
-as Uint32List?,
+block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
+as BlockDoc,depth: null == depth ? _self.depth : depth // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
-/// Create a copy of BlockActionDoc
+/// Create a copy of OrderedBlock
 /// with the given fields replaced by the non-null parameter values.
 @override
 @pragma('vm:prefer-inline')
@@ -84,8 +4297,8 @@ $BlockDocCopyWith<$Res> get block {
 }
 
 
-/// Adds pattern-matching-related methods to [BlockActionDoc].
-extension BlockActionDocPatterns on BlockActionDoc {
+/// Adds pattern-matching-related methods to [OrderedBlock].
+extension OrderedBlockPatterns on OrderedBlock {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -98,10 +4311,10 @@ extension BlockActionDocPatterns on BlockActionDoc {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BlockActionDoc value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _OrderedBlock value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _BlockActionDoc() when $default != null:
+case _OrderedBlock() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -120,10 +4333,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BlockActionDoc value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _OrderedBlock value)  $default,){
 final _that = this;
 switch (_that) {
-case _BlockActionDoc():
+case _OrderedBlock():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -138,10 +4351,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BlockActionDoc value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _OrderedBlock value)?  $default,){
 final _that = this;
 switch (_that) {
-case _BlockActionDoc() when $default != null:
+case _OrderedBlock() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -159,10 +4372,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BlockActionTypeDoc action,  BlockDoc block,  Uint32List path,  Uint32List? oldPath)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( BlockDoc block,  int depth)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _BlockActionDoc() when $default != null:
-return $default(_that.action,_that.block,_that.path,_that.oldPath);case _:
+case _OrderedBlock() when $default != null:
+return $default(_that.block,_that.depth);case _:
   return orElse();
 
 }
@@ -180,10 +4393,10 @@ return $default(_that.action,_that.block,_that.path,_that.oldPath);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BlockActionTypeDoc action,  BlockDoc block,  Uint32List path,  Uint32List? oldPath)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( BlockDoc block,  int depth)  $default,) {final _that = this;
 switch (_that) {
-case _BlockActionDoc():
-return $default(_that.action,_that.block,_that.path,_that.oldPath);}
+case _OrderedBlock():
+return $default(_that.block,_that.depth);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -197,10 +4410,10 @@ return $default(_that.action,_that.block,_that.path,_that.oldPath);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BlockActionTypeDoc action,  BlockDoc block,  Uint32List path,  Uint32List? oldPath)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( BlockDoc block,  int depth)?  $default,) {final _that = this;
 switch (_that) {
-case _BlockActionDoc() when $default != null:
-return $default(_that.action,_that.block,_that.path,_that.oldPath);case _:
+case _OrderedBlock() when $default != null:
+return $default(_that.block,_that.depth);case _:
   return null;
 
 }
@@ -211,46 +4424,44 @@ return $default(_that.action,_that.block,_that.path,_that.oldPath);case _:
 /// @nodoc
 
 
-class _BlockActionDoc implements BlockActionDoc {
-  const _BlockActionDoc({required this.action, required this.block, required this.path, this.oldPath});
+class _OrderedBlock implements OrderedBlock {
+  const _OrderedBlock({required this.block, required this.depth});
   
 
-@override final  BlockActionTypeDoc action;
 @override final  BlockDoc block;
-@override final  Uint32List path;
-@override final  Uint32List? oldPath;
+@override final  int depth;
 
-/// Create a copy of BlockActionDoc
+/// Create a copy of OrderedBlock
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$BlockActionDocCopyWith<_BlockActionDoc> get copyWith => __$BlockActionDocCopyWithImpl<_BlockActionDoc>(this, _$identity);
+_$OrderedBlockCopyWith<_OrderedBlock> get copyWith => __$OrderedBlockCopyWithImpl<_OrderedBlock>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BlockActionDoc&&(identical(other.action, action) || other.action == action)&&(identical(other.block, block) || other.block == block)&&const DeepCollectionEquality().equals(other.path, path)&&const DeepCollectionEquality().equals(other.oldPath, oldPath));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _OrderedBlock&&(identical(other.block, block) || other.block == block)&&(identical(other.depth, depth) || other.depth == depth));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,action,block,const DeepCollectionEquality().hash(path),const DeepCollectionEquality().hash(oldPath));
+int get hashCode => Object.hash(runtimeType,block,depth);
 
 @override
 String toString() {
-  return 'BlockActionDoc(action: $action, block: $block, path: $path, oldPath: $oldPath)';
+  return 'OrderedBlock(block: $block, depth: $depth)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$BlockActionDocCopyWith<$Res> implements $BlockActionDocCopyWith<$Res> {
-  factory _$BlockActionDocCopyWith(_BlockActionDoc value, $Res Function(_BlockActionDoc) _then) = __$BlockActionDocCopyWithImpl;
+abstract mixin class _$OrderedBlockCopyWith<$Res> implements $OrderedBlockCopyWith<$Res> {
+  factory _$OrderedBlockCopyWith(_OrderedBlock value, $Res Function(_OrderedBlock) _then) = __$OrderedBlockCopyWithImpl;
 @override @useResult
 $Res call({
- BlockActionTypeDoc action, BlockDoc block, Uint32List path, Uint32List? oldPath
+ BlockDoc block, int depth
 });
 
 
@@ -258,26 +4469,24 @@ $Res call({
 
 }
 /// @nodoc
-class __$BlockActionDocCopyWithImpl<$Res>
-    implements _$BlockActionDocCopyWith<$Res> {
-  __$BlockActionDocCopyWithImpl(this._self, this._then);
+class __$OrderedBlockCopyWithImpl<$Res>
+    implements _$OrderedBlockCopyWith<$Res> {
+  __$OrderedBlockCopyWithImpl(this._self, this._then);
 
-  final _BlockActionDoc _self;
-  final $Res Function(_BlockActionDoc) _then;
+  final _OrderedBlock _self;
+  final $Res Function(_OrderedBlock) _then;
 
-/// Create a copy of BlockActionDoc
+/// Create a copy of OrderedBlock
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? action = null,Object? block = null,Object? path = null,Object? oldPath = freezed,}) {
-  return _then(_BlockActionDoc(
-action: null == action ? _self.action : action // ignore: cast_nullable_to_non_nullable
-as BlockActionTypeDoc,block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
-as BlockDoc,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
-as Uint32List,oldPath: freezed == oldPath ? _self.oldPath : oldPath // ignore: cast_nullable_to_non_nullable
-as Uint32List?,
+@override @pragma('vm:prefer-inline') $Res call({Object? block = null,Object? depth = null,}) {
+  return _then(_OrderedBlock(
+block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
+as BlockDoc,depth: null == depth ? _self.depth : depth // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
-/// Create a copy of BlockActionDoc
+/// Create a copy of OrderedBlock
 /// with the given fields replaced by the non-null parameter values.
 @override
 @pragma('vm:prefer-inline')
@@ -290,40 +4499,40 @@ $BlockDocCopyWith<$Res> get block {
 }
 
 /// @nodoc
-mixin _$BlockDoc {
+mixin _$OriginUpdate {
 
- String get id; String get ty; Map<String, String> get attributes; String? get delta; String? get parentId; String? get prevId; String? get nextId; String? get oldParentId;
-/// Create a copy of BlockDoc
+ Uint8List get update; String? get origin;
+/// Create a copy of OriginUpdate
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$BlockDocCopyWith<BlockDoc> get copyWith => _$BlockDocCopyWithImpl<BlockDoc>(this as BlockDoc, _$identity);
+$OriginUpdateCopyWith<OriginUpdate> get copyWith => _$OriginUpdateCopyWithImpl<OriginUpdate>(this as OriginUpdate, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is BlockDoc&&(identical(other.id, id) || other.id == id)&&(identical(other.ty, ty) || other.ty == ty)&&const DeepCollectionEquality().equals(other.attributes, attributes)&&(identical(other.delta, delta) || other.delta == delta)&&(identical(other.parentId, parentId) || other.parentId == parentId)&&(identical(other.prevId, prevId) || other.prevId == prevId)&&(identical(other.nextId, nextId) || other.nextId == nextId)&&(identical(other.oldParentId, oldParentId) || other.oldParentId == oldParentId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OriginUpdate&&const DeepCollectionEquality().equals(other.update, update)&&(identical(other.origin, origin) || other.origin == origin));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,ty,const DeepCollectionEquality().hash(attributes),delta,parentId,prevId,nextId,oldParentId);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(update),origin);
 
 @override
 String toString() {
-  return 'BlockDoc(id: $id, ty: $ty, attributes: $attributes, delta: $delta, parentId: $parentId, prevId: $prevId, nextId: $nextId, oldParentId: $oldParentId)';
+  return 'OriginUpdate(update: $update, origin: $origin)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $BlockDocCopyWith<$Res>  {
-  factory $BlockDocCopyWith(BlockDoc value, $Res Function(BlockDoc) _then) = _$BlockDocCopyWithImpl;
+abstract mixin class $OriginUpdateCopyWith<$Res>  {
+  factory $OriginUpdateCopyWith(OriginUpdate value, $Res Function(OriginUpdate) _then) = _$OriginUpdateCopyWithImpl;
 @useResult
 $Res call({
- String id, String ty, Map<String, String> attributes, String? delta, String? parentId, String? prevId, String? nextId, String? oldParentId
+ Uint8List update, String? origin
 });
 
 
@@ -331,25 +4540,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$BlockDocCopyWithImpl<$Res>
-    implements $BlockDocCopyWith<$Res> {
-  _$BlockDocCopyWithImpl(this._self, this._then);
+class _$OriginUpdateCopyWithImpl<$Res>
+    implements $OriginUpdateCopyWith<$Res> {
+  _$OriginUpdateCopyWithImpl(this._self, this._then);
 
-  final BlockDoc _self;
-  final $Res Function(BlockDoc) _then;
+  final OriginUpdate _self;
+  final $Res Function(OriginUpdate) _then;
 
-/// Create a copy of BlockDoc
+/// Create a copy of OriginUpdate
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? id = null,Object? ty = null,Object? attributes = null,Object? delta = freezed,Object? parentId = freezed,Object? prevId = freezed,Object? nextId = freezed,Object? oldParentId = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? update = null,Object? origin = freezed,}) {
   return _then(_self.copyWith(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,ty: null == ty ? _self.ty : ty // ignore: cast_nullable_to_non_nullable
-as String,attributes: null == attributes ? _self.attributes : attributes // ignore: cast_nullable_to_non_nullable
-as Map<String, String>,delta: freezed == delta ? _self.delta : delta // ignore: cast_nullable_to_non_nullable
-as String?,parentId: freezed == parentId ? _self.parentId : parentId // ignore: cast_nullable_to_non_nullable
-as String?,prevId: freezed == prevId ? _self.prevId : prevId // ignore: cast_nullable_to_non_nullable
-as String?,nextId: freezed == nextId ? _self.nextId : nextId // ignore: cast_nullable_to_non_nullable
-as String?,oldParentId: freezed == oldParentId ? _self.oldParentId : oldParentId // ignore: cast_nullable_to_non_nullable
+update: null == update ? _self.update : update // ignore: cast_nullable_to_non_nullable
+as Uint8List,origin: freezed == origin ? _self.origin : origin // ignore: cast_nullable_to_non_nullable
 as String?,
   ));
 }
@@ -357,8 +4560,8 @@ as String?,
 }
 
 
-/// Adds pattern-matching-related methods to [BlockDoc].
-extension BlockDocPatterns on BlockDoc {
+/// Adds pattern-matching-related methods to [OriginUpdate].
+extension OriginUpdatePatterns on OriginUpdate {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -371,10 +4574,10 @@ extension BlockDocPatterns on BlockDoc {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BlockDoc value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _OriginUpdate value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _BlockDoc() when $default != null:
+case _OriginUpdate() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -393,10 +4596,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BlockDoc value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _OriginUpdate value)  $default,){
 final _that = this;
 switch (_that) {
-case _BlockDoc():
+case _OriginUpdate():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -411,10 +4614,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BlockDoc value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _OriginUpdate value)?  $default,){
 final _that = this;
 switch (_that) {
-case _BlockDoc() when $default != null:
+case _OriginUpdate() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -432,10 +4635,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String id,  String ty,  Map<String, String> attributes,  String? delta,  String? parentId,  String? prevId,  String? nextId,  String? oldParentId)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( Uint8List update,  String? origin)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _BlockDoc() when $default != null:
-return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_that.prevId,_that.nextId,_that.oldParentId);case _:
+case _OriginUpdate() when $default != null:
+return $default(_that.update,_that.origin);case _:
   return orElse();
 
 }
@@ -453,10 +4656,10 @@ return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_t
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String id,  String ty,  Map<String, String> attributes,  String? delta,  String? parentId,  String? prevId,  String? nextId,  String? oldParentId)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( Uint8List update,  String? origin)  $default,) {final _that = this;
 switch (_that) {
-case _BlockDoc():
-return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_that.prevId,_that.nextId,_that.oldParentId);}
+case _OriginUpdate():
+return $default(_that.update,_that.origin);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -470,10 +4673,10 @@ return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_t
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String id,  String ty,  Map<String, String> attributes,  String? delta,  String? parentId,  String? prevId,  String? nextId,  String? oldParentId)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( Uint8List update,  String? origin)?  $default,) {final _that = this;
 switch (_that) {
-case _BlockDoc() when $default != null:
-return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_that.prevId,_that.nextId,_that.oldParentId);case _:
+case _OriginUpdate() when $default != null:
+return $default(_that.update,_that.origin);case _:
   return null;
 
 }
@@ -484,56 +4687,44 @@ return $default(_that.id,_that.ty,_that.attributes,_that.delta,_that.parentId,_t
 /// @nodoc
 
 
-class _BlockDoc implements BlockDoc {
-  const _BlockDoc({required this.id, required this.ty, required final  Map<String, String> attributes, this.delta, this.parentId, this.prevId, this.nextId, this.oldParentId}): _attributes = attributes;
+class _OriginUpdate implements OriginUpdate {
+  const _OriginUpdate({required this.update, this.origin});
   
 
-@override final  String id;
-@override final  String ty;
- final  Map<String, String> _attributes;
-@override Map<String, String> get attributes {
-  if (_attributes is EqualUnmodifiableMapView) return _attributes;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableMapView(_attributes);
-}
-
-@override final  String? delta;
-@override final  String? parentId;
-@override final  String? prevId;
-@override final  String? nextId;
-@override final  String? oldParentId;
+@override final  Uint8List update;
+@override final  String? origin;
 
-/// Create a copy of BlockDoc
+/// Create a copy of OriginUpdate
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$BlockDocCopyWith<_BlockDoc> get copyWith => __$BlockDocCopyWithImpl<_BlockDoc>(this, _$identity);
+_$OriginUpdateCopyWith<_OriginUpdate> get copyWith => __$OriginUpdateCopyWithImpl<_OriginUpdate>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BlockDoc&&(identical(other.id, id) || other.id == id)&&(identical(other.ty, ty) || other.ty == ty)&&const DeepCollectionEquality().equals(other._attributes, _attributes)&&(identical(other.delta, delta) || other.delta == delta)&&(identical(other.parentId, parentId) || other.parentId == parentId)&&(identical(other.prevId, prevId) || other.prevId == prevId)&&(identical(other.nextId, nextId) || other.nextId == nextId)&&(identical(other.oldParentId, oldParentId) || other.oldParentId == oldParentId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _OriginUpdate&&const DeepCollectionEquality().equals(other.update, update)&&(identical(other.origin, origin) || other.origin == origin));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,id,ty,const DeepCollectionEquality().hash(_attributes),delta,parentId,prevId,nextId,oldParentId);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(update),origin);
 
 @override
 String toString() {
-  return 'BlockDoc(id: $id, ty: $ty, attributes: $attributes, delta: $delta, parentId: $parentId, prevId: $prevId, nextId: $nextId, oldParentId: $oldParentId)';
+  return 'OriginUpdate(update: $update, origin: $origin)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$BlockDocCopyWith<$Res> implements $BlockDocCopyWith<$Res> {
-  factory _$BlockDocCopyWith(_BlockDoc value, $Res Function(_BlockDoc) _then) = __$BlockDocCopyWithImpl;
+abstract mixin class _$OriginUpdateCopyWith<$Res> implements $OriginUpdateCopyWith<$Res> {
+  factory _$OriginUpdateCopyWith(_OriginUpdate value, $Res Function(_OriginUpdate) _then) = __$OriginUpdateCopyWithImpl;
 @override @useResult
 $Res call({
- String id, String ty, Map<String, String> attributes, String? delta, String? parentId, String? prevId, String? nextId, String? oldParentId
+ Uint8List update, String? origin
 });
 
 
@@ -541,25 +4732,19 @@ $Res call({
 
 }
 /// @nodoc
-class __$BlockDocCopyWithImpl<$Res>
-    implements _$BlockDocCopyWith<$Res> {
-  __$BlockDocCopyWithImpl(this._self, this._then);
+class __$OriginUpdateCopyWithImpl<$Res>
+    implements _$OriginUpdateCopyWith<$Res> {
+  __$OriginUpdateCopyWithImpl(this._self, this._then);
 
-  final _BlockDoc _self;
-  final $Res Function(_BlockDoc) _then;
+  final _OriginUpdate _self;
+  final $Res Function(_OriginUpdate) _then;
 
-/// Create a copy of BlockDoc
+/// Create a copy of OriginUpdate
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? ty = null,Object? attributes = null,Object? delta = freezed,Object? parentId = freezed,Object? prevId = freezed,Object? nextId = freezed,Object? oldParentId = freezed,}) {
-  return _then(_BlockDoc(
-id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
-as String,ty: null == ty ? _self.ty : ty // ignore: cast_nullable_to_non_nullable
-as String,attributes: null == attributes ? _self._attributes : attributes // ignore: cast_nullable_to_non_nullable
-as Map<String, String>,delta: freezed == delta ? _self.delta : delta // ignore: cast_nullable_to_non_nullable
-as String?,parentId: freezed == parentId ? _self.parentId : parentId // ignore: cast_nullable_to_non_nullable
-as String?,prevId: freezed == prevId ? _self.prevId : prevId // ignore: cast_nullable_to_non_nullable
-as String?,nextId: freezed == nextId ? _self.nextId : nextId // ignore: cast_nullable_to_non_nullable
-as String?,oldParentId: freezed == oldParentId ? _self.oldParentId : oldParentId // ignore: cast_nullable_to_non_nullable
+@override @pragma('vm:prefer-inline') $Res call({Object? update = null,Object? origin = freezed,}) {
+  return _then(_OriginUpdate(
+update: null == update ? _self.update : update // ignore: cast_nullable_to_non_nullable
+as Uint8List,origin: freezed == origin ? _self.origin : origin // ignore: cast_nullable_to_non_nullable
 as String?,
   ));
 }
@@ -568,40 +4753,40 @@ as String?,
 }
 
 /// @nodoc
-mixin _$DocumentState {
+mixin _$RelativePos {
 
- String get docId; Map<String, BlockDoc> get blocks; Map<String, List<String>> get childrenMap; String get rootId;
-/// Create a copy of DocumentState
+ String get blockId; int get offset;
+/// Create a copy of RelativePos
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$DocumentStateCopyWith<DocumentState> get copyWith => _$DocumentStateCopyWithImpl<DocumentState>(this as DocumentState, _$identity);
+$RelativePosCopyWith<RelativePos> get copyWith => _$RelativePosCopyWithImpl<RelativePos>(this as RelativePos, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is DocumentState&&(identical(other.docId, docId) || other.docId == docId)&&const DeepCollectionEquality().equals(other.blocks, blocks)&&const DeepCollectionEquality().equals(other.childrenMap, childrenMap)&&(identical(other.rootId, rootId) || other.rootId == rootId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RelativePos&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.offset, offset) || other.offset == offset));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,docId,const DeepCollectionEquality().hash(blocks),const DeepCollectionEquality().hash(childrenMap),rootId);
+int get hashCode => Object.hash(runtimeType,blockId,offset);
 
 @override
 String toString() {
-  return 'DocumentState(docId: $docId, blocks: $blocks, childrenMap: $childrenMap, rootId: $rootId)';
+  return 'RelativePos(blockId: $blockId, offset: $offset)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $DocumentStateCopyWith<$Res>  {
-  factory $DocumentStateCopyWith(DocumentState value, $Res Function(DocumentState) _then) = _$DocumentStateCopyWithImpl;
+abstract mixin class $RelativePosCopyWith<$Res>  {
+  factory $RelativePosCopyWith(RelativePos value, $Res Function(RelativePos) _then) = _$RelativePosCopyWithImpl;
 @useResult
 $Res call({
- String docId, Map<String, BlockDoc> blocks, Map<String, List<String>> childrenMap, String rootId
+ String blockId, int offset
 });
 
 
@@ -609,30 +4794,28 @@ $Res call({
 
 }
 /// @nodoc
-class _$DocumentStateCopyWithImpl<$Res>
-    implements $DocumentStateCopyWith<$Res> {
-  _$DocumentStateCopyWithImpl(this._self, this._then);
+class _$RelativePosCopyWithImpl<$Res>
+    implements $RelativePosCopyWith<$Res> {
+  _$RelativePosCopyWithImpl(this._self, this._then);
 
-  final DocumentState _self;
-  final $Res Function(DocumentState) _then;
+  final RelativePos _self;
+  final $Res Function(RelativePos) _then;
 
-/// Create a copy of DocumentState
+/// Create a copy of RelativePos
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? docId = null,Object? blocks = null,Object? childrenMap = null,Object? rootId = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? blockId = null,Object? offset = null,}) {
   return _then(_self.copyWith(
-docId: null == docId ? _self.docId : docId // ignore: cast_nullable_to_non_nullable
-as String,blocks: null == blocks ? _self.blocks : blocks // ignore: cast_nullable_to_non_nullable
-as Map<String, BlockDoc>,childrenMap: null == childrenMap ? _self.childrenMap : childrenMap // ignore: cast_nullable_to_non_nullable
-as Map<String, List<String>>,rootId: null == rootId ? _self.rootId : rootId // ignore: cast_nullable_to_non_nullable
-as String,
+blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [DocumentState].
-extension DocumentStatePatterns on DocumentState {
+/// Adds pattern-matching-related methods to [RelativePos].
+extension RelativePosPatterns on RelativePos {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -645,10 +4828,10 @@ extension DocumentStatePatterns on DocumentState {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _DocumentState value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _RelativePos value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _DocumentState() when $default != null:
+case _RelativePos() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -667,10 +4850,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _DocumentState value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _RelativePos value)  $default,){
 final _that = this;
 switch (_that) {
-case _DocumentState():
+case _RelativePos():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -685,10 +4868,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _DocumentState value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _RelativePos value)?  $default,){
 final _that = this;
 switch (_that) {
-case _DocumentState() when $default != null:
+case _RelativePos() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -706,10 +4889,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String docId,  Map<String, BlockDoc> blocks,  Map<String, List<String>> childrenMap,  String rootId)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String blockId,  int offset)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _DocumentState() when $default != null:
-return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);case _:
+case _RelativePos() when $default != null:
+return $default(_that.blockId,_that.offset);case _:
   return orElse();
 
 }
@@ -727,10 +4910,10 @@ return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String docId,  Map<String, BlockDoc> blocks,  Map<String, List<String>> childrenMap,  String rootId)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String blockId,  int offset)  $default,) {final _that = this;
 switch (_that) {
-case _DocumentState():
-return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);}
+case _RelativePos():
+return $default(_that.blockId,_that.offset);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -744,10 +4927,10 @@ return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String docId,  Map<String, BlockDoc> blocks,  Map<String, List<String>> childrenMap,  String rootId)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String blockId,  int offset)?  $default,) {final _that = this;
 switch (_that) {
-case _DocumentState() when $default != null:
-return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);case _:
+case _RelativePos() when $default != null:
+return $default(_that.blockId,_that.offset);case _:
   return null;
 
 }
@@ -758,58 +4941,44 @@ return $default(_that.docId,_that.blocks,_that.childrenMap,_that.rootId);case _:
 /// @nodoc
 
 
-class _DocumentState implements DocumentState {
-  const _DocumentState({required this.docId, required final  Map<String, BlockDoc> blocks, required final  Map<String, List<String>> childrenMap, required this.rootId}): _blocks = blocks,_childrenMap = childrenMap;
+class _RelativePos implements RelativePos {
+  const _RelativePos({required this.blockId, required this.offset});
   
 
-@override final  String docId;
- final  Map<String, BlockDoc> _blocks;
-@override Map<String, BlockDoc> get blocks {
-  if (_blocks is EqualUnmodifiableMapView) return _blocks;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableMapView(_blocks);
-}
-
- final  Map<String, List<String>> _childrenMap;
-@override Map<String, List<String>> get childrenMap {
-  if (_childrenMap is EqualUnmodifiableMapView) return _childrenMap;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableMapView(_childrenMap);
-}
-
-@override final  String rootId;
+@override final  String blockId;
+@override final  int offset;
 
-/// Create a copy of DocumentState
+/// Create a copy of RelativePos
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$DocumentStateCopyWith<_DocumentState> get copyWith => __$DocumentStateCopyWithImpl<_DocumentState>(this, _$identity);
+_$RelativePosCopyWith<_RelativePos> get copyWith => __$RelativePosCopyWithImpl<_RelativePos>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _DocumentState&&(identical(other.docId, docId) || other.docId == docId)&&const DeepCollectionEquality().equals(other._blocks, _blocks)&&const DeepCollectionEquality().equals(other._childrenMap, _childrenMap)&&(identical(other.rootId, rootId) || other.rootId == rootId));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _RelativePos&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.offset, offset) || other.offset == offset));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,docId,const DeepCollectionEquality().hash(_blocks),const DeepCollectionEquality().hash(_childrenMap),rootId);
+int get hashCode => Object.hash(runtimeType,blockId,offset);
 
 @override
 String toString() {
-  return 'DocumentState(docId: $docId, blocks: $blocks, childrenMap: $childrenMap, rootId: $rootId)';
+  return 'RelativePos(blockId: $blockId, offset: $offset)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$DocumentStateCopyWith<$Res> implements $DocumentStateCopyWith<$Res> {
-  factory _$DocumentStateCopyWith(_DocumentState value, $Res Function(_DocumentState) _then) = __$DocumentStateCopyWithImpl;
+abstract mixin class _$RelativePosCopyWith<$Res> implements $RelativePosCopyWith<$Res> {
+  factory _$RelativePosCopyWith(_RelativePos value, $Res Function(_RelativePos) _then) = __$RelativePosCopyWithImpl;
 @override @useResult
 $Res call({
- String docId, Map<String, BlockDoc> blocks, Map<String, List<String>> childrenMap, String rootId
+ String blockId, int offset
 });
 
 
@@ -817,22 +4986,20 @@ $Res call({
 
 }
 /// @nodoc
-class __$DocumentStateCopyWithImpl<$Res>
-    implements _$DocumentStateCopyWith<$Res> {
-  __$DocumentStateCopyWithImpl(this._self, this._then);
+class __$RelativePosCopyWithImpl<$Res>
+    implements _$RelativePosCopyWith<$Res> {
+  __$RelativePosCopyWithImpl(this._self, this._then);
 
-  final _DocumentState _self;
-  final $Res Function(_DocumentState) _then;
+  final _RelativePos _self;
+  final $Res Function(_RelativePos) _then;
 
-/// Create a copy of DocumentState
+/// Create a copy of RelativePos
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? docId = null,Object? blocks = null,Object? childrenMap = null,Object? rootId = null,}) {
-  return _then(_DocumentState(
-docId: null == docId ? _self.docId : docId // ignore: cast_nullable_to_non_nullable
-as String,blocks: null == blocks ? _self._blocks : blocks // ignore: cast_nullable_to_non_nullable
-as Map<String, BlockDoc>,childrenMap: null == childrenMap ? _self._childrenMap : childrenMap // ignore: cast_nullable_to_non_nullable
-as Map<String, List<String>>,rootId: null == rootId ? _self.rootId : rootId // ignore: cast_nullable_to_non_nullable
-as String,
+@override @pragma('vm:prefer-inline') $Res call({Object? blockId = null,Object? offset = null,}) {
+  return _then(_RelativePos(
+blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
@@ -840,40 +5007,40 @@ as String,
 }
 
 /// @nodoc
-mixin _$FailedToDecodeUpdates {
+mixin _$TextMatch {
 
- List<String> get failedUpdatesIds;
-/// Create a copy of FailedToDecodeUpdates
+ String get blockId; int get offset; int get length;
+/// Create a copy of TextMatch
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$FailedToDecodeUpdatesCopyWith<FailedToDecodeUpdates> get copyWith => _$FailedToDecodeUpdatesCopyWithImpl<FailedToDecodeUpdates>(this as FailedToDecodeUpdates, _$identity);
+$TextMatchCopyWith<TextMatch> get copyWith => _$TextMatchCopyWithImpl<TextMatch>(this as TextMatch, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is FailedToDecodeUpdates&&const DeepCollectionEquality().equals(other.failedUpdatesIds, failedUpdatesIds));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TextMatch&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.offset, offset) || other.offset == offset)&&(identical(other.length, length) || other.length == length));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(failedUpdatesIds));
+int get hashCode => Object.hash(runtimeType,blockId,offset,length);
 
 @override
 String toString() {
-  return 'FailedToDecodeUpdates(failedUpdatesIds: $failedUpdatesIds)';
+  return 'TextMatch(blockId: $blockId, offset: $offset, length: $length)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $FailedToDecodeUpdatesCopyWith<$Res>  {
-  factory $FailedToDecodeUpdatesCopyWith(FailedToDecodeUpdates value, $Res Function(FailedToDecodeUpdates) _then) = _$FailedToDecodeUpdatesCopyWithImpl;
+abstract mixin class $TextMatchCopyWith<$Res>  {
+  factory $TextMatchCopyWith(TextMatch value, $Res Function(TextMatch) _then) = _$TextMatchCopyWithImpl;
 @useResult
 $Res call({
- List<String> failedUpdatesIds
+ String blockId, int offset, int length
 });
 
 
@@ -881,27 +5048,29 @@ $Res call({
 
 }
 /// @nodoc
-class _$FailedToDecodeUpdatesCopyWithImpl<$Res>
-    implements $FailedToDecodeUpdatesCopyWith<$Res> {
-  _$FailedToDecodeUpdatesCopyWithImpl(this._self, this._then);
+class _$TextMatchCopyWithImpl<$Res>
+    implements $TextMatchCopyWith<$Res> {
+  _$TextMatchCopyWithImpl(this._self, this._then);
 
-  final FailedToDecodeUpdates _self;
-  final $Res Function(FailedToDecodeUpdates) _then;
+  final TextMatch _self;
+  final $Res Function(TextMatch) _then;
 
-/// Create a copy of FailedToDecodeUpdates
+/// Create a copy of TextMatch
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? failedUpdatesIds = null,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? blockId = null,Object? offset = null,Object? length = null,}) {
   return _then(_self.copyWith(
-failedUpdatesIds: null == failedUpdatesIds ? _self.failedUpdatesIds : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
-as List<String>,
+blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as int,length: null == length ? _self.length : length // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
 }
 
 
-/// Adds pattern-matching-related methods to [FailedToDecodeUpdates].
-extension FailedToDecodeUpdatesPatterns on FailedToDecodeUpdates {
+/// Adds pattern-matching-related methods to [TextMatch].
+extension TextMatchPatterns on TextMatch {
 /// A variant of `map` that fallback to returning `orElse`.
 ///
 /// It is equivalent to doing:
@@ -914,10 +5083,10 @@ extension FailedToDecodeUpdatesPatterns on FailedToDecodeUpdates {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _FailedToDecodeUpdates value)?  $default,{required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _TextMatch value)?  $default,{required TResult orElse(),}){
 final _that = this;
 switch (_that) {
-case _FailedToDecodeUpdates() when $default != null:
+case _TextMatch() when $default != null:
 return $default(_that);case _:
   return orElse();
 
@@ -936,10 +5105,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _FailedToDecodeUpdates value)  $default,){
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _TextMatch value)  $default,){
 final _that = this;
 switch (_that) {
-case _FailedToDecodeUpdates():
+case _TextMatch():
 return $default(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
@@ -954,10 +5123,10 @@ return $default(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _FailedToDecodeUpdates value)?  $default,){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _TextMatch value)?  $default,){
 final _that = this;
 switch (_that) {
-case _FailedToDecodeUpdates() when $default != null:
+case _TextMatch() when $default != null:
 return $default(_that);case _:
   return null;
 
@@ -975,10 +5144,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<String> failedUpdatesIds)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( String blockId,  int offset,  int length)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
-case _FailedToDecodeUpdates() when $default != null:
-return $default(_that.failedUpdatesIds);case _:
+case _TextMatch() when $default != null:
+return $default(_that.blockId,_that.offset,_that.length);case _:
   return orElse();
 
 }
@@ -996,10 +5165,10 @@ return $default(_that.failedUpdatesIds);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<String> failedUpdatesIds)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( String blockId,  int offset,  int length)  $default,) {final _that = this;
 switch (_that) {
-case _FailedToDecodeUpdates():
-return $default(_that.failedUpdatesIds);}
+case _TextMatch():
+return $default(_that.blockId,_that.offset,_that.length);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -1013,10 +5182,10 @@ return $default(_that.failedUpdatesIds);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<String> failedUpdatesIds)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( String blockId,  int offset,  int length)?  $default,) {final _that = this;
 switch (_that) {
-case _FailedToDecodeUpdates() when $default != null:
-return $default(_that.failedUpdatesIds);case _:
+case _TextMatch() when $default != null:
+return $default(_that.blockId,_that.offset,_that.length);case _:
   return null;
 
 }
@@ -1027,49 +5196,45 @@ return $default(_that.failedUpdatesIds);case _:
 /// @nodoc
 
 
-class _FailedToDecodeUpdates implements FailedToDecodeUpdates {
-  const _FailedToDecodeUpdates({required final  List<String> failedUpdatesIds}): _failedUpdatesIds = failedUpdatesIds;
+class _TextMatch implements TextMatch {
+  const _TextMatch({required this.blockId, required this.offset, required this.length});
   
 
- final  List<String> _failedUpdatesIds;
-@override List<String> get failedUpdatesIds {
-  if (_failedUpdatesIds is EqualUnmodifiableListView) return _failedUpdatesIds;
-  // ignore: implicit_dynamic_type
-  return EqualUnmodifiableListView(_failedUpdatesIds);
-}
-
+@override final  String blockId;
+@override final  int offset;
+@override final  int length;
 
-/// Create a copy of FailedToDecodeUpdates
+/// Create a copy of TextMatch
 /// with the given fields replaced by the non-null parameter values.
 @override @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-_$FailedToDecodeUpdatesCopyWith<_FailedToDecodeUpdates> get copyWith => __$FailedToDecodeUpdatesCopyWithImpl<_FailedToDecodeUpdates>(this, _$identity);
+_$TextMatchCopyWith<_TextMatch> get copyWith => __$TextMatchCopyWithImpl<_TextMatch>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _FailedToDecodeUpdates&&const DeepCollectionEquality().equals(other._failedUpdatesIds, _failedUpdatesIds));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _TextMatch&&(identical(other.blockId, blockId) || other.blockId == blockId)&&(identical(other.offset, offset) || other.offset == offset)&&(identical(other.length, length) || other.length == length));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_failedUpdatesIds));
+int get hashCode => Object.hash(runtimeType,blockId,offset,length);
 
 @override
 String toString() {
-  return 'FailedToDecodeUpdates(failedUpdatesIds: $failedUpdatesIds)';
+  return 'TextMatch(blockId: $blockId, offset: $offset, length: $length)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class _$FailedToDecodeUpdatesCopyWith<$Res> implements $FailedToDecodeUpdatesCopyWith<$Res> {
-  factory _$FailedToDecodeUpdatesCopyWith(_FailedToDecodeUpdates value, $Res Function(_FailedToDecodeUpdates) _then) = __$FailedToDecodeUpdatesCopyWithImpl;
+abstract mixin class _$TextMatchCopyWith<$Res> implements $TextMatchCopyWith<$Res> {
+  factory _$TextMatchCopyWith(_TextMatch value, $Res Function(_TextMatch) _then) = __$TextMatchCopyWithImpl;
 @override @useResult
 $Res call({
- List<String> failedUpdatesIds
+ String blockId, int offset, int length
 });
 
 
@@ -1077,19 +5242,21 @@ $Res call({
 
 }
 /// @nodoc
-class __$FailedToDecodeUpdatesCopyWithImpl<$Res>
-    implements _$FailedToDecodeUpdatesCopyWith<$Res> {
-  __$FailedToDecodeUpdatesCopyWithImpl(this._self, this._then);
+class __$TextMatchCopyWithImpl<$Res>
+    implements _$TextMatchCopyWith<$Res> {
+  __$TextMatchCopyWithImpl(this._self, this._then);
 
-  final _FailedToDecodeUpdates _self;
-  final $Res Function(_FailedToDecodeUpdates) _then;
+  final _TextMatch _self;
+  final $Res Function(_TextMatch) _then;
 
-/// Create a copy of FailedToDecodeUpdates
+/// Create a copy of TextMatch
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? failedUpdatesIds = null,}) {
-  return _then(_FailedToDecodeUpdates(
-failedUpdatesIds: null == failedUpdatesIds ? _self._failedUpdatesIds : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
-as List<String>,
+@override @pragma('vm:prefer-inline') $Res call({Object? blockId = null,Object? offset = null,Object? length = null,}) {
+  return _then(_TextMatch(
+blockId: null == blockId ? _self.blockId : blockId // ignore: cast_nullable_to_non_nullable
+as String,offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as int,length: null == length ? _self.length : length // ignore: cast_nullable_to_non_nullable
+as int,
   ));
 }
 
@@ -5,6 +5,7 @@
 
 import 'dart:async';
 import 'dart:convert';
+import 'doc/document_registry.dart';
 import 'doc/document_service.dart';
 import 'doc/document_types.dart';
 import 'frb_generated.dart';
@@ -65,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1752453603;
+  int get rustContentHash => 12287034;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -76,1847 +77,10436 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<Uint8List> crateDocDocumentRegistryDocumentRegistryApplyAction({
+    required DocumentRegistry that,
+    required String docId,
+    required List<BlockActionDoc> actions,
+  });
+
+  Future<AppliedBlockChanges>
+  crateDocDocumentRegistryDocumentRegistryApplyUpdates({
+    required DocumentRegistry that,
+    required String docId,
+    required List<Uint8List> updates,
+  });
+
+  Future<bool> crateDocDocumentRegistryDocumentRegistryClose({
+    required DocumentRegistry that,
+    required String docId,
+  });
+
+  Future<Uint8List> crateDocDocumentRegistryDocumentRegistryEncodeFullState({
+    required DocumentRegistry that,
+    required String docId,
+  });
+
+  Future<DocumentState>
+  crateDocDocumentRegistryDocumentRegistryGetDocumentState({
+    required DocumentRegistry that,
+    required String docId,
+  });
+
+  Future<Uint8List> crateDocDocumentRegistryDocumentRegistryInitEmptyDoc({
+    required DocumentRegistry that,
+    required String docId,
+  });
+
+  Future<List<String>> crateDocDocumentRegistryDocumentRegistryListOpen({
+    required DocumentRegistry that,
+  });
+
+  Future<DocumentRegistry> crateDocDocumentRegistryDocumentRegistryNew();
+
+  Future<void> crateDocDocumentRegistryDocumentRegistryOpen({
+    required DocumentRegistry that,
+    required String docId,
+  });
+
+  Future<int> crateDocDocumentServiceDocumentServiceActiveEditorCount({
+    required DocumentService that,
+    required BigInt windowMs,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceAddComment({
+    required DocumentService that,
+    required String blockId,
+    required String commentId,
+    required String author,
+    required String text,
+    int? anchorOffset,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceAdditiveUpdateFor({
+    required DocumentService that,
+    required Uint8List peerStateVector,
+  });
+
   Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyAction({
     required DocumentService that,
     required List<BlockActionDoc> actions,
   });
 
-  Future<void> crateDocDocumentServiceDocumentServiceApplyUpdates({
+  Future<ActionResult>
+  crateDocDocumentServiceDocumentServiceApplyActionWithIds({
     required DocumentService that,
-    required List<Uint8List> updates,
+    required List<BlockActionDoc> actions,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeFullState({
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceApplyActionWithOrigin({
     required DocumentService that,
+    required List<BlockActionDoc> actions,
+    required String origin,
   });
 
-  Future<String> crateDocDocumentServiceDocumentServiceGetAllMeta({
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceApplyActionWithTimestamp({
     required DocumentService that,
+    required List<BlockActionDoc> actions,
+    required PlatformInt64 timestampMs,
   });
 
-  Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
+  Future<String> crateDocDocumentServiceDocumentServiceApplyAwarenessUpdate({
     required DocumentService that,
+    required Uint8List update,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyDelta({
     required DocumentService that,
+    required String blockId,
+    required String deltaJson,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdates({
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyMetaOnlyUpdate({
     required DocumentService that,
-    required List<Uint8List> updates,
+    required Uint8List update,
   });
 
-  Future<DocumentService> crateDocDocumentServiceDocumentServiceNew();
-
-  Future<Uint8List> crateDocDocumentServiceDocumentServicePushMetaArrayItem({
+  Future<void> crateDocDocumentServiceDocumentServiceApplySubdocUpdate({
     required DocumentService that,
-    required String key,
-    required String value,
+    required String guid,
+    required Uint8List update,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaArrayItem({
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyTextOps({
     required DocumentService that,
-    required String key,
-    required String value,
+    required String blockId,
+    required String opsJson,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaKey({
+  Future<bool> crateDocDocumentServiceDocumentServiceApplyUpdate({
     required DocumentService that,
-    required String key,
+    required Uint8List update,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaBool({
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyUpdateRemapped({
     required DocumentService that,
-    required String key,
-    required bool value,
+    required Uint8List update,
+    required String idMapJson,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaFromJson({
+  Future<AppliedBlockChanges>
+  crateDocDocumentServiceDocumentServiceApplyUpdates({
     required DocumentService that,
-    required String jsonStr,
+    required List<Uint8List> updates,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaInt({
+  Future<AppliedBlockChanges>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesV1({
     required DocumentService that,
-    required String key,
-    required PlatformInt64 value,
+    required List<Uint8List> updates,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaString({
+  Future<String> crateDocDocumentServiceDocumentServiceApplyUpdatesWithChanges({
     required DocumentService that,
-    required String key,
-    required String value,
+    required List<Uint8List> updates,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaStringArray({
+  Future<BigInt> crateDocDocumentServiceDocumentServiceAttachmentTotalBytes({
     required DocumentService that,
-    required String key,
-    required List<String> values,
   });
 
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
+  Future<void> crateDocDocumentServiceDocumentServiceBeginBatch({
     required DocumentService that,
-    required String id,
   });
 
-  Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
-    required String message,
+  Future<bool> crateDocDocumentServiceDocumentServiceCanMergeBlocks({
+    required DocumentService that,
+    required String firstId,
+    required String secondId,
   });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DocumentService;
+  Future<bool> crateDocDocumentServiceDocumentServiceCanRedo({
+    required DocumentService that,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DocumentService;
+  Future<bool> crateDocDocumentServiceDocumentServiceCanUndo({
+    required DocumentService that,
+  });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_DocumentServicePtr;
-}
+  Future<String> crateDocDocumentServiceDocumentServiceChildrenOrderHash({
+    required DocumentService that,
+    required String parentId,
+  });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceClearDocument({
+    required DocumentService that,
   });
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyAction({
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceCommitBatch({
     required DocumentService that,
-    required List<BlockActionDoc> actions,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_list_block_action_doc(actions, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceApplyActionConstMeta,
-        argValues: [that, actions],
-        apiImpl: this,
-      ),
-    );
-  }
+  });
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceApplyActionConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_apply_action",
-        argNames: ["that", "actions"],
-      );
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceCompact({
+    required DocumentService that,
+  });
 
-  @override
-  Future<void> crateDocDocumentServiceDocumentServiceApplyUpdates({
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceCompactUpdates({
     required DocumentService that,
     required List<Uint8List> updates,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_list_list_prim_u_8_strict(updates, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta,
-        argValues: [that, updates],
-        apiImpl: this,
-      ),
-    );
-  }
+  });
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_apply_updates",
-        argNames: ["that", "updates"],
-      );
+  Future<String> crateDocDocumentServiceDocumentServiceContentHash({
+    required DocumentService that,
+  });
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeFullState({
+  Future<String> crateDocDocumentServiceDocumentServiceCopyBlocks({
     required DocumentService that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceEncodeFullStateConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
+    required List<String> blockIds,
+  });
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceEncodeFullStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_encode_full_state",
-        argNames: ["that"],
-      );
+  Future<String> crateDocDocumentServiceDocumentServiceCreateSubdoc({
+    required DocumentService that,
+    required String blockId,
+  });
 
-  @override
-  Future<String> crateDocDocumentServiceDocumentServiceGetAllMeta({
+  Future<RelativePos>
+  crateDocDocumentServiceDocumentServiceDecodeRelativePosition({
     required DocumentService that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceGetAllMetaConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
+    required Uint8List relativePosition,
+  });
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceGetAllMetaConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_get_all_meta",
-        argNames: ["that"],
-      );
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceDeleteBlockRecursive({
+    required DocumentService that,
+    required String blockId,
+  });
 
-  @override
-  Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
+  Future<String> crateDocDocumentServiceDocumentServiceDetectLanguage({
     required DocumentService that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
+  });
+
+  Future<List<BlockActionDoc>>
+  crateDocDocumentServiceDocumentServiceDiffStates({
+    required Uint8List oldUpdate,
+    required Uint8List newUpdate,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceDocId({
+    required DocumentService that,
+  });
+
+  Future<DuplicateResult> crateDocDocumentServiceDocumentServiceDuplicateBlock({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceEnableUndoManager({
+    required DocumentService that,
+    required List<String> origins,
+  });
+
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEncodeAwarenessUpdate({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeDiffSince({
+    required DocumentService that,
+    required Uint8List remoteStateVector,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeFullState({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeFullStateV1({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeMetaOnlyUpdate({
+    required DocumentService that,
+  });
+
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEncodeRelativePosition({
+    required DocumentService that,
+    required String blockId,
+    required int offset,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeStateVector({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeSubdocState({
+    required DocumentService that,
+    required String guid,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEnforceSingleRoot({
+    required DocumentService that,
+  });
+
+  Future<BigInt> crateDocDocumentServiceDocumentServiceEstimateDiffSize({
+    required DocumentService that,
+    required Uint8List remoteStateVector,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExportAppflowyJson({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExportMarkdown({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExportStructure({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExportTreeJson({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExtractDeleteSet({
+    required Uint8List update,
+  });
+
+  Future<List<String>>
+  crateDocDocumentServiceDocumentServiceFindOversizedBlocks({
+    required DocumentService that,
+    required int maxChars,
+  });
+
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceFork({
+    required DocumentService that,
+    required String newDocId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceFormatBlocks({
+    required DocumentService that,
+    required List<String> blockIds,
+    required String attributesJson,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceGcEnabled({
+    required DocumentService that,
+  });
+
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetAllBlockIds({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceGetAllMeta({
+    required DocumentService that,
+  });
+
+  Future<BlockDoc?> crateDocDocumentServiceDocumentServiceGetBlock({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<BigInt> crateDocDocumentServiceDocumentServiceGetBlockCount({
+    required DocumentService that,
+  });
+
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetBlockPath({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceGetBlockPlainText({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<List<OrderedBlock>>
+  crateDocDocumentServiceDocumentServiceGetBlocksInOrder({
+    required DocumentService that,
+  });
+
+  Future<BlockPage> crateDocDocumentServiceDocumentServiceGetBlocksPage({
+    required DocumentService that,
+    required BigInt offset,
+    required BigInt limit,
+  });
+
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetChildrenIds({
+    required DocumentService that,
+    required String parentId,
+  });
+
+  Future<List<CommentDoc>>
+  crateDocDocumentServiceDocumentServiceGetCommentsForBlock({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceGetDocumentJson({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceGetDocumentPlainText({
+    required DocumentService that,
+  });
+
+  Future<DocSize> crateDocDocumentServiceDocumentServiceGetDocumentSizeBytes({
+    required DocumentService that,
+  });
+
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
+    required DocumentService that,
+  });
+
+  Future<bool?> crateDocDocumentServiceDocumentServiceGetMetaBool({
+    required DocumentService that,
+    required String key,
+  });
+
+  Future<PlatformInt64?> crateDocDocumentServiceDocumentServiceGetMetaInt({
+    required DocumentService that,
+    required String key,
+  });
+
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetMetaKeys({
+    required DocumentService that,
+  });
+
+  Future<String?> crateDocDocumentServiceDocumentServiceGetMetaString({
+    required DocumentService that,
+    required String key,
+  });
+
+  Future<List<String>?>
+  crateDocDocumentServiceDocumentServiceGetMetaStringArray({
+    required DocumentService that,
+    required String key,
+  });
+
+  Future<String?> crateDocDocumentServiceDocumentServiceGetRootNodeId({
+    required DocumentService that,
+  });
+
+  Future<DocStats> crateDocDocumentServiceDocumentServiceGetStatistics({
+    required DocumentService that,
+  });
+
+  Future<DocStats>
+  crateDocDocumentServiceDocumentServiceGetStatisticsWithBlocks({
+    required DocumentService that,
+  });
+
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetSubdocGuids({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceImportAppflowyJson({
+    required DocumentService that,
+    required String json,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceImportMarkdown({
+    required DocumentService that,
+    required String markdown,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceImportTreeJson({
+    required DocumentService that,
+    required String json,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceIndentBlock({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInsertBlocksBulk({
+    required DocumentService that,
+    required List<BlockDoc> blocks,
+    required String parentId,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceIsBatching({
+    required DocumentService that,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceIsEmpty({
+    required DocumentService that,
+  });
+
+  Future<int> crateDocDocumentServiceDocumentServiceLastReplaceCount({
+    required DocumentService that,
+  });
+
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceLoadAndGetState({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceLoadFromFile({
+    required DocumentService that,
+    required String path,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeBlocks({
+    required DocumentService that,
+    required String firstId,
+    required String secondId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeThreeWay({
+    required Uint8List base,
+    required Uint8List left,
+    required Uint8List right,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdatesV1({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceMetaDiffAgainstJson({
+    required DocumentService that,
+    required String json,
+  });
+
+  Future<List<(BigInt, int)>>
+  crateDocDocumentServiceDocumentServiceMissingFrom({
+    required Uint8List localSv,
+    required Uint8List remoteSv,
+  });
+
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNew();
+
+  Future<DocumentService>
+  crateDocDocumentServiceDocumentServiceNewWithGcDisabled();
+
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNewWithId({
+    required String docId,
+  });
+
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNewWithOptions({
+    required String docId,
+    required bool gc,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceOutdentBlock({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServicePasteBlocks({
+    required DocumentService that,
+    required String payload,
+    required String targetParentId,
+    String? afterBlockId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServicePushMetaArrayItem({
+    required DocumentService that,
+    required String key,
+    required String value,
+  });
+
+  Future<DocumentState>
+  crateDocDocumentServiceDocumentServiceReadDocumentState({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRedo({
+    required DocumentService that,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceRemoveAwarenessClient({
+    required DocumentService that,
+    required BigInt clientId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaArrayItem({
+    required DocumentService that,
+    required String key,
+    required String value,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaKey({
+    required DocumentService that,
+    required String key,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRepairDocument({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceReplaceAll({
+    required DocumentService that,
+    required String query,
+    required String replacement,
+    required bool caseSensitive,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceResolveComment({
+    required DocumentService that,
+    required String commentId,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRestoreSnapshot({
+    required DocumentService that,
+    required Uint8List snapshot,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSaveToFile({
+    required DocumentService that,
+    required String path,
+  });
+
+  Future<List<TextMatch>> crateDocDocumentServiceDocumentServiceSearchText({
+    required DocumentService that,
+    required String query,
+    required bool caseSensitive,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetAllowedBlockTypes({
+    required DocumentService that,
+    required List<String> types,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetAttachmentBudget({
+    required DocumentService that,
+    BigInt? budget,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetBlockAttachment({
+    required DocumentService that,
+    required String blockId,
+    required String key,
+    required Uint8List bytes,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetBlockDataField({
+    required DocumentService that,
+    required String blockId,
+    required String field,
+    required String valueJson,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetIdGeneratorSeed({
+    required DocumentService that,
+    required BigInt seed,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetLocalAwareness({
+    required DocumentService that,
+    required String stateJson,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetLogLevel({
+    required String level,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetMaxTreeDepth({
+    required DocumentService that,
+    required int depth,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaBatch({
+    required DocumentService that,
+    required List<MetaOp> operations,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaBool({
+    required DocumentService that,
+    required String key,
+    required bool value,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaFromJson({
+    required DocumentService that,
+    required String jsonStr,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaInt({
+    required DocumentService that,
+    required String key,
+    required PlatformInt64 value,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaString({
+    required DocumentService that,
+    required String key,
+    required String value,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaStringArray({
+    required DocumentService that,
+    required String key,
+    required List<String> values,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
+    required DocumentService that,
+    required String id,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceSetStrictTypes({
+    required DocumentService that,
+    required bool strict,
+  });
+
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceStateAsOf({
+    required DocumentService that,
+    required Uint8List snapshot,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceStateVectorsEqual({
+    required Uint8List a,
+    required Uint8List b,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceStreamBlocks({
+    required DocumentService that,
+    required FutureOr<void> Function(String) callback,
+  });
+
+  Stream<String> crateDocDocumentServiceDocumentServiceSubscribeBlockChanges({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Stream<OriginUpdate> crateDocDocumentServiceDocumentServiceSubscribeChanges({
+    required DocumentService that,
+  });
+
+  Stream<bool> crateDocDocumentServiceDocumentServiceSubscribeEmptiness({
+    required DocumentService that,
+  });
+
+  Stream<String> crateDocDocumentServiceDocumentServiceSubscribeMetaChanges({
+    required DocumentService that,
+  });
+
+  Stream<Uint8List>
+  crateDocDocumentServiceDocumentServiceSubscribeSubdocChanges({
+    required DocumentService that,
+    required String guid,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceTakeSnapshot({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceUndo({
+    required DocumentService that,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceUnsubscribeBlockChanges({
+    required DocumentService that,
+    required String blockId,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceValidateAction({
+    required DocumentService that,
+    required List<BlockActionDoc> actions,
+  });
+
+  Future<List<String>>
+  crateDocDocumentServiceDocumentServiceValidateAppflowyJson({
+    required String json,
+  });
+
+  Future<DocumentValidation>
+  crateDocDocumentServiceDocumentServiceValidateDocument({
+    required DocumentService that,
+  });
+
+  Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
+    required String message,
+  });
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_DocumentRegistry;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_DocumentRegistry;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_DocumentRegistryPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_DocumentService;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_DocumentService;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_DocumentServicePtr;
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  Future<Uint8List> crateDocDocumentRegistryDocumentRegistryApplyAction({
+    required DocumentRegistry that,
+    required String docId,
+    required List<BlockActionDoc> actions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          sse_encode_list_block_action_doc(actions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentRegistryDocumentRegistryApplyActionConstMeta,
+        argValues: [that, docId, actions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentRegistryDocumentRegistryApplyActionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_apply_action",
+        argNames: ["that", "docId", "actions"],
+      );
+
+  @override
+  Future<AppliedBlockChanges>
+  crateDocDocumentRegistryDocumentRegistryApplyUpdates({
+    required DocumentRegistry that,
+    required String docId,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_applied_block_changes,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentRegistryDocumentRegistryApplyUpdatesConstMeta,
+        argValues: [that, docId, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentRegistryDocumentRegistryApplyUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_apply_updates",
+        argNames: ["that", "docId", "updates"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentRegistryDocumentRegistryClose({
+    required DocumentRegistry that,
+    required String docId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentRegistryDocumentRegistryCloseConstMeta,
+        argValues: [that, docId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentRegistryDocumentRegistryCloseConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_close",
+        argNames: ["that", "docId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentRegistryDocumentRegistryEncodeFullState({
+    required DocumentRegistry that,
+    required String docId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentRegistryDocumentRegistryEncodeFullStateConstMeta,
+        argValues: [that, docId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentRegistryDocumentRegistryEncodeFullStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_encode_full_state",
+        argNames: ["that", "docId"],
+      );
+
+  @override
+  Future<DocumentState>
+  crateDocDocumentRegistryDocumentRegistryGetDocumentState({
+    required DocumentRegistry that,
+    required String docId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentRegistryDocumentRegistryGetDocumentStateConstMeta,
+        argValues: [that, docId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentRegistryDocumentRegistryGetDocumentStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_get_document_state",
+        argNames: ["that", "docId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentRegistryDocumentRegistryInitEmptyDoc({
+    required DocumentRegistry that,
+    required String docId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentRegistryDocumentRegistryInitEmptyDocConstMeta,
+        argValues: [that, docId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentRegistryDocumentRegistryInitEmptyDocConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_init_empty_doc",
+        argNames: ["that", "docId"],
+      );
+
+  @override
+  Future<List<String>> crateDocDocumentRegistryDocumentRegistryListOpen({
+    required DocumentRegistry that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentRegistryDocumentRegistryListOpenConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentRegistryDocumentRegistryListOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_list_open",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocumentRegistry> crateDocDocumentRegistryDocumentRegistryNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentRegistryDocumentRegistryNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentRegistryDocumentRegistryNewConstMeta =>
+      const TaskConstMeta(debugName: "DocumentRegistry_new", argNames: []);
+
+  @override
+  Future<void> crateDocDocumentRegistryDocumentRegistryOpen({
+    required DocumentRegistry that,
+    required String docId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+            that,
+            serializer,
+          );
+          sse_encode_String(docId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentRegistryDocumentRegistryOpenConstMeta,
+        argValues: [that, docId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentRegistryDocumentRegistryOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentRegistry_open",
+        argNames: ["that", "docId"],
+      );
+
+  @override
+  Future<int> crateDocDocumentServiceDocumentServiceActiveEditorCount({
+    required DocumentService that,
+    required BigInt windowMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_64(windowMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceActiveEditorCountConstMeta,
+        argValues: [that, windowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceActiveEditorCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_active_editor_count",
+        argNames: ["that", "windowMs"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceAddComment({
+    required DocumentService that,
+    required String blockId,
+    required String commentId,
+    required String author,
+    required String text,
+    int? anchorOffset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_String(commentId, serializer);
+          sse_encode_String(author, serializer);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_box_autoadd_u_32(anchorOffset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceAddCommentConstMeta,
+        argValues: [that, blockId, commentId, author, text, anchorOffset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceAddCommentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_add_comment",
+        argNames: [
+          "that",
+          "blockId",
+          "commentId",
+          "author",
+          "text",
+          "anchorOffset",
+        ],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceAdditiveUpdateFor({
+    required DocumentService that,
+    required Uint8List peerStateVector,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(peerStateVector, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceAdditiveUpdateForConstMeta,
+        argValues: [that, peerStateVector],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceAdditiveUpdateForConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_additive_update_for",
+        argNames: ["that", "peerStateVector"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyAction({
+    required DocumentService that,
+    required List<BlockActionDoc> actions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_block_action_doc(actions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceApplyActionConstMeta,
+        argValues: [that, actions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyActionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_action",
+        argNames: ["that", "actions"],
+      );
+
+  @override
+  Future<ActionResult>
+  crateDocDocumentServiceDocumentServiceApplyActionWithIds({
+    required DocumentService that,
+    required List<BlockActionDoc> actions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_block_action_doc(actions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_action_result,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyActionWithIdsConstMeta,
+        argValues: [that, actions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyActionWithIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_action_with_ids",
+        argNames: ["that", "actions"],
+      );
+
+  @override
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceApplyActionWithOrigin({
+    required DocumentService that,
+    required List<BlockActionDoc> actions,
+    required String origin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_block_action_doc(actions, serializer);
+          sse_encode_String(origin, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyActionWithOriginConstMeta,
+        argValues: [that, actions, origin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyActionWithOriginConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_action_with_origin",
+        argNames: ["that", "actions", "origin"],
+      );
+
+  @override
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceApplyActionWithTimestamp({
+    required DocumentService that,
+    required List<BlockActionDoc> actions,
+    required PlatformInt64 timestampMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_block_action_doc(actions, serializer);
+          sse_encode_i_64(timestampMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyActionWithTimestampConstMeta,
+        argValues: [that, actions, timestampMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyActionWithTimestampConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_action_with_timestamp",
+        argNames: ["that", "actions", "timestampMs"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceApplyAwarenessUpdate({
+    required DocumentService that,
+    required Uint8List update,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(update, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyAwarenessUpdateConstMeta,
+        argValues: [that, update],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyAwarenessUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_awareness_update",
+        argNames: ["that", "update"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyDelta({
+    required DocumentService that,
+    required String blockId,
+    required String deltaJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_String(deltaJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceApplyDeltaConstMeta,
+        argValues: [that, blockId, deltaJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyDeltaConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_delta",
+        argNames: ["that", "blockId", "deltaJson"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyMetaOnlyUpdate({
+    required DocumentService that,
+    required Uint8List update,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(update, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyMetaOnlyUpdateConstMeta,
+        argValues: [that, update],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyMetaOnlyUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_meta_only_update",
+        argNames: ["that", "update"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceApplySubdocUpdate({
+    required DocumentService that,
+    required String guid,
+    required Uint8List update,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(guid, serializer);
+          sse_encode_list_prim_u_8_strict(update, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplySubdocUpdateConstMeta,
+        argValues: [that, guid, update],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplySubdocUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_subdoc_update",
+        argNames: ["that", "guid", "update"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyTextOps({
+    required DocumentService that,
+    required String blockId,
+    required String opsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_String(opsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceApplyTextOpsConstMeta,
+        argValues: [that, blockId, opsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyTextOpsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_text_ops",
+        argNames: ["that", "blockId", "opsJson"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceApplyUpdate({
+    required DocumentService that,
+    required Uint8List update,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(update, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceApplyUpdateConstMeta,
+        argValues: [that, update],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_update",
+        argNames: ["that", "update"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceApplyUpdateRemapped({
+    required DocumentService that,
+    required Uint8List update,
+    required String idMapJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(update, serializer);
+          sse_encode_String(idMapJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyUpdateRemappedConstMeta,
+        argValues: [that, update, idMapJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdateRemappedConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_update_remapped",
+        argNames: ["that", "update", "idMapJson"],
+      );
+
+  @override
+  Future<AppliedBlockChanges>
+  crateDocDocumentServiceDocumentServiceApplyUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_applied_block_changes,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_updates",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<AppliedBlockChanges>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesV1({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_applied_block_changes,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyUpdatesV1ConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesV1ConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_updates_v1",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceApplyUpdatesWithChanges({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyUpdatesWithChangesConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesWithChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_updates_with_changes",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<BigInt> crateDocDocumentServiceDocumentServiceAttachmentTotalBytes({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceAttachmentTotalBytesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceAttachmentTotalBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_attachment_total_bytes",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceBeginBatch({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceBeginBatchConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceBeginBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_begin_batch",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceCanMergeBlocks({
+    required DocumentService that,
+    required String firstId,
+    required String secondId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(firstId, serializer);
+          sse_encode_String(secondId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceCanMergeBlocksConstMeta,
+        argValues: [that, firstId, secondId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCanMergeBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_can_merge_blocks",
+        argNames: ["that", "firstId", "secondId"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceCanRedo({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceCanRedoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceCanRedoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_can_redo",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceCanUndo({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceCanUndoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceCanUndoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_can_undo",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceChildrenOrderHash({
+    required DocumentService that,
+    required String parentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(parentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceChildrenOrderHashConstMeta,
+        argValues: [that, parentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceChildrenOrderHashConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_children_order_hash",
+        argNames: ["that", "parentId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceClearDocument({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceClearDocumentConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceClearDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_clear_document",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceCommitBatch({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceCommitBatchConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCommitBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_commit_batch",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceCompact({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceCompactConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceCompactConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_compact",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceCompactUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceCompactUpdatesConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCompactUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_compact_updates",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceContentHash({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceContentHashConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceContentHashConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_content_hash",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceCopyBlocks({
+    required DocumentService that,
+    required List<String> blockIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_String(blockIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceCopyBlocksConstMeta,
+        argValues: [that, blockIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCopyBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_copy_blocks",
+        argNames: ["that", "blockIds"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceCreateSubdoc({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceCreateSubdocConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCreateSubdocConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_create_subdoc",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<RelativePos>
+  crateDocDocumentServiceDocumentServiceDecodeRelativePosition({
+    required DocumentService that,
+    required Uint8List relativePosition,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(relativePosition, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_relative_pos,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDecodeRelativePositionConstMeta,
+        argValues: [that, relativePosition],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDecodeRelativePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_decode_relative_position",
+        argNames: ["that", "relativePosition"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceDeleteBlockRecursive({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDeleteBlockRecursiveConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDeleteBlockRecursiveConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_delete_block_recursive",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceDetectLanguage({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDetectLanguageConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDetectLanguageConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_detect_language",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<BlockActionDoc>>
+  crateDocDocumentServiceDocumentServiceDiffStates({
+    required Uint8List oldUpdate,
+    required Uint8List newUpdate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_strict(oldUpdate, serializer);
+          sse_encode_list_prim_u_8_strict(newUpdate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_block_action_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceDiffStatesConstMeta,
+        argValues: [oldUpdate, newUpdate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDiffStatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_diff_states",
+        argNames: ["oldUpdate", "newUpdate"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceDocId({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceDocIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceDocIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_doc_id",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DuplicateResult> crateDocDocumentServiceDocumentServiceDuplicateBlock({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_duplicate_result,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDuplicateBlockConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDuplicateBlockConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_duplicate_block",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceEnableUndoManager({
+    required DocumentService that,
+    required List<String> origins,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_String(origins, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEnableUndoManagerConstMeta,
+        argValues: [that, origins],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEnableUndoManagerConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_enable_undo_manager",
+        argNames: ["that", "origins"],
+      );
+
+  @override
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEncodeAwarenessUpdate({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeAwarenessUpdateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeAwarenessUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_awareness_update",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeDiffSince({
+    required DocumentService that,
+    required Uint8List remoteStateVector,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(remoteStateVector, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeDiffSinceConstMeta,
+        argValues: [that, remoteStateVector],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeDiffSinceConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_diff_since",
+        argNames: ["that", "remoteStateVector"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeFullState({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeFullStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeFullStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_full_state",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeFullStateV1({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeFullStateV1ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeFullStateV1ConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_full_state_v1",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeMetaOnlyUpdate({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeMetaOnlyUpdateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeMetaOnlyUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_meta_only_update",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEncodeRelativePosition({
+    required DocumentService that,
+    required String blockId,
+    required int offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeRelativePositionConstMeta,
+        argValues: [that, blockId, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeRelativePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_relative_position",
+        argNames: ["that", "blockId", "offset"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeStateVector({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeStateVectorConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeStateVectorConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_state_vector",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeSubdocState({
+    required DocumentService that,
+    required String guid,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(guid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeSubdocStateConstMeta,
+        argValues: [that, guid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeSubdocStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_subdoc_state",
+        argNames: ["that", "guid"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEnforceSingleRoot({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEnforceSingleRootConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEnforceSingleRootConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_enforce_single_root",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<BigInt> crateDocDocumentServiceDocumentServiceEstimateDiffSize({
+    required DocumentService that,
+    required Uint8List remoteStateVector,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(remoteStateVector, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEstimateDiffSizeConstMeta,
+        argValues: [that, remoteStateVector],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEstimateDiffSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_estimate_diff_size",
+        argNames: ["that", "remoteStateVector"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExportAppflowyJson({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceExportAppflowyJsonConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExportAppflowyJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_export_appflowy_json",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExportMarkdown({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceExportMarkdownConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExportMarkdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_export_markdown",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExportStructure({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceExportStructureConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExportStructureConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_export_structure",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExportTreeJson({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceExportTreeJsonConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExportTreeJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_export_tree_json",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExtractDeleteSet({
+    required Uint8List update,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_strict(update, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceExtractDeleteSetConstMeta,
+        argValues: [update],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExtractDeleteSetConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_extract_delete_set",
+        argNames: ["update"],
+      );
+
+  @override
+  Future<List<String>>
+  crateDocDocumentServiceDocumentServiceFindOversizedBlocks({
+    required DocumentService that,
+    required int maxChars,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(maxChars, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceFindOversizedBlocksConstMeta,
+        argValues: [that, maxChars],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceFindOversizedBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_find_oversized_blocks",
+        argNames: ["that", "maxChars"],
+      );
+
+  @override
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceFork({
+    required DocumentService that,
+    required String newDocId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(newDocId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceForkConstMeta,
+        argValues: [that, newDocId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceForkConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_fork",
+        argNames: ["that", "newDocId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceFormatBlocks({
+    required DocumentService that,
+    required List<String> blockIds,
+    required String attributesJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_String(blockIds, serializer);
+          sse_encode_String(attributesJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceFormatBlocksConstMeta,
+        argValues: [that, blockIds, attributesJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceFormatBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_format_blocks",
+        argNames: ["that", "blockIds", "attributesJson"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceGcEnabled({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGcEnabledConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceGcEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_gc_enabled",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetAllBlockIds({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetAllBlockIdsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetAllBlockIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_all_block_ids",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceGetAllMeta({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGetAllMetaConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetAllMetaConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_all_meta",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<BlockDoc?> crateDocDocumentServiceDocumentServiceGetBlock({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_block_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGetBlockConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceGetBlockConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_block",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<BigInt> crateDocDocumentServiceDocumentServiceGetBlockCount({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetBlockCountConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetBlockCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_block_count",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetBlockPath({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGetBlockPathConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetBlockPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_block_path",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceGetBlockPlainText({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetBlockPlainTextConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetBlockPlainTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_block_plain_text",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<List<OrderedBlock>>
+  crateDocDocumentServiceDocumentServiceGetBlocksInOrder({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_ordered_block,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetBlocksInOrderConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetBlocksInOrderConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_blocks_in_order",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<BlockPage> crateDocDocumentServiceDocumentServiceGetBlocksPage({
+    required DocumentService that,
+    required BigInt offset,
+    required BigInt limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_usize(offset, serializer);
+          sse_encode_usize(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_block_page,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetBlocksPageConstMeta,
+        argValues: [that, offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetBlocksPageConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_blocks_page",
+        argNames: ["that", "offset", "limit"],
+      );
+
+  @override
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetChildrenIds({
+    required DocumentService that,
+    required String parentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(parentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetChildrenIdsConstMeta,
+        argValues: [that, parentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetChildrenIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_children_ids",
+        argNames: ["that", "parentId"],
+      );
+
+  @override
+  Future<List<CommentDoc>>
+  crateDocDocumentServiceDocumentServiceGetCommentsForBlock({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_comment_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetCommentsForBlockConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetCommentsForBlockConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_comments_for_block",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceGetDocumentJson({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetDocumentJsonConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetDocumentJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_document_json",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceGetDocumentPlainText({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetDocumentPlainTextConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetDocumentPlainTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_document_plain_text",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocSize> crateDocDocumentServiceDocumentServiceGetDocumentSizeBytes({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_doc_size,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetDocumentSizeBytesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetDocumentSizeBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_document_size_bytes",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_document_state",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool?> crateDocDocumentServiceDocumentServiceGetMetaBool({
+    required DocumentService that,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGetMetaBoolConstMeta,
+        argValues: [that, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetMetaBoolConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_meta_bool",
+        argNames: ["that", "key"],
+      );
+
+  @override
+  Future<PlatformInt64?> crateDocDocumentServiceDocumentServiceGetMetaInt({
+    required DocumentService that,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGetMetaIntConstMeta,
+        argValues: [that, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetMetaIntConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_meta_int",
+        argNames: ["that", "key"],
+      );
+
+  @override
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetMetaKeys({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceGetMetaKeysConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetMetaKeysConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_meta_keys",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String?> crateDocDocumentServiceDocumentServiceGetMetaString({
+    required DocumentService that,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetMetaStringConstMeta,
+        argValues: [that, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetMetaStringConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_meta_string",
+        argNames: ["that", "key"],
+      );
+
+  @override
+  Future<List<String>?>
+  crateDocDocumentServiceDocumentServiceGetMetaStringArray({
+    required DocumentService that,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetMetaStringArrayConstMeta,
+        argValues: [that, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetMetaStringArrayConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_meta_string_array",
+        argNames: ["that", "key"],
+      );
+
+  @override
+  Future<String?> crateDocDocumentServiceDocumentServiceGetRootNodeId({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetRootNodeIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetRootNodeIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_root_node_id",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocStats> crateDocDocumentServiceDocumentServiceGetStatistics({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_doc_stats,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetStatisticsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetStatisticsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_statistics",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocStats>
+  crateDocDocumentServiceDocumentServiceGetStatisticsWithBlocks({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_doc_stats,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetStatisticsWithBlocksConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetStatisticsWithBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_statistics_with_blocks",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<String>> crateDocDocumentServiceDocumentServiceGetSubdocGuids({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetSubdocGuidsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetSubdocGuidsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_subdoc_guids",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceImportAppflowyJson({
+    required DocumentService that,
+    required String json,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(json, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceImportAppflowyJsonConstMeta,
+        argValues: [that, json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceImportAppflowyJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_import_appflowy_json",
+        argNames: ["that", "json"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceImportMarkdown({
+    required DocumentService that,
+    required String markdown,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(markdown, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceImportMarkdownConstMeta,
+        argValues: [that, markdown],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceImportMarkdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_import_markdown",
+        argNames: ["that", "markdown"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceImportTreeJson({
+    required DocumentService that,
+    required String json,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(json, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceImportTreeJsonConstMeta,
+        argValues: [that, json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceImportTreeJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_import_tree_json",
+        argNames: ["that", "json"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceIndentBlock({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceIndentBlockConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceIndentBlockConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_indent_block",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_init_empty_doc",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInsertBlocksBulk({
+    required DocumentService that,
+    required List<BlockDoc> blocks,
+    required String parentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_block_doc(blocks, serializer);
+          sse_encode_String(parentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceInsertBlocksBulkConstMeta,
+        argValues: [that, blocks, parentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceInsertBlocksBulkConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_insert_blocks_bulk",
+        argNames: ["that", "blocks", "parentId"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceIsBatching({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceIsBatchingConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceIsBatchingConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_is_batching",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceIsEmpty({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceIsEmptyConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceIsEmptyConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_is_empty",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<int> crateDocDocumentServiceDocumentServiceLastReplaceCount({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceLastReplaceCountConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceLastReplaceCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_last_replace_count",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceLoadAndGetState({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceLoadAndGetStateConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceLoadAndGetStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_load_and_get_state",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceLoadFromFile({
+    required DocumentService that,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceLoadFromFileConstMeta,
+        argValues: [that, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceLoadFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_load_from_file",
+        argNames: ["that", "path"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeBlocks({
+    required DocumentService that,
+    required String firstId,
+    required String secondId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(firstId, serializer);
+          sse_encode_String(secondId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceMergeBlocksConstMeta,
+        argValues: [that, firstId, secondId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMergeBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_merge_blocks",
+        argNames: ["that", "firstId", "secondId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeThreeWay({
+    required Uint8List base,
+    required Uint8List left,
+    required Uint8List right,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_strict(base, serializer);
+          sse_encode_list_prim_u_8_strict(left, serializer);
+          sse_encode_list_prim_u_8_strict(right, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceMergeThreeWayConstMeta,
+        argValues: [base, left, right],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMergeThreeWayConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_merge_three_way",
+        argNames: ["base", "left", "right"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_merge_updates",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdatesV1({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceMergeUpdatesV1ConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMergeUpdatesV1ConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_merge_updates_v1",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceMetaDiffAgainstJson({
+    required DocumentService that,
+    required String json,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(json, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceMetaDiffAgainstJsonConstMeta,
+        argValues: [that, json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMetaDiffAgainstJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_meta_diff_against_json",
+        argNames: ["that", "json"],
+      );
+
+  @override
+  Future<List<(BigInt, int)>>
+  crateDocDocumentServiceDocumentServiceMissingFrom({
+    required Uint8List localSv,
+    required Uint8List remoteSv,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_strict(localSv, serializer);
+          sse_encode_list_prim_u_8_strict(remoteSv, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_record_u_64_u_32,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceMissingFromConstMeta,
+        argValues: [localSv, remoteSv],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMissingFromConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_missing_from",
+        argNames: ["localSv", "remoteSv"],
+      );
+
+  @override
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceNewConstMeta =>
+      const TaskConstMeta(debugName: "DocumentService_new", argNames: []);
+
+  @override
+  Future<DocumentService>
+  crateDocDocumentServiceDocumentServiceNewWithGcDisabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceNewWithGcDisabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceNewWithGcDisabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_new_with_gc_disabled",
+        argNames: [],
+      );
+
+  @override
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNewWithId({
+    required String docId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(docId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceNewWithIdConstMeta,
+        argValues: [docId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceNewWithIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_new_with_id",
+        argNames: ["docId"],
+      );
+
+  @override
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNewWithOptions({
+    required String docId,
+    required bool gc,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(docId, serializer);
+          sse_encode_bool(gc, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceNewWithOptionsConstMeta,
+        argValues: [docId, gc],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceNewWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_new_with_options",
+        argNames: ["docId", "gc"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceOutdentBlock({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceOutdentBlockConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceOutdentBlockConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_outdent_block",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServicePasteBlocks({
+    required DocumentService that,
+    required String payload,
+    required String targetParentId,
+    String? afterBlockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(payload, serializer);
+          sse_encode_String(targetParentId, serializer);
+          sse_encode_opt_String(afterBlockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServicePasteBlocksConstMeta,
+        argValues: [that, payload, targetParentId, afterBlockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServicePasteBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_paste_blocks",
+        argNames: ["that", "payload", "targetParentId", "afterBlockId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServicePushMetaArrayItem({
+    required DocumentService that,
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServicePushMetaArrayItemConstMeta,
+        argValues: [that, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServicePushMetaArrayItemConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_push_meta_array_item",
+        argNames: ["that", "key", "value"],
+      );
+
+  @override
+  Future<DocumentState>
+  crateDocDocumentServiceDocumentServiceReadDocumentState({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceReadDocumentStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceReadDocumentStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_read_document_state",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRedo({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceRedoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceRedoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_redo",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceRemoveAwarenessClient({
+    required DocumentService that,
+    required BigInt clientId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_64(clientId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceRemoveAwarenessClientConstMeta,
+        argValues: [that, clientId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRemoveAwarenessClientConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_remove_awareness_client",
+        argNames: ["that", "clientId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaArrayItem({
+    required DocumentService that,
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceRemoveMetaArrayItemConstMeta,
+        argValues: [that, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRemoveMetaArrayItemConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_remove_meta_array_item",
+        argNames: ["that", "key", "value"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaKey({
+    required DocumentService that,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceRemoveMetaKeyConstMeta,
+        argValues: [that, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRemoveMetaKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_remove_meta_key",
+        argNames: ["that", "key"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRepairDocument({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceRepairDocumentConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRepairDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_repair_document",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceReplaceAll({
+    required DocumentService that,
+    required String query,
+    required String replacement,
+    required bool caseSensitive,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(replacement, serializer);
+          sse_encode_bool(caseSensitive, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceReplaceAllConstMeta,
+        argValues: [that, query, replacement, caseSensitive],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceReplaceAllConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_replace_all",
+        argNames: ["that", "query", "replacement", "caseSensitive"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceResolveComment({
+    required DocumentService that,
+    required String commentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(commentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceResolveCommentConstMeta,
+        argValues: [that, commentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceResolveCommentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_resolve_comment",
+        argNames: ["that", "commentId"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRestoreSnapshot({
+    required DocumentService that,
+    required Uint8List snapshot,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(snapshot, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceRestoreSnapshotConstMeta,
+        argValues: [that, snapshot],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRestoreSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_restore_snapshot",
+        argNames: ["that", "snapshot"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSaveToFile({
+    required DocumentService that,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceSaveToFileConstMeta,
+        argValues: [that, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSaveToFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_save_to_file",
+        argNames: ["that", "path"],
+      );
+
+  @override
+  Future<List<TextMatch>> crateDocDocumentServiceDocumentServiceSearchText({
+    required DocumentService that,
+    required String query,
+    required bool caseSensitive,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_bool(caseSensitive, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_text_match,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceSearchTextConstMeta,
+        argValues: [that, query, caseSensitive],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSearchTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_search_text",
+        argNames: ["that", "query", "caseSensitive"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetAllowedBlockTypes({
+    required DocumentService that,
+    required List<String> types,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_String(types, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetAllowedBlockTypesConstMeta,
+        argValues: [that, types],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetAllowedBlockTypesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_allowed_block_types",
+        argNames: ["that", "types"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetAttachmentBudget({
+    required DocumentService that,
+    BigInt? budget,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_u_64(budget, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetAttachmentBudgetConstMeta,
+        argValues: [that, budget],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetAttachmentBudgetConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_attachment_budget",
+        argNames: ["that", "budget"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetBlockAttachment({
+    required DocumentService that,
+    required String blockId,
+    required String key,
+    required Uint8List bytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_list_prim_u_8_strict(bytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetBlockAttachmentConstMeta,
+        argValues: [that, blockId, key, bytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetBlockAttachmentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_block_attachment",
+        argNames: ["that", "blockId", "key", "bytes"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetBlockDataField({
+    required DocumentService that,
+    required String blockId,
+    required String field,
+    required String valueJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_String(field, serializer);
+          sse_encode_String(valueJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetBlockDataFieldConstMeta,
+        argValues: [that, blockId, field, valueJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetBlockDataFieldConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_block_data_field",
+        argNames: ["that", "blockId", "field", "valueJson"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetIdGeneratorSeed({
+    required DocumentService that,
+    required BigInt seed,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_64(seed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetIdGeneratorSeedConstMeta,
+        argValues: [that, seed],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetIdGeneratorSeedConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_id_generator_seed",
+        argNames: ["that", "seed"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetLocalAwareness({
+    required DocumentService that,
+    required String stateJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(stateJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetLocalAwarenessConstMeta,
+        argValues: [that, stateJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetLocalAwarenessConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_local_awareness",
+        argNames: ["that", "stateJson"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetLogLevel({
+    required String level,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceSetLogLevelConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetLogLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_log_level",
+        argNames: ["level"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetMaxTreeDepth({
+    required DocumentService that,
+    required int depth,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(depth, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetMaxTreeDepthConstMeta,
+        argValues: [that, depth],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMaxTreeDepthConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_max_tree_depth",
+        argNames: ["that", "depth"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaBatch({
+    required DocumentService that,
+    required List<MetaOp> operations,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_meta_op(operations, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceSetMetaBatchConstMeta,
+        argValues: [that, operations],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMetaBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_meta_batch",
+        argNames: ["that", "operations"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaBool({
+    required DocumentService that,
+    required String key,
+    required bool value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          sse_encode_bool(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceSetMetaBoolConstMeta,
+        argValues: [that, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMetaBoolConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_meta_bool",
+        argNames: ["that", "key", "value"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaFromJson({
+    required DocumentService that,
+    required String jsonStr,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(jsonStr, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetMetaFromJsonConstMeta,
+        argValues: [that, jsonStr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMetaFromJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_meta_from_json",
+        argNames: ["that", "jsonStr"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaInt({
+    required DocumentService that,
+    required String key,
+    required PlatformInt64 value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          sse_encode_i_64(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceSetMetaIntConstMeta,
+        argValues: [that, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMetaIntConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_meta_int",
+        argNames: ["that", "key", "value"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaString({
+    required DocumentService that,
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetMetaStringConstMeta,
+        argValues: [that, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMetaStringConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_meta_string",
+        argNames: ["that", "key", "value"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaStringArray({
+    required DocumentService that,
+    required String key,
+    required List<String> values,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(key, serializer);
+          sse_encode_list_String(values, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetMetaStringArrayConstMeta,
+        argValues: [that, key, values],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetMetaStringArrayConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_meta_string_array",
+        argNames: ["that", "key", "values"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
+    required DocumentService that,
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta,
+        argValues: [that, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_root_node_id",
+        argNames: ["that", "id"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceSetStrictTypes({
+    required DocumentService that,
+    required bool strict,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_bool(strict, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetStrictTypesConstMeta,
+        argValues: [that, strict],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetStrictTypesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_strict_types",
+        argNames: ["that", "strict"],
+      );
+
+  @override
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceStateAsOf({
+    required DocumentService that,
+    required Uint8List snapshot,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_strict(snapshot, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceStateAsOfConstMeta,
+        argValues: [that, snapshot],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceStateAsOfConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_state_as_of",
+        argNames: ["that", "snapshot"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceStateVectorsEqual({
+    required Uint8List a,
+    required Uint8List b,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_strict(a, serializer);
+          sse_encode_list_prim_u_8_strict(b, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceStateVectorsEqualConstMeta,
+        argValues: [a, b],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceStateVectorsEqualConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_state_vectors_equal",
+        argNames: ["a", "b"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceStreamBlocks({
+    required DocumentService that,
+    required FutureOr<void> Function(String) callback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+            callback,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceStreamBlocksConstMeta,
+        argValues: [that, callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceStreamBlocksConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_stream_blocks",
+        argNames: ["that", "callback"],
+      );
+
+  @override
+  Stream<String> crateDocDocumentServiceDocumentServiceSubscribeBlockChanges({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+              that,
+              serializer,
+            );
+            sse_encode_String(blockId, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 143,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_custom_rust_error,
+          ),
+          constMeta:
+              kCrateDocDocumentServiceDocumentServiceSubscribeBlockChangesConstMeta,
+          argValues: [that, blockId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSubscribeBlockChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_subscribe_block_changes",
+        argNames: ["that", "blockId", "sink"],
+      );
+
+  @override
+  Stream<OriginUpdate> crateDocDocumentServiceDocumentServiceSubscribeChanges({
+    required DocumentService that,
+  }) {
+    final sink = RustStreamSink<OriginUpdate>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_origin_update_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 144,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_custom_rust_error,
+          ),
+          constMeta:
+              kCrateDocDocumentServiceDocumentServiceSubscribeChangesConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSubscribeChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_subscribe_changes",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<bool> crateDocDocumentServiceDocumentServiceSubscribeEmptiness({
+    required DocumentService that,
+  }) {
+    final sink = RustStreamSink<bool>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_bool_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 145,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_custom_rust_error,
+          ),
+          constMeta:
+              kCrateDocDocumentServiceDocumentServiceSubscribeEmptinessConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSubscribeEmptinessConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_subscribe_emptiness",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<String> crateDocDocumentServiceDocumentServiceSubscribeMetaChanges({
+    required DocumentService that,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 146,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_custom_rust_error,
+          ),
+          constMeta:
+              kCrateDocDocumentServiceDocumentServiceSubscribeMetaChangesConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSubscribeMetaChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_subscribe_meta_changes",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<Uint8List>
+  crateDocDocumentServiceDocumentServiceSubscribeSubdocChanges({
+    required DocumentService that,
+    required String guid,
+  }) {
+    final sink = RustStreamSink<Uint8List>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+              that,
+              serializer,
+            );
+            sse_encode_String(guid, serializer);
+            sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 147,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_custom_rust_error,
+          ),
+          constMeta:
+              kCrateDocDocumentServiceDocumentServiceSubscribeSubdocChangesConstMeta,
+          argValues: [that, guid, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSubscribeSubdocChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_subscribe_subdoc_changes",
+        argNames: ["that", "guid", "sink"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceTakeSnapshot({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_document_state,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceTakeSnapshotConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceTakeSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_take_snapshot",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceUndo({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_custom_rust_error,
         ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceUndoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceUndoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_undo",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateDocDocumentServiceDocumentServiceUnsubscribeBlockChanges({
+    required DocumentService that,
+    required String blockId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
         constMeta:
-            kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta,
+            kCrateDocDocumentServiceDocumentServiceUnsubscribeBlockChangesConstMeta,
+        argValues: [that, blockId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceUnsubscribeBlockChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_unsubscribe_block_changes",
+        argNames: ["that", "blockId"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceValidateAction({
+    required DocumentService that,
+    required List<BlockActionDoc> actions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_block_action_doc(actions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceValidateActionConstMeta,
+        argValues: [that, actions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceValidateActionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_validate_action",
+        argNames: ["that", "actions"],
+      );
+
+  @override
+  Future<List<String>>
+  crateDocDocumentServiceDocumentServiceValidateAppflowyJson({
+    required String json,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceValidateAppflowyJsonConstMeta,
+        argValues: [json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceValidateAppflowyJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_validate_appflowy_json",
+        argNames: ["json"],
+      );
+
+  @override
+  Future<DocumentValidation>
+  crateDocDocumentServiceDocumentServiceValidateDocument({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_validation,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceValidateDocumentConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_get_document_state",
-        argNames: ["that"],
-      );
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceValidateDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_validate_document",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
+    required String message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_custom_rust_error,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesCustomRustErrorNewConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesCustomRustErrorNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "custom_rust_error_new",
+        argNames: ["message"],
+      );
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_DocumentRegistry =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_DocumentRegistry =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_DocumentService =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_DocumentService =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  DocumentRegistry
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentRegistry
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentRegistry
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  FutureOr<void> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, BlockDoc> dco_decode_Map_String_block_doc_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_block_doc(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, List<String>> dco_decode_Map_String_list_String_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_list_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  DocumentRegistry
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<bool> dco_decode_StreamSink_bool_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<OriginUpdate> dco_decode_StreamSink_origin_update_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  ActionResult dco_decode_action_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ActionResult(
+      update: dco_decode_list_prim_u_8_strict(arr[0]),
+      generatedIds: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  AppliedBlockChanges dco_decode_applied_block_changes(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppliedBlockChanges(
+      insertedBlockIds: dco_decode_list_String(arr[0]),
+      updatedBlockIds: dco_decode_list_String(arr[1]),
+      deletedBlockIds: dco_decode_list_String(arr[2]),
+      failedUpdates: dco_decode_failed_to_decode_updates(arr[3]),
+    );
+  }
+
+  @protected
+  BlockActionDoc dco_decode_block_action_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BlockActionDoc(
+      action: dco_decode_block_action_type_doc(arr[0]),
+      block: dco_decode_block_doc(arr[1]),
+      path: dco_decode_list_prim_u_32_strict(arr[2]),
+      oldPath: dco_decode_opt_list_prim_u_32_strict(arr[3]),
+    );
+  }
+
+  @protected
+  BlockActionTypeDoc dco_decode_block_action_type_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BlockActionTypeDoc.values[raw as int];
+  }
+
+  @protected
+  BlockDoc dco_decode_block_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return BlockDoc(
+      id: dco_decode_String(arr[0]),
+      ty: dco_decode_String(arr[1]),
+      attributes: dco_decode_Map_String_String_None(arr[2]),
+      delta: dco_decode_opt_String(arr[3]),
+      parentId: dco_decode_opt_String(arr[4]),
+      prevId: dco_decode_opt_String(arr[5]),
+      nextId: dco_decode_opt_String(arr[6]),
+      oldParentId: dco_decode_opt_String(arr[7]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[8]),
+      updatedAt: dco_decode_opt_box_autoadd_i_64(arr[9]),
+    );
+  }
+
+  @protected
+  BlockPage dco_decode_block_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BlockPage(
+      blocks: dco_decode_list_block_doc(arr[0]),
+      total: dco_decode_usize(arr[1]),
+      hasMore: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  BlockStats dco_decode_block_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BlockStats(
+      blockId: dco_decode_String(arr[0]),
+      wordCount: dco_decode_u_32(arr[1]),
+      charCount: dco_decode_u_32(arr[2]),
+      charCountNoSpaces: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BlockDoc dco_decode_box_autoadd_block_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_block_doc(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CommentDoc dco_decode_comment_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CommentDoc(
+      commentId: dco_decode_String(arr[0]),
+      blockId: dco_decode_String(arr[1]),
+      author: dco_decode_String(arr[2]),
+      text: dco_decode_String(arr[3]),
+      resolved: dco_decode_bool(arr[4]),
+      anchorOffset: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  CustomRustError dco_decode_custom_rust_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CustomRustError(
+      code: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DocSize dco_decode_doc_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DocSize(
+      fullStateBytes: dco_decode_u_64(arr[0]),
+      contentBytes: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  DocStats dco_decode_doc_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DocStats(
+      wordCount: dco_decode_u_32(arr[0]),
+      charCount: dco_decode_u_32(arr[1]),
+      charCountNoSpaces: dco_decode_u_32(arr[2]),
+      blockCount: dco_decode_u_32(arr[3]),
+      blocks: dco_decode_opt_list_block_stats(arr[4]),
+    );
+  }
+
+  @protected
+  DocumentState dco_decode_document_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DocumentState(
+      docId: dco_decode_String(arr[0]),
+      blocks: dco_decode_Map_String_block_doc_None(arr[1]),
+      childrenMap: dco_decode_Map_String_list_String_None(arr[2]),
+      rootId: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  DocumentValidation dco_decode_document_validation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DocumentValidation(
+      orphanedBlockIds: dco_decode_list_String(arr[0]),
+      cyclicBlockIds: dco_decode_list_String(arr[1]),
+      danglingReferenceIds: dco_decode_list_String(arr[2]),
+      orphanedCommentIds: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  DuplicateResult dco_decode_duplicate_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DuplicateResult(
+      update: dco_decode_list_prim_u_8_strict(arr[0]),
+      idMap: dco_decode_Map_String_String_None(arr[1]),
+    );
+  }
+
+  @protected
+  FailedToDecodeUpdates dco_decode_failed_to_decode_updates(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FailedToDecodeUpdates(
+      failedUpdatesIds: dco_decode_list_String(arr[0]),
+      errors: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BlockActionDoc> dco_decode_list_block_action_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_block_action_doc).toList();
+  }
+
+  @protected
+  List<BlockDoc> dco_decode_list_block_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_block_doc).toList();
+  }
+
+  @protected
+  List<BlockStats> dco_decode_list_block_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_block_stats).toList();
+  }
+
+  @protected
+  List<CommentDoc> dco_decode_list_comment_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_comment_doc).toList();
+  }
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  List<MetaOp> dco_decode_list_meta_op(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_meta_op).toList();
+  }
+
+  @protected
+  List<OrderedBlock> dco_decode_list_ordered_block(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ordered_block).toList();
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, BlockDoc)> dco_decode_list_record_string_block_doc(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_block_doc)
+        .toList();
+  }
+
+  @protected
+  List<(String, List<String>)> dco_decode_list_record_string_list_string(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_list_string)
+        .toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<(BigInt, int)> dco_decode_list_record_u_64_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_u_64_u_32).toList();
+  }
+
+  @protected
+  List<TextMatch> dco_decode_list_text_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_match).toList();
+  }
+
+  @protected
+  MetaOp dco_decode_meta_op(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MetaOp_SetString(
+      key: dco_decode_String(raw[1]),
+      value: dco_decode_String(raw[2]),
+    );
+      case 1:
+        return MetaOp_SetInt(
+      key: dco_decode_String(raw[1]),
+      value: dco_decode_i_64(raw[2]),
+    );
+      case 2:
+        return MetaOp_SetBool(
+      key: dco_decode_String(raw[1]),
+      value: dco_decode_bool(raw[2]),
+    );
+      case 3:
+        return MetaOp_SetStringArray(
+      key: dco_decode_String(raw[1]),
+      values: dco_decode_list_String(raw[2]),
+    );
+      case 4:
+        return MetaOp_Remove(key: dco_decode_String(raw[1]));
+      case 5:
+        return MetaOp_PushArrayItem(
+      key: dco_decode_String(raw[1]),
+      value: dco_decode_String(raw[2]),
+    );
+      case 6:
+        return MetaOp_RemoveArrayItem(
+      key: dco_decode_String(raw[1]),
+      value: dco_decode_String(raw[2]),
+    );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  BlockDoc? dco_decode_opt_box_autoadd_block_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_block_doc(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<BlockStats>? dco_decode_opt_list_block_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_block_stats(raw);
+  }
+
+  @protected
+  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_32_strict(raw);
+  }
+
+  @protected
+  OrderedBlock dco_decode_ordered_block(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OrderedBlock(
+      block: dco_decode_block_doc(arr[0]),
+      depth: dco_decode_i_32(arr[1]),
+    );
+  }
+
+  @protected
+  OriginUpdate dco_decode_origin_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OriginUpdate(
+      update: dco_decode_list_prim_u_8_strict(arr[0]),
+      origin: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  (String, BlockDoc) dco_decode_record_string_block_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_block_doc(arr[1]));
+  }
+
+  @protected
+  (String, List<String>) dco_decode_record_string_list_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_list_String(arr[1]));
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  (BigInt, int) dco_decode_record_u_64_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_u_64(arr[0]), dco_decode_u_32(arr[1]));
+  }
+
+  @protected
+  RelativePos dco_decode_relative_pos(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RelativePos(
+      blockId: dco_decode_String(arr[0]),
+      offset: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  TextMatch dco_decode_text_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TextMatch(
+      blockId: dco_decode_String(arr[0]),
+      offset: dco_decode_u_32(arr[1]),
+      length: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  DocumentRegistry
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentService
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentRegistry
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentService
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentRegistry
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentService
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  FutureOr<void> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_usize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, BlockDoc> sse_decode_Map_String_block_doc_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_block_doc(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, List<String>> sse_decode_Map_String_list_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_list_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  DocumentRegistry
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentRegistryImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentService
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<bool> sse_decode_StreamSink_bool_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<OriginUpdate> sse_decode_StreamSink_origin_update_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  ActionResult sse_decode_action_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_generatedIds = sse_decode_list_String(deserializer);
+    return ActionResult(update: var_update, generatedIds: var_generatedIds);
+  }
+
+  @protected
+  AppliedBlockChanges sse_decode_applied_block_changes(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_insertedBlockIds = sse_decode_list_String(deserializer);
+    var var_updatedBlockIds = sse_decode_list_String(deserializer);
+    var var_deletedBlockIds = sse_decode_list_String(deserializer);
+    var var_failedUpdates = sse_decode_failed_to_decode_updates(deserializer);
+    return AppliedBlockChanges(
+      insertedBlockIds: var_insertedBlockIds,
+      updatedBlockIds: var_updatedBlockIds,
+      deletedBlockIds: var_deletedBlockIds,
+      failedUpdates: var_failedUpdates,
+    );
+  }
+
+  @protected
+  BlockActionDoc sse_decode_block_action_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_block_action_type_doc(deserializer);
+    var var_block = sse_decode_block_doc(deserializer);
+    var var_path = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_oldPath = sse_decode_opt_list_prim_u_32_strict(deserializer);
+    return BlockActionDoc(
+      action: var_action,
+      block: var_block,
+      path: var_path,
+      oldPath: var_oldPath,
+    );
+  }
+
+  @protected
+  BlockActionTypeDoc sse_decode_block_action_type_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BlockActionTypeDoc.values[inner];
+  }
+
+  @protected
+  BlockDoc sse_decode_block_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_ty = sse_decode_String(deserializer);
+    var var_attributes = sse_decode_Map_String_String_None(deserializer);
+    var var_delta = sse_decode_opt_String(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_prevId = sse_decode_opt_String(deserializer);
+    var var_nextId = sse_decode_opt_String(deserializer);
+    var var_oldParentId = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_updatedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return BlockDoc(
+      id: var_id,
+      ty: var_ty,
+      attributes: var_attributes,
+      delta: var_delta,
+      parentId: var_parentId,
+      prevId: var_prevId,
+      nextId: var_nextId,
+      oldParentId: var_oldParentId,
+      createdAt: var_createdAt,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  BlockPage sse_decode_block_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blocks = sse_decode_list_block_doc(deserializer);
+    var var_total = sse_decode_usize(deserializer);
+    var var_hasMore = sse_decode_bool(deserializer);
+    return BlockPage(
+      blocks: var_blocks,
+      total: var_total,
+      hasMore: var_hasMore,
+    );
+  }
+
+  @protected
+  BlockStats sse_decode_block_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blockId = sse_decode_String(deserializer);
+    var var_wordCount = sse_decode_u_32(deserializer);
+    var var_charCount = sse_decode_u_32(deserializer);
+    var var_charCountNoSpaces = sse_decode_u_32(deserializer);
+    return BlockStats(
+      blockId: var_blockId,
+      wordCount: var_wordCount,
+      charCount: var_charCount,
+      charCountNoSpaces: var_charCountNoSpaces,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BlockDoc sse_decode_box_autoadd_block_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_block_doc(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CommentDoc sse_decode_comment_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_commentId = sse_decode_String(deserializer);
+    var var_blockId = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_resolved = sse_decode_bool(deserializer);
+    var var_anchorOffset = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return CommentDoc(
+      commentId: var_commentId,
+      blockId: var_blockId,
+      author: var_author,
+      text: var_text,
+      resolved: var_resolved,
+      anchorOffset: var_anchorOffset,
+    );
+  }
+
+  @protected
+  CustomRustError sse_decode_custom_rust_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return CustomRustError(code: var_code, message: var_message);
+  }
+
+  @protected
+  DocSize sse_decode_doc_size(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fullStateBytes = sse_decode_u_64(deserializer);
+    var var_contentBytes = sse_decode_u_64(deserializer);
+    return DocSize(
+      fullStateBytes: var_fullStateBytes,
+      contentBytes: var_contentBytes,
+    );
+  }
+
+  @protected
+  DocStats sse_decode_doc_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_wordCount = sse_decode_u_32(deserializer);
+    var var_charCount = sse_decode_u_32(deserializer);
+    var var_charCountNoSpaces = sse_decode_u_32(deserializer);
+    var var_blockCount = sse_decode_u_32(deserializer);
+    var var_blocks = sse_decode_opt_list_block_stats(deserializer);
+    return DocStats(
+      wordCount: var_wordCount,
+      charCount: var_charCount,
+      charCountNoSpaces: var_charCountNoSpaces,
+      blockCount: var_blockCount,
+      blocks: var_blocks,
+    );
+  }
+
+  @protected
+  DocumentState sse_decode_document_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_docId = sse_decode_String(deserializer);
+    var var_blocks = sse_decode_Map_String_block_doc_None(deserializer);
+    var var_childrenMap = sse_decode_Map_String_list_String_None(deserializer);
+    var var_rootId = sse_decode_String(deserializer);
+    return DocumentState(
+      docId: var_docId,
+      blocks: var_blocks,
+      childrenMap: var_childrenMap,
+      rootId: var_rootId,
+    );
+  }
+
+  @protected
+  DocumentValidation sse_decode_document_validation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_orphanedBlockIds = sse_decode_list_String(deserializer);
+    var var_cyclicBlockIds = sse_decode_list_String(deserializer);
+    var var_danglingReferenceIds = sse_decode_list_String(deserializer);
+    var var_orphanedCommentIds = sse_decode_list_String(deserializer);
+    return DocumentValidation(
+      orphanedBlockIds: var_orphanedBlockIds,
+      cyclicBlockIds: var_cyclicBlockIds,
+      danglingReferenceIds: var_danglingReferenceIds,
+      orphanedCommentIds: var_orphanedCommentIds,
+    );
+  }
+
+  @protected
+  DuplicateResult sse_decode_duplicate_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_idMap = sse_decode_Map_String_String_None(deserializer);
+    return DuplicateResult(update: var_update, idMap: var_idMap);
+  }
+
+  @protected
+  FailedToDecodeUpdates sse_decode_failed_to_decode_updates(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_failedUpdatesIds = sse_decode_list_String(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return FailedToDecodeUpdates(
+      failedUpdatesIds: var_failedUpdatesIds,
+      errors: var_errors,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BlockActionDoc> sse_decode_list_block_action_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BlockActionDoc>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_block_action_doc(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BlockDoc> sse_decode_list_block_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BlockDoc>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_block_doc(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BlockStats> sse_decode_list_block_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BlockStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_block_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CommentDoc> sse_decode_list_comment_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CommentDoc>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_comment_doc(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint8List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MetaOp> sse_decode_list_meta_op(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MetaOp>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_meta_op(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OrderedBlock> sse_decode_list_ordered_block(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OrderedBlock>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ordered_block(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, BlockDoc)> sse_decode_list_record_string_block_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, BlockDoc)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_block_doc(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, List<String>)> sse_decode_list_record_string_list_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, List<String>)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_list_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(BigInt, int)> sse_decode_list_record_u_64_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(BigInt, int)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_u_64_u_32(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextMatch> sse_decode_list_text_match(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextMatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_match(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MetaOp sse_decode_meta_op(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_key = sse_decode_String(deserializer);
+        var var_value = sse_decode_String(deserializer);
+        return MetaOp_SetString(key: var_key, value: var_value);
+      case 1:
+        var var_key = sse_decode_String(deserializer);
+        var var_value = sse_decode_i_64(deserializer);
+        return MetaOp_SetInt(key: var_key, value: var_value);
+      case 2:
+        var var_key = sse_decode_String(deserializer);
+        var var_value = sse_decode_bool(deserializer);
+        return MetaOp_SetBool(key: var_key, value: var_value);
+      case 3:
+        var var_key = sse_decode_String(deserializer);
+        var var_values = sse_decode_list_String(deserializer);
+        return MetaOp_SetStringArray(key: var_key, values: var_values);
+      case 4:
+        var var_key = sse_decode_String(deserializer);
+        return MetaOp_Remove(key: var_key);
+      case 5:
+        var var_key = sse_decode_String(deserializer);
+        var var_value = sse_decode_String(deserializer);
+        return MetaOp_PushArrayItem(key: var_key, value: var_value);
+      case 6:
+        var var_key = sse_decode_String(deserializer);
+        var var_value = sse_decode_String(deserializer);
+        return MetaOp_RemoveArrayItem(key: var_key, value: var_value);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BlockDoc? sse_decode_opt_box_autoadd_block_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_block_doc(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
-    required DocumentService that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_init_empty_doc",
-        argNames: ["that"],
-      );
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdates({
-    required DocumentService that,
-    required List<Uint8List> updates,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_list_list_prim_u_8_strict(updates, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta,
-        argValues: [that, updates],
-        apiImpl: this,
-      ),
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<BlockStats>? sse_decode_opt_list_block_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_block_stats(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint32List? sse_decode_opt_list_prim_u_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OrderedBlock sse_decode_ordered_block(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_block = sse_decode_block_doc(deserializer);
+    var var_depth = sse_decode_i_32(deserializer);
+    return OrderedBlock(block: var_block, depth: var_depth);
+  }
+
+  @protected
+  OriginUpdate sse_decode_origin_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_origin = sse_decode_opt_String(deserializer);
+    return OriginUpdate(update: var_update, origin: var_origin);
+  }
+
+  @protected
+  (String, BlockDoc) sse_decode_record_string_block_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_block_doc(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, List<String>) sse_decode_record_string_list_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_list_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (BigInt, int) sse_decode_record_u_64_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_64(deserializer);
+    var var_field1 = sse_decode_u_32(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RelativePos sse_decode_relative_pos(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blockId = sse_decode_String(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    return RelativePos(blockId: var_blockId, offset: var_offset);
+  }
+
+  @protected
+  TextMatch sse_decode_text_match(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blockId = sse_decode_String(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_length = sse_decode_u_32(deserializer);
+    return TextMatch(
+      blockId: var_blockId,
+      offset: var_offset,
+      length: var_length,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_merge_updates",
-        argNames: ["that", "updates"],
-      );
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
 
-  @override
-  Future<DocumentService> crateDocDocumentServiceDocumentServiceNew() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentRegistryImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
-  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceNewConstMeta =>
-      const TaskConstMeta(debugName: "DocumentService_new", argNames: []);
-
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServicePushMetaArrayItem({
-    required DocumentService that,
-    required String key,
-    required String value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 9,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServicePushMetaArrayItemConstMeta,
-        argValues: [that, key, value],
-        apiImpl: this,
-      ),
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    DocumentService self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentServiceImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServicePushMetaArrayItemConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_push_meta_array_item",
-        argNames: ["that", "key", "value"],
-      );
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentRegistryImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaArrayItem({
-    required DocumentService that,
-    required String key,
-    required String value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceRemoveMetaArrayItemConstMeta,
-        argValues: [that, key, value],
-        apiImpl: this,
-      ),
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    DocumentService self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentServiceImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceRemoveMetaArrayItemConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_remove_meta_array_item",
-        argNames: ["that", "key", "value"],
-      );
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentRegistryImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceRemoveMetaKey({
-    required DocumentService that,
-    required String key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceRemoveMetaKeyConstMeta,
-        argValues: [that, key],
-        apiImpl: this,
-      ),
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    DocumentService self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentServiceImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceRemoveMetaKeyConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_remove_meta_key",
-        argNames: ["that", "key"],
-      );
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaBool({
-    required DocumentService that,
-    required String key,
-    required bool value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_bool(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      PlatformPointerUtil.ptrToBigInt(
+        encodeDartOpaque(
+          self,
+          portManager.dartHandlerPort,
+          generalizedFrbRustBinding,
         ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceSetMetaBoolConstMeta,
-        argValues: [that, key, value],
-        apiImpl: this,
       ),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetMetaBoolConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_set_meta_bool",
-        argNames: ["that", "key", "value"],
-      );
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaFromJson({
-    required DocumentService that,
-    required String jsonStr,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(jsonStr, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceSetMetaFromJsonConstMeta,
-        argValues: [that, jsonStr],
-        apiImpl: this,
-      ),
+  @protected
+  void sse_encode_Map_String_block_doc_None(
+    Map<String, BlockDoc> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_block_doc(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetMetaFromJsonConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_set_meta_from_json",
-        argNames: ["that", "jsonStr"],
-      );
+  @protected
+  void sse_encode_Map_String_list_String_None(
+    Map<String, List<String>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_list_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaInt({
-    required DocumentService that,
-    required String key,
-    required PlatformInt64 value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_i_64(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
-        ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceSetMetaIntConstMeta,
-        argValues: [that, key, value],
-        apiImpl: this,
-      ),
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentRegistryImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetMetaIntConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_set_meta_int",
-        argNames: ["that", "key", "value"],
-      );
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    DocumentService self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as DocumentServiceImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaString({
-    required DocumentService that,
-    required String key,
-    required String value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
+  @protected
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceSetMetaStringConstMeta,
-        argValues: [that, key, value],
-        apiImpl: this,
       ),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetMetaStringConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_set_meta_string",
-        argNames: ["that", "key", "value"],
-      );
-
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetMetaStringArray({
-    required DocumentService that,
-    required String key,
-    required List<String> values,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(key, serializer);
-          sse_encode_list_String(values, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
+  @protected
+  void sse_encode_StreamSink_bool_Sse(
+    RustStreamSink<bool> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceSetMetaStringArrayConstMeta,
-        argValues: [that, key, values],
-        apiImpl: this,
       ),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetMetaStringArrayConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_set_meta_string_array",
-        argNames: ["that", "key", "values"],
-      );
-
-  @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
-    required DocumentService that,
-    required String id,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-            that,
-            serializer,
-          );
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta,
-        argValues: [that, id],
-        apiImpl: this,
       ),
+      serializer,
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "DocumentService_set_root_node_id",
-        argNames: ["that", "id"],
-      );
-
-  @override
-  Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
-    required String message,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
+  @protected
+  void sse_encode_StreamSink_origin_update_Sse(
+    RustStreamSink<OriginUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
         codec: SseCodec(
-          decodeSuccessData: sse_decode_custom_rust_error,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_origin_update,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateDocDocumentTypesCustomRustErrorNewConstMeta,
-        argValues: [message],
-        apiImpl: this,
       ),
+      serializer,
     );
   }
 
-  TaskConstMeta get kCrateDocDocumentTypesCustomRustErrorNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "custom_rust_error_new",
-        argNames: ["message"],
-      );
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DocumentService =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
+  @protected
+  void sse_encode_action_result(ActionResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+    sse_encode_list_String(self.generatedIds, serializer);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DocumentService =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
+  @protected
+  void sse_encode_applied_block_changes(
+    AppliedBlockChanges self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.insertedBlockIds, serializer);
+    sse_encode_list_String(self.updatedBlockIds, serializer);
+    sse_encode_list_String(self.deletedBlockIds, serializer);
+    sse_encode_failed_to_decode_updates(self.failedUpdates, serializer);
+  }
+
+  @protected
+  void sse_encode_block_action_doc(
+    BlockActionDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_block_action_type_doc(self.action, serializer);
+    sse_encode_block_doc(self.block, serializer);
+    sse_encode_list_prim_u_32_strict(self.path, serializer);
+    sse_encode_opt_list_prim_u_32_strict(self.oldPath, serializer);
+  }
 
   @protected
-  DocumentService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    dynamic raw,
+  void sse_encode_block_action_type_doc(
+    BlockActionTypeDoc self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  DocumentService
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  void sse_encode_block_doc(BlockDoc self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.ty, serializer);
+    sse_encode_Map_String_String_None(self.attributes, serializer);
+    sse_encode_opt_String(self.delta, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_opt_String(self.prevId, serializer);
+    sse_encode_opt_String(self.nextId, serializer);
+    sse_encode_opt_String(self.oldParentId, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.updatedAt, serializer);
   }
 
   @protected
-  DocumentService
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  void sse_encode_block_page(BlockPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_block_doc(self.blocks, serializer);
+    sse_encode_usize(self.total, serializer);
+    sse_encode_bool(self.hasMore, serializer);
   }
 
   @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+  void sse_encode_block_stats(BlockStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.blockId, serializer);
+    sse_encode_u_32(self.wordCount, serializer);
+    sse_encode_u_32(self.charCount, serializer);
+    sse_encode_u_32(self.charCountNoSpaces, serializer);
   }
 
   @protected
-  Map<String, BlockDoc> dco_decode_Map_String_block_doc_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_block_doc(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  Map<String, List<String>> dco_decode_Map_String_list_String_None(
-    dynamic raw,
+  void sse_encode_box_autoadd_block_doc(
+    BlockDoc self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_list_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_block_doc(self, serializer);
   }
 
   @protected
-  DocumentService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    dynamic raw,
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  BlockActionDoc dco_decode_block_action_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return BlockActionDoc(
-      action: dco_decode_block_action_type_doc(arr[0]),
-      block: dco_decode_block_doc(arr[1]),
-      path: dco_decode_list_prim_u_32_strict(arr[2]),
-      oldPath: dco_decode_opt_list_prim_u_32_strict(arr[3]),
-    );
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  BlockActionTypeDoc dco_decode_block_action_type_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BlockActionTypeDoc.values[raw as int];
+  void sse_encode_comment_doc(CommentDoc self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.commentId, serializer);
+    sse_encode_String(self.blockId, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_bool(self.resolved, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.anchorOffset, serializer);
   }
 
   @protected
-  BlockDoc dco_decode_block_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return BlockDoc(
-      id: dco_decode_String(arr[0]),
-      ty: dco_decode_String(arr[1]),
-      attributes: dco_decode_Map_String_String_None(arr[2]),
-      delta: dco_decode_opt_String(arr[3]),
-      parentId: dco_decode_opt_String(arr[4]),
-      prevId: dco_decode_opt_String(arr[5]),
-      nextId: dco_decode_opt_String(arr[6]),
-      oldParentId: dco_decode_opt_String(arr[7]),
-    );
+  void sse_encode_custom_rust_error(
+    CustomRustError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.code, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_doc_size(DocSize self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.fullStateBytes, serializer);
+    sse_encode_u_64(self.contentBytes, serializer);
   }
 
   @protected
-  CustomRustError dco_decode_custom_rust_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return CustomRustError(message: dco_decode_String(arr[0]));
+  void sse_encode_doc_stats(DocStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.wordCount, serializer);
+    sse_encode_u_32(self.charCount, serializer);
+    sse_encode_u_32(self.charCountNoSpaces, serializer);
+    sse_encode_u_32(self.blockCount, serializer);
+    sse_encode_opt_list_block_stats(self.blocks, serializer);
   }
 
   @protected
-  DocumentState dco_decode_document_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return DocumentState(
-      docId: dco_decode_String(arr[0]),
-      blocks: dco_decode_Map_String_block_doc_None(arr[1]),
-      childrenMap: dco_decode_Map_String_list_String_None(arr[2]),
-      rootId: dco_decode_String(arr[3]),
-    );
+  void sse_encode_document_state(DocumentState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.docId, serializer);
+    sse_encode_Map_String_block_doc_None(self.blocks, serializer);
+    sse_encode_Map_String_list_String_None(self.childrenMap, serializer);
+    sse_encode_String(self.rootId, serializer);
   }
 
   @protected
-  FailedToDecodeUpdates dco_decode_failed_to_decode_updates(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return FailedToDecodeUpdates(
-      failedUpdatesIds: dco_decode_list_String(arr[0]),
-    );
+  void sse_encode_document_validation(
+    DocumentValidation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.orphanedBlockIds, serializer);
+    sse_encode_list_String(self.cyclicBlockIds, serializer);
+    sse_encode_list_String(self.danglingReferenceIds, serializer);
+    sse_encode_list_String(self.orphanedCommentIds, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_duplicate_result(
+    DuplicateResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+    sse_encode_Map_String_String_None(self.idMap, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_failed_to_decode_updates(
+    FailedToDecodeUpdates self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.failedUpdatesIds, serializer);
+    sse_encode_list_String(self.errors, serializer);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  List<BlockActionDoc> dco_decode_list_block_action_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_block_action_doc).toList();
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint32List;
+  void sse_encode_list_block_action_doc(
+    List<BlockActionDoc> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_block_action_doc(item, serializer);
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_list_block_doc(
+    List<BlockDoc> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_block_doc(item, serializer);
+    }
   }
 
   @protected
-  List<(String, BlockDoc)> dco_decode_list_record_string_block_doc(
-    dynamic raw,
+  void sse_encode_list_block_stats(
+    List<BlockStats> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_block_doc)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_block_stats(item, serializer);
+    }
   }
 
   @protected
-  List<(String, List<String>)> dco_decode_list_record_string_list_string(
-    dynamic raw,
+  void sse_encode_list_comment_doc(
+    List<CommentDoc> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_list_string)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_comment_doc(item, serializer);
+    }
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  void sse_encode_list_list_prim_u_8_strict(
+    List<Uint8List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_8_strict(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_meta_op(List<MetaOp> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_meta_op(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_ordered_block(
+    List<OrderedBlock> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ordered_block(item, serializer);
+    }
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_32_strict(raw);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  (String, BlockDoc) dco_decode_record_string_block_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
+  void sse_encode_list_record_string_block_doc(
+    List<(String, BlockDoc)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_block_doc(item, serializer);
     }
-    return (dco_decode_String(arr[0]), dco_decode_block_doc(arr[1]));
   }
 
   @protected
-  (String, List<String>) dco_decode_record_string_list_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
+  void sse_encode_list_record_string_list_string(
+    List<(String, List<String>)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_list_string(item, serializer);
     }
-    return (dco_decode_String(arr[0]), dco_decode_list_String(arr[1]));
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
     }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_record_u_64_u_32(
+    List<(BigInt, int)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_u_64_u_32(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_text_match(
+    List<TextMatch> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_match(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_meta_op(MetaOp self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MetaOp_SetString(key: final key, value: final value):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_String(value, serializer);
+      case MetaOp_SetInt(key: final key, value: final value):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_i_64(value, serializer);
+      case MetaOp_SetBool(key: final key, value: final value):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_bool(value, serializer);
+      case MetaOp_SetStringArray(key: final key, values: final values):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_list_String(values, serializer);
+      case MetaOp_Remove(key: final key):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(key, serializer);
+      case MetaOp_PushArrayItem(key: final key, value: final value):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_String(value, serializer);
+      case MetaOp_RemoveArrayItem(key: final key, value: final value):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_String(value, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  DocumentService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_block_doc(
+    BlockDoc? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_block_doc(self, serializer);
+    }
   }
 
   @protected
-  DocumentService
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  DocumentService
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  Map<String, BlockDoc> sse_decode_Map_String_block_doc_None(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_block_doc(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  Map<String, List<String>> sse_decode_Map_String_list_String_None(
-    SseDeserializer deserializer,
+  void sse_encode_opt_list_String(
+    List<String>? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_list_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
   }
 
   @protected
-  DocumentService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    SseDeserializer deserializer,
+  void sse_encode_opt_list_block_stats(
+    List<BlockStats>? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_block_stats(self, serializer);
+    }
   }
 
   @protected
-  BlockActionDoc sse_decode_block_action_doc(SseDeserializer deserializer) {
+  void sse_encode_opt_list_prim_u_32_strict(
+    Uint32List? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_action = sse_decode_block_action_type_doc(deserializer);
-    var var_block = sse_decode_block_doc(deserializer);
-    var var_path = sse_decode_list_prim_u_32_strict(deserializer);
-    var var_oldPath = sse_decode_opt_list_prim_u_32_strict(deserializer);
-    return BlockActionDoc(
-      action: var_action,
-      block: var_block,
-      path: var_path,
-      oldPath: var_oldPath,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_32_strict(self, serializer);
+    }
   }
 
   @protected
-  BlockActionTypeDoc sse_decode_block_action_type_doc(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_ordered_block(OrderedBlock self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return BlockActionTypeDoc.values[inner];
+    sse_encode_block_doc(self.block, serializer);
+    sse_encode_i_32(self.depth, serializer);
   }
 
   @protected
-  BlockDoc sse_decode_block_doc(SseDeserializer deserializer) {
+  void sse_encode_origin_update(OriginUpdate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_ty = sse_decode_String(deserializer);
-    var var_attributes = sse_decode_Map_String_String_None(deserializer);
-    var var_delta = sse_decode_opt_String(deserializer);
-    var var_parentId = sse_decode_opt_String(deserializer);
-    var var_prevId = sse_decode_opt_String(deserializer);
-    var var_nextId = sse_decode_opt_String(deserializer);
-    var var_oldParentId = sse_decode_opt_String(deserializer);
-    return BlockDoc(
-      id: var_id,
-      ty: var_ty,
-      attributes: var_attributes,
-      delta: var_delta,
-      parentId: var_parentId,
-      prevId: var_prevId,
-      nextId: var_nextId,
-      oldParentId: var_oldParentId,
-    );
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+    sse_encode_opt_String(self.origin, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_record_string_block_doc(
+    (String, BlockDoc) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.$1, serializer);
+    sse_encode_block_doc(self.$2, serializer);
   }
 
   @protected
-  CustomRustError sse_decode_custom_rust_error(SseDeserializer deserializer) {
+  void sse_encode_record_string_list_string(
+    (String, List<String>) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_message = sse_decode_String(deserializer);
-    return CustomRustError(message: var_message);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_list_String(self.$2, serializer);
   }
 
   @protected
-  DocumentState sse_decode_document_state(SseDeserializer deserializer) {
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_docId = sse_decode_String(deserializer);
-    var var_blocks = sse_decode_Map_String_block_doc_None(deserializer);
-    var var_childrenMap = sse_decode_Map_String_list_String_None(deserializer);
-    var var_rootId = sse_decode_String(deserializer);
-    return DocumentState(
-      docId: var_docId,
-      blocks: var_blocks,
-      childrenMap: var_childrenMap,
-      rootId: var_rootId,
-    );
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
   }
 
   @protected
-  FailedToDecodeUpdates sse_decode_failed_to_decode_updates(
-    SseDeserializer deserializer,
+  void sse_encode_record_u_64_u_32(
+    (BigInt, int) self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_failedUpdatesIds = sse_decode_list_String(deserializer);
-    return FailedToDecodeUpdates(failedUpdatesIds: var_failedUpdatesIds);
+    sse_encode_u_64(self.$1, serializer);
+    sse_encode_u_32(self.$2, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_relative_pos(RelativePos self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_String(self.blockId, serializer);
+    sse_encode_u_32(self.offset, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_text_match(TextMatch self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_String(self.blockId, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_u_32(self.length, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  List<BlockActionDoc> sse_decode_list_block_action_doc(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BlockActionDoc>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_block_action_doc(deserializer));
-    }
-    return ans_;
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putBigUint64(self);
   }
+}
 
-  @protected
-  List<(String, BlockDoc)> sse_decode_list_record_string_block_doc(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+@sealed
+class DocumentRegistryImpl extends RustOpaque implements DocumentRegistry {
+  // Not to be used by end users
+  DocumentRegistryImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, BlockDoc)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_block_doc(deserializer));
-    }
-    return ans_;
-  }
+  // Not to be used by end users
+  DocumentRegistryImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
-  @protected
-  List<(String, List<String>)> sse_decode_list_record_string_list_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_DocumentRegistry,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_DocumentRegistry,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_DocumentRegistryPtr,
+  );
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, List<String>)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_list_string(deserializer));
-    }
-    return ans_;
-  }
+  Future<Uint8List> applyAction({
+    required String docId,
+    required List<BlockActionDoc> actions,
+  }) =>
+      RustLib.instance.api.crateDocDocumentRegistryDocumentRegistryApplyAction(
+        that: this,
+        docId: docId,
+        actions: actions,
+      );
 
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+  Future<AppliedBlockChanges> applyUpdates({
+    required String docId,
+    required List<Uint8List> updates,
+  }) =>
+      RustLib.instance.api.crateDocDocumentRegistryDocumentRegistryApplyUpdates(
+        that: this,
+        docId: docId,
+        updates: updates,
+      );
+
+  /// 문서를 닫고 Doc을 해제
+  ///
+  /// [doc_id] 문서 id
+  ///
+  /// 반환: 열려 있던 문서였는지 여부
+  Future<bool> close({required String docId}) => RustLib.instance.api
+      .crateDocDocumentRegistryDocumentRegistryClose(that: this, docId: docId);
+
+  Future<Uint8List> encodeFullState({required String docId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentRegistryDocumentRegistryEncodeFullState(
+        that: this,
+        docId: docId,
+      );
+
+  Future<DocumentState> getDocumentState({required String docId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentRegistryDocumentRegistryGetDocumentState(
+        that: this,
+        docId: docId,
+      );
+
+  Future<Uint8List> initEmptyDoc({required String docId}) =>
+      RustLib.instance.api.crateDocDocumentRegistryDocumentRegistryInitEmptyDoc(
+        that: this,
+        docId: docId,
+      );
+
+  /// 열려 있는 문서 id 목록 (정렬됨)
+  Future<List<String>> listOpen() => RustLib.instance.api
+      .crateDocDocumentRegistryDocumentRegistryListOpen(that: this);
+
+  /// 문서를 열기 (이미 열려 있으면 그대로 둠)
+  ///
+  /// [doc_id] 문서 id
+  Future<void> open({required String docId}) => RustLib.instance.api
+      .crateDocDocumentRegistryDocumentRegistryOpen(that: this, docId: docId);
+}
+
+@sealed
+class DocumentServiceImpl extends RustOpaque implements DocumentService {
+  // Not to be used by end users
+  DocumentServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  DocumentServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_DocumentService,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_DocumentService,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_DocumentServicePtr,
+  );
+
+  /// 최근 window_ms 동안 awareness 상태를 갱신한 편집자 수 ("3명 편집 중" 표시용)
+  ///
+  /// [window_ms] 활동으로 인정할 기간 (밀리초)
+  ///
+  /// AWARENESS_TIMEOUT_MS 동안 소식이 없는 원격 클라이언트는 먼저 오프라인 처리됨
+  Future<int> activeEditorCount({required BigInt windowMs}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceActiveEditorCount(
+        that: this,
+        windowMs: windowMs,
+      );
+
+  /// 블록에 댓글 추가
+  ///
+  /// [block_id] 댓글을 붙일 블록 id
+  /// [comment_id] 댓글 id (Dart에서 생성, 문서 안에서 고유해야 함)
+  /// [author] 작성자
+  /// [text] 댓글 내용
+  /// [anchor_offset] 블록 텍스트 안의 위치 (None이면 블록 전체에 대한 댓글).
+  /// 상대 위치로 저장되므로 앞쪽 텍스트가 편집되어도 같은 글자를 따라감
+  ///
+  /// 블록이 없거나, 같은 id의 댓글이 있거나, 위치가 텍스트 범위를 벗어나면 에러
+  Future<Uint8List> addComment({
+    required String blockId,
+    required String commentId,
+    required String author,
+    required String text,
+    int? anchorOffset,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceAddComment(
+    that: this,
+    blockId: blockId,
+    commentId: commentId,
+    author: author,
+    text: text,
+    anchorOffset: anchorOffset,
+  );
+
+  /// 피어에게 없는 내용만 담고 삭제는 모두 뺀 업데이트 생성 (병합 전용 동기화용)
+  ///
+  /// [peer_state_vector] 피어의 state vector (v1 인코딩)
+  ///
+  /// 주의: 로컬에서 삭제한 내용이 피어에는 남으므로, 삭제가 실제로 필요한 경우 두 문서가 갈라질 수 있음.
+  /// 이미 삭제된 상태로 전달되는 새 항목은 피어에서도 삭제된 상태로 들어감 (피어의 기존 내용만 보존됨).
+  Future<Uint8List> additiveUpdateFor({required Uint8List peerStateVector}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceAdditiveUpdateFor(
+        that: this,
+        peerStateVector: peerStateVector,
+      );
+
+  Future<Uint8List> applyAction({required List<BlockActionDoc> actions}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyAction(
+        that: this,
+        actions: actions,
+      );
+
+  /// apply_action과 같지만 id 없이 보낸 Insert 블록에 Rust에서 만든 id도 함께 반환 (새 블록에 바로 포커스 주기 위함)
+  ///
+  /// [actions] 적용할 블록 작업 목록, Insert의 block.id가 빈 문자열이면 UUID를 새로 생성
+  ///
+  /// 반환: 업데이트와 생성된 id 목록 (작업 순서대로, id를 지정한 Insert는 포함되지 않음).
+  /// apply_action도 빈 id에 id를 생성하지만 기존 호출과의 호환을 위해 업데이트만 반환함
+  Future<ActionResult> applyActionWithIds({
+    required List<BlockActionDoc> actions,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyActionWithIds(
+        that: this,
+        actions: actions,
+      );
+
+  /// apply_action과 같지만 트랜잭션에 origin을 붙여 실행
+  ///
+  /// [actions] 적용할 블록 작업 목록
+  /// [origin] 트랜잭션 origin (subscribe_changes 스트림에 그대로 전달됨)
+  ///
+  /// origin 없는 apply_action은 기본(origin 없음) 트랜잭션을 쓰므로 기존 undo 추적에 영향 없음
+  Future<Uint8List> applyActionWithOrigin({
+    required List<BlockActionDoc> actions,
+    required String origin,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyActionWithOrigin(
+        that: this,
+        actions: actions,
+        origin: origin,
+      );
+
+  /// apply_action과 같지만 Insert/Update 블록에 생성/수정 시각을 기록 ("2분 전 편집" 표시용)
+  ///
+  /// [actions] 적용할 블록 작업 목록
+  /// [timestamp_ms] Dart에서 넘겨주는 현재 시각 (밀리초), 협업자마다 시계가 다르므로 Rust에서 시스템 시간을 읽지 않음
+  ///
+  /// Insert는 createdAt과 updatedAt을, Update는 updatedAt만 기록.
+  /// updatedAt은 클라이언트별로 저장하고 가장 큰 값을 보여주므로, 동시 편집이나 시계 차이로 뒤로 가지 않음.
+  /// 기록된 시각은 get_block/get_document_state의 created_at/updated_at으로 확인
+  Future<Uint8List> applyActionWithTimestamp({
+    required List<BlockActionDoc> actions,
+    required PlatformInt64 timestampMs,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyActionWithTimestamp(
+        that: this,
+        actions: actions,
+        timestampMs: timestampMs,
+      );
+
+  /// 다른 클라이언트의 awareness 업데이트 적용
+  ///
+  /// [update] encode_awareness_update로 만든 바이트 (v1)
+  ///
+  /// 반환: 바뀐 클라이언트 id JSON (예: {"added":[12],"updated":[34],"removed":[]})
+  Future<String> applyAwarenessUpdate({required Uint8List update}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceApplyAwarenessUpdate(
+        that: this,
+        update: update,
+      );
+
+  /// 블록의 기존 텍스트에 Quill delta를 적용 (글자 단위 CRDT 병합 유지)
+  ///
+  /// [block_id] 대상 블록 id
+  /// [delta_json] retain/insert/delete 연산 목록 (attributes 포함 가능)
+  ///
+  /// 블록이 없거나 텍스트 필드가 없으면 에러 반환 (텍스트를 새로 만들지 않음)
+  Future<Uint8List> applyDelta({
+    required String blockId,
+    required String deltaJson,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyDelta(
+    that: this,
+    blockId: blockId,
+    deltaJson: deltaJson,
+  );
+
+  /// encode_meta_only_update로 만든 업데이트의 메타데이터를 이 문서의 META 맵에 반영
+  ///
+  /// [update] encode_meta_only_update 결과 (encode_full_state 같은 전체 상태도 가능)
+  ///
+  /// 업데이트에 블록 데이터가 있어도 무시하고 META만 반영. 업데이트에 없는 키는 제거되고 값이 다른 키만 다시 씀.
+  /// 업데이트에 META 맵이 없으면 (일부만 담긴 diff 등) 메타를 지우지 않도록 에러 반환
+  ///
+  /// 주의: CRDT 병합이 아니라 마지막에 적용한 쪽이 이기는 교체임. 업데이트를 만든 뒤에 이 문서에서 바꾼 메타도
+  /// 업데이트 시점의 값으로 덮어쓰거나 지우므로, 오래된 스냅샷을 적용하면 더 최신의 로컬 메타 변경이 사라짐.
+  /// 노트 목록처럼 메타를 읽기만 하는 복사본을 최신으로 맞추는 용도로만 쓰고, 편집 중인 문서의 메타 동기화는
+  /// encode_diff/apply_updates로 해야 함
+  ///
+  /// 반환: 이 문서에 적용된 변경의 업데이트 (로컬 저장용)
+  Future<Uint8List> applyMetaOnlyUpdate({required Uint8List update}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceApplyMetaOnlyUpdate(
+        that: this,
+        update: update,
+      );
+
+  /// 원격 하위 문서 업데이트(v2)를 적용 (부모 문서 변경 스트림에는 나타나지 않음)
+  ///
+  /// [guid] 하위 문서 guid
+  /// [update] 적용할 업데이트
+  Future<void> applySubdocUpdate({
+    required String guid,
+    required Uint8List update,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplySubdocUpdate(
+        that: this,
+        guid: guid,
+        update: update,
+      );
+
+  /// 블록 텍스트에 여러 편집(retain/insert/delete)을 하나의 트랜잭션으로 적용
+  ///
+  /// [block_id] 대상 블록 id
+  /// [ops_json] Quill delta 형식의 연산 목록 (예: [{"retain": 5}, {"insert": "!"}, {"delete": 2}])
+  ///
+  /// 모든 연산의 범위를 먼저 검사하므로, 하나라도 범위를 벗어나면 아무것도 적용하지 않고 에러 반환
+  Future<Uint8List> applyTextOps({
+    required String blockId,
+    required String opsJson,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyTextOps(
+    that: this,
+    blockId: blockId,
+    opsJson: opsJson,
+  );
+
+  /// 원격 업데이트 하나를 적용하고 새 내용이 들어왔는지 반환 (이미 받은 업데이트를 다시 브로드캐스트하지 않기 위함)
+  ///
+  /// [update] 적용할 업데이트 (v2)
+  ///
+  /// 반환: 적용 전후 state vector를 비교해 앞으로 나아갔으면 true, 이미 가진 내용뿐이면 false.
+  /// 삭제만 담긴 업데이트는 state vector를 바꾸지 않으므로 false가 반환됨. 디코딩에 실패하면 에러
+  Future<bool> applyUpdate({required Uint8List update}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyUpdate(
+        that: this,
+        update: update,
+      );
+
+  /// 블록 id를 매핑에 따라 바꿔서 업데이트를 현재 문서에 적용 (템플릿 인스턴스화 용도)
+  ///
+  /// [update] 적용할 업데이트 (v2 인코딩)
+  /// [id_map_json] 원래 id -> 새 id 매핑 JSON (예: {"tpl-heading": "a8Kd2x"})
+  ///
+  /// 매핑에 없는 id는 그대로 유지되며, 결과 id가 기존 블록과 겹치면 아무것도 적용하지 않고 에러 반환
+  Future<Uint8List> applyUpdateRemapped({
+    required Uint8List update,
+    required String idMapJson,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyUpdateRemapped(
+        that: this,
+        update: update,
+        idMapJson: idMapJson,
+      );
+
+  /// 원격 업데이트를 적용하고 추가/수정/삭제된 블록 id를 반환
+  ///
+  /// [updates] 적용할 업데이트 목록
+  ///
+  /// 메타만 바뀐 경우 세 목록 모두 비어 있음
+  Future<AppliedBlockChanges> applyUpdates({
+    required List<Uint8List> updates,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyUpdates(
+    that: this,
+    updates: updates,
+  );
+
+  /// v1 인코딩 업데이트를 적용하고 추가/수정/삭제된 블록 id를 반환
+  ///
+  /// [updates] 적용할 업데이트 목록 (v1 인코딩)
+  ///
+  /// v1과 v2는 호환되지 않으므로 v1으로 디코딩한 뒤 apply_updates와 같은 경로로 적용함.
+  /// 디코딩에 실패한 업데이트는 로그를 남기고 건너뜀
+  Future<AppliedBlockChanges> applyUpdatesV1({
+    required List<Uint8List> updates,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyUpdatesV1(
+        that: this,
+        updates: updates,
+      );
+
+  /// 원격 업데이트를 적용하고 블록별 변경 종류를 JSON으로 반환
+  ///
+  /// [updates] 적용할 업데이트 목록
+  ///
+  /// 반환: {"blockA": "inserted", "blockB": "text_changed", "blockC": "deleted"}
+  /// 변경 종류: inserted, deleted, text_changed, data_changed, text_and_data_changed
+  /// 같은 업데이트 안에서 추가 후 삭제된 블록은 포함되지 않음
+  Future<String> applyUpdatesWithChanges({required List<Uint8List> updates}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceApplyUpdatesWithChanges(
+        that: this,
+        updates: updates,
+      );
+
+  /// 블록 데이터에 저장된 모든 바이너리 첨부의 총 바이트 수
+  Future<BigInt> attachmentTotalBytes() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceAttachmentTotalBytes(that: this);
+
+  /// 업데이트 묶음 시작. 이후 commit_batch까지 이 문서에서 일어난 로컬 변경의 업데이트를 모아둠
+  ///
+  /// 각 편집 함수는 여전히 자신의 업데이트를 반환하지만, 묶음 중에는 전송하지 않고 commit_batch 결과만 보내면 됨.
+  /// apply_updates로 적용된 원격 업데이트(REMOTE_ORIGIN)는 다시 보낼 필요가 없으므로 모으지 않음.
+  /// 이미 묶음이 진행 중이면 에러
+  Future<void> beginBatch() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceBeginBatch(that: this);
+
+  /// 두 블록을 합칠 수 있는지 여부 ("이전 블록과 합치기" 메뉴 활성화용)
+  ///
+  /// [first_id] 남게 되는 앞 블록 id
+  /// [second_id] 앞 블록에 합쳐질 블록 id
+  ///
+  /// 두 블록이 모두 존재하고, 둘 다 텍스트를 가지며, 타입이 호환되고(서식 있는 텍스트 블록끼리, code는 code끼리),
+  /// second가 first의 조상이 아니어야 함. 판정은 merge_blocks가 쓰는 BlockOperations::check_merge 그대로
+  Future<bool> canMergeBlocks({
+    required String firstId,
+    required String secondId,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceCanMergeBlocks(
+        that: this,
+        firstId: firstId,
+        secondId: secondId,
+      );
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
-  }
+  /// 다시 실행 가능 여부 (툴바 버튼 활성화용, UndoManager가 없으면 false)
+  Future<bool> canRedo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCanRedo(that: this);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+  /// 실행 취소 가능 여부 (툴바 버튼 활성화용, UndoManager가 없으면 false)
+  Future<bool> canUndo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCanUndo(that: this);
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
+  /// 부모의 자식 순서를 SHA-256 해시로 반환 (피어 간 형제 순서가 갈라진 곳을 빠르게 찾기 위함)
+  ///
+  /// [parent_id] 자식 순서를 확인할 부모 블록 id (부모가 없는 최상위 블록은 "root")
+  ///
+  /// 문서 상태와 같은 prevId 체인 정렬을 사용하며, 체인이 끊기거나 순환하는 자식이 있으면 에러 반환
+  Future<String> childrenOrderHash({required String parentId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceChildrenOrderHash(
+        that: this,
+        parentId: parentId,
+      );
 
-  @protected
-  Uint32List? sse_decode_opt_list_prim_u_32_strict(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+  /// 같은 Doc을 유지한 채 모든 블록, 메타, 댓글을 지움 ("모든 내용 삭제"용)
+  ///
+  /// BLOCKS/META 맵은 비운 상태로 남아 있어 다시 초기화하지 않고 apply_action 가능
+  /// 반환: 피어가 같은 상태가 되도록 적용할 업데이트
+  Future<Uint8List> clearDocument() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceClearDocument(that: this);
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_32_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
+  /// 묶음을 끝내고 모아둔 업데이트를 merge_updates_v2로 합친 업데이트 하나를 반환
+  ///
+  /// 묶음 중 변경이 없었으면 빈 업데이트 반환. begin_batch 없이 호출하면 에러
+  Future<Uint8List> commitBatch() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCommitBatch(that: this);
 
-  @protected
-  (String, BlockDoc) sse_decode_record_string_block_doc(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_block_doc(deserializer);
-    return (var_field0, var_field1);
-  }
+  /// 삭제 기록(툼스톤) 없이 현재 내용만 담은 새 문서의 전체 상태 업데이트 생성 (서버 스냅샷 교체용)
+  ///
+  /// 블록, 메타데이터, 댓글, 루트 포인터를 새 Doc에 다시 써서 인코딩하므로 결과는 기존 기록과 이어지지 않음.
+  /// 주의: 예전 기록을 가진 피어의 업데이트는 압축된 문서와 병합되지 않고 내용이 중복/유실될 수 있으므로,
+  /// 모든 클라이언트가 압축본으로 다시 시작하도록 맞춘 뒤에만 사용해야 함.
+  /// 이 인스턴스는 바뀌지 않으며, 압축본은 새 DocumentService에 apply_updates로 불러와야 함
+  Future<Uint8List> compact() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCompact(that: this);
+
+  /// 업데이트 목록을 중복 없이 하나로 합침 (서버의 업데이트 로그 정리용)
+  ///
+  /// [updates] v2 인코딩 업데이트 목록 (같은 업데이트가 여러 번 들어 있어도 됨)
+  ///
+  /// 새 Doc에 모두 적용한 뒤 전체 상태를 다시 인코딩하므로 겹치는 작업은 한 번만 남고,
+  /// merge_updates와 달리 삭제된 내용은 GC되어 길이만 남음.
+  /// compact와 달리 기존 기록과 이어지므로 예전 업데이트를 가진 피어와도 그대로 병합됨.
+  /// 선행 업데이트가 빠진 업데이트가 있으면 에러 (빈 구간이 생긴 결과는 나중에 빠진 업데이트를 받아도 적용되지 않음)
+  Future<Uint8List> compactUpdates({required List<Uint8List> updates}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceCompactUpdates(
+        that: this,
+        updates: updates,
+      );
 
-  @protected
-  (String, List<String>) sse_decode_record_string_list_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_list_String(deserializer);
-    return (var_field0, var_field1);
-  }
+  /// 문서 내용(블록 트리와 메타데이터)의 SHA-256 해시 (복제본이 수렴했는지 빠르게 확인하기 위함)
+  ///
+  /// 업데이트 바이트가 아니라 문서 상태를 해시하므로 편집 순서나 툼스톤이 달라도 보이는 내용이 같으면 같은 값.
+  /// 댓글이 있으면 댓글도 포함하고, doc_id는 내용이 아니므로 제외
+  Future<String> contentHash() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceContentHash(that: this);
 
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
-  }
+  /// 선택한 블록과 모든 하위 블록을 다른 문서에도 붙여넣을 수 있는 클립보드 JSON으로 복사
+  ///
+  /// [block_ids] 복사할 블록 id 목록 (순서와 상관없이 문서 순서로 담김)
+  ///
+  /// 반환: {"format", "version", "blocks": [AppFlowy 노드]} 형식의 JSON. 텍스트 서식과 블록 데이터가 그대로 담기며
+  /// yrs client id 같은 문서 내부 정보는 포함하지 않음. 다른 선택 블록의 하위 블록은 상위 블록에 포함되어 한 번만 담김
+  Future<String> copyBlocks({required List<String> blockIds}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceCopyBlocks(
+        that: this,
+        blockIds: blockIds,
+      );
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
+  /// 블록에 yrs 하위 문서를 붙이고 guid 반환 (임베드된 보드가 페이지 텍스트와 따로 동기화되도록 함)
+  ///
+  /// [block_id] 하위 문서를 붙일 블록 id
+  ///
+  /// 이미 하위 문서가 있으면 새로 만들지 않고 기존 guid 반환.
+  /// 블록에 하위 문서를 연결하는 부모 문서 변경은 subscribe_changes 스트림이나 encode_diff로 전파해야 하며,
+  /// 하위 문서 내용은 부모 문서 업데이트에 포함되지 않으므로 encode_subdoc_state/apply_subdoc_update로 따로 주고받음
+  Future<String> createSubdoc({required String blockId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceCreateSubdoc(
+        that: this,
+        blockId: blockId,
+      );
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
+  /// encode_relative_position으로 만든 상대 위치를 현재 문서 기준의 블록 id와 위치로 변환
+  ///
+  /// [relative_position] 인코딩된 상대 위치
+  ///
+  /// 블록이 삭제되었거나 아직 받지 못한 내용을 가리키면 에러
+  Future<RelativePos> decodeRelativePosition({
+    required Uint8List relativePosition,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDecodeRelativePosition(
+        that: this,
+        relativePosition: relativePosition,
+      );
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
+  /// 블록과 그 아래 모든 하위 블록을 한 트랜잭션으로 삭제 ("섹션 삭제" 용)
+  ///
+  /// [block_id] 삭제할 블록 id
+  ///
+  /// 하위 블록 전체를 먼저 모은 뒤 BLOCKS에서 제거하고, 다음 형제의 prev_id는 삭제된 블록의 prev_id로 다시 연결.
+  /// 루트 페이지 블록은 삭제할 수 없음
+  Future<Uint8List> deleteBlockRecursive({required String blockId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceDeleteBlockRecursive(
+        that: this,
+        blockId: blockId,
+      );
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
+  /// 문서 텍스트를 샘플링해서 주 언어를 추정 (맞춤법 검사/현지화 힌트용)
+  ///
+  /// 문자 체계와 흔한 단어를 보는 간단한 휴리스틱이며, BCP-47 코드(예: "en", "ko", "ja")를 반환
+  /// 텍스트가 너무 적어 판단할 수 없으면 "und" 반환
+  Future<String> detectLanguage() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDetectLanguage(that: this);
 
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    DocumentService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DocumentServiceImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
+  /// 문서 id 반환
+  Future<String> docId() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDocId(that: this);
 
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    DocumentService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DocumentServiceImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
+  /// 블록과 모든 하위 블록을 새 id로 복제해서 원본 바로 뒤에 삽입
+  ///
+  /// [block_id] 복제할 블록 id
+  ///
+  /// 반환: 업데이트와 원래 id -> 새 id 매핑. 텍스트는 서식까지 복사되며 복제본은 원본과 독립적으로 편집 가능
+  Future<DuplicateResult> duplicateBlock({required String blockId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceDuplicateBlock(
+        that: this,
+        blockId: blockId,
+      );
 
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    DocumentService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DocumentServiceImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
+  /// BLOCKS 맵을 대상으로 UndoManager 활성화 (이미 활성화되어 있으면 스택을 비우고 새로 생성)
+  ///
+  /// [origins] 추적할 트랜잭션 origin 목록, 비어 있으면 origin 없는 로컬 편집만 추적
+  ///
+  /// apply_updates로 적용된 원격 업데이트는 REMOTE_ORIGIN으로 적용되므로 추적되지 않음
+  Future<void> enableUndoManager({required List<String> origins}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceEnableUndoManager(
+        that: this,
+        origins: origins,
+      );
 
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
+  /// 현재 알고 있는 모든 편집자의 awareness 상태를 인코딩 (v1, 다른 클라이언트로 전송용)
+  Future<Uint8List> encodeAwarenessUpdate() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeAwarenessUpdate(that: this);
 
-  @protected
-  void sse_encode_Map_String_block_doc_None(
-    Map<String, BlockDoc> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_block_doc(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
+  /// 상대의 state vector 이후 변경분만 인코딩하여 반환 (동기화 2단계)
+  ///
+  /// [remote_state_vector] 상대의 state vector (v1 인코딩)
+  Future<Uint8List> encodeDiffSince({required Uint8List remoteStateVector}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceEncodeDiffSince(
+        that: this,
+        remoteStateVector: remoteStateVector,
+      );
 
-  @protected
-  void sse_encode_Map_String_list_String_None(
-    Map<String, List<String>> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_list_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
+  /// 현재 문서의 전체 상태를 인코딩하여 반환
+  Future<Uint8List> encodeFullState() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeFullState(that: this);
 
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    DocumentService self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DocumentServiceImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
+  /// 현재 문서의 전체 상태를 v1 인코딩으로 반환 (v1만 쓰는 서버/저장소와 호환용)
+  Future<Uint8List> encodeFullStateV1() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeFullStateV1(that: this);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
+  /// 블록 없이 META 맵 내용만 담은 업데이트 생성 (노트 목록 화면의 제목/색상 동기화용)
+  ///
+  /// 반환: 현재 메타데이터만 새 Doc에 기록한 전체 상태. 원본 문서의 변경 기록이 아니므로
+  /// apply_updates가 아닌 apply_meta_only_update로 적용해야 하며, 적용하면 CRDT 병합이 아니라
+  /// 받는 쪽 META를 이 시점의 값으로 통째로 교체함 (apply_meta_only_update 주석 참고)
+  Future<Uint8List> encodeMetaOnlyUpdate() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeMetaOnlyUpdate(that: this);
 
-  @protected
-  void sse_encode_block_action_doc(
-    BlockActionDoc self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_block_action_type_doc(self.action, serializer);
-    sse_encode_block_doc(self.block, serializer);
-    sse_encode_list_prim_u_32_strict(self.path, serializer);
-    sse_encode_opt_list_prim_u_32_strict(self.oldPath, serializer);
-  }
+  /// 블록 텍스트 안의 위치를 원격 편집에도 유지되는 상대 위치로 인코딩 (협업 커서 저장용)
+  ///
+  /// [block_id] 대상 블록 id
+  /// [offset] 텍스트 안의 위치 (delta retain과 같은 단위)
+  ///
+  /// 텍스트 필드가 없는 블록이거나 위치가 텍스트 길이를 넘으면 에러
+  Future<Uint8List> encodeRelativePosition({
+    required String blockId,
+    required int offset,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeRelativePosition(
+        that: this,
+        blockId: blockId,
+        offset: offset,
+      );
 
-  @protected
-  void sse_encode_block_action_type_doc(
-    BlockActionTypeDoc self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
+  /// 현재 문서의 state vector를 인코딩하여 반환 (동기화 1단계, v1 인코딩)
+  Future<Uint8List> encodeStateVector() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeStateVector(that: this);
 
-  @protected
-  void sse_encode_block_doc(BlockDoc self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.ty, serializer);
-    sse_encode_Map_String_String_None(self.attributes, serializer);
-    sse_encode_opt_String(self.delta, serializer);
-    sse_encode_opt_String(self.parentId, serializer);
-    sse_encode_opt_String(self.prevId, serializer);
-    sse_encode_opt_String(self.nextId, serializer);
-    sse_encode_opt_String(self.oldParentId, serializer);
-  }
+  /// 하위 문서의 전체 상태를 업데이트(v2)로 인코딩
+  ///
+  /// [guid] create_subdoc 또는 get_subdoc_guids로 얻은 하위 문서 guid
+  Future<Uint8List> encodeSubdocState({required String guid}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceEncodeSubdocState(
+        that: this,
+        guid: guid,
+      );
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
+  /// 최상위 블록이 하나만 있도록 정규화 (잘못된 병합으로 최상위 블록이 여러 개가 된 경우)
+  ///
+  /// 루트 포인터(ROOT_ID)가 가리키는 최상위 블록이 있으면 나머지 최상위 블록을 그 아래로 옮기고,
+  /// 없으면 새 페이지 블록을 만들어 모두 그 아래로 옮긴 뒤 루트 포인터를 갱신함.
+  /// 이미 최상위 블록이 하나 이하이면 아무것도 변경하지 않음.
+  Future<Uint8List> enforceSingleRoot() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEnforceSingleRoot(that: this);
 
-  @protected
-  void sse_encode_custom_rust_error(
-    CustomRustError self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
+  /// encode_diff_since로 보낼 변경분의 크기(바이트)를 미리 계산
+  ///
+  /// [remote_state_vector] 상대의 state vector (v1 인코딩)
+  ///
+  /// 상대가 이미 모든 삽입을 가지고 있으면 0 반환.
+  /// state vector에는 삭제 정보가 없으므로 상대에게 삭제만 빠진 경우도 0이 반환됨 (apply_update와 같은 한계).
+  /// 그 밖의 경우 크기는 encode_diff_since와 같은 변경분을 실제로 인코딩해서 잰 값
+  Future<BigInt> estimateDiffSize({required Uint8List remoteStateVector}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceEstimateDiffSize(
+        that: this,
+        remoteStateVector: remoteStateVector,
+      );
 
-  @protected
-  void sse_encode_document_state(DocumentState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.docId, serializer);
-    sse_encode_Map_String_block_doc_None(self.blocks, serializer);
-    sse_encode_Map_String_list_String_None(self.childrenMap, serializer);
-    sse_encode_String(self.rootId, serializer);
-  }
+  /// AppFlowy 문서 JSON 형식으로 내보내기 (.json 파일로 저장해서 데스크톱 AppFlowy에서 열기 위함)
+  ///
+  /// 반환: {"document": {"id": "page", "type": "page", "data": {}, "children": [...]}} 형식의 JSON
+  /// 텍스트가 없는 블록은 delta 필드를 생략
+  Future<String> exportAppflowyJson() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceExportAppflowyJson(that: this);
 
-  @protected
-  void sse_encode_failed_to_decode_updates(
-    FailedToDecodeUpdates self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_String(self.failedUpdatesIds, serializer);
-  }
+  /// 문서를 Markdown으로 내보내기 (노트를 Markdown으로 복사하기 위함)
+  ///
+  /// 제목/문단/글머리 기호·번호·할 일 목록/인용/코드 블록을 블록 type으로 구분하고,
+  /// 목록 들여쓰기는 부모를 따라 올라간 깊이로 정함. bold/italic/code 속성은 **, *, `로 변환.
+  /// 알 수 없는 type은 텍스트만 출력
+  Future<String> exportMarkdown() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceExportMarkdown(that: this);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
+  /// 문서 구조만 중첩 JSON으로 반환 (delta, 데이터 제외 - 사이드바/아웃라인용)
+  ///
+  /// 반환: {"id": "...", "type": "page", "children": [{"id": "...", "type": "heading", "children": []}]}
+  Future<String> exportStructure() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceExportStructure(that: this);
 
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
+  /// 루트 블록부터 시작하는 중첩 트리 JSON으로 내보내기 (트리 구조를 선호하는 웹 렌더러용)
+  ///
+  /// 반환: {"id", "type", "data", "delta", "children": [...]} 노드를 문서 순서로 중첩한 JSON.
+  /// delta는 op 배열이고 텍스트가 없는 블록은 null. 이미 방문한 블록을 다시 만나면 (순환)
+  /// 더 내려가지 않고 {"id": "...", "cycle": true} 표시 노드로 대체
+  Future<String> exportTreeJson() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceExportTreeJson(that: this);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
+  /// 텍스트가 너무 긴 블록의 id 목록 반환 (렌더링 성능 경고용)
+  ///
+  /// [max_chars] 허용하는 최대 글자 수 (바이트가 아닌 유니코드 문자 기준)
+  Future<List<String>> findOversizedBlocks({required int maxChars}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceFindOversizedBlocks(
+        that: this,
+        maxChars: maxChars,
+      );
 
-  @protected
-  void sse_encode_list_block_action_doc(
-    List<BlockActionDoc> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_block_action_doc(item, serializer);
-    }
-  }
+  /// 현재 내용을 그대로 복사한 독립 문서 생성 (노트 복제용)
+  ///
+  /// [new_doc_id] 복제된 문서의 id
+  ///
+  /// 전체 상태를 새 Doc에 적용하므로 이후 편집은 서로 영향을 주지 않음.
+  /// 복제본은 원본 기록에 없는 client id를 사용하므로 나중에 두 문서를 병합해도 항목이 겹치지 않음.
+  /// GC 설정은 원본을 따르고, 구독/undo/awareness 상태는 복사되지 않음
+  Future<DocumentService> fork({required String newDocId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceFork(
+        that: this,
+        newDocId: newDocId,
+      );
 
-  @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
-  }
+  /// 여러 블록의 전체 텍스트에 서식 속성을 한 트랜잭션으로 적용
+  ///
+  /// [block_ids] 서식을 적용할 블록 id 목록
+  /// [attributes_json] 적용할 속성 JSON 객체 (예: {"bold": true, "italic": null})
+  ///
+  /// 값이 null인 속성은 제거되며, 존재하지 않거나 텍스트가 없는 블록은 로그만 남기고 건너뜀
+  Future<Uint8List> formatBlocks({
+    required List<String> blockIds,
+    required String attributesJson,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceFormatBlocks(
+    that: this,
+    blockIds: blockIds,
+    attributesJson: attributesJson,
+  );
 
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(self);
-  }
+  /// GC 사용 여부 반환 (false일 때만 스냅샷 사용 가능)
+  Future<bool> gcEnabled() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGcEnabled(that: this);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
+  /// BLOCKS 맵의 모든 블록 id (루트 페이지 블록 포함, 내용은 읽지 않음)
+  ///
+  /// 순서는 맵 순회 순서이며 문서 순서가 보장되지 않음. 문서 순서가 필요하면 get_children_ids를 사용
+  Future<List<String>> getAllBlockIds() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetAllBlockIds(that: this);
 
-  @protected
-  void sse_encode_list_record_string_block_doc(
-    List<(String, BlockDoc)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_block_doc(item, serializer);
-    }
-  }
+  /// 모든 메타데이터를 JSON 문자열로 반환
+  ///
+  /// 반환: JSON 형식의 메타데이터 (예: {"title": "노트", "color": 123, "status": "active"})
+  Future<String> getAllMeta() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetAllMeta(that: this);
 
-  @protected
-  void sse_encode_list_record_string_list_string(
-    List<(String, List<String>)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_list_string(item, serializer);
-    }
-  }
+  /// 전체 상태를 추출하지 않고 블록 하나만 읽기
+  ///
+  /// [block_id] 읽을 블록 id
+  ///
+  /// 블록이 없으면 None 반환
+  Future<BlockDoc?> getBlock({required String blockId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceGetBlock(
+        that: this,
+        blockId: blockId,
+      );
 
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
-  }
+  /// BLOCKS 맵에 있는 블록 수 (루트 페이지 블록 포함)
+  Future<BigInt> getBlockCount() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetBlockCount(that: this);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+  /// 블록의 조상 id 목록을 최상위부터 순서대로 반환 (대상 블록은 제외)
+  ///
+  /// [block_id] 경로를 찾을 블록 id
+  ///
+  /// 부모가 없는 최상위 블록이면 빈 목록, 블록이 없으면 에러 반환
+  Future<List<String>> getBlockPath({required String blockId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceGetBlockPath(
+        that: this,
+        blockId: blockId,
+      );
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
+  /// 블록 텍스트를 서식 없이 순수 문자열로 반환 (검색 색인, 글자 수 계산용)
+  ///
+  /// [block_id] 대상 블록 id
+  ///
+  /// 속성은 버리고 블록 안의 줄바꿈은 유지, 텍스트가 없는 블록은 빈 문자열 반환
+  /// 인라인 임베드(이미지, 수식 등)는 \u{FFFC} 한 글자로 표시해 위치가 텍스트 안의 위치와 맞도록 함
+  Future<String> getBlockPlainText({required String blockId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceGetBlockPlainText(
+        that: this,
+        blockId: blockId,
+      );
 
-  @protected
-  void sse_encode_opt_list_prim_u_32_strict(
-    Uint32List? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+  /// 블록을 문서 순서(깊이 우선, 자식 순서대로)로 깊이와 함께 반환 (목차, 내보내기 등 트리 순회용)
+  ///
+  /// 깊이는 루트 페이지 블록이 0, 그 자식이 1부터 시작.
+  /// 루트에서 닿지 않는 블록(고아)은 id 순으로 맨 뒤에 붙고 깊이가 -1
+  Future<List<OrderedBlock>> getBlocksInOrder() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetBlocksInOrder(that: this);
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_32_strict(self, serializer);
-    }
-  }
+  /// 블록을 get_blocks_in_order와 같은 문서 순서로 나눠서 반환 (아주 큰 노트를 조금씩 화면에 불러오기 위함)
+  ///
+  /// [offset] 건너뛸 블록 수
+  /// [limit] 최대 반환 블록 수
+  ///
+  /// 반환: 해당 구간의 블록, 전체 블록 수, 뒤에 블록이 더 있는지 여부.
+  /// 순서는 문서 상태에서 결정적으로 정해지므로 그 사이에 문서가 바뀌지 않으면 페이지가 겹치거나 빠지지 않음.
+  /// 문서 상태 캐시를 쓰므로 페이지마다 전체 상태를 다시 만들지 않음
+  Future<BlockPage> getBlocksPage({
+    required BigInt offset,
+    required BigInt limit,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceGetBlocksPage(
+        that: this,
+        offset: offset,
+        limit: limit,
+      );
 
-  @protected
-  void sse_encode_record_string_block_doc(
-    (String, BlockDoc) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_block_doc(self.$2, serializer);
-  }
+  /// 전체 문서를 직렬화하지 않고 부모의 자식 id를 문서 순서대로 반환 (접힌 섹션을 펼칠 때 사용)
+  ///
+  /// [parent_id] 부모 블록 id (부모가 없는 최상위 블록은 "root")
+  ///
+  /// 자식이 없으면 빈 목록, 부모 블록이 없을 때만 에러 반환
+  Future<List<String>> getChildrenIds({required String parentId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceGetChildrenIds(
+        that: this,
+        parentId: parentId,
+      );
 
-  @protected
-  void sse_encode_record_string_list_string(
-    (String, List<String>) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_list_String(self.$2, serializer);
-  }
+  /// 블록에 붙은 댓글 목록 (해결된 댓글 포함)
+  ///
+  /// [block_id] 블록 id
+  ///
+  /// 텍스트 위치 순으로 정렬되고 블록 전체 댓글은 맨 뒤, 같은 위치는 댓글 id 순.
+  /// 블록이 삭제되어도 에러 없이 남은 댓글을 반환하며, 이런 댓글은 validate_document의 orphaned_comment_ids로 확인
+  Future<List<CommentDoc>> getCommentsForBlock({required String blockId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceGetCommentsForBlock(
+        that: this,
+        blockId: blockId,
+      );
 
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-  }
+  /// 문서 상태 전체를 JSON 문자열로 반환 (디버깅, 로그, 웹 뷰 전달용)
+  ///
+  /// DocumentState와 같은 필드(doc_id, blocks, children_map, root_id)를 쓰되,
+  /// 블록의 delta는 문자열이 아니라 delta 배열 그대로 넣음 (예: "delta": [{"insert": "Hello"}])
+  Future<String> getDocumentJson() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetDocumentJson(that: this);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
+  /// 문서 전체 텍스트를 문서 순서대로 줄바꿈으로 이어서 반환 (미리보기, 검색 색인용)
+  ///
+  /// get_document_state와 같은 순서로 순회하며, 텍스트 필드가 없는 블록(구분선, 이미지 등)은 건너뜀
+  /// 인라인 임베드는 get_block_plain_text처럼 \u{FFFC} 한 글자로 표시
+  Future<String> getDocumentPlainText() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetDocumentPlainText(that: this);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
+  /// 문서 저장 용량 (노트별 용량 표시, 할당량 경고용)
+  ///
+  /// 반환: full_state_bytes는 encode_full_state 크기 (삭제 기록 포함), content_bytes는 현재 내용만 담은
+  /// compact 결과 크기. 삭제가 많이 쌓였거나 GC가 돌면 둘의 차이가 커짐. 문서는 변경하지 않음
+  Future<DocSize> getDocumentSizeBytes() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetDocumentSizeBytes(that: this);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
+  Future<DocumentState> getDocumentState() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetDocumentState(that: this);
+
+  /// 메타데이터 불리언 값 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 불리언이 아니면 None
+  Future<bool?> getMetaBool({required String key}) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetMetaBool(that: this, key: key);
+
+  /// 메타데이터 정수 값 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 정수가 아니면 None (소수점이 있는 숫자도 None)
+  Future<PlatformInt64?> getMetaInt({required String key}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceGetMetaInt(
+        that: this,
+        key: key,
+      );
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
+  /// 메타데이터 키 목록만 반환 (값은 직렬화하지 않음)
+  ///
+  /// 반환: 정렬된 키 목록, META 맵이 없으면 빈 목록
+  Future<List<String>> getMetaKeys() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetMetaKeys(that: this);
 
-@sealed
-class DocumentServiceImpl extends RustOpaque implements DocumentService {
-  // Not to be used by end users
-  DocumentServiceImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
+  /// 메타데이터 문자열 값 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 문자열이 아니면 None
+  Future<String?> getMetaString({required String key}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceGetMetaString(
+        that: this,
+        key: key,
+      );
 
-  // Not to be used by end users
-  DocumentServiceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+  /// 메타데이터 문자열 배열 조회
+  ///
+  /// [key] 메타데이터 키
+  ///
+  /// 키가 없거나 배열이 아니면 None, 문자열이 아닌 항목이 섞여 있어도 None
+  Future<List<String>?> getMetaStringArray({required String key}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceGetMetaStringArray(
+        that: this,
+        key: key,
+      );
 
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_DocumentService,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_DocumentService,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_DocumentServicePtr,
-  );
+  /// set_root_node_id로 저장된 루트 블록 id 읽기 (apply_updates로 불러온 문서의 루트 확인용)
+  ///
+  /// 반환: 루트 블록 id, 한 번도 설정되지 않았으면 None
+  Future<String?> getRootNodeId() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetRootNodeId(that: this);
 
-  Future<Uint8List> applyAction({required List<BlockActionDoc> actions}) =>
-      RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyAction(
+  /// 문서 전체 단어 수, 글자 수(공백 포함/제외), 블록 수 (글쓰기 목표 기능용)
+  ///
+  /// 단어는 공백으로 나눈 단위로 세지만, 한자와 가나는 띄어쓰기를 하지 않으므로 글자 하나를 한 단어로 셈 (한글은 띄어쓰기 기준).
+  /// 블록 경계도 단어 경계로 취급하고, 블록 수는 루트 페이지 블록을 뺀 블록 수.
+  /// 인라인 임베드(이미지, 수식 등)는 글자나 단어로 세지 않음 (search_text 위치와 달리 \u{FFFC}를 넣지 않음).
+  /// 빈 문서나 초기화 전 문서는 에러 없이 모두 0 반환
+  Future<DocStats> getStatistics() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetStatistics(that: this);
+
+  /// get_statistics와 같지만 블록별 수치도 문서 순서대로 함께 반환 (여백에 블록별 글자 수 표시용)
+  ///
+  /// 텍스트 필드가 없는 블록(구분선, 이미지 등)은 블록별 목록에서 빠짐
+  Future<DocStats> getStatisticsWithBlocks() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetStatisticsWithBlocks(
         that: this,
-        actions: actions,
       );
 
-  Future<void> applyUpdates({required List<Uint8List> updates}) =>
-      RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyUpdates(
+  /// 문서에 연결된 모든 하위 문서 guid (정렬됨, 원격 업데이트로 받은 하위 문서 포함)
+  Future<List<String>> getSubdocGuids() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetSubdocGuids(that: this);
+
+  /// AppFlowy 문서 JSON을 빈 문서로 가져오기 (기존 AppFlowy 노트 이전용)
+  ///
+  /// [json] {"document": {"type": "page", "data": {}, "delta": [], "children": [...]}} 형식의 JSON
+  ///
+  /// 노드 id는 유지하고 없으면 새로 만들며, 루트 노드를 루트 포인터(ROOT_ID)로 설정.
+  /// 루트 노드가 없거나 구조가 잘못되었거나 이미 블록이 있는 문서면 에러 반환
+  Future<Uint8List> importAppflowyJson({required String json}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceImportAppflowyJson(
         that: this,
-        updates: updates,
+        json: json,
       );
 
-  /// 현재 문서의 전체 상태를 인코딩하여 반환
-  Future<Uint8List> encodeFullState() => RustLib.instance.api
-      .crateDocDocumentServiceDocumentServiceEncodeFullState(that: this);
+  /// Markdown을 블록으로 변환해서 루트 블록의 마지막 자식 뒤에 추가 ("Markdown으로 붙여넣기"용)
+  ///
+  /// [markdown] 붙여넣을 Markdown 텍스트
+  ///
+  /// 제목은 heading, 목록은 들여쓰기에 따라 중첩된 bulleted_list/numbered_list/todo_list, 코드 펜스는 code 블록으로 변환.
+  /// **굵게**, *기울임*, `코드`는 delta 속성으로 변환. 루트 포인터가 없으면 새 페이지 블록을 만들어 루트로 설정
+  Future<Uint8List> importMarkdown({required String markdown}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceImportMarkdown(
+        that: this,
+        markdown: markdown,
+      );
 
-  /// 모든 메타데이터를 JSON 문자열로 반환
+  /// export_tree_json 형식의 중첩 트리 JSON을 빈 문서로 가져오기
   ///
-  /// 반환: JSON 형식의 메타데이터 (예: {"title": "노트", "color": 123, "status": "active"})
-  Future<String> getAllMeta() => RustLib.instance.api
-      .crateDocDocumentServiceDocumentServiceGetAllMeta(that: this);
+  /// [json] {"id", "type", "data", "delta", "children": [...]} 형식의 루트 노드 JSON
+  ///
+  /// 노드 id는 유지하고 없으면 새로 만들며, 루트 노드를 루트 포인터(ROOT_ID)로 설정.
+  /// delta가 null이면 텍스트 없는 블록이고, 순환 표시 노드({"cycle": true})는 건너뜀.
+  /// set_max_tree_depth로 정한 깊이보다 깊게 중첩된 트리, 구조가 잘못된 트리, 이미 블록이 있는 문서는 에러 반환
+  Future<Uint8List> importTreeJson({required String json}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceImportTreeJson(
+        that: this,
+        json: json,
+      );
 
-  Future<DocumentState> getDocumentState() => RustLib.instance.api
-      .crateDocDocumentServiceDocumentServiceGetDocumentState(that: this);
+  /// 블록을 바로 앞 형제의 마지막 자식으로 들여쓰기 (Tab)
+  ///
+  /// [block_id] 들여쓸 블록 id
+  ///
+  /// 앞 형제가 없는 첫 번째 자식이면 아무것도 하지 않고 빈 업데이트 반환
+  Future<Uint8List> indentBlock({required String blockId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceIndentBlock(
+        that: this,
+        blockId: blockId,
+      );
 
   Future<Uint8List> initEmptyDoc() => RustLib.instance.api
       .crateDocDocumentServiceDocumentServiceInitEmptyDoc(that: this);
 
+  /// 많은 블록을 한 부모 아래에 한 번에 삽입 (큰 문서 가져오기용)
+  ///
+  /// [blocks] 삽입할 블록 목록, 입력 순서대로 형제 순서가 정해짐 (각 블록의 parent_id/prev_id/next_id는 무시)
+  /// [parent_id] 부모 블록 id, "root"면 최상위
+  ///
+  /// 부모의 기존 마지막 자식 뒤에 붙이며, apply_action과 달리 블록마다 형제 체인을 다시 찾지 않음.
+  /// 부모가 없거나 id가 중복되거나 delta가 잘못되면 아무것도 적용하지 않고 에러
+  Future<Uint8List> insertBlocksBulk({
+    required List<BlockDoc> blocks,
+    required String parentId,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceInsertBlocksBulk(
+        that: this,
+        blocks: blocks,
+        parentId: parentId,
+      );
+
+  /// 묶음이 진행 중인지 여부
+  Future<bool> isBatching() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceIsBatching(that: this);
+
+  /// 문서에 내용 블록이 하나도 없는지 확인
+  ///
+  /// "비어 있음" = BLOCKS 맵에 루트 포인터(ROOT_ID)가 가리키는 루트 페이지 블록 외의 블록이 없음.
+  /// 메타데이터는 보지 않으며, 텍스트가 없는 빈 문단도 내용 블록으로 셈
+  Future<bool> isEmpty() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceIsEmpty(that: this);
+
+  /// 마지막 replace_all에서 바꾼 개수 ("12개 바꿈" 표시용)
+  Future<int> lastReplaceCount() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceLastReplaceCount(that: this);
+
+  /// 문서를 처음 열 때 업데이트 적용과 상태 추출을 한 번에 처리 (큰 노트 열기용)
+  ///
+  /// [updates] 적용할 업데이트 목록 (v2)
+  ///
+  /// apply_updates 후 get_document_state를 부른 것과 같은 상태를 반환하지만, apply_updates가 변경 블록을 구하려고
+  /// 하는 적용 전후 전체 순회를 건너뛰고 같은 트랜잭션 안에서 상태를 한 번만 추출함.
+  /// 결과는 상태 캐시에 저장되므로 바로 이어지는 get_document_state는 다시 추출하지 않음.
+  /// 디코딩에 실패한 업데이트는 apply_updates처럼 로그만 남기고 건너뜀
+  Future<DocumentState> loadAndGetState({required List<Uint8List> updates}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceLoadAndGetState(
+        that: this,
+        updates: updates,
+      );
+
+  /// save_to_file로 저장한 파일을 읽어서 apply_updates로 적용
+  ///
+  /// [path] 읽을 파일 경로
+  ///
+  /// 파일이 올바른 업데이트가 아니면 빈 문서로 넘어가지 않고 디코딩 에러 반환
+  Future<void> loadFromFile({required String path}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceLoadFromFile(
+        that: this,
+        path: path,
+      );
+
+  /// 뒤 블록을 앞 블록에 합침 ("이전 블록과 합치기", 블록 맨 앞에서 Backspace)
+  ///
+  /// [first_id] 남게 되는 앞 블록 id
+  /// [second_id] 앞 블록에 합쳐질 블록 id
+  ///
+  /// 같은 쓰기 트랜잭션 안에서 can_merge_blocks와 같은 BlockOperations::check_merge로 먼저 검사하고, 합칠 수 없으면 아무것도 바꾸지 않고 에러.
+  /// second의 텍스트는 서식과 임베드까지 first 끝에 이어 붙이고, second의 자식은 순서대로 first의 마지막 자식 뒤로 옮긴 뒤 second를 삭제
+  Future<Uint8List> mergeBlocks({
+    required String firstId,
+    required String secondId,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceMergeBlocks(
+    that: this,
+    firstId: firstId,
+    secondId: secondId,
+  );
+
   Future<Uint8List> mergeUpdates({required List<Uint8List> updates}) =>
       RustLib.instance.api.crateDocDocumentServiceDocumentServiceMergeUpdates(
         that: this,
         updates: updates,
       );
 
+  /// v1 인코딩 업데이트 병합 (v1만 쓰는 서버/저장소와 호환용)
+  Future<Uint8List> mergeUpdatesV1({required List<Uint8List> updates}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceMergeUpdatesV1(
+        that: this,
+        updates: updates,
+      );
+
+  /// JSON을 set_meta_from_json으로 적용했을 때 바뀔 메타데이터 미리보기
+  ///
+  /// [json] 비교할 메타데이터 JSON (set_meta_from_json 입력과 같은 형식)
+  ///
+  /// 반환: {"added": {"key": 값}, "removed": ["key"], "changed": {"key": {"from": 이전값, "to": 새값}}}
+  /// null 값은 키 제거를 의미하고, JSON에 없는 키는 변경되지 않음. 타입이 다르면 변경으로 취급.
+  Future<String> metaDiffAgainstJson({required String json}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceMetaDiffAgainstJson(
+        that: this,
+        json: json,
+      );
+
+  /// 블록을 부모의 형제로 내어쓰기 (Shift-Tab), 부모 바로 뒤에 삽입
+  ///
+  /// [block_id] 내어쓸 블록 id
+  ///
+  /// 최상위 블록이나 루트 페이지의 자식처럼 더 나갈 곳이 없으면 아무것도 하지 않고 빈 업데이트 반환
+  Future<Uint8List> outdentBlock({required String blockId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceOutdentBlock(
+        that: this,
+        blockId: blockId,
+      );
+
+  /// copy_blocks로 만든 클립보드 JSON을 새 id로 지정한 위치에 붙여넣기
+  ///
+  /// [payload] copy_blocks가 반환한 JSON (다른 DocumentService에서 복사한 것도 가능)
+  /// [target_parent_id] 붙여넣을 부모 블록 id (부모가 없는 최상위는 "root")
+  /// [after_block_id] 이 형제 블록 바로 뒤에 붙여넣음, None이면 부모의 첫 자식으로 붙여넣음
+  ///
+  /// 같은 클립보드를 여러 번 붙여넣어도 매번 새 id를 사용. 엄격 type 모드에서는 허용되지 않은 type이 있으면 에러
+  Future<Uint8List> pasteBlocks({
+    required String payload,
+    required String targetParentId,
+    String? afterBlockId,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServicePasteBlocks(
+    that: this,
+    payload: payload,
+    targetParentId: targetParentId,
+    afterBlockId: afterBlockId,
+  );
+
   /// 메타데이터 배열에 문자열 항목 추가 (중복 체크)
   ///
   /// [key] 메타데이터 키
@@ -1931,6 +10521,31 @@ class DocumentServiceImpl extends RustOpaque implements DocumentService {
         value: value,
       );
 
+  /// 문서를 전혀 바꾸지 않고 현재 상태를 읽기 (get_document_state와 달리 캐시를 쓰지 않음)
+  ///
+  /// root 맵이 아직 없는 문서(초기화 전)는 root 맵을 만들지 않고 빈 DocumentState 반환 (root_id는 빈 문자열)
+  Future<DocumentState> readDocumentState() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceReadDocumentState(that: this);
+
+  /// 마지막으로 실행 취소한 편집을 다시 실행하고 그 결과 업데이트를 반환
+  ///
+  /// 다시 실행할 항목이 없으면 빈 변경의 업데이트 반환
+  Future<Uint8List> redo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceRedo(that: this);
+
+  /// 원격 클라이언트의 awareness 상태 제거 (Dart 쪽 타임아웃으로 연결이 끊긴 편집자 정리용)
+  ///
+  /// [client_id] 제거할 클라이언트 id
+  ///
+  /// 반환: 상태가 있던 클라이언트였는지 여부
+  Future<bool> removeAwarenessClient({required BigInt clientId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceRemoveAwarenessClient(
+        that: this,
+        clientId: clientId,
+      );
+
   /// 메타데이터 배열에서 문자열 항목 제거
   ///
   /// [key] 메타데이터 키
@@ -1954,6 +10569,184 @@ class DocumentServiceImpl extends RustOpaque implements DocumentService {
         key: key,
       );
 
+  /// validate_document가 찾는 구조 문제를 고쳐서 피어에 보낼 업데이트로 반환
+  ///
+  /// 없는 블록을 가리키는 prevId/nextId는 지우고, 부모가 없는 블록과 순환마다 id가 가장 작은 블록은
+  /// 최상위(DEFAULT_PARENT)로 옮겨 기존 최상위 블록 뒤에 이어 붙임.
+  /// 고칠 것이 없으면 아무것도 변경하지 않으므로 두 번 실행해도 결과가 같음.
+  Future<Uint8List> repairDocument() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceRepairDocument(that: this);
+
+  /// 문서 전체에서 찾아 바꾸기를 한 트랜잭션으로 적용
+  ///
+  /// [query] 찾을 문자열
+  /// [replacement] 바꿀 문자열
+  /// [case_sensitive] 대소문자 구분 여부
+  ///
+  /// 겹치는 일치는 앞쪽 것만 바꾸고, 바뀐 부분은 원래 첫 글자의 서식을 유지
+  /// 바꾼 개수는 last_replace_count로 확인
+  Future<Uint8List> replaceAll({
+    required String query,
+    required String replacement,
+    required bool caseSensitive,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceReplaceAll(
+    that: this,
+    query: query,
+    replacement: replacement,
+    caseSensitive: caseSensitive,
+  );
+
+  /// 댓글을 해결됨으로 표시 (댓글은 남아 있고 get_comments_for_block의 resolved가 true가 됨)
+  ///
+  /// [comment_id] 댓글 id
+  ///
+  /// 댓글이 없으면 에러
+  Future<Uint8List> resolveComment({required String commentId}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceResolveComment(
+        that: this,
+        commentId: commentId,
+      );
+
+  /// 스냅샷 시점의 내용으로 문서를 되돌리고, 피어도 같은 상태로 옮길 업데이트를 반환
+  ///
+  /// [snapshot] take_snapshot으로 얻은 스냅샷
+  ///
+  /// 되돌리기는 새 편집으로 기록되므로 이후 변경 기록은 그대로 남음.
+  /// 현재 문서에 없는 항목을 가리키는 스냅샷이면 아무것도 바꾸지 않고 에러 반환
+  Future<Uint8List> restoreSnapshot({required Uint8List snapshot}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceRestoreSnapshot(
+        that: this,
+        snapshot: snapshot,
+      );
+
+  /// 전체 상태(encode_full_state)를 파일에 저장 (오프라인 우선 앱에서 Rust가 직접 디스크에 저장)
+  ///
+  /// [path] 저장할 파일 경로 (이미 있으면 덮어씀)
+  Future<void> saveToFile({required String path}) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSaveToFile(that: this, path: path);
+
+  /// 문서 전체에서 텍스트 검색 (문서 내 찾기용)
+  ///
+  /// [query] 찾을 문자열
+  /// [case_sensitive] 대소문자 구분 여부
+  ///
+  /// 문서 순서대로 블록 id, 블록 텍스트 안의 글자 위치, 일치 길이를 반환
+  /// 위치는 인라인 임베드를 한 글자로 세므로 get_block_plain_text, YText 위치와 같음
+  /// 겹치는 일치도 각각 보고하며, 빈 검색어는 빈 목록 반환
+  Future<List<TextMatch>> searchText({
+    required String query,
+    required bool caseSensitive,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceSearchText(
+    that: this,
+    query: query,
+    caseSensitive: caseSensitive,
+  );
+
+  /// 엄격 모드에서 허용할 블록 type 목록 설정 (커스텀 블록을 쓰는 앱용)
+  ///
+  /// [types] 허용할 type 전체 목록, 기본 목록을 대체하므로 계속 쓸 기본 type(page, paragraph 등)도 포함해야 함
+  Future<void> setAllowedBlockTypes({required List<String> types}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceSetAllowedBlockTypes(
+        that: this,
+        types: types,
+      );
+
+  /// 문서 전체 첨부 용량 상한 설정 (None이면 제한 없음)
+  ///
+  /// [budget] 최대 바이트 수
+  Future<void> setAttachmentBudget({BigInt? budget}) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSetAttachmentBudget(
+        that: this,
+        budget: budget,
+      );
+
+  /// 블록 데이터에 바이너리 첨부 저장
+  ///
+  /// [block_id] 대상 블록 id
+  /// [key] 블록 데이터 키
+  /// [bytes] 저장할 바이너리
+  ///
+  /// 첨부 용량 상한이 설정되어 있으면 (현재 총량 - 같은 키의 기존 첨부 + 새 첨부)가 상한을 넘을 때 에러 반환
+  Future<Uint8List> setBlockAttachment({
+    required String blockId,
+    required String key,
+    required Uint8List bytes,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSetBlockAttachment(
+        that: this,
+        blockId: blockId,
+        key: key,
+        bytes: bytes,
+      );
+
+  /// 블록 데이터의 필드 하나만 설정 (체크박스 checked 토글 등)
+  ///
+  /// [block_id] 대상 블록 id
+  /// [field] 필드 키, 점으로 구분하면 중첩 맵 경로 (예: "style.color")
+  /// [value_json] 저장할 값의 JSON 문자열 (apply_action의 attributes와 같은 형식으로 저장)
+  ///
+  /// 해당 키만 쓰므로 다른 클라이언트가 같은 블록의 다른 필드를 동시에 바꿔도 둘 다 유지됨.
+  /// 경로 중간에 맵이 아닌 값이 있으면 덮어쓰지 않고 에러 반환
+  Future<Uint8List> setBlockDataField({
+    required String blockId,
+    required String field,
+    required String valueJson,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSetBlockDataField(
+        that: this,
+        blockId: blockId,
+        field: field,
+        valueJson: valueJson,
+      );
+
+  /// 블록 id 생성을 시드 기반의 결정적 순서로 전환 (테스트 전용)
+  ///
+  /// [seed] 시드 (같은 시드면 duplicate, paste, import 등이 같은 id를 같은 순서로 생성)
+  ///
+  /// 시드 id는 다른 문서/피어와 겹칠 수 있으므로 프로덕션에서는 호출하지 말고 기본 UUID v4를 사용
+  Future<void> setIdGeneratorSeed({required BigInt seed}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceSetIdGeneratorSeed(
+        that: this,
+        seed: seed,
+      );
+
+  /// 로컬 편집자의 awareness 상태(이름, 커서, 색상 등) 설정
+  ///
+  /// [state_json] 상태 JSON (예: {"user":"taeuk","color":"#ff0000","cursor":{"blockId":"a","offset":3}})
+  ///
+  /// 문서 내용과 무관하므로 문서 업데이트를 만들지 않음
+  Future<void> setLocalAwareness({required String stateJson}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceSetLocalAwareness(
+        that: this,
+        stateJson: stateJson,
+      );
+
+  /// import_tree_json이 받아들이는 최대 중첩 깊이 설정 (루트 아래 단계 수, 기본값 DEFAULT_MAX_TREE_DEPTH)
+  ///
+  /// [depth] 최대 깊이
+  Future<void> setMaxTreeDepth({required int depth}) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSetMaxTreeDepth(
+        that: this,
+        depth: depth,
+      );
+
+  /// 여러 메타데이터 변경을 한 트랜잭션으로 적용 (apply_action처럼 업데이트 하나만 생성)
+  ///
+  /// [operations] 순서대로 적용할 메타 변경 목록
+  Future<Uint8List> setMetaBatch({required List<MetaOp> operations}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceSetMetaBatch(
+        that: this,
+        operations: operations,
+      );
+
   /// 메타데이터에 불리언 값 설정
   ///
   /// [key] 메타데이터 키
@@ -1969,7 +10762,8 @@ class DocumentServiceImpl extends RustOpaque implements DocumentService {
   ///
   /// [json_str] 설정할 메타데이터 JSON (예: {"title": "노트", "status": "active"})
   ///
-  /// 지원 타입: string, number (int/double), boolean, string array
+  /// 지원 타입: string, number (int/double), boolean, array, object
+  /// 중첩 객체는 YMap으로 저장되어 필드 단위로 병합되고 (null 필드는 제거), 배열은 YArray로 통째로 교체됨
   Future<Uint8List> setMetaFromJson({required String jsonStr}) => RustLib
       .instance
       .api
@@ -2022,4 +10816,135 @@ class DocumentServiceImpl extends RustOpaque implements DocumentService {
   /// Setting a root node id in the root map
   Future<Uint8List> setRootNodeId({required String id}) => RustLib.instance.api
       .crateDocDocumentServiceDocumentServiceSetRootNodeId(that: this, id: id);
+
+  /// 블록 type 검사 사용 여부 설정 (기본값 false, 기존 호출과의 호환을 위해 켜야만 검사함)
+  ///
+  /// [strict] true면 허용 목록에 없는 type의 Insert/Update를 에러로 거부 ("paragrah" 같은 오타 방지).
+  /// 가져오기(import_*)와 apply_update_remapped로 들어오는 블록도 같은 목록으로 검사함
+  Future<void> setStrictTypes({required bool strict}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceSetStrictTypes(
+        that: this,
+        strict: strict,
+      );
+
+  /// 기록해 둔 스냅샷 시점의 문서 상태를 재구성 (문서는 바꾸지 않음)
+  ///
+  /// [snapshot] take_snapshot으로 기록한 스냅샷 (v1 인코딩)
+  ///
+  /// state vector만으로는 언제 삭제되었는지 알 수 없으므로 state vector가 아니라 delete set까지 담긴 스냅샷을 받음
+  /// (encode_state_vector로 저장해 둔 값은 쓸 수 없으며, 넘기면 디코딩 에러).
+  /// 기록 이후에 추가된 블록은 빠지고, 기록 이후에 삭제된 블록은 그대로 보임.
+  /// GC가 꺼진 문서에서만 동작하며, 현재 문서에 없는 항목을 가리키는 스냅샷이면 에러 반환
+  Future<DocumentState> stateAsOf({required Uint8List snapshot}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceStateAsOf(
+        that: this,
+        snapshot: snapshot,
+      );
+
+  /// 문서 순서대로 블록 JSON을 하나씩 콜백으로 전달 (큰 문서의 점진적 렌더링용)
+  ///
+  /// [callback] 블록 JSON(BlockDoc 형식)을 받는 Dart 콜백, 각 호출이 끝난 뒤 다음 블록 전달
+  ///
+  /// 마지막 블록 전달이 끝나야 완료됨
+  Future<void> streamBlocks({
+    required FutureOr<void> Function(String) callback,
+  }) => RustLib.instance.api.crateDocDocumentServiceDocumentServiceStreamBlocks(
+    that: this,
+    callback: callback,
+  );
+
+  /// 블록 하나의 변경만 JSON으로 Dart 스트림에 전달 (집중 편집 화면용, apply_updates로 받은 원격 변경 포함)
+  ///
+  /// [block_id] 관찰할 블록 id
+  /// [sink] 변경 JSON을 받을 스트림, 같은 블록으로 다시 호출하면 이전 스트림을 대체
+  ///
+  /// 변경: {"blockId":"a","event":"updated","fields":["attributes.level","type"],"delta":[...],"childrenReordered":false}
+  /// (delta는 텍스트가 바뀐 경우에만 포함)
+  /// 블록이 삭제되면 {"blockId":"a","event":"deleted"}를 마지막으로 보내고 구독이 끝남
+  Stream<String> subscribeBlockChanges({required String blockId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceSubscribeBlockChanges(
+        that: this,
+        blockId: blockId,
+      );
+
+  /// 문서에 커밋되는 모든 업데이트(v2)를 트랜잭션 origin과 함께 Dart 스트림으로 전달 (apply_updates로 받은 원격 변경 포함)
+  ///
+  /// [sink] 업데이트와 origin을 받을 스트림, 다시 호출하면 이전 스트림을 대체
+  ///
+  /// origin: apply_action_with_origin으로 준 값, apply_updates로 받은 원격 변경은 "remote", 그 외 로컬 편집은 None
+  /// (Dart 쪽에서 자기가 보낸 변경을 서버로 다시 보내지 않도록 걸러낼 때 사용)
+  Stream<OriginUpdate> subscribeChanges() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSubscribeChanges(that: this);
+
+  /// 문서가 비어 있음/내용 있음 사이를 오갈 때만 Dart 스트림에 전달 (빈 문서 안내 문구 표시용)
+  ///
+  /// [sink] is_empty 결과를 받을 스트림, 다시 호출하면 이전 스트림을 대체
+  ///
+  /// 구독 즉시 현재 값을 한 번 보내고, 이후에는 값이 바뀐 트랜잭션에서만 보냄 (apply_updates로 받은 원격 변경 포함)
+  Stream<bool> subscribeEmptiness() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSubscribeEmptiness(that: this);
+
+  /// 메타데이터가 바뀔 때마다 바뀐 키만 JSON으로 Dart 스트림에 전달 (apply_updates로 받은 원격 변경 포함)
+  ///
+  /// [sink] {"title": "새 제목", "status": null} 형식의 JSON을 받을 스트림 (null은 키 삭제)
+  ///
+  /// 중첩 메타가 바뀌면 해당 최상위 키의 현재 값 전체를 보냄. 다시 호출하면 이전 스트림을 대체
+  Stream<String> subscribeMetaChanges() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSubscribeMetaChanges(that: this);
+
+  /// 하위 문서에 커밋되는 업데이트(v2)를 Dart 스트림으로 전달 (부모 문서의 subscribe_changes와 별개)
+  ///
+  /// [guid] 하위 문서 guid
+  /// [sink] 업데이트 바이트를 받을 스트림, 같은 guid로 다시 호출하면 이전 스트림을 대체
+  Stream<Uint8List> subscribeSubdocChanges({required String guid}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceSubscribeSubdocChanges(
+        that: this,
+        guid: guid,
+      );
+
+  /// 현재 시점의 스냅샷을 인코딩하여 반환 (버전 기록용, v1 인코딩)
+  ///
+  /// GC가 꺼진 문서(new_with_gc_disabled)에서만 동작함
+  Future<Uint8List> takeSnapshot() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceTakeSnapshot(that: this);
+
+  /// 마지막 로컬 편집을 실행 취소하고 그 결과 업데이트를 반환
+  ///
+  /// 취소할 항목이 없으면 빈 변경의 업데이트 반환
+  Future<Uint8List> undo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceUndo(that: this);
+
+  /// subscribe_block_changes 구독 해제
+  ///
+  /// 반환: 구독 중이던 블록이었는지 여부
+  Future<bool> unsubscribeBlockChanges({required String blockId}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceUnsubscribeBlockChanges(
+        that: this,
+        blockId: blockId,
+      );
+
+  /// apply_action을 실제로 적용하지 않고 성공할지만 검사 (드래그/붙여넣기 확정 전 미리 확인용)
+  ///
+  /// [actions] 검사할 블록 작업 목록
+  ///
+  /// apply_action이 적용 전에 하는 검사(move 필수 필드, 부모 존재, 자기 하위로 이동 금지, 엄격 모드 type 등)를
+  /// 읽기 트랜잭션에서 그대로 실행하고 첫 번째 에러를 반환. 문서는 변경하지 않음
+  Future<void> validateAction({required List<BlockActionDoc> actions}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceValidateAction(
+        that: this,
+        actions: actions,
+      );
+
+  /// 블록 트리 구조 검사 (렌더링 전에 깨진 구조를 찾기 위한 읽기 전용 진단)
+  ///
+  /// 반환: 부모가 없는 블록, 부모-자식 순환에 속한 블록, 없는 블록을 prevId/nextId로 가리키는 블록,
+  /// 블록이 삭제되어 남은 댓글 목록
+  Future<DocumentValidation> validateDocument() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceValidateDocument(that: this);
 }
@@ -8,6 +8,7 @@
 
 import 'dart:async';
 import 'dart:convert';
+import 'doc/document_registry.dart';
 import 'doc/document_service.dart';
 import 'doc/document_types.dart';
 import 'frb_generated.dart';
@@ -21,28 +22,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_DocumentRegistryPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_DocumentServicePtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  DocumentRegistry
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  );
+
   @protected
   DocumentService
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     dynamic raw,
   );
 
+  @protected
+  DocumentRegistry
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  );
+
   @protected
   DocumentService
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     dynamic raw,
   );
 
+  @protected
+  DocumentRegistry
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  );
+
   @protected
   DocumentService
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     dynamic raw,
   );
 
+  @protected
+  FutureOr<void> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw);
+
   @protected
   Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
 
@@ -52,15 +87,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Map<String, List<String>> dco_decode_Map_String_list_String_None(dynamic raw);
 
+  @protected
+  DocumentRegistry
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    dynamic raw,
+  );
+
   @protected
   DocumentService
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<bool> dco_decode_StreamSink_bool_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<OriginUpdate> dco_decode_StreamSink_origin_update_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  ActionResult dco_decode_action_result(dynamic raw);
+
+  @protected
+  AppliedBlockChanges dco_decode_applied_block_changes(dynamic raw);
+
   @protected
   BlockActionDoc dco_decode_block_action_doc(dynamic raw);
 
@@ -70,15 +133,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BlockDoc dco_decode_block_doc(dynamic raw);
 
+  @protected
+  BlockPage dco_decode_block_page(dynamic raw);
+
+  @protected
+  BlockStats dco_decode_block_stats(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  BlockDoc dco_decode_box_autoadd_block_doc(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CommentDoc dco_decode_comment_doc(dynamic raw);
+
   @protected
   CustomRustError dco_decode_custom_rust_error(dynamic raw);
 
+  @protected
+  DocSize dco_decode_doc_size(dynamic raw);
+
+  @protected
+  DocStats dco_decode_doc_stats(dynamic raw);
+
   @protected
   DocumentState dco_decode_document_state(dynamic raw);
 
+  @protected
+  DocumentValidation dco_decode_document_validation(dynamic raw);
+
+  @protected
+  DuplicateResult dco_decode_duplicate_result(dynamic raw);
+
   @protected
   FailedToDecodeUpdates dco_decode_failed_to_decode_updates(dynamic raw);
 
@@ -94,9 +193,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<BlockActionDoc> dco_decode_list_block_action_doc(dynamic raw);
 
+  @protected
+  List<BlockDoc> dco_decode_list_block_doc(dynamic raw);
+
+  @protected
+  List<BlockStats> dco_decode_list_block_stats(dynamic raw);
+
+  @protected
+  List<CommentDoc> dco_decode_list_comment_doc(dynamic raw);
+
   @protected
   List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<MetaOp> dco_decode_list_meta_op(dynamic raw);
+
+  @protected
+  List<OrderedBlock> dco_decode_list_ordered_block(dynamic raw);
+
   @protected
   Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
 
@@ -114,12 +228,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<(BigInt, int)> dco_decode_list_record_u_64_u_32(dynamic raw);
+
+  @protected
+  List<TextMatch> dco_decode_list_text_match(dynamic raw);
+
+  @protected
+  MetaOp dco_decode_meta_op(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  BlockDoc? dco_decode_opt_box_autoadd_block_doc(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<BlockStats>? dco_decode_opt_list_block_stats(dynamic raw);
+
   @protected
   Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw);
 
+  @protected
+  OrderedBlock dco_decode_ordered_block(dynamic raw);
+
+  @protected
+  OriginUpdate dco_decode_origin_update(dynamic raw);
+
   @protected
   (String, BlockDoc) dco_decode_record_string_block_doc(dynamic raw);
 
@@ -129,9 +279,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  (BigInt, int) dco_decode_record_u_64_u_32(dynamic raw);
+
+  @protected
+  RelativePos dco_decode_relative_pos(dynamic raw);
+
+  @protected
+  TextMatch dco_decode_text_match(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -141,24 +303,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  DocumentRegistry
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DocumentService
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     SseDeserializer deserializer,
   );
 
+  @protected
+  DocumentRegistry
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DocumentService
   sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     SseDeserializer deserializer,
   );
 
+  @protected
+  DocumentRegistry
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DocumentService
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     SseDeserializer deserializer,
   );
 
+  @protected
+  FutureOr<void> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
   @protected
   Map<String, String> sse_decode_Map_String_String_None(
     SseDeserializer deserializer,
@@ -174,15 +366,49 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  DocumentRegistry
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DocumentService
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<bool> sse_decode_StreamSink_bool_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<OriginUpdate> sse_decode_StreamSink_origin_update_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  ActionResult sse_decode_action_result(SseDeserializer deserializer);
+
+  @protected
+  AppliedBlockChanges sse_decode_applied_block_changes(
+    SseDeserializer deserializer,
+  );
+
   @protected
   BlockActionDoc sse_decode_block_action_doc(SseDeserializer deserializer);
 
@@ -194,15 +420,53 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BlockDoc sse_decode_block_doc(SseDeserializer deserializer);
 
+  @protected
+  BlockPage sse_decode_block_page(SseDeserializer deserializer);
+
+  @protected
+  BlockStats sse_decode_block_stats(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  BlockDoc sse_decode_box_autoadd_block_doc(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CommentDoc sse_decode_comment_doc(SseDeserializer deserializer);
+
   @protected
   CustomRustError sse_decode_custom_rust_error(SseDeserializer deserializer);
 
+  @protected
+  DocSize sse_decode_doc_size(SseDeserializer deserializer);
+
+  @protected
+  DocStats sse_decode_doc_stats(SseDeserializer deserializer);
+
   @protected
   DocumentState sse_decode_document_state(SseDeserializer deserializer);
 
+  @protected
+  DocumentValidation sse_decode_document_validation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DuplicateResult sse_decode_duplicate_result(SseDeserializer deserializer);
+
   @protected
   FailedToDecodeUpdates sse_decode_failed_to_decode_updates(
     SseDeserializer deserializer,
@@ -222,11 +486,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<BlockDoc> sse_decode_list_block_doc(SseDeserializer deserializer);
+
+  @protected
+  List<BlockStats> sse_decode_list_block_stats(SseDeserializer deserializer);
+
+  @protected
+  List<CommentDoc> sse_decode_list_comment_doc(SseDeserializer deserializer);
+
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MetaOp> sse_decode_list_meta_op(SseDeserializer deserializer);
+
+  @protected
+  List<OrderedBlock> sse_decode_list_ordered_block(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
 
@@ -248,14 +529,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<(BigInt, int)> sse_decode_list_record_u_64_u_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TextMatch> sse_decode_list_text_match(SseDeserializer deserializer);
+
+  @protected
+  MetaOp sse_decode_meta_op(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  BlockDoc? sse_decode_opt_box_autoadd_block_doc(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<BlockStats>? sse_decode_opt_list_block_stats(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint32List? sse_decode_opt_list_prim_u_32_strict(
     SseDeserializer deserializer,
   );
 
+  @protected
+  OrderedBlock sse_decode_ordered_block(SseDeserializer deserializer);
+
+  @protected
+  OriginUpdate sse_decode_origin_update(SseDeserializer deserializer);
+
   @protected
   (String, BlockDoc) sse_decode_record_string_block_doc(
     SseDeserializer deserializer,
@@ -271,9 +592,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  (BigInt, int) sse_decode_record_u_64_u_32(SseDeserializer deserializer);
+
+  @protected
+  RelativePos sse_decode_relative_pos(SseDeserializer deserializer);
+
+  @protected
+  TextMatch sse_decode_text_match(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -283,6 +616,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
@@ -290,6 +636,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
@@ -297,6 +650,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
@@ -304,6 +664,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
   @protected
   void sse_encode_Map_String_String_None(
     Map<String, String> self,
@@ -322,6 +691,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    DocumentRegistry self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
@@ -329,9 +705,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_bool_Sse(
+    RustStreamSink<bool> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_origin_update_Sse(
+    RustStreamSink<OriginUpdate> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_action_result(ActionResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_applied_block_changes(
+    AppliedBlockChanges self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_block_action_doc(
     BlockActionDoc self,
@@ -347,18 +756,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_block_doc(BlockDoc self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_block_page(BlockPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_block_stats(BlockStats self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_block_doc(
+    BlockDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_comment_doc(CommentDoc self, SseSerializer serializer);
+
   @protected
   void sse_encode_custom_rust_error(
     CustomRustError self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_doc_size(DocSize self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_doc_stats(DocStats self, SseSerializer serializer);
+
   @protected
   void sse_encode_document_state(DocumentState self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_document_validation(
+    DocumentValidation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_duplicate_result(
+    DuplicateResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_failed_to_decode_updates(
     FailedToDecodeUpdates self,
@@ -380,12 +837,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_block_doc(List<BlockDoc> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_block_stats(
+    List<BlockStats> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_comment_doc(
+    List<CommentDoc> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_list_prim_u_8_strict(
     List<Uint8List> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_meta_op(List<MetaOp> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_ordered_block(
+    List<OrderedBlock> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_32_strict(
     Uint32List self,
@@ -416,15 +897,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_u_64_u_32(
+    List<(BigInt, int)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_text_match(
+    List<TextMatch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_meta_op(MetaOp self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_block_doc(
+    BlockDoc? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_block_stats(
+    List<BlockStats>? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_list_prim_u_32_strict(
     Uint32List? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_ordered_block(OrderedBlock self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_origin_update(OriginUpdate self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_block_doc(
     (String, BlockDoc) self,
@@ -443,9 +975,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_u_64_u_32(
+    (BigInt, int) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_relative_pos(RelativePos self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_match(TextMatch self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -461,6 +1008,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     int ptr,
@@ -484,6 +1047,16 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
     int ptr,
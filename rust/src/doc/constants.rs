@@ -3,6 +3,14 @@
 /// Constants for document structure
 pub const BLOCKS: &str = "blocks";
 pub const META: &str = "meta";
+/// Comment threads, keyed by comment id, next to BLOCKS and META in the root map
+pub const COMMENTS: &str = "comments";
+pub const COMMENT_BLOCK_ID: &str = "blockId";
+pub const COMMENT_AUTHOR: &str = "author";
+pub const COMMENT_TEXT: &str = "text";
+pub const COMMENT_RESOLVED: &str = "resolved";
+/// Encoded sticky index into the block's text, absent for whole-block comments
+pub const COMMENT_ANCHOR: &str = "anchor";
 pub const ROOT_ID: &str = "document";
 pub const ROOT_TYPE: &str = "page";
 pub const ATTRIBUTES: &str = "attributes";
@@ -12,4 +20,41 @@ pub const TYPE: &str = "type";
 pub const PARENT_ID: &str = "parentId";
 pub const PREV_ID: &str = "prevId";
 pub const NEXT_ID: &str = "nextId";
-pub const DEFAULT_PARENT: &str = "default_parent";
\ No newline at end of file
+pub const CREATED_AT: &str = "createdAt";
+/// Subdocument (embedded board/database) attached to a block
+pub const SUBDOC: &str = "subdoc";
+/// Map of client id -> last edit time, so concurrent edits keep the latest instead of the last writer
+pub const UPDATED_AT: &str = "updatedAt";
+pub const DEFAULT_PARENT: &str = "default_parent";
+
+/// Block types accepted in strict type mode until the app sets its own list
+pub const DEFAULT_BLOCK_TYPES: &[&str] = &[
+    ROOT_TYPE, "paragraph", "heading", "bulleted_list", "numbered_list", "todo_list", "quote",
+    "code", "divider", "image", "callout", "toggle_list", "math_equation", "table", "table/cell",
+];
+
+/// Rich-text block types whose text can be merged into one another ("merge with previous").
+/// Code blocks only merge with code blocks; every other type (divider, image, table, page, ...) never merges.
+pub const MERGEABLE_TEXT_TYPES: &[&str] = &[
+    "paragraph", "heading", "bulleted_list", "numbered_list", "todo_list", "quote", "callout", "toggle_list",
+];
+pub const CODE_TYPE: &str = "code";
+
+/// Marks a copy_blocks payload so paste_blocks can reject unrelated clipboard JSON
+pub const CLIPBOARD_FORMAT: &str = "appflowy_editor_sync_plugin/blocks";
+pub const CLIPBOARD_VERSION: u32 = 1;
+
+/// Deepest nesting below the root that import_tree_json accepts until the app sets its own limit
+pub const DEFAULT_MAX_TREE_DEPTH: u32 = 128;
+
+/// Transaction origin for updates received from other peers
+pub const REMOTE_ORIGIN: &str = "remote";
+
+/// Awareness clients silent for longer than this are treated as offline (milliseconds)
+pub const AWARENESS_TIMEOUT_MS: u64 = 30_000;
+
+/// How much block text detect_language reads before deciding (characters)
+pub const LANGUAGE_SAMPLE_CHARS: usize = 2_000;
+
+/// Largest integer a JSON/JS number (f64) holds exactly; yrs stores integers up to this as f64
+pub const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
@@ -1,13 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use serde_json::{Value, Map as JsonMap, json};
-use yrs::{Any as YrsAny, types::Delta, ReadTxn, Map, Array};
+use yrs::{Any as YrsAny, types::{Delta, ToJson}, ReadTxn, Map, Array};
 
 use crate::doc::document_types::{BlockDoc, DocumentState};
-use crate::doc::constants::{ID, TYPE, PARENT_ID, PREV_ID, TEXT, ATTRIBUTES};
+use crate::doc::constants::{ID, TYPE, PARENT_ID, PREV_ID, TEXT, ATTRIBUTES, CLIPBOARD_FORMAT, CLIPBOARD_VERSION};
 use crate::doc::error::DocError;
+use crate::doc::operations::update_ops::UpdateOperations;
 use crate::doc::document_types::CustomRustError;
-use crate::log_info;
+use crate::doc::utils::util::IdGenerator;
+use crate::{log_info, log_error};
 
 /// Utilities for converting between different data representations
 pub struct Conversion;
@@ -35,9 +37,16 @@ impl Conversion {
         delta: Delta<yrs::Out>
     ) -> Result<Value, CustomRustError> {
         match delta {
-            Delta::Inserted(text, attrs) => {
+            Delta::Inserted(value, attrs) => {
                 let mut map = JsonMap::new();
-                map.insert("insert".to_string(), Value::String(text.to_string(txn)));
+                // Strings are text; anything else is an inline embed (image, formula, ...)
+                let insert = match value {
+                    yrs::Out::Any(yrs::Any::String(text)) => Value::String(text.to_string()),
+                    yrs::Out::Any(any) => Self::any_to_json(&any),
+                    yrs::Out::YMap(embed) => Self::any_to_json(&embed.to_json(txn)),
+                    other => Value::String(other.to_string(txn)),
+                };
+                map.insert("insert".to_string(), insert);
                 
                 if let Some(attributes) = attrs {
                     let attrs_json: JsonMap<String, Value> = attributes
@@ -176,6 +185,415 @@ impl Conversion {
         Ok(Value::Object(doc_json))
     }
 
+    /// Convert the block tree into nested `{id, type, children}` JSON in document order,
+    /// leaving out deltas and attributes. A block reached twice (cycle) is skipped.
+    pub fn structure_to_json(doc_state: &DocumentState) -> Value {
+        let mut visited = HashSet::new();
+        Self::structure_node_to_json(doc_state, &doc_state.root_id, &mut visited)
+            .unwrap_or(Value::Null)
+    }
+
+    /// Convert the block tree into AppFlowy's document JSON: `{"document": node}` where each
+    /// node is `{id, type, data, delta?, children}` in document order. Attribute values that
+    /// hold JSON numbers, booleans, objects or arrays are emitted as such, other values as
+    /// strings. Blocks without text have no `delta` field. A block reached twice (cycle) is skipped.
+    pub fn appflowy_document_to_json(doc_state: &DocumentState) -> Result<Value, CustomRustError> {
+        let mut visited = HashSet::new();
+        let root = Self::appflowy_node_to_json(doc_state, &doc_state.root_id, &mut visited)?
+            .ok_or_else(|| DocError::StateError(format!("Root block {} not found", doc_state.root_id)))?;
+        Ok(json!({ "document": root }))
+    }
+
+    fn appflowy_node_to_json(
+        doc_state: &DocumentState,
+        id: &str,
+        visited: &mut HashSet<String>
+    ) -> Result<Option<Value>, CustomRustError> {
+        if !visited.insert(id.to_string()) {
+            log_info!("appflowy_node_to_json: Block {} already visited, skipping", id);
+            return Ok(None);
+        }
+        let Some(block) = doc_state.blocks.get(id) else { return Ok(None) };
+
+        let mut children = Vec::new();
+        for child_id in doc_state.children_map.get(id).into_iter().flatten() {
+            if let Some(child) = Self::appflowy_node_to_json(doc_state, child_id, visited)? {
+                children.push(child);
+            }
+        }
+
+        let mut node = JsonMap::new();
+        node.insert("id".to_string(), json!(block.id));
+        node.insert("type".to_string(), json!(block.ty));
+        node.insert("data".to_string(), Self::block_data_to_json(block));
+        if let Some(delta) = Self::block_delta_to_json(block)? {
+            node.insert("delta".to_string(), delta);
+        }
+        node.insert("children".to_string(), Value::Array(children));
+        Ok(Some(Value::Object(node)))
+    }
+
+    /// Convert the block tree into nested `{id, type, data, delta, children}` JSON starting at
+    /// the root block, in document order. Data values are converted as in
+    /// `appflowy_document_to_json`, and `delta` is the op array, or null for blocks without text.
+    /// A block reached a second time is replaced by a `{"id", "cycle": true}` marker node
+    /// instead of being expanded again.
+    pub fn tree_to_json(doc_state: &DocumentState) -> Result<Value, CustomRustError> {
+        if !doc_state.blocks.contains_key(&doc_state.root_id) {
+            return Err(DocError::StateError(format!("Root block {} not found", doc_state.root_id)).into());
+        }
+        let mut visited = HashSet::new();
+        Self::tree_node_to_json(doc_state, &doc_state.root_id, &mut visited)
+    }
+
+    fn tree_node_to_json(
+        doc_state: &DocumentState,
+        id: &str,
+        visited: &mut HashSet<String>
+    ) -> Result<Value, CustomRustError> {
+        if !visited.insert(id.to_string()) {
+            log_error!("tree_node_to_json: Block {} reached twice, cutting the cycle", id);
+            return Ok(json!({ "id": id, "cycle": true }));
+        }
+        let Some(block) = doc_state.blocks.get(id) else {
+            return Err(DocError::BlockNotFound(format!("Block {} not found", id)).into());
+        };
+
+        let mut children = Vec::new();
+        for child_id in doc_state.children_map.get(id).into_iter().flatten() {
+            if doc_state.blocks.contains_key(child_id) {
+                children.push(Self::tree_node_to_json(doc_state, child_id, visited)?);
+            }
+        }
+
+        Ok(json!({
+            "id": block.id,
+            "type": block.ty,
+            "data": Self::block_data_to_json(block),
+            "delta": Self::block_delta_to_json(block)?.unwrap_or(Value::Null),
+            "children": children,
+        }))
+    }
+
+    /// Flatten a tree from `tree_to_json` into blocks, the same way `appflowy_document_to_blocks`
+    /// flattens an AppFlowy node: ids are kept and missing ones generated. A null `delta` means a
+    /// block without text, and cycle marker nodes are dropped since they only point back at a
+    /// block that is already in the tree. Trees nested more than `max_depth` levels below the
+    /// root are rejected before anything recurses into them.
+    pub fn tree_to_blocks(json: &Value, max_depth: u32, ids: &IdGenerator) -> Result<Vec<BlockDoc>, CustomRustError> {
+        let mut pending = vec![(json, 0u32)];
+        while let Some((node, depth)) = pending.pop() {
+            if depth > max_depth {
+                return Err(DocError::ValidationError(format!(
+                    "Tree is nested deeper than the limit of {} levels", max_depth
+                )).into());
+            }
+            for child in node.get("children").and_then(Value::as_array).into_iter().flatten() {
+                pending.push((child, depth + 1));
+            }
+        }
+
+        let root = Self::tree_node_to_appflowy(json)
+            .ok_or_else(|| DocError::ValidationError("Root node is a cycle marker".into()))?;
+        Self::appflowy_document_to_blocks(&json!({ "document": root }), ids)
+    }
+
+    /// Rewrite a tree node into an AppFlowy node, or None for a cycle marker
+    fn tree_node_to_appflowy(node: &Value) -> Option<Value> {
+        let Some(obj) = node.as_object() else { return Some(node.clone()) };
+        if obj.get("cycle").and_then(Value::as_bool) == Some(true) {
+            return None;
+        }
+
+        let mut converted = obj.clone();
+        if converted.get("delta").is_some_and(Value::is_null) {
+            converted.remove("delta");
+        }
+        if let Some(Value::Array(children)) = obj.get("children") {
+            let children = children.iter().filter_map(Self::tree_node_to_appflowy).collect();
+            converted.insert("children".to_string(), Value::Array(children));
+        }
+        Some(Value::Object(converted))
+    }
+
+    /// Block attributes as a JSON object; values holding JSON numbers, booleans, objects or
+    /// arrays are emitted as such, other values as strings
+    fn block_data_to_json(block: &BlockDoc) -> Value {
+        let data: JsonMap<String, Value> = block.attributes
+            .iter()
+            .map(|(k, v)| {
+                let value = match serde_json::from_str::<Value>(v) {
+                    Ok(parsed) if !parsed.is_string() && !parsed.is_null() => parsed,
+                    _ => Value::String(v.clone()),
+                };
+                (k.clone(), value)
+            })
+            .collect();
+        Value::Object(data)
+    }
+
+    /// The block's delta as a JSON op array, or None when the block has no text
+    fn block_delta_to_json(block: &BlockDoc) -> Result<Option<Value>, CustomRustError> {
+        block.delta
+            .as_ref()
+            .map(|delta| serde_json::from_str(delta)
+                .map_err(|e| DocError::EncodingError(format!("Invalid delta in block {}: {}", block.id, e)).into()))
+            .transpose()
+    }
+
+    /// Flatten an AppFlowy document JSON into blocks in depth-first order, parents before
+    /// children and each block right after its previous sibling. Node ids are kept, missing
+    /// ones get a fresh id. The inverse of `appflowy_document_to_json`: string data values
+    /// become attributes as-is, other values are stored as their JSON text.
+    pub fn appflowy_document_to_blocks(json: &Value, ids: &IdGenerator) -> Result<Vec<BlockDoc>, CustomRustError> {
+        let problems = Self::validate_appflowy_document(json);
+        if !problems.is_empty() {
+            return Err(DocError::ValidationError(format!("Invalid AppFlowy document: {}", problems.join("; "))).into());
+        }
+
+        let mut blocks = Vec::new();
+        Self::appflowy_node_to_blocks(&json["document"], None, None, &mut blocks, ids)?;
+        Ok(blocks)
+    }
+
+    fn appflowy_node_to_blocks(
+        node: &Value,
+        parent_id: Option<String>,
+        prev_id: Option<String>,
+        blocks: &mut Vec<BlockDoc>,
+        ids: &IdGenerator
+    ) -> Result<String, CustomRustError> {
+        let id = node.get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| ids.next_id());
+
+        let attributes = node.get("data")
+            .and_then(Value::as_object)
+            .map(|data| {
+                data.iter()
+                    .map(|(k, v)| {
+                        let value = match v {
+                            Value::String(s) => s.clone(),
+                            other => other.to_string(),
+                        };
+                        (k.clone(), value)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let delta = node.get("delta")
+            .map(|delta| serde_json::to_string(delta)
+                .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e))))
+            .transpose()?;
+
+        blocks.push(BlockDoc {
+            id: id.clone(),
+            ty: node["type"].as_str().unwrap_or_default().to_string(),
+            attributes,
+            delta,
+            parent_id,
+            prev_id,
+            next_id: None,
+            old_parent_id: None,
+            created_at: None,
+            updated_at: None,
+        });
+
+        let mut prev_child = None;
+        for child in node.get("children").and_then(Value::as_array).into_iter().flatten() {
+            prev_child = Some(Self::appflowy_node_to_blocks(child, Some(id.clone()), prev_child, blocks, ids)?);
+        }
+        Ok(id)
+    }
+
+    /// Serialize blocks and their descendants as a clipboard payload `{format, version, blocks}`,
+    /// each entry of `blocks` an AppFlowy node (see `appflowy_document_to_json`) in document order.
+    /// A selected block that sits under another selected block is only carried by its ancestor.
+    pub fn blocks_to_clipboard(doc_state: &DocumentState, block_ids: &[String]) -> Result<Value, CustomRustError> {
+        if let Some(missing) = block_ids.iter().find(|id| !doc_state.blocks.contains_key(*id)) {
+            return Err(DocError::BlockNotFound(format!("Block {} not found", missing)).into());
+        }
+
+        let selected: HashSet<&str> = block_ids.iter().map(String::as_str).collect();
+        let has_selected_ancestor = |id: &str| {
+            let mut current = doc_state.blocks.get(id).and_then(|block| block.parent_id.as_deref());
+            let mut steps = 0;
+            while let Some(parent_id) = current {
+                if selected.contains(parent_id) {
+                    return true;
+                }
+                steps += 1;
+                if steps > doc_state.blocks.len() {
+                    break;
+                }
+                current = doc_state.blocks.get(parent_id).and_then(|block| block.parent_id.as_deref());
+            }
+            false
+        };
+
+        let mut visited = HashSet::new();
+        let mut nodes = Vec::new();
+        for (id, _) in UpdateOperations::blocks_in_order(doc_state) {
+            if selected.contains(id.as_str()) && !has_selected_ancestor(&id) {
+                if let Some(node) = Self::appflowy_node_to_json(doc_state, &id, &mut visited)? {
+                    nodes.push(node);
+                }
+            }
+        }
+
+        Ok(json!({ "format": CLIPBOARD_FORMAT, "version": CLIPBOARD_VERSION, "blocks": nodes }))
+    }
+
+    /// Read a `blocks_to_clipboard` payload back into blocks in depth-first order, every block
+    /// with a fresh id so the same payload can be pasted repeatedly and into any document.
+    /// Top-level blocks have no parent and are chained to each other through prev_id.
+    pub fn clipboard_to_blocks(payload: &Value, ids: &IdGenerator) -> Result<Vec<BlockDoc>, CustomRustError> {
+        if payload.get("format").and_then(Value::as_str) != Some(CLIPBOARD_FORMAT) {
+            return Err(DocError::ValidationError("Not a block clipboard payload".into()).into());
+        }
+        let nodes = payload.get("blocks")
+            .and_then(Value::as_array)
+            .ok_or_else(|| DocError::ValidationError("Clipboard payload has no \"blocks\" array".into()))?;
+
+        let mut problems = Vec::new();
+        let mut seen_ids = HashSet::new();
+        for (i, node) in nodes.iter().enumerate() {
+            Self::validate_appflowy_node(node, &format!("blocks[{}]", i), &mut seen_ids, &mut problems);
+        }
+        if !problems.is_empty() {
+            return Err(DocError::ValidationError(format!("Invalid clipboard payload: {}", problems.join("; "))).into());
+        }
+
+        let mut blocks = Vec::new();
+        let mut prev_id = None;
+        for node in nodes {
+            prev_id = Some(Self::appflowy_node_to_blocks(node, None, prev_id, &mut blocks, ids)?);
+        }
+
+        let id_map: HashMap<String, String> = blocks.iter()
+            .map(|block| (block.id.clone(), ids.next_id()))
+            .collect();
+        let remap = |id: String| id_map.get(&id).cloned().unwrap_or(id);
+        Ok(blocks.into_iter()
+            .map(|block| BlockDoc {
+                id: remap(block.id),
+                parent_id: block.parent_id.map(remap),
+                prev_id: block.prev_id.map(remap),
+                ..block
+            })
+            .collect())
+    }
+
+    /// Block ids in document order (pre-order DFS from the root through `children_map`).
+    /// A block reached twice (cycle) or unreachable from the root is skipped.
+    pub fn document_order(doc_state: &DocumentState) -> Vec<String> {
+        UpdateOperations::blocks_in_order(doc_state)
+            .filter(|(_, depth)| *depth >= 0)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Check an AppFlowy document JSON (`{"document": node}`, each node `{type, id?, data?, delta?, children?}`)
+    /// and collect every structural problem found, each prefixed with the node path.
+    pub fn validate_appflowy_document(json: &Value) -> Vec<String> {
+        let mut problems = Vec::new();
+        match json.get("document") {
+            Some(root) => {
+                let mut seen_ids = HashSet::new();
+                Self::validate_appflowy_node(root, "document", &mut seen_ids, &mut problems);
+            }
+            None => problems.push("missing root node \"document\"".to_string()),
+        }
+        problems
+    }
+
+    fn validate_appflowy_node(
+        node: &Value,
+        path: &str,
+        seen_ids: &mut HashSet<String>,
+        problems: &mut Vec<String>
+    ) {
+        let Some(obj) = node.as_object() else {
+            problems.push(format!("{}: node must be an object", path));
+            return;
+        };
+
+        match obj.get("type") {
+            Some(Value::String(ty)) if !ty.is_empty() => {}
+            Some(_) => problems.push(format!("{}: \"type\" must be a non-empty string", path)),
+            None => problems.push(format!("{}: missing \"type\"", path)),
+        }
+
+        match obj.get("id").map(|id| id.as_str().filter(|id| !id.is_empty())) {
+            Some(Some(id)) if !seen_ids.insert(id.to_string()) => {
+                problems.push(format!("{}: duplicate id \"{}\"", path, id));
+            }
+            Some(None) => problems.push(format!("{}: \"id\" must be a non-empty string", path)),
+            _ => {}
+        }
+
+        if let Some(data) = obj.get("data") {
+            if !data.is_object() {
+                problems.push(format!("{}: \"data\" must be an object", path));
+            }
+        }
+
+        if let Some(delta) = obj.get("delta") {
+            match delta.as_array() {
+                Some(ops) => {
+                    for (i, op) in ops.iter().enumerate() {
+                        let valid = op.as_object().is_some_and(|op| {
+                            op.contains_key("insert") || op.contains_key("retain") || op.contains_key("delete")
+                        });
+                        if !valid {
+                            problems.push(format!("{}.delta[{}]: expected an insert, retain or delete operation", path, i));
+                        }
+                    }
+                }
+                None => problems.push(format!("{}: \"delta\" must be an array", path)),
+            }
+        }
+
+        match obj.get("children") {
+            Some(Value::Array(children)) => {
+                for (i, child) in children.iter().enumerate() {
+                    Self::validate_appflowy_node(child, &format!("{}.children[{}]", path, i), seen_ids, problems);
+                }
+            }
+            Some(_) => problems.push(format!("{}: \"children\" must be an array", path)),
+            None => {}
+        }
+    }
+
+    fn structure_node_to_json(
+        doc_state: &DocumentState,
+        id: &str,
+        visited: &mut HashSet<String>
+    ) -> Option<Value> {
+        if !visited.insert(id.to_string()) {
+            log_info!("structure_node_to_json: Block {} already visited, skipping", id);
+            return None;
+        }
+        let block = doc_state.blocks.get(id)?;
+
+        let children: Vec<Value> = doc_state.children_map
+            .get(id)
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|child_id| Self::structure_node_to_json(doc_state, child_id, visited))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(json!({
+            "id": block.id,
+            "type": block.ty,
+            "children": children,
+        }))
+    }
+
 }
 
 
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::doc::operations::update_ops::UpdateOperations;
+use crate::doc::document_types::{BlockDoc, CustomRustError, DocumentState};
+use crate::doc::error::DocError;
+use crate::doc::utils::util::IdGenerator;
+
+/// Renders a document as Markdown, block by block in document order.
+/// The root page block itself isn't rendered, only what's under it.
+pub struct MarkdownExport;
+
+/// Spaces per nesting level of list items
+const LIST_INDENT: usize = 2;
+
+impl MarkdownExport {
+    pub fn render(doc_state: &DocumentState) -> Result<String, CustomRustError> {
+        let mut output = String::new();
+        let mut previous_was_list = false;
+
+        // Depth 0 is the root page and -1 a block unreachable from it; neither is rendered
+        for (id, depth) in UpdateOperations::blocks_in_order(doc_state) {
+            if depth <= 0 {
+                continue;
+            }
+            let Some(block) = doc_state.blocks.get(&id) else { continue };
+            let depth = (depth - 1) as usize;
+            let text = Self::inline_text(block, block.ty != "code")?;
+            let indent = " ".repeat(depth * LIST_INDENT);
+
+            let (rendered, is_list) = match block.ty.as_str() {
+                "heading" => {
+                    let level = block.attributes.get("level")
+                        .and_then(|level| level.parse::<usize>().ok())
+                        .unwrap_or(1)
+                        .clamp(1, 6);
+                    (format!("{} {}", "#".repeat(level), text), false)
+                }
+                "bulleted_list" => (format!("{}- {}", indent, text), true),
+                "numbered_list" => {
+                    let number = Self::list_number(doc_state, block);
+                    (format!("{}{}. {}", indent, number, text), true)
+                }
+                "todo_list" => {
+                    let checked = block.attributes.get("checked").is_some_and(|checked| checked == "true");
+                    (format!("{}- [{}] {}", indent, if checked { "x" } else { " " }, text), true)
+                }
+                "quote" => {
+                    let quoted: Vec<String> = text.lines().map(|line| format!("> {}", line)).collect();
+                    (if quoted.is_empty() { ">".to_string() } else { quoted.join("\n") }, false)
+                }
+                "code" => {
+                    let language = block.attributes.get("language").map(String::as_str).unwrap_or("");
+                    (format!("```{}\n{}\n```", language, text), false)
+                }
+                "divider" => ("---".to_string(), false),
+                _ => (text, false),
+            };
+
+            if !output.is_empty() {
+                output.push_str(if previous_was_list && is_list { "\n" } else { "\n\n" });
+            }
+            output.push_str(&rendered);
+            previous_was_list = is_list;
+        }
+
+        Ok(output)
+    }
+
+    /// Position of a numbered list item within the run of numbered siblings right before it
+    fn list_number(doc_state: &DocumentState, block: &BlockDoc) -> usize {
+        let parent_id = block.parent_id.as_deref().unwrap_or("root");
+        let Some(siblings) = doc_state.children_map.get(parent_id) else { return 1 };
+        let Some(position) = siblings.iter().position(|id| *id == block.id) else { return 1 };
+        let preceding = siblings[..position]
+            .iter()
+            .rev()
+            .take_while(|id| doc_state.blocks.get(*id).is_some_and(|sibling| sibling.ty == "numbered_list"))
+            .count();
+        preceding + 1
+    }
+
+    /// Concatenate the block's text inserts, wrapping bold, italic and inline code runs
+    fn inline_text(block: &BlockDoc, formatted: bool) -> Result<String, CustomRustError> {
+        let Some(delta) = &block.delta else { return Ok(String::new()) };
+        let ops: Vec<Value> = serde_json::from_str(delta)
+            .map_err(|e| DocError::DecodingError(format!("Invalid delta in block {}: {}", block.id, e)))?;
+
+        let mut text = String::new();
+        for op in &ops {
+            let Some(insert) = op.get("insert").and_then(Value::as_str) else { continue };
+            if !formatted || insert.trim().is_empty() {
+                text.push_str(insert);
+                continue;
+            }
+            let attributes = op.get("attributes");
+            let enabled = |name: &str| attributes
+                .and_then(|attrs| attrs.get(name))
+                .is_some_and(|value| value.as_bool().unwrap_or(false));
+
+            let mut run = insert.to_string();
+            if enabled("code") {
+                run = format!("`{}`", run);
+            }
+            if enabled("italic") {
+                run = format!("*{}*", run);
+            }
+            if enabled("bold") {
+                run = format!("**{}**", run);
+            }
+            text.push_str(&run);
+        }
+        Ok(text)
+    }
+}
+
+/// Parses Markdown into blocks: headings, bulleted/numbered/todo list items nested by
+/// indentation, fenced code, quotes, dividers and paragraphs. Inline `**bold**`, `*italic*`
+/// and `` `code` `` become delta attributes.
+pub struct MarkdownImport;
+
+impl MarkdownImport {
+    /// Build blocks for `markdown` under `parent_id`, the first top-level one placed after
+    /// `prev_id`. Blocks come out parents first, each right after its previous sibling.
+    pub fn parse(markdown: &str, parent_id: &str, prev_id: Option<String>, ids: &IdGenerator) -> Vec<BlockDoc> {
+        let mut blocks: Vec<BlockDoc> = Vec::new();
+        // Open list items by depth; a deeper item becomes a child of the item above it
+        let mut list_stack: Vec<String> = Vec::new();
+        // Last block placed under each parent, so the next sibling can point to it
+        let mut last_child: HashMap<String, String> = HashMap::new();
+        if let Some(prev_id) = prev_id {
+            last_child.insert(parent_id.to_string(), prev_id);
+        }
+
+        let mut push = |blocks: &mut Vec<BlockDoc>, parent: &str, ty: &str, attributes: HashMap<String, String>, delta: Value| {
+            let id = ids.next_id();
+            blocks.push(BlockDoc {
+                id: id.clone(),
+                ty: ty.to_string(),
+                attributes,
+                delta: Some(delta.to_string()),
+                parent_id: Some(parent.to_string()),
+                prev_id: last_child.insert(parent.to_string(), id.clone()),
+                next_id: None,
+                old_parent_id: None,
+                created_at: None,
+                updated_at: None,
+            });
+            id
+        };
+
+        let lines: Vec<&str> = markdown.lines().collect();
+        let mut i = 0;
+        let mut paragraph: Vec<&str> = Vec::new();
+        while i < lines.len() {
+            let line = lines[i];
+            let trimmed = line.trim_start();
+            let indent = line.len() - trimmed.len();
+            i += 1;
+
+            let list_item = Self::list_item(trimmed);
+            let starts_block = trimmed.is_empty()
+                || list_item.is_some()
+                || trimmed.starts_with('#')
+                || trimmed.starts_with("```")
+                || trimmed.starts_with('>')
+                || Self::is_divider(trimmed);
+            if !starts_block {
+                paragraph.push(trimmed);
+                continue;
+            }
+            if !paragraph.is_empty() {
+                list_stack.clear();
+                push(&mut blocks, parent_id, "paragraph", HashMap::new(), Self::inline_delta(&paragraph.join(" ")));
+                paragraph.clear();
+            }
+
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if let Some((ty, attributes, text)) = list_item {
+                let depth = (indent / LIST_INDENT).min(list_stack.len());
+                list_stack.truncate(depth);
+                let parent = list_stack.last().cloned().unwrap_or_else(|| parent_id.to_string());
+                let id = push(&mut blocks, &parent, ty, attributes, Self::inline_delta(text));
+                list_stack.push(id);
+                continue;
+            }
+            list_stack.clear();
+
+            if let Some(fence) = trimmed.strip_prefix("```") {
+                let mut code_lines = Vec::new();
+                while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                    code_lines.push(lines[i]);
+                    i += 1;
+                }
+                i += 1; // closing fence
+                let mut attributes = HashMap::new();
+                if !fence.trim().is_empty() {
+                    attributes.insert("language".to_string(), fence.trim().to_string());
+                }
+                push(&mut blocks, parent_id, "code", attributes, json!([{ "insert": code_lines.join("\n") }]));
+            } else if Self::is_divider(trimmed) {
+                push(&mut blocks, parent_id, "divider", HashMap::new(), json!([]));
+            } else if let Some(quoted) = trimmed.strip_prefix('>') {
+                push(&mut blocks, parent_id, "quote", HashMap::new(), Self::inline_delta(quoted.trim_start()));
+            } else {
+                let level = trimmed.chars().take_while(|c| *c == '#').count();
+                match trimmed[level..].strip_prefix(' ') {
+                    Some(title) if level <= 6 => {
+                        let attributes = HashMap::from([("level".to_string(), level.to_string())]);
+                        push(&mut blocks, parent_id, "heading", attributes, Self::inline_delta(title));
+                    }
+                    _ => {
+                        push(&mut blocks, parent_id, "paragraph", HashMap::new(), Self::inline_delta(trimmed));
+                    }
+                }
+            }
+        }
+        if !paragraph.is_empty() {
+            push(&mut blocks, parent_id, "paragraph", HashMap::new(), Self::inline_delta(&paragraph.join(" ")));
+        }
+
+        blocks
+    }
+
+    /// Recognize `- item`, `- [x] item` and `1. item`, returning the block type, attributes and text
+    fn list_item(line: &str) -> Option<(&'static str, HashMap<String, String>, &str)> {
+        for marker in ["- ", "* ", "+ "] {
+            if let Some(rest) = line.strip_prefix(marker) {
+                for (checkbox, checked) in [("[ ] ", "false"), ("[x] ", "true"), ("[X] ", "true")] {
+                    if let Some(text) = rest.strip_prefix(checkbox) {
+                        return Some(("todo_list", HashMap::from([("checked".to_string(), checked.to_string())]), text));
+                    }
+                }
+                return Some(("bulleted_list", HashMap::new(), rest));
+            }
+        }
+
+        let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        line[digits..]
+            .strip_prefix(". ")
+            .or_else(|| line[digits..].strip_prefix(") "))
+            .map(|text| ("numbered_list", HashMap::new(), text))
+    }
+
+    fn is_divider(line: &str) -> bool {
+        let line = line.trim_end();
+        line.len() >= 3 && ['-', '*', '_'].iter().any(|rule| line.chars().all(|c| c == *rule))
+    }
+
+    /// Split text into delta inserts, turning `**`, `*` and `` ` `` pairs into bold, italic
+    /// and code attributes. A marker without a closing partner stays as literal text.
+    fn inline_delta(text: &str) -> Value {
+        let chars: Vec<char> = text.chars().collect();
+        let mut ops: Vec<Value> = Vec::new();
+        let mut run = String::new();
+        let (mut bold, mut italic, mut code) = (false, false, false);
+
+        let flush = |run: &mut String, ops: &mut Vec<Value>, bold: bool, italic: bool, code: bool| {
+            if run.is_empty() {
+                return;
+            }
+            let mut attributes = serde_json::Map::new();
+            for (name, enabled) in [("bold", bold), ("italic", italic), ("code", code)] {
+                if enabled {
+                    attributes.insert(name.to_string(), Value::Bool(true));
+                }
+            }
+            let mut op = serde_json::Map::new();
+            if !attributes.is_empty() {
+                op.insert("attributes".to_string(), Value::Object(attributes));
+            }
+            op.insert("insert".to_string(), Value::String(std::mem::take(run)));
+            ops.push(Value::Object(op));
+        };
+        let closes = |from: usize, marker: &str| {
+            chars[from..].iter().collect::<String>().contains(marker)
+        };
+
+        let mut i = 0;
+        while i < chars.len() {
+            let marker = if chars[i] == '`' {
+                Some("`")
+            } else if code {
+                None
+            } else if chars[i] == '*' && chars.get(i + 1) == Some(&'*') {
+                Some("**")
+            } else if chars[i] == '*' {
+                Some("*")
+            } else {
+                None
+            };
+
+            match marker {
+                Some(marker) => {
+                    let len = marker.chars().count();
+                    let active = match marker {
+                        "`" => code,
+                        "**" => bold,
+                        _ => italic,
+                    };
+                    if active || closes(i + len, marker) {
+                        flush(&mut run, &mut ops, bold, italic, code);
+                        match marker {
+                            "`" => code = !code,
+                            "**" => bold = !bold,
+                            _ => italic = !italic,
+                        }
+                    } else {
+                        run.push_str(marker);
+                    }
+                    i += len;
+                }
+                None => {
+                    run.push(chars[i]);
+                    i += 1;
+                }
+            }
+        }
+        flush(&mut run, &mut ops, bold, italic, code);
+
+        Value::Array(ops)
+    }
+}
@@ -1,2 +1,5 @@
 /// flutter_rust_bridge:ignore
-pub mod conversion;
\ No newline at end of file
+pub mod conversion;
+
+/// flutter_rust_bridge:ignore
+pub mod markdown;
\ No newline at end of file
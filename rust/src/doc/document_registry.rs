@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use flutter_rust_bridge::frb;
+
+use super::document_service::DocumentService;
+use super::error::DocError;
+use crate::doc::document_types::{AppliedBlockChanges, BlockActionDoc, CustomRustError, DocumentState};
+use crate::{log_info, log_error};
+
+/// 여러 문서를 id로 관리하는 레지스트리 (탭처럼 여러 노트를 한 번에 열어둘 때 사용)
+#[frb]
+pub struct DocumentRegistry {
+    documents: HashMap<String, DocumentService>,
+}
+
+impl DocumentRegistry {
+
+    #[frb]
+    pub fn new() -> Self {
+        log_info!("Creating new document registry");
+        Self { documents: HashMap::new() }
+    }
+
+    #[frb]
+    /// 문서를 열기 (이미 열려 있으면 그대로 둠)
+    ///
+    /// [doc_id] 문서 id
+    pub fn open(&mut self, doc_id: String) {
+        log_info!("DocumentRegistry.open: doc_id: {}", doc_id);
+        self.documents
+            .entry(doc_id.clone())
+            .or_insert_with(|| DocumentService::new_with_id(doc_id));
+    }
+
+    #[frb]
+    /// 문서를 닫고 Doc을 해제
+    ///
+    /// [doc_id] 문서 id
+    ///
+    /// 반환: 열려 있던 문서였는지 여부
+    pub fn close(&mut self, doc_id: String) -> bool {
+        log_info!("DocumentRegistry.close: doc_id: {}", doc_id);
+        self.documents.remove(&doc_id).is_some()
+    }
+
+    #[frb]
+    /// 열려 있는 문서 id 목록 (정렬됨)
+    pub fn list_open(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.documents.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    #[frb]
+    pub fn init_empty_doc(&mut self, doc_id: String) -> Result<Vec<u8>, CustomRustError> {
+        self.with_document(&doc_id, |doc| doc.init_empty_doc())
+    }
+
+    #[frb]
+    pub fn apply_action(&mut self, doc_id: String, actions: Vec<BlockActionDoc>) -> Result<Vec<u8>, CustomRustError> {
+        self.with_document(&doc_id, |doc| doc.apply_action(actions))
+    }
+
+    #[frb]
+    pub fn apply_updates(&mut self, doc_id: String, updates: Vec<Vec<u8>>) -> Result<AppliedBlockChanges, CustomRustError> {
+        self.with_document(&doc_id, |doc| doc.apply_updates(updates))
+    }
+
+    #[frb]
+    pub fn get_document_state(&mut self, doc_id: String) -> Result<DocumentState, CustomRustError> {
+        self.with_document(&doc_id, |doc| doc.get_document_state())
+    }
+
+    #[frb]
+    pub fn encode_full_state(&mut self, doc_id: String) -> Result<Vec<u8>, CustomRustError> {
+        self.with_document(&doc_id, |doc| doc.encode_full_state())
+    }
+
+    /// 열린 문서에 작업을 실행 (열려 있지 않으면 에러)
+    #[frb(ignore)]
+    pub fn with_document<R>(
+        &mut self,
+        doc_id: &str,
+        f: impl FnOnce(&mut DocumentService) -> Result<R, CustomRustError>,
+    ) -> Result<R, CustomRustError> {
+        match self.documents.get_mut(doc_id) {
+            Some(doc) => f(doc),
+            None => {
+                log_error!("DocumentRegistry: Document {} is not open", doc_id);
+                Err(DocError::StateError(format!("Document {} is not open", doc_id)).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::document_types::{BlockActionTypeDoc, BlockDoc};
+
+    fn insert(id: &str) -> BlockActionDoc {
+        BlockActionDoc {
+            action: BlockActionTypeDoc::Insert,
+            block: BlockDoc {
+                id: id.to_string(),
+                ty: "paragraph".to_string(),
+                attributes: HashMap::new(),
+                delta: None,
+                parent_id: None,
+                prev_id: None,
+                next_id: None,
+                old_parent_id: None,
+                created_at: None,
+                updated_at: None,
+            },
+            path: vec![],
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_registry_routes_by_doc_id() {
+        let mut registry = DocumentRegistry::new();
+        registry.open("note-b".to_string());
+        registry.open("note-a".to_string());
+        registry.open("note-a".to_string());
+        assert_eq!(registry.list_open(), vec!["note-a".to_string(), "note-b".to_string()]);
+
+        registry.init_empty_doc("note-a".to_string()).unwrap();
+        registry.apply_action("note-a".to_string(), vec![insert("a")]).unwrap();
+        let full_state = registry.encode_full_state("note-a".to_string()).unwrap();
+        registry.apply_updates("note-b".to_string(), vec![full_state]).unwrap();
+        registry.apply_action("note-b".to_string(), vec![insert("b")]).unwrap();
+
+        let blocks_a = registry.with_document("note-a", |doc| doc.get_block("b".to_string())).unwrap();
+        assert!(blocks_a.is_none());
+        let blocks_b = registry.with_document("note-b", |doc| doc.get_block("a".to_string())).unwrap();
+        assert!(blocks_b.is_some());
+        assert_eq!(registry.with_document("note-b", |doc| Ok(doc.doc_id())).unwrap(), "note-b");
+
+        assert!(registry.close("note-a".to_string()));
+        assert!(!registry.close("note-a".to_string()));
+        assert_eq!(registry.list_open(), vec!["note-b".to_string()]);
+        assert!(registry.encode_full_state("note-a".to_string()).is_err());
+    }
+}
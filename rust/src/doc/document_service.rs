@@ -1,30 +1,145 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
 use flutter_rust_bridge::{frb, DartFnFuture};
 use log::{error, info};
 use serde_json::{json, Value as JsonValue};
-use yrs::{merge_updates_v2, Array, Doc, Map, ReadTxn, Transact};
+use yrs::sync::{Awareness, AwarenessUpdate};
+use yrs::types::{DeepObservable, PathSegment, ToJson};
+use yrs::updates::decoder::Decode;
+use yrs::updates::encoder::{Encode, Encoder, EncoderV2};
+use yrs::{merge_updates_v2, Array, Assoc, IndexedSequence, StickyIndex, DeleteSet, Doc, GetString, Map, ReadTxn, Snapshot, StateVector, Text, Transact, UndoManager, Update};
 
+use super::conversions::conversion::Conversion;
+use super::conversions::markdown::{MarkdownExport, MarkdownImport};
 use super::error::DocError;
-use super::operations::{block_ops::BlockOperations, delta_ops::DeltaOperations, update_ops::UpdateOperations};
+use super::operations::{block_ops::BlockOperations, delta_ops::DeltaOperations, update_ops::{BlockChangeKind, UpdateOperations}};
+
+use crate::doc::constants::{ATTRIBUTES, AWARENESS_TIMEOUT_MS, BLOCKS, COMMENTS, COMMENT_ANCHOR, COMMENT_AUTHOR, COMMENT_BLOCK_ID, COMMENT_RESOLVED, COMMENT_TEXT, DEFAULT_BLOCK_TYPES, DEFAULT_MAX_TREE_DEPTH, DEFAULT_PARENT, LANGUAGE_SAMPLE_CHARS, MAX_SAFE_INTEGER, META, NEXT_ID, PARENT_ID, PREV_ID, REMOTE_ORIGIN, ROOT_ID, ROOT_TYPE, SUBDOC, TEXT};
+use crate::doc::document_types::{ActionResult, AppliedBlockChanges, BlockActionDoc, BlockActionTypeDoc, BlockDoc, BlockPage, BlockStats, CommentDoc, CustomRustError, DocSize, DocStats, DocumentState, DocumentValidation, DuplicateResult, FailedToDecodeUpdates, MetaOp, OrderedBlock, OriginUpdate, RelativePos, TextMatch};
+use crate::doc::utils::language::LanguageDetection;
+use crate::doc::utils::text_stats::TextStatistics;
+use crate::doc::utils::sorting::ChainSorting;
+use crate::doc::utils::util::{find_text_matches, sha256_hex, IdGenerator, MapExt, TextExt};
+use crate::frb_generated::StreamSink;
+use crate::{log_content, log_info, log_error};
+use crate::doc::utils::logging;
+
+
+/// 문서 업데이트(v2)와 트랜잭션 origin을 받는 리스너 (subscribe_changes에서 StreamSink를 감싸서 등록)
+type ChangeListener = Arc<dyn Fn(Vec<u8>, Option<String>) + Send + Sync>;
 
-use crate::doc::constants::{BLOCKS, DEFAULT_PARENT, META, ROOT_ID};
-use crate::doc::document_types::{BlockActionDoc, BlockActionTypeDoc, CustomRustError, DocumentState, FailedToDecodeUpdates};
-use crate::doc::utils::util::MapExt;
-use crate::{log_info, log_error};
+/// 바뀐 메타 키/값 JSON을 받는 리스너 (subscribe_meta_changes에서 StreamSink를 감싸서 등록)
+type MetaListener = Arc<dyn Fn(String) + Send + Sync>;
+
+/// 한 블록의 변경 JSON을 받는 리스너 (subscribe_block_changes에서 StreamSink를 감싸서 등록)
+type BlockListener = Arc<dyn Fn(String) + Send + Sync>;
+
+/// 문서가 비었는지 여부를 받는 리스너 (subscribe_emptiness에서 StreamSink를 감싸서 등록)
+type EmptinessListener = Arc<dyn Fn(bool) + Send + Sync>;
+
+/// get_document_state 결과 캐시 (문서가 바뀌면 업데이트 옵저버가 dirty로 표시)
+#[derive(Default)]
+struct StateCache {
+    state: Option<DocumentState>,
+    dirty: Arc<AtomicBool>,
+    subscription: Option<yrs::Subscription>,
+    builds: usize,
+}
 
+/// begin_batch부터 commit_batch까지 로컬 트랜잭션의 업데이트를 모아두는 버퍼
+struct UpdateBatch {
+    updates: Arc<Mutex<Vec<Vec<u8>>>>,
+    _subscription: yrs::Subscription,
+}
 
 #[frb]
 pub struct DocumentService {
     doc: Doc,
     doc_id: String,
+    attachment_budget: Option<u64>,
+    allowed_block_types: HashSet<String>,
+    strict_types: bool,
+    undo_manager: Option<UndoManager>,
+    awareness: Awareness,
+    change_listener: Option<ChangeListener>,
+    change_subscription: Option<yrs::Subscription>,
+    meta_subscription: Option<yrs::Subscription>,
+    emptiness_subscription: Option<yrs::Subscription>,
+    /// 블록 id별 구독과, 블록이 삭제되어 구독이 끝났는지 여부
+    block_subscriptions: HashMap<String, (yrs::Subscription, Arc<AtomicBool>)>,
+    /// 하위 문서 guid별 업데이트 구독 (부모 문서 변경 스트림과 분리)
+    subdoc_subscriptions: HashMap<String, yrs::Subscription>,
+    state_cache: Mutex<StateCache>,
+    last_replace_count: u32,
+    batch: Option<UpdateBatch>,
+    max_tree_depth: u32,
+    /// 새 블록 id 생성기 (모든 내부 id 생성이 여기를 거침)
+    id_generator: IdGenerator,
 }
 
 impl DocumentService {
 
     #[frb]
     pub fn new() -> Self {
-        log_info!("Creating new document service");
-        let doc_id = "xxxx".to_string();
-        Self { doc_id, doc: Doc::new() }
+        Self::new_with_id(uuid::Uuid::new_v4().to_string())
+    }
+
+    #[frb]
+    /// 지정한 문서 id로 서비스 생성 (로그와 문서별 라우팅에 사용)
+    ///
+    /// [doc_id] 문서 id
+    pub fn new_with_id(doc_id: String) -> Self {
+        Self::new_with_options(doc_id, true)
+    }
+
+    #[frb]
+    /// 문서 id와 GC 사용 여부를 지정해서 서비스 생성
+    ///
+    /// [doc_id] 문서 id
+    /// [gc] true면 삭제된 내용을 정리해서 메모리를 아끼고, false면 스냅샷/버전 기록을 위해 남겨둠
+    pub fn new_with_options(doc_id: String, gc: bool) -> Self {
+        log_info!("Creating new document service for doc_id: {} (gc: {})", doc_id, gc);
+        let doc = Doc::with_options(yrs::Options { skip_gc: !gc, ..Default::default() });
+        let awareness = Awareness::new(doc.clone());
+        let allowed_block_types = DEFAULT_BLOCK_TYPES.iter().map(|ty| ty.to_string()).collect();
+        Self { doc_id, doc, attachment_budget: None, allowed_block_types, strict_types: false, undo_manager: None, awareness, change_listener: None, change_subscription: None, meta_subscription: None, emptiness_subscription: None, block_subscriptions: HashMap::new(), subdoc_subscriptions: HashMap::new(), state_cache: Mutex::default(), last_replace_count: 0, batch: None, max_tree_depth: DEFAULT_MAX_TREE_DEPTH, id_generator: IdGenerator::default() }
+    }
+
+    #[frb]
+    /// GC를 끈 문서로 서비스 생성 (스냅샷/버전 기록용)
+    ///
+    /// 삭제된 항목도 툼스톤으로 계속 남기 때문에 편집이 쌓일수록 메모리와 업데이트 크기가 커짐.
+    /// take_snapshot, restore_snapshot, state_as_of는 GC가 꺼진 문서에서만 동작함
+    pub fn new_with_gc_disabled() -> Self {
+        Self::new_with_options(uuid::Uuid::new_v4().to_string(), false)
+    }
+
+    #[frb]
+    /// 문서 id 반환
+    pub fn doc_id(&self) -> String {
+        self.doc_id.clone()
+    }
+
+    #[frb]
+    /// GC 사용 여부 반환 (false일 때만 스냅샷 사용 가능)
+    pub fn gc_enabled(&self) -> bool {
+        !self.doc.skip_gc()
+    }
+
+    #[frb]
+    /// 로그 출력 수준 설정 (모든 문서에 적용, 재컴파일 없이 릴리스 빌드 로그를 줄이기 위함)
+    ///
+    /// [level] "off", "error", "warn", "info", "debug", "trace" 중 하나 (대소문자 무관)
+    ///
+    /// 기본값은 디버그 빌드 info, 릴리스 빌드 warn. 메타데이터 값처럼 노트 내용이 담긴 로그는 debug 이상에서만 출력됨
+    pub fn set_log_level(level: String) -> Result<(), CustomRustError> {
+        let filter = level.parse::<log::LevelFilter>()
+            .map_err(|_| DocError::ValidationError(format!("Unknown log level: {}", level)))?;
+        logging::set_max_level(filter);
+        log_info!("set_log_level: {}", filter);
+        Ok(())
     }
 
     #[no_mangle]
@@ -52,6 +167,34 @@ impl DocumentService {
         Ok(update)
     }
 
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 같은 Doc을 유지한 채 모든 블록, 메타, 댓글을 지움 ("모든 내용 삭제"용)
+    ///
+    /// BLOCKS/META 맵은 비운 상태로 남아 있어 다시 초기화하지 않고 apply_action 가능
+    /// 반환: 피어가 같은 상태가 되도록 적용할 업데이트
+    pub fn clear_document(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("clear_document: Starting for doc_id: {}", self.doc_id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        let meta_map = root.get_or_init_map(&mut txn, META);
+        let removed = blocks_map.len(&txn);
+        blocks_map.clear(&mut txn);
+        meta_map.clear(&mut txn);
+        if let Some(yrs::Out::YMap(comments)) = root.get(&txn, COMMENTS) {
+            comments.clear(&mut txn);
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("clear_document: Removed {} blocks for doc_id: {}", removed, self.doc_id);
+        Ok(update)
+    }
+
     /// 현재 문서의 전체 상태를 인코딩하여 반환
     #[no_mangle]
     #[inline(never)]
@@ -64,6 +207,190 @@ impl DocumentService {
         Ok(update)
     }
 
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 전체 상태(encode_full_state)를 파일에 저장 (오프라인 우선 앱에서 Rust가 직접 디스크에 저장)
+    ///
+    /// [path] 저장할 파일 경로 (이미 있으면 덮어씀)
+    pub fn save_to_file(&self, path: String) -> Result<(), CustomRustError> {
+        log_info!("save_to_file: path={} for doc_id: {}", path, self.doc_id);
+
+        let state = self.encode_full_state()?;
+        std::fs::write(&path, &state)
+            .map_err(|e| DocError::Io(format!("Failed to write {}: {}", path, e)))?;
+
+        log_info!("save_to_file: Wrote {} bytes", state.len());
+        Ok(())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// save_to_file로 저장한 파일을 읽어서 apply_updates로 적용
+    ///
+    /// [path] 읽을 파일 경로
+    ///
+    /// 파일이 올바른 업데이트가 아니면 빈 문서로 넘어가지 않고 디코딩 에러 반환
+    pub fn load_from_file(&mut self, path: String) -> Result<(), CustomRustError> {
+        log_info!("load_from_file: path={} for doc_id: {}", path, self.doc_id);
+
+        let state = std::fs::read(&path)
+            .map_err(|e| DocError::Io(format!("Failed to read {}: {}", path, e)))?;
+        // apply_updates는 디코딩에 실패한 업데이트를 건너뛰므로 먼저 확인
+        UpdateOperations::decode_update_v2(&state)
+            .map_err(|e| DocError::UpdateDecodingFailed(format!("{} is not a valid document update: {}", path, e)))?;
+        self.apply_updates(vec![state])?;
+
+        log_info!("load_from_file: Loaded {} for doc_id: {}", path, self.doc_id);
+        Ok(())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 현재 문서의 전체 상태를 v1 인코딩으로 반환 (v1만 쓰는 서버/저장소와 호환용)
+    pub fn encode_full_state_v1(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_full_state_v1: doc_id: {}", self.doc_id);
+        Ok(self.doc.transact().encode_state_as_update_v1(&StateVector::default()))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// v1 인코딩 업데이트를 적용하고 추가/수정/삭제된 블록 id를 반환
+    ///
+    /// [updates] 적용할 업데이트 목록 (v1 인코딩)
+    ///
+    /// v1과 v2는 호환되지 않으므로 v1으로 디코딩한 뒤 apply_updates와 같은 경로로 적용함.
+    /// 디코딩에 실패한 업데이트는 로그를 남기고 건너뜀
+    pub fn apply_updates_v1(&mut self, updates: Vec<Vec<u8>>) -> Result<AppliedBlockChanges, CustomRustError> {
+        log_info!("apply_updates_v1: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
+
+        let mut failed = FailedToDecodeUpdates::default();
+        let mut converted = Vec::with_capacity(updates.len());
+        // Original batch index of each converted update, so failures are reported against the caller's indices
+        let mut original_indices = Vec::with_capacity(updates.len());
+        for (index, update) in updates.iter().enumerate() {
+            match Update::decode_v1(update) {
+                Ok(decoded) => {
+                    converted.push(decoded.encode_v2());
+                    original_indices.push(index);
+                }
+                Err(e) => {
+                    failed.failed_updates_ids.push(index.to_string());
+                    failed.errors.push(e.to_string());
+                }
+            }
+        }
+        if !failed.failed_updates_ids.is_empty() {
+            log_error!("apply_updates_v1: Skipped undecodable updates {:?} for doc_id: {}", failed.failed_updates_ids, self.doc_id);
+        }
+
+        let mut changes = self.apply_updates(converted)?;
+        let inner = std::mem::take(&mut changes.failed_updates);
+        for (id, error) in inner.failed_updates_ids.into_iter().zip(inner.errors) {
+            let index = id.parse::<usize>().ok().and_then(|i| original_indices.get(i).copied());
+            failed.failed_updates_ids.push(index.map(|i| i.to_string()).unwrap_or(id));
+            failed.errors.push(error);
+        }
+        changes.failed_updates = failed;
+        Ok(changes)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 현재 문서의 state vector를 인코딩하여 반환 (동기화 1단계, v1 인코딩)
+    pub fn encode_state_vector(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_state_vector: doc_id: {}", self.doc_id);
+        Ok(self.doc.transact().state_vector().encode_v1())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 상대의 state vector 이후 변경분만 인코딩하여 반환 (동기화 2단계)
+    ///
+    /// [remote_state_vector] 상대의 state vector (v1 인코딩)
+    pub fn encode_diff_since(&self, remote_state_vector: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_diff_since: {} bytes of state vector for doc_id: {}", remote_state_vector.len(), self.doc_id);
+
+        let remote_sv = StateVector::decode_v1(&remote_state_vector)
+            .map_err(|e| DocError::EncodingError(format!("Failed to decode state vector: {}", e)))?;
+        let update = self.doc.transact().encode_state_as_update_v2(&remote_sv);
+        log_info!("encode_diff_since: Encoded {} bytes", update.len());
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// encode_diff_since로 보낼 변경분의 크기(바이트)를 미리 계산
+    ///
+    /// [remote_state_vector] 상대의 state vector (v1 인코딩)
+    ///
+    /// 상대가 이미 모든 삽입을 가지고 있으면 0 반환.
+    /// state vector에는 삭제 정보가 없으므로 상대에게 삭제만 빠진 경우도 0이 반환됨 (apply_update와 같은 한계).
+    /// 그 밖의 경우 크기는 encode_diff_since와 같은 변경분을 실제로 인코딩해서 잰 값
+    pub fn estimate_diff_size(&self, remote_state_vector: Vec<u8>) -> Result<usize, CustomRustError> {
+        log_info!("estimate_diff_size: {} bytes of state vector for doc_id: {}", remote_state_vector.len(), self.doc_id);
+
+        let remote_sv = StateVector::decode_v1(&remote_state_vector)
+            .map_err(|e| DocError::EncodingError(format!("Failed to decode state vector: {}", e)))?;
+        let txn = self.doc.transact();
+        let up_to_date = txn.state_vector().iter().all(|(client, clock)| remote_sv.get(client) >= *clock);
+        if up_to_date {
+            return Ok(0);
+        }
+        let size = txn.encode_state_as_update_v2(&remote_sv).len();
+        log_info!("estimate_diff_size: {} bytes", size);
+        Ok(size)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 두 state vector가 같은지 비교 (내용을 주고받지 않고 두 복제본이 동기화되었는지 확인, DocumentService 인스턴스 불필요)
+    ///
+    /// [a] 첫 번째 state vector (v1 인코딩)
+    /// [b] 두 번째 state vector (v1 인코딩)
+    ///
+    /// state vector에는 삭제 정보가 없으므로 삭제만 다른 경우는 같다고 판단함
+    pub fn state_vectors_equal(a: Vec<u8>, b: Vec<u8>) -> Result<bool, CustomRustError> {
+        let a = Self::decode_state_vector(&a)?;
+        let b = Self::decode_state_vector(&b)?;
+        Ok(a == b)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 로컬 복제본에 없는 클라이언트별 clock 목록 ("3개 클라이언트 동기화 안 됨" 표시용, DocumentService 인스턴스 불필요)
+    ///
+    /// [local_sv] 로컬 state vector (v1 인코딩)
+    /// [remote_sv] 원격 state vector (v1 인코딩)
+    ///
+    /// 반환: 원격이 로컬보다 앞선 클라이언트마다 (client id, 원격 clock), client id 순으로 정렬.
+    /// 로컬은 해당 클라이언트의 로컬 clock부터 원격 clock 전까지의 내용이 없음. 비어 있으면 로컬이 원격을 모두 가짐
+    pub fn missing_from(local_sv: Vec<u8>, remote_sv: Vec<u8>) -> Result<Vec<(u64, u32)>, CustomRustError> {
+        let local_sv = Self::decode_state_vector(&local_sv)?;
+        let remote_sv = Self::decode_state_vector(&remote_sv)?;
+
+        let mut missing: Vec<(u64, u32)> = remote_sv.iter()
+            .filter(|(client, clock)| local_sv.get(client) < **clock)
+            .map(|(client, clock)| (*client, *clock))
+            .collect();
+        missing.sort();
+        log_info!("missing_from: {} clients ahead of local", missing.len());
+        Ok(missing)
+    }
+
+    fn decode_state_vector(bytes: &[u8]) -> Result<StateVector, CustomRustError> {
+        StateVector::decode_v1(bytes)
+            .map_err(|e| DocError::EncodingError(format!("Failed to decode state vector: {}", e)).into())
+    }
+
     #[no_mangle]
 #[inline(never)]
 #[frb]
@@ -71,25 +398,148 @@ pub fn apply_action(
     &mut self,
     actions: Vec<BlockActionDoc>,
 ) -> Result<Vec<u8>, CustomRustError> {
-    log_info!("apply_action: Starting with {} actions for doc_id: {}", 
-             actions.len(), self.doc_id);
+    self.apply_action_in(actions, None, None).map(|result| result.update)
+}
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// apply_action과 같지만 id 없이 보낸 Insert 블록에 Rust에서 만든 id도 함께 반환 (새 블록에 바로 포커스 주기 위함)
+    ///
+    /// [actions] 적용할 블록 작업 목록, Insert의 block.id가 빈 문자열이면 UUID를 새로 생성
+    ///
+    /// 반환: 업데이트와 생성된 id 목록 (작업 순서대로, id를 지정한 Insert는 포함되지 않음).
+    /// apply_action도 빈 id에 id를 생성하지만 기존 호출과의 호환을 위해 업데이트만 반환함
+    pub fn apply_action_with_ids(&mut self, actions: Vec<BlockActionDoc>) -> Result<ActionResult, CustomRustError> {
+        self.apply_action_in(actions, None, None)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// apply_action을 실제로 적용하지 않고 성공할지만 검사 (드래그/붙여넣기 확정 전 미리 확인용)
+    ///
+    /// [actions] 검사할 블록 작업 목록
+    ///
+    /// apply_action이 적용 전에 하는 검사(move 필수 필드, 부모 존재, 자기 하위로 이동 금지, 엄격 모드 type 등)를
+    /// 읽기 트랜잭션에서 그대로 실행하고 첫 번째 에러를 반환. 문서는 변경하지 않음
+    pub fn validate_action(&self, mut actions: Vec<BlockActionDoc>) -> Result<(), CustomRustError> {
+        log_info!("validate_action: {} actions for doc_id: {}", actions.len(), self.doc_id);
+
+        // apply_action처럼 id 없는 Insert는 id가 생기는 것으로 보고 검사.
+        // 일회용 생성기를 써서 시드된 id 순서(set_id_generator_seed)를 건드리지 않음
+        BlockOperations::assign_missing_ids(&mut actions, &IdGenerator::default());
+
+        let txn = self.doc.transact();
+        let blocks_map = UpdateOperations::blocks_map(&txn);
+        self.check_actions(&txn, blocks_map.as_ref(), &actions)
+    }
+
+    /// apply_action과 validate_action이 공유하는 적용 전 검사 (엄격 모드면 블록 type도 검사)
+    fn check_actions<T: ReadTxn>(&self, txn: &T, blocks_map: Option<&yrs::MapRef>, actions: &[BlockActionDoc]) -> Result<(), CustomRustError> {
+        BlockOperations::validate_actions(txn, blocks_map, actions, self.strict_types.then_some(&self.allowed_block_types))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// apply_action과 같지만 트랜잭션에 origin을 붙여 실행
+    ///
+    /// [actions] 적용할 블록 작업 목록
+    /// [origin] 트랜잭션 origin (subscribe_changes 스트림에 그대로 전달됨)
+    ///
+    /// origin 없는 apply_action은 기본(origin 없음) 트랜잭션을 쓰므로 기존 undo 추적에 영향 없음
+    pub fn apply_action_with_origin(&mut self, actions: Vec<BlockActionDoc>, origin: String) -> Result<Vec<u8>, CustomRustError> {
+        self.apply_action_in(actions, Some(origin), None).map(|result| result.update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// apply_action과 같지만 Insert/Update 블록에 생성/수정 시각을 기록 ("2분 전 편집" 표시용)
+    ///
+    /// [actions] 적용할 블록 작업 목록
+    /// [timestamp_ms] Dart에서 넘겨주는 현재 시각 (밀리초), 협업자마다 시계가 다르므로 Rust에서 시스템 시간을 읽지 않음
+    ///
+    /// Insert는 createdAt과 updatedAt을, Update는 updatedAt만 기록.
+    /// updatedAt은 클라이언트별로 저장하고 가장 큰 값을 보여주므로, 동시 편집이나 시계 차이로 뒤로 가지 않음.
+    /// 기록된 시각은 get_block/get_document_state의 created_at/updated_at으로 확인
+    pub fn apply_action_with_timestamp(&mut self, actions: Vec<BlockActionDoc>, timestamp_ms: i64) -> Result<Vec<u8>, CustomRustError> {
+        self.apply_action_in(actions, None, Some(timestamp_ms)).map(|result| result.update)
+    }
+
+    #[frb]
+    /// 엄격 모드에서 허용할 블록 type 목록 설정 (커스텀 블록을 쓰는 앱용)
+    ///
+    /// [types] 허용할 type 전체 목록, 기본 목록을 대체하므로 계속 쓸 기본 type(page, paragraph 등)도 포함해야 함
+    pub fn set_allowed_block_types(&mut self, types: Vec<String>) {
+        log_info!("set_allowed_block_types: {} types for doc_id: {}", types.len(), self.doc_id);
+        self.allowed_block_types = types.into_iter().collect();
+    }
+
+    #[frb]
+    /// 블록 type 검사 사용 여부 설정 (기본값 false, 기존 호출과의 호환을 위해 켜야만 검사함)
+    ///
+    /// [strict] true면 허용 목록에 없는 type의 Insert/Update를 에러로 거부 ("paragrah" 같은 오타 방지).
+    /// 가져오기(import_*)와 apply_update_remapped로 들어오는 블록도 같은 목록으로 검사함
+    pub fn set_strict_types(&mut self, strict: bool) {
+        log_info!("set_strict_types: {} for doc_id: {}", strict, self.doc_id);
+        self.strict_types = strict;
+    }
+
+    /// 엄격 모드일 때 쓰기 전에 블록 목록 전체의 type을 검사 (하나라도 허용되지 않으면 아무것도 쓰지 않도록)
+    fn check_block_types(&self, blocks: &[BlockDoc]) -> Result<(), CustomRustError> {
+        let allowed_types = self.strict_types.then_some(&self.allowed_block_types);
+        for (index, block) in blocks.iter().enumerate() {
+            BlockOperations::check_block_type(index, &block.ty, allowed_types)?;
+        }
+        Ok(())
+    }
+
+fn apply_action_in(
+    &mut self,
+    mut actions: Vec<BlockActionDoc>,
+    origin: Option<String>,
+    timestamp: Option<i64>,
+) -> Result<ActionResult, CustomRustError> {
+    log_info!("apply_action: Starting with {} actions for doc_id: {} (origin: {:?})", 
+             actions.len(), self.doc_id, origin);
     
     // Get document handle and start transaction
     let doc = &self.doc;
     let root = doc.get_or_insert_map(ROOT_ID);
-    let mut txn = doc.transact_mut();
+    let mut txn = match origin {
+        Some(origin) => doc.transact_mut_with(origin),
+        None => doc.transact_mut(),
+    };
+
+    // Inserts sent without an id get one here, before validation rejects empty ids
+    let generated_ids = BlockOperations::assign_missing_ids(&mut actions, &self.id_generator);
+
+    // Validate the whole batch first so a failing action can't leave earlier ones applied
+    let existing_blocks = match root.get(&txn, BLOCKS) {
+        Some(yrs::Out::YMap(blocks_map)) => Some(blocks_map),
+        _ => None,
+    };
+    if let Err(e) = self.check_actions(&txn, existing_blocks.as_ref(), &actions) {
+        log_error!("apply_action: Rejected batch for doc_id: {}: {}", self.doc_id, e.message);
+        return Err(e);
+    }
     
+    // The blocks map handle stays valid for the whole transaction, so fetch it once
+    let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
     // Process each action
     for action in actions {
-        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        let blocks_map = blocks_map.clone();
         
         // Delegate to specialized operation handlers
         match action.action {
             BlockActionTypeDoc::Insert => {
-                BlockOperations::insert_node(&mut txn, blocks_map, action)?;
+                BlockOperations::insert_node(&mut txn, blocks_map, action, timestamp)?;
             },
             BlockActionTypeDoc::Update => {
-                BlockOperations::update_node(&mut txn, blocks_map, action)?;
+                BlockOperations::update_node(&mut txn, blocks_map, action, timestamp)?;
             },
             BlockActionTypeDoc::Delete => {
                 let parent_id = action.block.parent_id
@@ -117,455 +567,7123 @@ pub fn apply_action(
     let before_state = txn.before_state();
     let update = txn.encode_diff_v2(before_state);
     
-    Ok(update)
+    Ok(ActionResult { update, generated_ids })
 }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    pub fn apply_updates(&mut self, updates: Vec<Vec<u8>>) -> Result<(), CustomRustError> {
-        log_info!("apply_updates: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
-
-        // Create a new document to apply updates to
-        let new_doc = Doc::new();
-
-        // Apply updates to the new document
-        let result = UpdateOperations::apply_updates_inner(new_doc.clone(), &self.doc_id, updates)?;
+    /// 많은 블록을 한 부모 아래에 한 번에 삽입 (큰 문서 가져오기용)
+    ///
+    /// [blocks] 삽입할 블록 목록, 입력 순서대로 형제 순서가 정해짐 (각 블록의 parent_id/prev_id/next_id는 무시)
+    /// [parent_id] 부모 블록 id, "root"면 최상위
+    ///
+    /// 부모의 기존 마지막 자식 뒤에 붙이며, apply_action과 달리 블록마다 형제 체인을 다시 찾지 않음.
+    /// 부모가 없거나 id가 중복되거나 delta가 잘못되면 아무것도 적용하지 않고 에러
+    pub fn insert_blocks_bulk(&mut self, blocks: Vec<BlockDoc>, parent_id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("insert_blocks_bulk: {} blocks under {} for doc_id: {}", blocks.len(), parent_id, self.doc_id);
 
-        // Replace the current document with the new one
-        self.doc = new_doc;
+        let mut prev_id = self.get_children_ids(parent_id.clone())?.pop();
 
-        // Debug: Check root map structure after update
+        // 쓰기 전에 배치 전체를 검사해서 중간에 실패해도 일부만 적용되지 않도록 함
         {
             let txn = self.doc.transact();
-            if let Some(root) = txn.get_map(ROOT_ID) {
-                let keys: Vec<String> = root.keys(&txn).map(|k| k.to_string()).collect();
-                log_info!("apply_updates: root keys after update = {:?}", keys);
-
-                if let Some(yrs::Value::YMap(meta)) = root.get(&txn, META) {
-                    let meta_keys: Vec<String> = meta.keys(&txn).map(|k| k.to_string()).collect();
-                    log_info!("apply_updates: meta keys = {:?}", meta_keys);
-                } else {
-                    log_info!("apply_updates: META map not found in root!");
+            let existing = UpdateOperations::blocks_map(&txn);
+            let mut seen = std::collections::HashSet::new();
+            let allowed_types = self.strict_types.then_some(&self.allowed_block_types);
+            for (index, block) in blocks.iter().enumerate() {
+                BlockOperations::check_block_type(index, &block.ty, allowed_types)?;
+                if !seen.insert(block.id.as_str()) || existing.as_ref().is_some_and(|map| map.get(&txn, &block.id).is_some()) {
+                    return Err(DocError::InvalidOperation(format!("Block {} already exists", block.id)).into());
+                }
+                if let Some(delta) = &block.delta {
+                    DeltaOperations::delta_len_after(0, delta)?;
                 }
-            } else {
-                log_info!("apply_updates: ROOT map not found!");
             }
         }
 
-        log_info!("apply_updates: Successfully applied updates for doc_id: {}", self.doc_id);
-        Ok(result)
+        let block_parent = (parent_id != "root").then_some(parent_id);
+        let count = blocks.len();
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        for block in blocks {
+            let id = block.id.clone();
+            let block = BlockDoc {
+                parent_id: block_parent.clone(),
+                prev_id: prev_id.take(),
+                next_id: None,
+                old_parent_id: None,
+                ..block
+            };
+            BlockOperations::write_block(&mut txn, &blocks_map, &block)?;
+            prev_id = Some(id);
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("insert_blocks_bulk: Inserted {} blocks, {} bytes", count, update.len());
+        Ok(update)
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    pub fn get_document_state(&self) -> Result<DocumentState, CustomRustError> {
-        log_info!("get_document_state: Starting for doc_id: {}", self.doc_id);
-        
-        let doc = &self.doc;
-        let root = doc.get_or_insert_map(ROOT_ID);
-        let txn = doc.transact();
-        
-        // Extract document state through specialized function
-        let state = UpdateOperations::extract_document_state(&txn, root, &self.doc_id)?;
-        
-        log_info!("get_document_state: Finished for doc_id: {}", self.doc_id);
-        Ok(state)
+    /// 원격 업데이트를 적용하고 추가/수정/삭제된 블록 id를 반환
+    ///
+    /// [updates] 적용할 업데이트 목록
+    ///
+    /// 메타만 바뀐 경우 세 목록 모두 비어 있음
+    pub fn apply_updates(&mut self, updates: Vec<Vec<u8>>) -> Result<AppliedBlockChanges, CustomRustError> {
+        let (block_changes, failed_updates) = self.apply_updates_and_diff(updates)?;
+        let mut changes = AppliedBlockChanges { failed_updates, ..AppliedBlockChanges::default() };
+        for (id, kind) in block_changes {
+            match kind {
+                BlockChangeKind::Inserted => changes.inserted_block_ids.push(id),
+                BlockChangeKind::Deleted => changes.deleted_block_ids.push(id),
+                _ => changes.updated_block_ids.push(id),
+            }
+        }
+        Ok(changes)
     }
 
+    #[no_mangle]
+    #[inline(never)]
     #[frb]
-    pub fn merge_updates(&self, updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("merge_updates: Merging {} updates", updates.len());
-        
-        match merge_updates_v2(updates) {
-            Ok(update) => {
-                log_info!("merge_updates: Successfully merged updates");
-                Ok(update)
-            },
-            Err(e) => {
-                log_error!("merge_updates: Failed to merge updates: {}", e);
-                Err(DocError::EncodingError(format!("Failed to merge updates: {}", e)).into())
-            }
+    /// 원격 업데이트 하나를 적용하고 새 내용이 들어왔는지 반환 (이미 받은 업데이트를 다시 브로드캐스트하지 않기 위함)
+    ///
+    /// [update] 적용할 업데이트 (v2)
+    ///
+    /// 반환: 적용 전후 state vector를 비교해 앞으로 나아갔으면 true, 이미 가진 내용뿐이면 false.
+    /// 삭제만 담긴 업데이트는 state vector를 바꾸지 않으므로 false가 반환됨. 디코딩에 실패하면 에러
+    pub fn apply_update(&mut self, update: Vec<u8>) -> Result<bool, CustomRustError> {
+        log_info!("apply_update: {} bytes for doc_id: {}", update.len(), self.doc_id);
+
+        let before = self.doc.transact().state_vector();
+        let (_, failed) = UpdateOperations::apply_updates_inner(&self.doc, &self.doc_id, vec![update])?;
+        if let Some(error) = failed.errors.first() {
+            return Err(DocError::UpdateDecodingFailed(format!("Failed to decode update: {}", error)).into());
+        }
+        let advanced = self.doc.transact().state_vector() != before;
+
+        log_info!("apply_update: advanced={} for doc_id: {}", advanced, self.doc_id);
+        Ok(advanced)
+    }
+
+    /// 업데이트를 적용하면서 그 트랜잭션의 깊은 관찰 이벤트로 블록별 변경 종류를 구해 반환
+    /// (적용 전후 문서 전체를 읽지 않음). 디코딩에 실패해 건너뛴 업데이트 목록도 함께 반환
+    fn apply_updates_and_diff(&mut self, updates: Vec<Vec<u8>>) -> Result<(BTreeMap<String, BlockChangeKind>, FailedToDecodeUpdates), CustomRustError> {
+        log_info!("apply_updates: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
+
+        let changes: Arc<Mutex<BTreeMap<String, BlockChangeKind>>> = Arc::default();
+        let sink = changes.clone();
+        let subscription = self.doc.get_or_insert_map(ROOT_ID).observe_deep(move |txn, events| match sink.lock() {
+            Ok(mut changes) => UpdateOperations::classify_block_events(txn, events, &mut changes),
+            Err(_) => log_error!("apply_updates: Change buffer lock poisoned, block changes dropped"),
+        });
+        // Apply directly onto the current document so observers and the undo manager stay attached
+        let (_, failed) = UpdateOperations::apply_updates_inner(&self.doc, &self.doc_id, updates)?;
+        drop(subscription);
+        if !failed.failed_updates_ids.is_empty() {
+            log_error!("apply_updates: Skipped undecodable updates {:?} for doc_id: {}", failed.failed_updates_ids, self.doc_id);
         }
+
+        let changes = std::mem::take(
+            &mut *changes.lock().map_err(|_| DocError::StateError("Change buffer lock poisoned".into()))?
+        );
+        log_info!("apply_updates: Successfully applied updates for doc_id: {}", self.doc_id);
+        Ok((changes, failed))
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// Setting a root node id in the root map
-    pub fn set_root_node_id(&mut self, id: String) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("set_root_node_id: Setting root node id to {}", id);
+    /// 원격 업데이트를 적용하고 블록별 변경 종류를 JSON으로 반환
+    ///
+    /// [updates] 적용할 업데이트 목록
+    ///
+    /// 반환: {"blockA": "inserted", "blockB": "text_changed", "blockC": "deleted"}
+    /// 변경 종류: inserted, deleted, text_changed, data_changed, text_and_data_changed
+    /// 같은 업데이트 안에서 추가 후 삭제된 블록은 포함되지 않음
+    pub fn apply_updates_with_changes(&mut self, updates: Vec<Vec<u8>>) -> Result<String, CustomRustError> {
+        let changes: serde_json::Map<String, JsonValue> = self.apply_updates_and_diff(updates)?
+            .0
+            .into_iter()
+            .map(|(id, kind)| (id, JsonValue::String(kind.as_str().to_string())))
+            .collect();
+        log_info!("apply_updates_with_changes: {} blocks changed for doc_id: {}", changes.len(), self.doc_id);
+
+        serde_json::to_string(&JsonValue::Object(changes))
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)).into())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 텍스트에 여러 편집(retain/insert/delete)을 하나의 트랜잭션으로 적용
+    ///
+    /// [block_id] 대상 블록 id
+    /// [ops_json] Quill delta 형식의 연산 목록 (예: [{"retain": 5}, {"insert": "!"}, {"delete": 2}])
+    ///
+    /// 모든 연산의 범위를 먼저 검사하므로, 하나라도 범위를 벗어나면 아무것도 적용하지 않고 에러 반환
+    pub fn apply_text_ops(&mut self, block_id: String, ops_json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("apply_text_ops: block_id={} for doc_id: {}", block_id, self.doc_id);
 
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        root.insert(&mut txn, ROOT_ID, id.clone());
-        log_info!("set_root_node_id: Successfully set root node id to {}", id);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        let block = match blocks_map.get(&txn, &block_id) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        // yrs 트랜잭션은 되돌릴 수 없으므로, 텍스트를 새로 만들기 전에 연산 범위부터 검사 (텍스트가 없으면 길이 0)
+        let current_len = match block.get(&txn, TEXT) {
+            Some(yrs::Out::YText(text)) => text.len(&txn),
+            _ => 0,
+        };
+        DeltaOperations::delta_len_after(current_len, &ops_json)?;
+        let text = block.get_or_init_text(&mut txn, TEXT);
+        DeltaOperations::apply_delta_to_text(&mut txn, text, ops_json)?;
 
-        // Encode the state as an update
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("set_root_node_id: Finished for doc_id: {}", self.doc_id);
+        log_info!("apply_text_ops: Finished for block_id={}", block_id);
         Ok(update)
     }
 
-    // ============================================
-    // Meta API - YDoc 메타데이터 조작
-    // ============================================
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 텍스트를 서식 없이 순수 문자열로 반환 (검색 색인, 글자 수 계산용)
+    ///
+    /// [block_id] 대상 블록 id
+    ///
+    /// 속성은 버리고 블록 안의 줄바꿈은 유지, 텍스트가 없는 블록은 빈 문자열 반환
+    /// 인라인 임베드(이미지, 수식 등)는 \u{FFFC} 한 글자로 표시해 위치가 텍스트 안의 위치와 맞도록 함
+    pub fn get_block_plain_text(&self, block_id: String) -> Result<String, CustomRustError> {
+        log_info!("get_block_plain_text: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let txn = self.doc.transact();
+        let block = match UpdateOperations::blocks_map(&txn).and_then(|blocks| blocks.get(&txn, &block_id)) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        match block.get(&txn, TEXT) {
+            Some(yrs::Out::YText(text)) => Ok(DeltaOperations::plain_text_with_embeds(&txn, &text)),
+            _ => Ok(String::new()),
+        }
+    }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터에 문자열 값 설정
+    /// 블록 텍스트 안의 위치를 원격 편집에도 유지되는 상대 위치로 인코딩 (협업 커서 저장용)
     ///
-    /// [key] 메타데이터 키
-    /// [value] 설정할 문자열 값
-    pub fn set_meta_string(&mut self, key: String, value: String) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("set_meta_string: key={}, value={}", key, value);
+    /// [block_id] 대상 블록 id
+    /// [offset] 텍스트 안의 위치 (delta retain과 같은 단위)
+    ///
+    /// 텍스트 필드가 없는 블록이거나 위치가 텍스트 길이를 넘으면 에러
+    pub fn encode_relative_position(&self, block_id: String, offset: u32) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_relative_position: block_id={} offset={} for doc_id: {}", block_id, offset, self.doc_id);
 
-        let doc = &self.doc;
-        let root = doc.get_or_insert_map(ROOT_ID);
-        let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
+        let text = {
+            let txn = self.doc.transact();
+            let block = match UpdateOperations::blocks_map(&txn).and_then(|blocks| blocks.get(&txn, &block_id)) {
+                Some(yrs::Out::YMap(block)) => block,
+                _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+            };
+            match block.get(&txn, TEXT) {
+                Some(yrs::Out::YText(text)) => text,
+                _ => return Err(DocError::InvalidOperation(format!("Block {} has no text", block_id)).into()),
+            }
+        };
+        if offset > text.len(&self.doc.transact()) {
+            return Err(DocError::InvalidOperation(format!("Offset {} is out of range for block {}", offset, block_id)).into());
+        }
 
-        meta.insert(&mut txn, key.clone(), value);
+        // sticky_index는 쓰기 트랜잭션을 요구하지만 문서는 바꾸지 않음
+        let mut txn = self.doc.transact_mut();
+        let index = text.sticky_index(&mut txn, offset, Assoc::After)
+            .ok_or_else(|| DocError::InvalidOperation(format!("Offset {} is out of range for block {}", offset, block_id)))?;
+        Ok(index.encode_v1())
+    }
 
-        let before_state = txn.before_state();
-        let update = txn.encode_diff_v2(before_state);
-        log_info!("set_meta_string: Finished for key={}", key);
-        Ok(update)
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// encode_relative_position으로 만든 상대 위치를 현재 문서 기준의 블록 id와 위치로 변환
+    ///
+    /// [relative_position] 인코딩된 상대 위치
+    ///
+    /// 블록이 삭제되었거나 아직 받지 못한 내용을 가리키면 에러
+    pub fn decode_relative_position(&self, relative_position: Vec<u8>) -> Result<RelativePos, CustomRustError> {
+        log_info!("decode_relative_position: {} bytes for doc_id: {}", relative_position.len(), self.doc_id);
+
+        let index = StickyIndex::decode_v1(&relative_position)
+            .map_err(|e| DocError::DecodingError(format!("Failed to decode relative position: {}", e)))?;
+        let txn = self.doc.transact();
+        let resolved = index.get_offset(&txn)
+            .ok_or_else(|| DocError::StateError("Relative position points to content that is not in the document".into()))?;
+
+        let branch_id = resolved.branch.id();
+        let block_id = UpdateOperations::blocks_map(&txn).and_then(|blocks_map| {
+            blocks_map.iter(&txn).find_map(|(id, value)| match value {
+                yrs::Out::YMap(block) => match block.get(&txn, TEXT) {
+                    Some(yrs::Out::YText(text)) if AsRef::<yrs::branch::Branch>::as_ref(&text).id() == branch_id => Some(id.to_string()),
+                    _ => None,
+                },
+                _ => None,
+            })
+        });
+        match block_id {
+            Some(block_id) => Ok(RelativePos { block_id, offset: resolved.index }),
+            None => Err(DocError::BlockNotFound("Block of relative position not found".into()).into()),
+        }
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터 키 제거
+    /// 문서 전체 텍스트를 문서 순서대로 줄바꿈으로 이어서 반환 (미리보기, 검색 색인용)
     ///
-    /// [key] 제거할 메타데이터 키
-    pub fn remove_meta_key(&mut self, key: String) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("remove_meta_key: key={}", key);
+    /// get_document_state와 같은 순서로 순회하며, 텍스트 필드가 없는 블록(구분선, 이미지 등)은 건너뜀
+    /// 인라인 임베드는 get_block_plain_text처럼 \u{FFFC} 한 글자로 표시
+    pub fn get_document_plain_text(&self) -> Result<String, CustomRustError> {
+        log_info!("get_document_plain_text: Starting for doc_id: {}", self.doc_id);
 
-        let doc = &self.doc;
-        let root = doc.get_or_insert_map(ROOT_ID);
-        let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
+        let lines: Vec<String> = self.block_texts_in_order()?
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect();
 
-        meta.remove(&mut txn, &key);
+        log_info!("get_document_plain_text: Joined {} text blocks", lines.len());
+        Ok(lines.join("\n"))
+    }
 
-        let before_state = txn.before_state();
-        let update = txn.encode_diff_v2(before_state);
-        log_info!("remove_meta_key: Finished for key={}", key);
-        Ok(update)
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 전체에서 텍스트 검색 (문서 내 찾기용)
+    ///
+    /// [query] 찾을 문자열
+    /// [case_sensitive] 대소문자 구분 여부
+    ///
+    /// 문서 순서대로 블록 id, 블록 텍스트 안의 글자 위치, 일치 길이를 반환
+    /// 위치는 인라인 임베드를 한 글자로 세므로 get_block_plain_text, YText 위치와 같음
+    /// 겹치는 일치도 각각 보고하며, 빈 검색어는 빈 목록 반환
+    pub fn search_text(&self, query: String, case_sensitive: bool) -> Result<Vec<TextMatch>, CustomRustError> {
+        log_info!("search_text: query_len={} case_sensitive={} for doc_id: {}", query.chars().count(), case_sensitive, self.doc_id);
+
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let length = query.chars().count() as u32;
+        let matches: Vec<TextMatch> = self.block_texts_in_order()?
+            .into_iter()
+            .flat_map(|(block_id, text)| {
+                find_text_matches(&text, &query, case_sensitive)
+                    .into_iter()
+                    .map(move |offset| TextMatch { block_id: block_id.clone(), offset, length })
+            })
+            .collect();
+
+        log_info!("search_text: Found {} matches", matches.len());
+        Ok(matches)
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터에 정수 값 설정
+    /// 문서 전체에서 찾아 바꾸기를 한 트랜잭션으로 적용
     ///
-    /// [key] 메타데이터 키
-    /// [value] 설정할 정수 값
-    pub fn set_meta_int(&mut self, key: String, value: i64) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("set_meta_int: key={}, value={}", key, value);
+    /// [query] 찾을 문자열
+    /// [replacement] 바꿀 문자열
+    /// [case_sensitive] 대소문자 구분 여부
+    ///
+    /// 겹치는 일치는 앞쪽 것만 바꾸고, 바뀐 부분은 원래 첫 글자의 서식을 유지
+    /// 바꾼 개수는 last_replace_count로 확인
+    pub fn replace_all(&mut self, query: String, replacement: String, case_sensitive: bool) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("replace_all: query_len={} case_sensitive={} for doc_id: {}", query.chars().count(), case_sensitive, self.doc_id);
 
+        let order = Conversion::document_order(&self.get_document_state()?);
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        meta.insert(&mut txn, key.clone(), value);
+        let mut replaced = 0;
+        if !query.is_empty() {
+            for id in order {
+                if let Some(yrs::Out::YMap(block)) = blocks_map.get(&txn, &id) {
+                    if let Some(yrs::Out::YText(text)) = block.get(&txn, TEXT) {
+                        replaced += DeltaOperations::replace_matches(&mut txn, &text, &query, &replacement, case_sensitive);
+                    }
+                }
+            }
+        }
+        self.last_replace_count = replaced;
 
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("set_meta_int: Finished for key={}", key);
+        log_info!("replace_all: Replaced {} matches for doc_id: {}", replaced, self.doc_id);
         Ok(update)
     }
 
+    #[frb]
+    /// 마지막 replace_all에서 바꾼 개수 ("12개 바꿈" 표시용)
+    pub fn last_replace_count(&self) -> u32 {
+        self.last_replace_count
+    }
+
+    /// 텍스트 필드가 있는 블록의 (id, 순수 텍스트)를 문서 순서대로 반환 (임베드는 \u{FFFC}로 표시)
+    fn block_texts_in_order(&self) -> Result<Vec<(String, String)>, CustomRustError> {
+        let state = self.get_document_state()?;
+        let order = Conversion::document_order(&state);
+
+        let txn = self.doc.transact();
+        let blocks_map = UpdateOperations::blocks_map(&txn)
+            .ok_or_else(|| DocError::StateError("Blocks map not found in document".into()))?;
+        Ok(order
+            .into_iter()
+            .filter_map(|id| match blocks_map.get(&txn, &id) {
+                Some(yrs::Out::YMap(block)) => match block.get(&txn, TEXT) {
+                    Some(yrs::Out::YText(text)) => Some((id, DeltaOperations::plain_text_with_embeds(&txn, &text))),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect())
+    }
+
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터에 불리언 값 설정
+    /// 블록의 기존 텍스트에 Quill delta를 적용 (글자 단위 CRDT 병합 유지)
     ///
-    /// [key] 메타데이터 키
-    /// [value] 설정할 불리언 값
-    pub fn set_meta_bool(&mut self, key: String, value: bool) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("set_meta_bool: key={}, value={}", key, value);
+    /// [block_id] 대상 블록 id
+    /// [delta_json] retain/insert/delete 연산 목록 (attributes 포함 가능)
+    ///
+    /// 블록이 없거나 텍스트 필드가 없으면 에러 반환 (텍스트를 새로 만들지 않음)
+    pub fn apply_delta(&mut self, block_id: String, delta_json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("apply_delta: block_id={} for doc_id: {}", block_id, self.doc_id);
 
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        meta.insert(&mut txn, key.clone(), value);
+        let block = match blocks_map.get(&txn, &block_id) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        let text = match block.get(&txn, TEXT) {
+            Some(yrs::Out::YText(text)) => text,
+            _ => return Err(DocError::InvalidOperation(format!("Block {} has no text", block_id)).into()),
+        };
+        DeltaOperations::apply_delta_to_text(&mut txn, text, delta_json)?;
 
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("set_meta_bool: Finished for key={}", key);
+        log_info!("apply_delta: Finished for block_id={}", block_id);
         Ok(update)
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터에 문자열 배열 설정 (기존 배열 전체 교체)
+    /// 여러 블록의 전체 텍스트에 서식 속성을 한 트랜잭션으로 적용
     ///
-    /// [key] 메타데이터 키
-    /// [values] 설정할 문자열 배열
-    pub fn set_meta_string_array(&mut self, key: String, values: Vec<String>) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("set_meta_string_array: key={}, count={}", key, values.len());
+    /// [block_ids] 서식을 적용할 블록 id 목록
+    /// [attributes_json] 적용할 속성 JSON 객체 (예: {"bold": true, "italic": null})
+    ///
+    /// 값이 null인 속성은 제거되며, 존재하지 않거나 텍스트가 없는 블록은 로그만 남기고 건너뜀
+    pub fn format_blocks(&mut self, block_ids: Vec<String>, attributes_json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("format_blocks: {} blocks for doc_id: {}", block_ids.len(), self.doc_id);
+
+        let attributes: HashMap<String, JsonValue> = serde_json::from_str(&attributes_json)
+            .map_err(|e| DocError::DecodingError(format!("Failed to parse attributes: {}", e)))?;
+        let attrs: yrs::types::Attrs = attributes
+            .iter()
+            .map(|(k, v)| (Arc::from(k.as_str()), Conversion::json_value_to_yrs_any(v)))
+            .collect();
 
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
-
-        // 기존 배열이 있으면 제거하고 새로 생성
-        meta.remove(&mut txn, &key);
-        let array = meta.get_or_init_array(&mut txn, key.clone());
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        for value in values {
-            array.push_back(&mut txn, value);
+        let mut skipped = Vec::new();
+        for block_id in &block_ids {
+            let text = match blocks_map.get(&txn, block_id) {
+                Some(yrs::Out::YMap(block)) => match block.get(&txn, TEXT) {
+                    Some(yrs::Out::YText(text)) => text,
+                    _ => {
+                        skipped.push(block_id.as_str());
+                        continue;
+                    }
+                },
+                _ => {
+                    skipped.push(block_id.as_str());
+                    continue;
+                }
+            };
+            let len = text.len(&txn);
+            if len > 0 {
+                text.format(&mut txn, 0, len, attrs.clone());
+            }
+        }
+        if !skipped.is_empty() {
+            log_error!("format_blocks: Skipped missing blocks or blocks without text: {:?}", skipped);
         }
 
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("set_meta_string_array: Finished for key={}", key);
+        log_info!("format_blocks: Finished, {} bytes", update.len());
         Ok(update)
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터 배열에 문자열 항목 추가 (중복 체크)
+    /// 블록 id를 매핑에 따라 바꿔서 업데이트를 현재 문서에 적용 (템플릿 인스턴스화 용도)
     ///
-    /// [key] 메타데이터 키
-    /// [value] 추가할 문자열 값
-    pub fn push_meta_array_item(&mut self, key: String, value: String) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("push_meta_array_item: key={}, value={}", key, value);
+    /// [update] 적용할 업데이트 (v2 인코딩)
+    /// [id_map_json] 원래 id -> 새 id 매핑 JSON (예: {"tpl-heading": "a8Kd2x"})
+    ///
+    /// 매핑에 없는 id는 그대로 유지되며, 결과 id가 기존 블록과 겹치면 아무것도 적용하지 않고 에러 반환
+    pub fn apply_update_remapped(&mut self, update: Vec<u8>, id_map_json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("apply_update_remapped: Starting with {} bytes for doc_id: {}", update.len(), self.doc_id);
+
+        let id_map: HashMap<String, String> = serde_json::from_str(&id_map_json)
+            .map_err(|e| DocError::DecodingError(format!("Failed to parse id map: {}", e)))?;
+        let remap = |id: String| id_map.get(&id).cloned().unwrap_or(id);
+
+        // 임시 문서에 업데이트를 적용한 뒤 블록만 읽어옴
+        let source = Doc::new();
+        let (_, failed) = UpdateOperations::apply_updates_inner(&source, &self.doc_id, vec![update])?;
+        if !failed.failed_updates_ids.is_empty() {
+            return Err(DocError::UpdateDecodingFailed("Failed to decode template update".into()).into());
+        }
+        let source_blocks = UpdateOperations::collect_blocks(&source.transact())?;
+
+        let remapped: Vec<BlockDoc> = source_blocks
+            .into_values()
+            .map(|block| BlockDoc {
+                id: remap(block.id),
+                parent_id: block.parent_id.map(remap),
+                prev_id: block.prev_id.map(remap),
+                next_id: block.next_id.map(remap),
+                ..block
+            })
+            .collect();
+        self.check_block_types(&remapped)?;
 
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
-        let array = meta.get_or_init_array(&mut txn, key.clone());
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        // 중복 체크
-        let exists = array.iter(&txn).any(|v| {
-            if let yrs::Value::Any(yrs::Any::String(s)) = v {
-                s.as_ref() == value.as_str()
-            } else {
-                false
+        // 쓰기 전에 충돌을 모두 확인해서 일부만 적용되는 일이 없도록 함
+        let mut seen = std::collections::HashSet::new();
+        for block in &remapped {
+            if !seen.insert(block.id.as_str()) || blocks_map.contains_key(&txn, &block.id) {
+                log_error!("apply_update_remapped: Block id {} already exists", block.id);
+                return Err(DocError::ValidationError(format!("Block id {} already exists in document", block.id)).into());
             }
-        });
+        }
 
-        if !exists {
-            array.push_back(&mut txn, value.clone());
+        for block in &remapped {
+            BlockOperations::write_block(&mut txn, &blocks_map, block)?;
         }
 
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("push_meta_array_item: Finished for key={}", key);
+        log_info!("apply_update_remapped: Applied {} blocks for doc_id: {}", remapped.len(), self.doc_id);
         Ok(update)
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 메타데이터 배열에서 문자열 항목 제거
+    /// 블록과 그 아래 모든 하위 블록을 한 트랜잭션으로 삭제 ("섹션 삭제" 용)
     ///
-    /// [key] 메타데이터 키
-    /// [value] 제거할 문자열 값
-    pub fn remove_meta_array_item(&mut self, key: String, value: String) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("remove_meta_array_item: key={}, value={}", key, value);
+    /// [block_id] 삭제할 블록 id
+    ///
+    /// 하위 블록 전체를 먼저 모은 뒤 BLOCKS에서 제거하고, 다음 형제의 prev_id는 삭제된 블록의 prev_id로 다시 연결.
+    /// 루트 페이지 블록은 삭제할 수 없음
+    pub fn delete_block_recursive(&mut self, block_id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("delete_block_recursive: block_id={} for doc_id: {}", block_id, self.doc_id);
 
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        if let Some(yrs::Value::YArray(array)) = meta.get(&txn, &key) {
-            // 제거할 인덱스 찾기
-            let mut index_to_remove: Option<u32> = None;
-            for (i, v) in array.iter(&txn).enumerate() {
-                if let yrs::Value::Any(yrs::Any::String(s)) = v {
-                    if s.as_ref() == value.as_str() {
-                        index_to_remove = Some(i as u32);
-                        break;
-                    }
-                }
+        if let Some(yrs::Out::Any(yrs::Any::String(root_id))) = root.get(&txn, ROOT_ID) {
+            if *root_id == *block_id {
+                log_error!("delete_block_recursive: Block {} is the root page", block_id);
+                return Err(DocError::InvalidOperation(format!("Cannot delete root block {}", block_id)).into());
             }
+        }
+        if !matches!(blocks_map.get(&txn, &block_id), Some(yrs::Out::YMap(_))) {
+            return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into());
+        }
 
-            if let Some(index) = index_to_remove {
-                array.remove(&mut txn, index);
-            }
+        // 1단계: 하위 블록을 모두 모음 (방문 집합으로 부모가 순환해도 한 번씩만 모음)
+        let blocks_by_parent = BlockOperations::build_parent_child_structure(&mut txn, blocks_map.clone());
+        let descendants = BlockOperations::find_descendants(&block_id, &blocks_by_parent);
+
+        // 2단계: 대상 블록만 형제 체인에서 빼고, 모은 블록을 모두 제거 (하위 블록의 형제도 함께 지워지므로 다시 연결할 필요 없음)
+        BlockOperations::remove_block_from_prev_id_chain(&mut txn, blocks_map.clone(), &block_id)?;
+        for id in descendants.iter().chain(std::iter::once(&block_id)) {
+            blocks_map.remove(&mut txn, id);
         }
 
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("remove_meta_array_item: Finished for key={}", key);
+        log_info!("delete_block_recursive: Removed {} blocks for block_id={}", descendants.len() + 1, block_id);
         Ok(update)
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 모든 메타데이터를 JSON 문자열로 반환
+    /// 블록과 모든 하위 블록을 새 id로 복제해서 원본 바로 뒤에 삽입
     ///
-    /// 반환: JSON 형식의 메타데이터 (예: {"title": "노트", "color": 123, "status": "active"})
-    pub fn get_all_meta(&self) -> Result<String, CustomRustError> {
-        log_info!("get_all_meta: Starting");
+    /// [block_id] 복제할 블록 id
+    ///
+    /// 반환: 업데이트와 원래 id -> 새 id 매핑. 텍스트는 서식까지 복사되며 복제본은 원본과 독립적으로 편집 가능
+    pub fn duplicate_block(&mut self, block_id: String) -> Result<DuplicateResult, CustomRustError> {
+        log_info!("duplicate_block: block_id={} for doc_id: {}", block_id, self.doc_id);
 
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
-        let txn = doc.transact();
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        let mut result = serde_json::Map::new();
+        let blocks = UpdateOperations::collect_blocks(&txn)?;
+        let original = blocks.get(&block_id)
+            .ok_or_else(|| DocError::BlockNotFound(format!("Block {} not found", block_id)))?;
 
-        if let Some(yrs::Value::YMap(meta)) = root.get(&txn, META) {
-            for (key, value) in meta.iter(&txn) {
-                let json_value = Self::yrs_value_to_json(&txn, value);
-                result.insert(key.to_string(), json_value);
+        let mut blocks_by_parent: HashMap<String, Vec<String>> = HashMap::new();
+        for block in blocks.values() {
+            if let Some(parent_id) = &block.parent_id {
+                blocks_by_parent.entry(parent_id.clone()).or_default().push(block.id.clone());
             }
         }
+        let mut subtree = vec![block_id.clone()];
+        subtree.extend(BlockOperations::find_descendants(&block_id, &blocks_by_parent));
 
-        let json_str = serde_json::to_string(&JsonValue::Object(result))
-            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+        let id_map: HashMap<String, String> = subtree.iter()
+            .map(|id| (id.clone(), self.id_generator.next_id()))
+            .collect();
+        let remap = |id: &String| id_map.get(id).cloned().unwrap_or_else(|| id.clone());
 
-        log_info!("get_all_meta: Finished");
-        Ok(json_str)
-    }
+        // 원본 다음 형제가 복제본을 가리키도록 먼저 연결
+        let copy_id = id_map[&block_id].clone();
+        let next_sibling = blocks.values()
+            .find(|block| block.parent_id == original.parent_id && block.prev_id.as_deref() == Some(block_id.as_str()))
+            .map(|block| block.id.clone());
+        if let Some(next_id) = next_sibling {
+            let next = blocks_map.get_or_init_map(&mut txn, next_id.as_str());
+            next.insert(&mut txn, PREV_ID, copy_id.clone());
+        }
 
-    /// yrs::Value를 serde_json::Value로 변환
-    fn yrs_value_to_json<T: ReadTxn>(txn: &T, value: yrs::Value) -> JsonValue {
-        match value {
-            yrs::Value::Any(any) => Self::yrs_any_to_json(any),
-            yrs::Value::YArray(array) => {
-                let items: Vec<JsonValue> = array.iter(txn)
-                    .map(|v| Self::yrs_value_to_json(txn, v))
-                    .collect();
-                JsonValue::Array(items)
-            }
-            yrs::Value::YMap(map) => {
-                let mut obj = serde_json::Map::new();
-                for (k, v) in map.iter(txn) {
-                    obj.insert(k.to_string(), Self::yrs_value_to_json(txn, v));
-                }
-                JsonValue::Object(obj)
-            }
-            _ => JsonValue::Null,
+        for id in &subtree {
+            let source = &blocks[id];
+            let copy = BlockDoc {
+                id: remap(id),
+                parent_id: source.parent_id.as_ref().map(remap),
+                prev_id: if *id == block_id { Some(block_id.clone()) } else { source.prev_id.as_ref().map(remap) },
+                next_id: None,
+                old_parent_id: None,
+                created_at: None,
+                updated_at: None,
+                ..source.clone()
+            };
+            BlockOperations::write_block(&mut txn, &blocks_map, &copy)?;
         }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("duplicate_block: Copied {} blocks, new root {}", subtree.len(), copy_id);
+        Ok(DuplicateResult { update, id_map })
     }
 
-    /// yrs::Any를 serde_json::Value로 변환
-    fn yrs_any_to_json(any: yrs::Any) -> JsonValue {
-        match any {
-            yrs::Any::Null => JsonValue::Null,
-            yrs::Any::Undefined => JsonValue::Null,
-            yrs::Any::Bool(b) => JsonValue::Bool(b),
-            yrs::Any::Number(n) => json!(n),
-            yrs::Any::BigInt(n) => json!(n),
-            yrs::Any::String(s) => JsonValue::String(s.to_string()),
-            yrs::Any::Array(arr) => {
-                let items: Vec<JsonValue> = arr.iter()
-                    .map(|v| Self::yrs_any_to_json(v.clone()))
-                    .collect();
-                JsonValue::Array(items)
-            }
-            yrs::Any::Map(map) => {
-                let mut obj = serde_json::Map::new();
-                for (k, v) in map.iter() {
-                    obj.insert(k.clone(), Self::yrs_any_to_json(v.clone()));
-                }
-                JsonValue::Object(obj)
-            }
-            yrs::Any::Buffer(buf) => {
-                // Base64 인코딩 또는 배열로 변환
-                JsonValue::Array(buf.iter().map(|b| json!(*b)).collect())
-            }
-        }
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 선택한 블록과 모든 하위 블록을 다른 문서에도 붙여넣을 수 있는 클립보드 JSON으로 복사
+    ///
+    /// [block_ids] 복사할 블록 id 목록 (순서와 상관없이 문서 순서로 담김)
+    ///
+    /// 반환: {"format", "version", "blocks": [AppFlowy 노드]} 형식의 JSON. 텍스트 서식과 블록 데이터가 그대로 담기며
+    /// yrs client id 같은 문서 내부 정보는 포함하지 않음. 다른 선택 블록의 하위 블록은 상위 블록에 포함되어 한 번만 담김
+    pub fn copy_blocks(&self, block_ids: Vec<String>) -> Result<String, CustomRustError> {
+        log_info!("copy_blocks: {} blocks for doc_id: {}", block_ids.len(), self.doc_id);
+
+        let state = self.read_document_state()?;
+        let payload = Conversion::blocks_to_clipboard(&state, &block_ids)?;
+        serde_json::to_string(&payload)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)).into())
     }
 
     #[no_mangle]
     #[inline(never)]
     #[frb]
-    /// 여러 메타데이터 필드를 한 번에 설정 (JSON 입력)
+    /// copy_blocks로 만든 클립보드 JSON을 새 id로 지정한 위치에 붙여넣기
     ///
-    /// [json_str] 설정할 메타데이터 JSON (예: {"title": "노트", "status": "active"})
+    /// [payload] copy_blocks가 반환한 JSON (다른 DocumentService에서 복사한 것도 가능)
+    /// [target_parent_id] 붙여넣을 부모 블록 id (부모가 없는 최상위는 "root")
+    /// [after_block_id] 이 형제 블록 바로 뒤에 붙여넣음, None이면 부모의 첫 자식으로 붙여넣음
     ///
-    /// 지원 타입: string, number (int/double), boolean, string array
-    pub fn set_meta_from_json(&mut self, json_str: String) -> Result<Vec<u8>, CustomRustError> {
-        log_info!("set_meta_from_json: {}", json_str);
+    /// 같은 클립보드를 여러 번 붙여넣어도 매번 새 id를 사용. 엄격 type 모드에서는 허용되지 않은 type이 있으면 에러
+    pub fn paste_blocks(&mut self, payload: String, target_parent_id: String, after_block_id: Option<String>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("paste_blocks: parent={} after={:?} for doc_id: {}", target_parent_id, after_block_id, self.doc_id);
 
-        let json: JsonValue = serde_json::from_str(&json_str)
-            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let value: JsonValue = serde_json::from_str(&payload)
+            .map_err(|e| DocError::ValidationError(format!("Invalid clipboard payload: {}", e)))?;
+        let blocks = Conversion::clipboard_to_blocks(&value, &self.id_generator)?;
+        self.check_block_types(&blocks)?;
 
-        let obj = json.as_object()
-            .ok_or_else(|| DocError::InvalidOperation("Expected JSON object".into()))?;
+        let siblings = self.get_children_ids(target_parent_id.clone())?;
+        let next_sibling = match &after_block_id {
+            Some(after) => {
+                let position = siblings.iter().position(|id| id == after).ok_or_else(|| DocError::ValidationError(
+                    format!("Block {} is not a child of {}", after, target_parent_id)
+                ))?;
+                siblings.get(position + 1).cloned()
+            }
+            None => siblings.first().cloned(),
+        };
 
+        let block_parent = (target_parent_id != "root").then_some(target_parent_id);
+        let count = blocks.len();
         let doc = &self.doc;
         let root = doc.get_or_insert_map(ROOT_ID);
         let mut txn = doc.transact_mut();
-        let meta = root.get_or_init_map(&mut txn, META);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
 
-        for (key, value) in obj {
-            match value {
-                JsonValue::Null => { meta.remove(&mut txn, key); }
-                JsonValue::Bool(b) => { meta.insert(&mut txn, key.clone(), *b); }
-                JsonValue::Number(n) => {
-                    if let Some(i) = n.as_i64() {
-                        meta.insert(&mut txn, key.clone(), i);
-                    } else if let Some(f) = n.as_f64() {
-                        meta.insert(&mut txn, key.clone(), f);
-                    }
-                }
-                JsonValue::String(s) => { meta.insert(&mut txn, key.clone(), s.clone()); }
-                JsonValue::Array(arr) => {
-                    // 문자열 배열로 가정
-                    meta.remove(&mut txn, key);
-                    let array = meta.get_or_init_array(&mut txn, key.clone());
-                    for item in arr {
-                        if let JsonValue::String(s) = item {
-                            array.push_back(&mut txn, s.clone());
-                        }
-                    }
+        let mut last_top_level = None;
+        for block in blocks {
+            let block = if block.parent_id.is_none() {
+                last_top_level = Some(block.id.clone());
+                BlockDoc {
+                    parent_id: block_parent.clone(),
+                    prev_id: block.prev_id.or_else(|| after_block_id.clone()),
+                    ..block
                 }
-                JsonValue::Object(_) => {
-                    // 중첩 객체는 JSON 문자열로 저장
-                    let nested_json = serde_json::to_string(value)
-                        .map_err(|e| DocError::EncodingError(format!("JSON serialize failed: {}", e)))?;
-                    meta.insert(&mut txn, key.clone(), nested_json);
-                }
-            }
+            } else {
+                block
+            };
+            BlockOperations::write_block(&mut txn, &blocks_map, &block)?;
+        }
+        // 붙여넣은 위치 다음 형제가 마지막으로 붙여넣은 블록을 가리키도록 연결
+        if let (Some(next_id), Some(last_id)) = (next_sibling, last_top_level) {
+            let next = blocks_map.get_or_init_map(&mut txn, next_id.as_str());
+            next.insert(&mut txn, PREV_ID, last_id);
         }
 
         let before_state = txn.before_state();
         let update = txn.encode_diff_v2(before_state);
-        log_info!("set_meta_from_json: Finished");
+        log_info!("paste_blocks: Pasted {} blocks, {} bytes for doc_id: {}", count, update.len(), self.doc_id);
         Ok(update)
     }
-}
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_meta_save_and_load() {
-        // 첫 번째 DocumentService에서 메타 저장
-        let mut doc_service1 = DocumentService::new();
-        doc_service1.init_empty_doc().unwrap();
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서를 전혀 바꾸지 않고 현재 상태를 읽기 (get_document_state와 달리 캐시를 쓰지 않음)
+    ///
+    /// root 맵이 아직 없는 문서(초기화 전)는 root 맵을 만들지 않고 빈 DocumentState 반환 (root_id는 빈 문자열)
+    pub fn read_document_state(&self) -> Result<DocumentState, CustomRustError> {
+        let txn = self.doc.transact();
+        self.extract_state(&txn)
+    }
 
-        let meta_json = r#"{"title":"테스트 노트","color":4294924083,"status":"pinned","labelIds":["label1","label2"]}"#;
-        let update = doc_service1.set_meta_from_json(meta_json.to_string()).unwrap();
+    /// 트랜잭션 안에서 상태 추출. root 맵이 없으면 빈 DocumentState (모든 상태 읽기가 이 경로를 씀)
+    fn extract_state<T: ReadTxn>(&self, txn: &T) -> Result<DocumentState, CustomRustError> {
+        match txn.get_map(ROOT_ID) {
+            Some(root) => UpdateOperations::extract_document_state(txn, root, &self.doc_id),
+            None => {
+                log_info!("extract_state: No root map yet for doc_id: {}", self.doc_id);
+                Ok(DocumentState {
+                    doc_id: self.doc_id.clone(),
+                    blocks: HashMap::new(),
+                    children_map: HashMap::new(),
+                    root_id: String::new(),
+                })
+            }
+        }
+    }
 
-        println!("Update size: {} bytes", update.len());
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    pub fn get_document_state(&self) -> Result<DocumentState, CustomRustError> {
+        log_info!("get_document_state: Starting for doc_id: {}", self.doc_id);
 
-        // 메타데이터 로드
-        let loaded_meta = doc_service1.get_all_meta().unwrap();
+        let mut cache = self.state_cache.lock()
+            .map_err(|_| DocError::StateError("State cache lock poisoned".into()))?;
+        self.observe_for_cache(&mut cache)?;
+        if let Some(state) = cache.state.as_ref().filter(|_| !cache.dirty.load(Ordering::Acquire)) {
+            log_info!("get_document_state: Served from cache for doc_id: {}", self.doc_id);
+            return Ok(state.clone());
+        }
+
+        let state = self.read_document_state()?;
+        cache.dirty.store(false, Ordering::Release);
+        cache.state = Some(state.clone());
+        cache.builds += 1;
+        
+        log_info!("get_document_state: Finished for doc_id: {}", self.doc_id);
+        Ok(state)
+    }
+
+    /// 처음 읽을 때 옵저버를 등록해서 이후 모든 변경(로컬/원격/메타)이 캐시를 무효화하도록 함
+    fn observe_for_cache(&self, cache: &mut StateCache) -> Result<(), CustomRustError> {
+        if cache.subscription.is_none() {
+            let dirty = cache.dirty.clone();
+            let subscription = self.doc
+                .observe_update_v2(move |_, _| dirty.store(true, Ordering::Release))
+                .map_err(|e| DocError::StateError(format!("Failed to observe document updates: {}", e)))?;
+            cache.subscription = Some(subscription);
+        }
+        Ok(())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서를 처음 열 때 업데이트 적용과 상태 추출을 한 번에 처리 (큰 노트 열기용)
+    ///
+    /// [updates] 적용할 업데이트 목록 (v2)
+    ///
+    /// apply_updates 후 get_document_state를 부른 것과 같은 상태를 반환하지만, apply_updates가 변경 블록을 구하려고
+    /// 하는 적용 전후 전체 순회를 건너뛰고 같은 트랜잭션 안에서 상태를 한 번만 추출함.
+    /// 결과는 상태 캐시에 저장되므로 바로 이어지는 get_document_state는 다시 추출하지 않음.
+    /// 디코딩에 실패한 업데이트는 apply_updates처럼 로그만 남기고 건너뜀
+    pub fn load_and_get_state(&mut self, updates: Vec<Vec<u8>>) -> Result<DocumentState, CustomRustError> {
+        log_info!("load_and_get_state: {} updates for doc_id: {}", updates.len(), self.doc_id);
+
+        let (merged, failed) = UpdateOperations::decode_and_merge(&self.doc_id, &updates);
+        if !failed.failed_updates_ids.is_empty() {
+            log_error!("load_and_get_state: Skipped undecodable updates {:?} for doc_id: {}", failed.failed_updates_ids, self.doc_id);
+        }
+
+        let state = {
+            let mut txn = self.doc.transact_mut_with(REMOTE_ORIGIN);
+            UpdateOperations::apply_decoded(&mut txn, &self.doc_id, merged);
+            self.extract_state(&txn)?
+        };
+
+        // 커밋 후에 저장해야 옵저버가 캐시를 다시 dirty로 표시하지 않음
+        let mut cache = self.state_cache.lock()
+            .map_err(|_| DocError::StateError("State cache lock poisoned".into()))?;
+        self.observe_for_cache(&mut cache)?;
+        cache.dirty.store(false, Ordering::Release);
+        cache.state = Some(state.clone());
+        cache.builds += 1;
+
+        log_info!("load_and_get_state: {} blocks for doc_id: {}", state.blocks.len(), self.doc_id);
+        Ok(state)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 상태 전체를 JSON 문자열로 반환 (디버깅, 로그, 웹 뷰 전달용)
+    ///
+    /// DocumentState와 같은 필드(doc_id, blocks, children_map, root_id)를 쓰되,
+    /// 블록의 delta는 문자열이 아니라 delta 배열 그대로 넣음 (예: "delta": [{"insert": "Hello"}])
+    pub fn get_document_json(&self) -> Result<String, CustomRustError> {
+        log_info!("get_document_json: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let mut json = serde_json::to_value(&state)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+        if let Some(blocks) = json.get_mut("blocks").and_then(JsonValue::as_object_mut) {
+            for block in blocks.values_mut() {
+                let parsed = match block.get("delta") {
+                    Some(JsonValue::String(delta)) => serde_json::from_str::<JsonValue>(delta).ok(),
+                    _ => None,
+                };
+                if let Some(parsed) = parsed {
+                    block["delta"] = parsed;
+                }
+            }
+        }
+
+        serde_json::to_string(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)).into())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 전체 상태를 추출하지 않고 블록 하나만 읽기
+    ///
+    /// [block_id] 읽을 블록 id
+    ///
+    /// 블록이 없으면 None 반환
+    pub fn get_block(&self, block_id: String) -> Result<Option<BlockDoc>, CustomRustError> {
+        log_info!("get_block: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let txn = self.doc.transact();
+        match UpdateOperations::blocks_map(&txn) {
+            Some(blocks_map) => UpdateOperations::extract_block(&txn, &blocks_map, &block_id),
+            None => Ok(None),
+        }
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// BLOCKS 맵에 있는 블록 수 (루트 페이지 블록 포함)
+    pub fn get_block_count(&self) -> Result<usize, CustomRustError> {
+        log_info!("get_block_count: doc_id: {}", self.doc_id);
+
+        let txn = self.doc.transact();
+        Ok(UpdateOperations::blocks_map(&txn).map_or(0, |blocks_map| blocks_map.len(&txn) as usize))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// BLOCKS 맵의 모든 블록 id (루트 페이지 블록 포함, 내용은 읽지 않음)
+    ///
+    /// 순서는 맵 순회 순서이며 문서 순서가 보장되지 않음. 문서 순서가 필요하면 get_children_ids를 사용
+    pub fn get_all_block_ids(&self) -> Result<Vec<String>, CustomRustError> {
+        log_info!("get_all_block_ids: doc_id: {}", self.doc_id);
+
+        let txn = self.doc.transact();
+        Ok(UpdateOperations::blocks_map(&txn)
+            .map(|blocks_map| blocks_map.keys(&txn).map(|id| id.to_string()).collect())
+            .unwrap_or_default())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록을 문서 순서(깊이 우선, 자식 순서대로)로 깊이와 함께 반환 (목차, 내보내기 등 트리 순회용)
+    ///
+    /// 깊이는 루트 페이지 블록이 0, 그 자식이 1부터 시작.
+    /// 루트에서 닿지 않는 블록(고아)은 id 순으로 맨 뒤에 붙고 깊이가 -1
+    pub fn get_blocks_in_order(&self) -> Result<Vec<OrderedBlock>, CustomRustError> {
+        log_info!("get_blocks_in_order: doc_id: {}", self.doc_id);
+
+        let mut state = self.read_document_state()?;
+        let order: Vec<(String, i32)> = UpdateOperations::blocks_in_order(&state).collect();
+        let ordered: Vec<OrderedBlock> = order
+            .into_iter()
+            .filter_map(|(id, depth)| state.blocks.remove(&id).map(|block| OrderedBlock { block, depth }))
+            .collect();
+
+        log_info!("get_blocks_in_order: {} blocks for doc_id: {}", ordered.len(), self.doc_id);
+        Ok(ordered)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록을 get_blocks_in_order와 같은 문서 순서로 나눠서 반환 (아주 큰 노트를 조금씩 화면에 불러오기 위함)
+    ///
+    /// [offset] 건너뛸 블록 수
+    /// [limit] 최대 반환 블록 수
+    ///
+    /// 반환: 해당 구간의 블록, 전체 블록 수, 뒤에 블록이 더 있는지 여부.
+    /// 순서는 문서 상태에서 결정적으로 정해지므로 그 사이에 문서가 바뀌지 않으면 페이지가 겹치거나 빠지지 않음.
+    /// 문서 상태 캐시를 쓰므로 페이지마다 전체 상태를 다시 만들지 않음
+    pub fn get_blocks_page(&self, offset: usize, limit: usize) -> Result<BlockPage, CustomRustError> {
+        log_info!("get_blocks_page: offset={}, limit={} for doc_id: {}", offset, limit, self.doc_id);
+
+        let mut state = self.get_document_state()?;
+        let order: Vec<String> = UpdateOperations::blocks_in_order(&state).map(|(id, _)| id).collect();
+        let total = order.len();
+        let blocks: Vec<BlockDoc> = order
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .filter_map(|id| state.blocks.remove(id))
+            .collect();
+        let has_more = offset.saturating_add(limit) < total;
+
+        log_info!("get_blocks_page: {} of {} blocks, has_more={}", blocks.len(), total, has_more);
+        Ok(BlockPage { blocks, total, has_more })
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서에 내용 블록이 하나도 없는지 확인
+    ///
+    /// "비어 있음" = BLOCKS 맵에 루트 포인터(ROOT_ID)가 가리키는 루트 페이지 블록 외의 블록이 없음.
+    /// 메타데이터는 보지 않으며, 텍스트가 없는 빈 문단도 내용 블록으로 셈
+    pub fn is_empty(&self) -> Result<bool, CustomRustError> {
+        log_info!("is_empty: doc_id: {}", self.doc_id);
+
+        let txn = self.doc.transact();
+        Ok(Self::is_empty_in(&txn))
+    }
+
+    /// is_empty 판정 (subscribe_emptiness가 트랜잭션마다 같은 기준으로 검사)
+    fn is_empty_in<T: ReadTxn>(txn: &T) -> bool {
+        let blocks_map = match UpdateOperations::blocks_map(txn) {
+            Some(blocks_map) => blocks_map,
+            None => return true,
+        };
+        let root_pointer = match txn.get_map(ROOT_ID).and_then(|root| root.get(txn, ROOT_ID)) {
+            Some(yrs::Out::Any(yrs::Any::String(id))) => Some(id.to_string()),
+            _ => None,
+        };
+        blocks_map.keys(txn).all(|id| root_pointer.as_deref() == Some(id))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 피어에게 없는 내용만 담고 삭제는 모두 뺀 업데이트 생성 (병합 전용 동기화용)
+    ///
+    /// [peer_state_vector] 피어의 state vector (v1 인코딩)
+    ///
+    /// 주의: 로컬에서 삭제한 내용이 피어에는 남으므로, 삭제가 실제로 필요한 경우 두 문서가 갈라질 수 있음.
+    /// 이미 삭제된 상태로 전달되는 새 항목은 피어에서도 삭제된 상태로 들어감 (피어의 기존 내용만 보존됨).
+    pub fn additive_update_for(&self, peer_state_vector: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("additive_update_for: Starting for doc_id: {}", self.doc_id);
+
+        let peer_sv = StateVector::decode_v1(&peer_state_vector)
+            .map_err(|e| DocError::DecodingError(format!("Failed to decode state vector: {}", e)))?;
+        let diff = self.doc.transact().encode_diff_v1(&peer_sv);
+        let update = Update::decode_v1(&diff)
+            .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to decode diff: {}", e)))?;
+
+        // yrs 0.22는 delete set 없이 struct만 인코딩하는 API를 공개하지 않으므로(encode_diff/write_blocks_*는 pub(crate))
+        // 인코딩된 바이트를 직접 다룸. Yjs 업데이트 형식(v1)은 struct 목록 뒤에 delete set이 붙는 구조이고,
+        // Update::encode_v1도 struct를 쓴 뒤 같은 DeleteSet::encode_v1 바이트를 덧붙이므로 끝의 delete set을 빈 것으로 교체함.
+        // 이 배치에 의존하므로 Cargo.toml에서 yrs 버전을 고정해 두었고, 올릴 때는 이 함수의 테스트로 확인해야 함
+        let encoded = update.encode_v1();
+        let delete_set = update.delete_set().encode_v1();
+        let structs = encoded.strip_suffix(delete_set.as_slice())
+            .ok_or_else(|| DocError::StateEncodingFailed("Unexpected update layout while stripping deletes".into()))?;
+        let mut additive = structs.to_vec();
+        additive.extend(DeleteSet::new().encode_v1());
+
+        let update = Update::decode_v1(&additive)
+            .map_err(|e| DocError::StateEncodingFailed(format!("Failed to rebuild additive update: {}", e)))?;
+        log_info!("additive_update_for: Dropped {} bytes of deletes for doc_id: {}", delete_set.len(), self.doc_id);
+        Ok(update.encode_v2())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 기록해 둔 스냅샷 시점의 문서 상태를 재구성 (문서는 바꾸지 않음)
+    ///
+    /// [snapshot] take_snapshot으로 기록한 스냅샷 (v1 인코딩)
+    ///
+    /// state vector만으로는 언제 삭제되었는지 알 수 없으므로 state vector가 아니라 delete set까지 담긴 스냅샷을 받음
+    /// (encode_state_vector로 저장해 둔 값은 쓸 수 없으며, 넘기면 디코딩 에러).
+    /// 기록 이후에 추가된 블록은 빠지고, 기록 이후에 삭제된 블록은 그대로 보임.
+    /// GC가 꺼진 문서에서만 동작하며, 현재 문서에 없는 항목을 가리키는 스냅샷이면 에러 반환
+    pub fn state_as_of(&self, snapshot: Vec<u8>) -> Result<DocumentState, CustomRustError> {
+        log_info!("state_as_of: Starting for doc_id: {}", self.doc_id);
+
+        if self.gc_enabled() {
+            return Err(DocError::StateError(
+                "state_as_of requires a document created with GC disabled".into()
+            ).into());
+        }
+        let recorded = Snapshot::decode_v1(&snapshot)
+            .map_err(|e| DocError::DecodingError(format!(
+                "Failed to decode snapshot (expected take_snapshot output, not a state vector): {}", e
+            )))?;
+
+        let past_doc = self.reconstruct_snapshot(recorded)?;
+        let state = self.extract_state(&past_doc.transact())?;
+
+        log_info!("state_as_of: Reconstructed {} blocks for doc_id: {}", state.blocks.len(), self.doc_id);
+        Ok(state)
+    }
+
+    /// 스냅샷 시점의 내용만 담은 별도 Doc을 만듦 (GC가 꺼져 있어 과거 항목이 남아 있어야 함)
+    fn reconstruct_snapshot(&self, recorded: Snapshot) -> Result<Doc, CustomRustError> {
+        let past_update = {
+            let txn = self.doc.transact();
+            let current_sv = txn.state_vector();
+            let mut sv = StateVector::default();
+            for (client, clock) in recorded.state_map.iter() {
+                if current_sv.get(client) < *clock {
+                    return Err(DocError::ValidationError(format!(
+                        "Snapshot references client {} up to clock {}, but the document only has {}",
+                        client, clock, current_sv.get(client)
+                    )).into());
+                }
+                // clock이 0인 클라이언트는 해당 시점에 아무것도 없었으므로 제외
+                if *clock > 0 {
+                    sv.set_max(*client, *clock);
+                }
+            }
+            let mut encoder = EncoderV2::new();
+            txn.encode_state_from_snapshot(&Snapshot::new(sv, recorded.delete_set), &mut encoder)
+                .map_err(|e| DocError::StateError(format!("History for the snapshot is not available: {}", e)))?;
+            encoder.to_vec()
+        };
+
+        let past_doc = Doc::new();
+        let (_, failed) = UpdateOperations::apply_updates_inner(&past_doc, &self.doc_id, vec![past_update])?;
+        if !failed.failed_updates_ids.is_empty() {
+            return Err(DocError::UpdateDecodingFailed("Failed to decode reconstructed state".into()).into());
+        }
+        Ok(past_doc)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 현재 시점의 스냅샷을 인코딩하여 반환 (버전 기록용, v1 인코딩)
+    ///
+    /// GC가 꺼진 문서(new_with_gc_disabled)에서만 동작함
+    pub fn take_snapshot(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("take_snapshot: Starting for doc_id: {}", self.doc_id);
+
+        if self.gc_enabled() {
+            return Err(DocError::StateError(
+                "take_snapshot requires a document created with GC disabled".into()
+            ).into());
+        }
+        Ok(self.doc.transact().snapshot().encode_v1())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 스냅샷 시점의 내용으로 문서를 되돌리고, 피어도 같은 상태로 옮길 업데이트를 반환
+    ///
+    /// [snapshot] take_snapshot으로 얻은 스냅샷
+    ///
+    /// 되돌리기는 새 편집으로 기록되므로 이후 변경 기록은 그대로 남음.
+    /// 현재 문서에 없는 항목을 가리키는 스냅샷이면 아무것도 바꾸지 않고 에러 반환
+    pub fn restore_snapshot(&mut self, snapshot: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("restore_snapshot: Starting for doc_id: {}", self.doc_id);
+
+        if self.gc_enabled() {
+            return Err(DocError::StateError(
+                "restore_snapshot requires a document created with GC disabled".into()
+            ).into());
+        }
+        let recorded = Snapshot::decode_v1(&snapshot)
+            .map_err(|e| DocError::DecodingError(format!("Failed to decode snapshot: {}", e)))?;
+
+        let past_doc = self.reconstruct_snapshot(recorded)?;
+        let past_comments = Self::read_comments(&past_doc.transact());
+        let (past_blocks, past_meta, past_root_id) = {
+            let txn = past_doc.transact();
+            let meta: HashMap<String, JsonValue> = match txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, META)) {
+                Some(yrs::Out::YMap(meta)) => meta.iter(&txn).map(|(k, v)| (k.to_string(), Self::yrs_any_to_json(v.to_json(&txn)))).collect(),
+                _ => HashMap::new(),
+            };
+            let root_id = txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, ROOT_ID));
+            let root_id = match root_id {
+                Some(yrs::Out::Any(any)) => Some(any),
+                _ => None,
+            };
+            (UpdateOperations::collect_blocks(&txn)?, meta, root_id)
+        };
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let current_blocks = UpdateOperations::collect_blocks(&txn)?;
+        let current_comments: HashMap<String, CommentDoc> = Self::read_comments(&txn)
+            .into_iter()
+            .map(|comment| (comment.comment_id.clone(), comment))
+            .collect();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        let meta_map = root.get_or_init_map(&mut txn, META);
+
+        // 달라진 블록만 다시 써서 업데이트 크기를 줄임
+        let changes = UpdateOperations::diff_blocks(&current_blocks, &past_blocks);
+        for (id, kind) in &changes {
+            match kind {
+                BlockChangeKind::Deleted => {
+                    blocks_map.remove(&mut txn, id);
+                }
+                _ => {
+                    BlockOperations::write_block(&mut txn, &blocks_map, &past_blocks[id])?;
+                }
+            }
+        }
+
+        // 중첩 메타는 YMap/YArray로 다시 써야 필드 단위 병합이 유지됨
+        let current_meta: HashMap<String, JsonValue> = meta_map.iter(&txn).map(|(k, v)| (k.to_string(), Self::yrs_any_to_json(v.to_json(&txn)))).collect();
+        for key in current_meta.keys().filter(|key| !past_meta.contains_key(*key)) {
+            meta_map.remove(&mut txn, key);
+        }
+        for (key, value) in &past_meta {
+            if current_meta.get(key) != Some(value) {
+                Self::prune_meta_fields(&mut txn, &meta_map, key, value);
+                Self::write_meta_json(&mut txn, &meta_map, key, value);
+            }
+        }
+        if let Some(root_id) = past_root_id {
+            root.insert(&mut txn, ROOT_ID, root_id);
+        }
+
+        // 달라진 댓글과, 텍스트가 다시 쓰인 블록에 붙은 댓글(위치를 새 텍스트에 다시 고정해야 함)만 기록
+        if !past_comments.is_empty() || !current_comments.is_empty() {
+            let comments_map = root.get_or_init_map(&mut txn, COMMENTS);
+            let past_ids: HashSet<&str> = past_comments.iter().map(|comment| comment.comment_id.as_str()).collect();
+            for id in current_comments.keys().filter(|id| !past_ids.contains(id.as_str())) {
+                comments_map.remove(&mut txn, id);
+            }
+            for comment in &past_comments {
+                if current_comments.get(&comment.comment_id) != Some(comment) || changes.contains_key(&comment.block_id) {
+                    Self::write_comment(&mut txn, &comments_map, &blocks_map, comment);
+                }
+            }
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("restore_snapshot: Restored {} changed blocks for doc_id: {}", changes.len(), self.doc_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 텍스트가 너무 긴 블록의 id 목록 반환 (렌더링 성능 경고용)
+    ///
+    /// [max_chars] 허용하는 최대 글자 수 (바이트가 아닌 유니코드 문자 기준)
+    pub fn find_oversized_blocks(&self, max_chars: u32) -> Result<Vec<String>, CustomRustError> {
+        log_info!("find_oversized_blocks: max_chars={} for doc_id: {}", max_chars, self.doc_id);
+
+        let txn = self.doc.transact();
+        let mut oversized = Vec::new();
+
+        if let Some(blocks_map) = UpdateOperations::blocks_map(&txn) {
+            for (id, block) in blocks_map.iter(&txn) {
+                if let yrs::Out::YMap(block_map) = block {
+                    if let Some(yrs::Out::YText(text)) = block_map.get(&txn, TEXT) {
+                        if text.get_string(&txn).chars().count() > max_chars as usize {
+                            oversized.push(id.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        oversized.sort();
+        log_info!("find_oversized_blocks: Found {} blocks", oversized.len());
+        Ok(oversized)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 전체 단어 수, 글자 수(공백 포함/제외), 블록 수 (글쓰기 목표 기능용)
+    ///
+    /// 단어는 공백으로 나눈 단위로 세지만, 한자와 가나는 띄어쓰기를 하지 않으므로 글자 하나를 한 단어로 셈 (한글은 띄어쓰기 기준).
+    /// 블록 경계도 단어 경계로 취급하고, 블록 수는 루트 페이지 블록을 뺀 블록 수.
+    /// 인라인 임베드(이미지, 수식 등)는 글자나 단어로 세지 않음 (search_text 위치와 달리 \u{FFFC}를 넣지 않음).
+    /// 빈 문서나 초기화 전 문서는 에러 없이 모두 0 반환
+    pub fn get_statistics(&self) -> Result<DocStats, CustomRustError> {
+        self.statistics(false)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// get_statistics와 같지만 블록별 수치도 문서 순서대로 함께 반환 (여백에 블록별 글자 수 표시용)
+    ///
+    /// 텍스트 필드가 없는 블록(구분선, 이미지 등)은 블록별 목록에서 빠짐
+    pub fn get_statistics_with_blocks(&self) -> Result<DocStats, CustomRustError> {
+        self.statistics(true)
+    }
+
+    fn statistics(&self, include_blocks: bool) -> Result<DocStats, CustomRustError> {
+        log_info!("statistics: include_blocks={} for doc_id: {}", include_blocks, self.doc_id);
+
+        let state = self.read_document_state()?;
+        let mut stats = DocStats { word_count: 0, char_count: 0, char_count_no_spaces: 0, block_count: 0, blocks: None };
+        let mut per_block = Vec::new();
+
+        let txn = self.doc.transact();
+        if let Some(blocks_map) = UpdateOperations::blocks_map(&txn) {
+            for (id, depth) in UpdateOperations::blocks_in_order(&state) {
+                if depth != 0 {
+                    stats.block_count += 1;
+                }
+                let Some(yrs::Out::YMap(block)) = blocks_map.get(&txn, &id) else { continue };
+                let Some(yrs::Out::YText(text)) = block.get(&txn, TEXT) else { continue };
+
+                // 임베드는 글자나 단어가 아니므로 일부러 plain_text로 빼고 셈 (검색 위치와 달리 YText 위치와 맞출 필요 없음)
+                let counts = TextStatistics::count(&DeltaOperations::plain_text(&txn, &text));
+                stats.word_count += counts.words;
+                stats.char_count += counts.chars;
+                stats.char_count_no_spaces += counts.chars_no_spaces;
+                per_block.push(BlockStats {
+                    block_id: id,
+                    word_count: counts.words,
+                    char_count: counts.chars,
+                    char_count_no_spaces: counts.chars_no_spaces,
+                });
+            }
+        }
+        if include_blocks {
+            stats.blocks = Some(per_block);
+        }
+
+        log_info!("statistics: {} words in {} blocks for doc_id: {}", stats.word_count, stats.block_count, self.doc_id);
+        Ok(stats)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 텍스트를 샘플링해서 주 언어를 추정 (맞춤법 검사/현지화 힌트용)
+    ///
+    /// 문자 체계와 흔한 단어를 보는 간단한 휴리스틱이며, BCP-47 코드(예: "en", "ko", "ja")를 반환
+    /// 텍스트가 너무 적어 판단할 수 없으면 "und" 반환
+    pub fn detect_language(&self) -> Result<String, CustomRustError> {
+        log_info!("detect_language: Starting for doc_id: {}", self.doc_id);
+
+        let txn = self.doc.transact();
+        let mut texts: Vec<(String, String)> = Vec::new();
+        if let Some(blocks_map) = UpdateOperations::blocks_map(&txn) {
+            for (id, block) in blocks_map.iter(&txn) {
+                if let yrs::Out::YMap(block_map) = block {
+                    if let Some(yrs::Out::YText(text)) = block_map.get(&txn, TEXT) {
+                        texts.push((id.to_string(), text.get_string(&txn)));
+                    }
+                }
+            }
+        }
+
+        // 블록 id 순으로 정렬해서 같은 문서는 항상 같은 샘플을 보도록 함
+        texts.sort();
+        let mut sample = String::new();
+        for (_, text) in texts {
+            if sample.chars().count() >= LANGUAGE_SAMPLE_CHARS {
+                break;
+            }
+            sample.push_str(&text);
+            sample.push('\n');
+        }
+
+        let language = LanguageDetection::detect(&sample);
+        log_info!("detect_language: Detected {} for doc_id: {}", language, self.doc_id);
+        Ok(language)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 두 블록을 합칠 수 있는지 여부 ("이전 블록과 합치기" 메뉴 활성화용)
+    ///
+    /// [first_id] 남게 되는 앞 블록 id
+    /// [second_id] 앞 블록에 합쳐질 블록 id
+    ///
+    /// 두 블록이 모두 존재하고, 둘 다 텍스트를 가지며, 타입이 호환되고(서식 있는 텍스트 블록끼리, code는 code끼리),
+    /// second가 first의 조상이 아니어야 함. 판정은 merge_blocks가 쓰는 BlockOperations::check_merge 그대로
+    pub fn can_merge_blocks(&self, first_id: String, second_id: String) -> Result<bool, CustomRustError> {
+        let txn = self.doc.transact();
+        let blocks_map = match UpdateOperations::blocks_map(&txn) {
+            Some(map) => map,
+            None => return Ok(false),
+        };
+
+        match BlockOperations::check_merge(&txn, &blocks_map, &first_id, &second_id) {
+            Ok(()) => Ok(true),
+            Err(e) => {
+                log_info!("can_merge_blocks: {} <- {} not mergeable: {}", first_id, second_id, e);
+                Ok(false)
+            }
+        }
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 뒤 블록을 앞 블록에 합침 ("이전 블록과 합치기", 블록 맨 앞에서 Backspace)
+    ///
+    /// [first_id] 남게 되는 앞 블록 id
+    /// [second_id] 앞 블록에 합쳐질 블록 id
+    ///
+    /// 같은 쓰기 트랜잭션 안에서 can_merge_blocks와 같은 BlockOperations::check_merge로 먼저 검사하고, 합칠 수 없으면 아무것도 바꾸지 않고 에러.
+    /// second의 텍스트는 서식과 임베드까지 first 끝에 이어 붙이고, second의 자식은 순서대로 first의 마지막 자식 뒤로 옮긴 뒤 second를 삭제
+    pub fn merge_blocks(&mut self, first_id: String, second_id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("merge_blocks: {} <- {} for doc_id: {}", first_id, second_id, self.doc_id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        BlockOperations::check_merge(&txn, &blocks_map, &first_id, &second_id)?;
+        let text_of = |txn: &yrs::TransactionMut, id: &str| match blocks_map.get(txn, id) {
+            Some(yrs::Out::YMap(block)) => match block.get(txn, TEXT) {
+                Some(yrs::Out::YText(text)) => Some(text),
+                _ => None,
+            },
+            _ => None,
+        };
+        let (Some(first_text), Some(second_text)) = (text_of(&txn, &first_id), text_of(&txn, &second_id)) else {
+            return Err(DocError::StateError(format!("Block {} or {} lost its text", first_id, second_id)).into());
+        };
+
+        // 앞 블록 끝으로 retain한 뒤 뒤 블록의 delta를 그대로 삽입
+        let mut ops = vec![json!({ "retain": first_text.len(&txn) })];
+        if let JsonValue::Array(second_ops) = Conversion::deltas_to_json(&txn, second_text.delta(&txn))? {
+            ops.extend(second_ops);
+        }
+        let ops_json = serde_json::to_string(&ops)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+        DeltaOperations::apply_delta_to_text(&mut txn, first_text, ops_json)?;
+
+        let mut prev_id = BlockOperations::children_in_order(&txn, &blocks_map, &first_id, "")?.pop();
+        let children = BlockOperations::children_in_order(&txn, &blocks_map, &second_id, "")?;
+        for child_id in &children {
+            BlockOperations::move_block(&mut txn, blocks_map.clone(), &[], &[], &first_id, &second_id, child_id, prev_id, None)?;
+            prev_id = Some(child_id.clone());
+        }
+
+        BlockOperations::remove_block_from_prev_id_chain(&mut txn, blocks_map.clone(), &second_id)?;
+        blocks_map.remove(&mut txn, &second_id);
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("merge_blocks: Merged {} into {}, moved {} children", second_id, first_id, children.len());
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 구조만 중첩 JSON으로 반환 (delta, 데이터 제외 - 사이드바/아웃라인용)
+    ///
+    /// 반환: {"id": "...", "type": "page", "children": [{"id": "...", "type": "heading", "children": []}]}
+    pub fn export_structure(&self) -> Result<String, CustomRustError> {
+        log_info!("export_structure: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let json = Conversion::structure_to_json(&state);
+        let json_str = serde_json::to_string(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+
+        log_info!("export_structure: Finished for doc_id: {}", self.doc_id);
+        Ok(json_str)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 루트 블록부터 시작하는 중첩 트리 JSON으로 내보내기 (트리 구조를 선호하는 웹 렌더러용)
+    ///
+    /// 반환: {"id", "type", "data", "delta", "children": [...]} 노드를 문서 순서로 중첩한 JSON.
+    /// delta는 op 배열이고 텍스트가 없는 블록은 null. 이미 방문한 블록을 다시 만나면 (순환)
+    /// 더 내려가지 않고 {"id": "...", "cycle": true} 표시 노드로 대체
+    pub fn export_tree_json(&self) -> Result<String, CustomRustError> {
+        log_info!("export_tree_json: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let json = Conversion::tree_to_json(&state)?;
+        let json_str = serde_json::to_string(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+
+        log_info!("export_tree_json: Finished for doc_id: {}", self.doc_id);
+        Ok(json_str)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// AppFlowy 문서 JSON 형식으로 내보내기 (.json 파일로 저장해서 데스크톱 AppFlowy에서 열기 위함)
+    ///
+    /// 반환: {"document": {"id": "page", "type": "page", "data": {}, "children": [...]}} 형식의 JSON
+    /// 텍스트가 없는 블록은 delta 필드를 생략
+    pub fn export_appflowy_json(&self) -> Result<String, CustomRustError> {
+        log_info!("export_appflowy_json: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let json = Conversion::appflowy_document_to_json(&state)?;
+        let json_str = serde_json::to_string(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+
+        log_info!("export_appflowy_json: Finished for doc_id: {}", self.doc_id);
+        Ok(json_str)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서를 Markdown으로 내보내기 (노트를 Markdown으로 복사하기 위함)
+    ///
+    /// 제목/문단/글머리 기호·번호·할 일 목록/인용/코드 블록을 블록 type으로 구분하고,
+    /// 목록 들여쓰기는 부모를 따라 올라간 깊이로 정함. bold/italic/code 속성은 **, *, `로 변환.
+    /// 알 수 없는 type은 텍스트만 출력
+    pub fn export_markdown(&self) -> Result<String, CustomRustError> {
+        log_info!("export_markdown: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let markdown = MarkdownExport::render(&state)?;
+
+        log_info!("export_markdown: Rendered {} chars for doc_id: {}", markdown.chars().count(), self.doc_id);
+        Ok(markdown)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Markdown을 블록으로 변환해서 루트 블록의 마지막 자식 뒤에 추가 ("Markdown으로 붙여넣기"용)
+    ///
+    /// [markdown] 붙여넣을 Markdown 텍스트
+    ///
+    /// 제목은 heading, 목록은 들여쓰기에 따라 중첩된 bulleted_list/numbered_list/todo_list, 코드 펜스는 code 블록으로 변환.
+    /// **굵게**, *기울임*, `코드`는 delta 속성으로 변환. 루트 포인터가 없으면 새 페이지 블록을 만들어 루트로 설정
+    pub fn import_markdown(&mut self, markdown: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("import_markdown: Starting with {} chars for doc_id: {}", markdown.chars().count(), self.doc_id);
+
+        let root_pointer = {
+            let txn = self.doc.transact();
+            let pointer = match txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, ROOT_ID)) {
+                Some(yrs::Out::Any(yrs::Any::String(id))) => Some(id.to_string()),
+                _ => None,
+            };
+            let blocks_map = UpdateOperations::blocks_map(&txn);
+            pointer.filter(|id| blocks_map.is_some_and(|blocks_map| blocks_map.get(&txn, id).is_some()))
+        };
+        let last_child = match &root_pointer {
+            Some(root_block_id) => self.get_children_ids(root_block_id.clone())?.pop(),
+            None => None,
+        };
+
+        // 루트 블록 id가 있어야 변환할 수 있으므로 새 루트는 미리 만들어 두고, 검사를 통과한 뒤에 함께 기록
+        let (root_block_id, new_root) = match root_pointer {
+            Some(id) => (id, None),
+            None => {
+                let id = self.id_generator.next_id();
+                (id.clone(), Some(BlockDoc {
+                    id,
+                    ty: ROOT_TYPE.to_string(),
+                    attributes: HashMap::new(),
+                    delta: None,
+                    parent_id: None,
+                    prev_id: None,
+                    next_id: None,
+                    old_parent_id: None,
+                    created_at: None,
+                    updated_at: None,
+                }))
+            }
+        };
+        let blocks = MarkdownImport::parse(&markdown, &root_block_id, last_child, &self.id_generator);
+        self.check_block_types(new_root.as_slice())?;
+        self.check_block_types(&blocks)?;
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        if let Some(new_root) = &new_root {
+            log_info!("import_markdown: Creating new root block {}", new_root.id);
+            BlockOperations::write_block(&mut txn, &blocks_map, new_root)?;
+            root.insert(&mut txn, ROOT_ID, new_root.id.clone());
+        }
+        for block in &blocks {
+            BlockOperations::write_block(&mut txn, &blocks_map, block)?;
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("import_markdown: Added {} blocks under {}", blocks.len(), root_block_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// AppFlowy 문서 JSON을 빈 문서로 가져오기 (기존 AppFlowy 노트 이전용)
+    ///
+    /// [json] {"document": {"type": "page", "data": {}, "delta": [], "children": [...]}} 형식의 JSON
+    ///
+    /// 노드 id는 유지하고 없으면 새로 만들며, 루트 노드를 루트 포인터(ROOT_ID)로 설정.
+    /// 루트 노드가 없거나 구조가 잘못되었거나 이미 블록이 있는 문서면 에러 반환
+    pub fn import_appflowy_json(&mut self, json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("import_appflowy_json: Starting for doc_id: {}", self.doc_id);
+
+        let value: JsonValue = serde_json::from_str(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let blocks = Conversion::appflowy_document_to_blocks(&value, &self.id_generator)?;
+
+        let update = self.write_imported_blocks(&blocks)?;
+        log_info!("import_appflowy_json: Imported {} blocks under root {}", blocks.len(), blocks[0].id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// export_tree_json 형식의 중첩 트리 JSON을 빈 문서로 가져오기
+    ///
+    /// [json] {"id", "type", "data", "delta", "children": [...]} 형식의 루트 노드 JSON
+    ///
+    /// 노드 id는 유지하고 없으면 새로 만들며, 루트 노드를 루트 포인터(ROOT_ID)로 설정.
+    /// delta가 null이면 텍스트 없는 블록이고, 순환 표시 노드({"cycle": true})는 건너뜀.
+    /// set_max_tree_depth로 정한 깊이보다 깊게 중첩된 트리, 구조가 잘못된 트리, 이미 블록이 있는 문서는 에러 반환
+    pub fn import_tree_json(&mut self, json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("import_tree_json: Starting for doc_id: {}", self.doc_id);
+
+        let value: JsonValue = serde_json::from_str(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let blocks = Conversion::tree_to_blocks(&value, self.max_tree_depth, &self.id_generator)?;
+
+        let update = self.write_imported_blocks(&blocks)?;
+        log_info!("import_tree_json: Imported {} blocks under root {}", blocks.len(), blocks[0].id);
+        Ok(update)
+    }
+
+    #[frb]
+    /// import_tree_json이 받아들이는 최대 중첩 깊이 설정 (루트 아래 단계 수, 기본값 DEFAULT_MAX_TREE_DEPTH)
+    ///
+    /// [depth] 최대 깊이
+    pub fn set_max_tree_depth(&mut self, depth: u32) {
+        log_info!("set_max_tree_depth: {} for doc_id: {}", depth, self.doc_id);
+        self.max_tree_depth = depth;
+    }
+
+    #[frb]
+    /// 블록 id 생성을 시드 기반의 결정적 순서로 전환 (테스트 전용)
+    ///
+    /// [seed] 시드 (같은 시드면 duplicate, paste, import 등이 같은 id를 같은 순서로 생성)
+    ///
+    /// 시드 id는 다른 문서/피어와 겹칠 수 있으므로 프로덕션에서는 호출하지 말고 기본 UUID v4를 사용
+    pub fn set_id_generator_seed(&mut self, seed: u64) {
+        log_info!("set_id_generator_seed: {} for doc_id: {}", seed, self.doc_id);
+        self.id_generator.set_seed(seed);
+    }
+
+    /// 가져온 블록을 빈 문서에 기록하고 첫 블록을 루트 포인터로 설정 (이미 블록이 있거나 엄격 모드에서 허용되지 않은 type이면 에러)
+    fn write_imported_blocks(&self, blocks: &[BlockDoc]) -> Result<Vec<u8>, CustomRustError> {
+        self.check_block_types(blocks)?;
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        if blocks_map.len(&txn) > 0 {
+            return Err(DocError::StateError("Cannot import into a document that already has blocks".into()).into());
+        }
+
+        for block in blocks {
+            BlockOperations::write_block(&mut txn, &blocks_map, block)?;
+        }
+        root.insert(&mut txn, ROOT_ID, blocks[0].id.clone());
+
+        let before_state = txn.before_state();
+        Ok(txn.encode_diff_v2(before_state))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// AppFlowy 문서 JSON을 가져오기 전에 구조 검사 (DocumentService 인스턴스 불필요)
+    ///
+    /// [json] {"document": {"type": "page", "data": {}, "delta": [], "children": [...]}} 형식의 JSON
+    ///
+    /// 반환: 발견된 문제 목록 (노드 경로 포함), 비어 있으면 가져오기 가능
+    pub fn validate_appflowy_json(json: String) -> Result<Vec<String>, CustomRustError> {
+        log_info!("validate_appflowy_json: Starting");
+
+        let value: JsonValue = serde_json::from_str(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let problems = Conversion::validate_appflowy_document(&value);
+
+        log_info!("validate_appflowy_json: Found {} problems", problems.len());
+        Ok(problems)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 업데이트의 삭제 집합만 JSON으로 반환 (삭제/툼스톤 충돌 분석용, DocumentService 인스턴스 불필요)
+    ///
+    /// [update] 분석할 업데이트 (v2 또는 v1 인코딩)
+    ///
+    /// 반환: {"encoding": "v2", "has_deletions": true, "clients": {"123": [[0, 5], [8, 9]]}}
+    /// 범위는 [시작 clock, 끝 clock) 형식이며, 삭제가 없으면 has_deletions가 false이고 clients가 비어 있음
+    pub fn extract_delete_set(update: Vec<u8>) -> Result<String, CustomRustError> {
+        log_info!("extract_delete_set: Starting with {} bytes", update.len());
+
+        let (decoded, encoding) = match UpdateOperations::decode_update_v2(&update) {
+            Ok(decoded) => (decoded, "v2"),
+            Err(_) => match UpdateOperations::decode_update_v1(&update) {
+                Ok(decoded) => (decoded, "v1"),
+                Err(e) => return Err(DocError::UpdateDecodingFailed(format!("Failed to decode update as v2 or v1: {}", e)).into()),
+            },
+        };
+
+        let mut clients = serde_json::Map::new();
+        for (client, range) in decoded.delete_set().iter() {
+            let ranges: Vec<JsonValue> = range.iter().map(|r| json!([r.start, r.end])).collect();
+            clients.insert(client.to_string(), JsonValue::Array(ranges));
+        }
+        let has_deletions = !clients.is_empty();
+        log_info!("extract_delete_set: {} encoding, {} clients with deletions", encoding, clients.len());
+
+        serde_json::to_string(&json!({
+            "encoding": encoding,
+            "has_deletions": has_deletions,
+            "clients": clients,
+        }))
+        .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)).into())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 두 문서 상태의 차이를 apply_action으로 다시 적용할 수 있는 블록 액션으로 반환 (감사 로그용, DocumentService 인스턴스 불필요)
+    ///
+    /// [old_update] 이전 상태의 전체 업데이트 (v2)
+    /// [new_update] 이후 상태의 전체 업데이트 (v2)
+    ///
+    /// Insert/Move는 새 문서 순서대로, Update는 바뀐 속성과 텍스트 전체 교체 delta만, Delete는 삭제된 최상위 블록만 포함
+    pub fn diff_states(old_update: Vec<u8>, new_update: Vec<u8>) -> Result<Vec<BlockActionDoc>, CustomRustError> {
+        log_info!("diff_states: Comparing {} and {} bytes", old_update.len(), new_update.len());
+
+        let load_blocks = |update: Vec<u8>| -> Result<HashMap<String, BlockDoc>, CustomRustError> {
+            let doc = Doc::new();
+            let (_, failed) = UpdateOperations::apply_updates_inner(&doc, "diff_states", vec![update])?;
+            if !failed.failed_updates_ids.is_empty() {
+                return Err(DocError::UpdateDecodingFailed("Failed to decode document state".into()).into());
+            }
+            let txn = doc.transact();
+            UpdateOperations::collect_blocks(&txn)
+        };
+        let before = load_blocks(old_update)?;
+        let after = load_blocks(new_update)?;
+
+        let actions = UpdateOperations::diff_to_actions(&before, &after)?;
+        log_info!("diff_states: Found {} actions", actions.len());
+        Ok(actions)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 같은 기준 상태에서 오프라인으로 갈라진 두 편집본을 하나의 상태로 병합 (DocumentService 인스턴스 불필요)
+    ///
+    /// [base] 두 편집본이 공통으로 시작한 상태의 전체 업데이트 (v2)
+    /// [left] 한쪽 클라이언트의 기준 상태 이후 전체 업데이트 (v2)
+    /// [right] 다른 쪽 클라이언트의 기준 상태 이후 전체 업데이트 (v2)
+    ///
+    /// left/right의 state vector가 base의 state vector를 포함하지 않으면 base에서 갈라진 것이 아니므로 에러 반환.
+    /// 반환: 병합된 문서의 전체 상태 업데이트 (v2)
+    pub fn merge_three_way(base: Vec<u8>, left: Vec<u8>, right: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("merge_three_way: base {} bytes, left {} bytes, right {} bytes", base.len(), left.len(), right.len());
+
+        let load_state_vector = |name: &str, update: Vec<u8>| -> Result<StateVector, CustomRustError> {
+            let doc = Doc::new();
+            let (_, failed) = UpdateOperations::apply_updates_inner(&doc, "merge_three_way", vec![update])?;
+            if !failed.failed_updates_ids.is_empty() {
+                return Err(DocError::UpdateDecodingFailed(format!("Failed to decode {} state", name)).into());
+            }
+            let sv = doc.transact().state_vector();
+            Ok(sv)
+        };
+        let base_sv = load_state_vector("base", base.clone())?;
+        for (name, update) in [("left", &left), ("right", &right)] {
+            let side_sv = load_state_vector(name, update.clone())?;
+            let missing = base_sv.iter().any(|(client, clock)| side_sv.get(client) < *clock);
+            if missing {
+                log_error!("merge_three_way: {} is not derived from base", name);
+                return Err(DocError::MergeError(format!("{} state is not derived from base", name)).into());
+            }
+        }
+
+        let merged = Doc::new();
+        let (_, failed) = UpdateOperations::apply_updates_inner(&merged, "merge_three_way", vec![base, left, right])?;
+        if !failed.failed_updates_ids.is_empty() {
+            return Err(DocError::MergeError("Failed to apply states while merging".into()).into());
+        }
+
+        let update = merged.transact().encode_state_as_update_v2(&StateVector::default());
+        log_info!("merge_three_way: Merged state is {} bytes", update.len());
+        Ok(update)
+    }
+
+    #[frb]
+    /// 문서 순서대로 블록 JSON을 하나씩 콜백으로 전달 (큰 문서의 점진적 렌더링용)
+    ///
+    /// [callback] 블록 JSON(BlockDoc 형식)을 받는 Dart 콜백, 각 호출이 끝난 뒤 다음 블록 전달
+    ///
+    /// 마지막 블록 전달이 끝나야 완료됨
+    pub async fn stream_blocks(
+        &self,
+        callback: impl Fn(String) -> DartFnFuture<()>,
+    ) -> Result<(), CustomRustError> {
+        log_info!("stream_blocks: Starting for doc_id: {}", self.doc_id);
+
+        // 콜백을 await하는 동안 트랜잭션을 잡고 있지 않도록 먼저 직렬화
+        let state = self.read_document_state()?;
+        let block_jsons = Conversion::document_order(&state)
+            .iter()
+            .map(|id| serde_json::to_string(&state.blocks[id])
+                .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e))))
+            .collect::<Result<Vec<String>, DocError>>()?;
+
+        let count = block_jsons.len();
+        for block_json in block_jsons {
+            callback(block_json).await;
+        }
+
+        log_info!("stream_blocks: Delivered {} blocks for doc_id: {}", count, self.doc_id);
+        Ok(())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 부모의 자식 순서를 SHA-256 해시로 반환 (피어 간 형제 순서가 갈라진 곳을 빠르게 찾기 위함)
+    ///
+    /// [parent_id] 자식 순서를 확인할 부모 블록 id (부모가 없는 최상위 블록은 "root")
+    ///
+    /// 문서 상태와 같은 prevId 체인 정렬을 사용하며, 체인이 끊기거나 순환하는 자식이 있으면 에러 반환
+    pub fn children_order_hash(&self, parent_id: String) -> Result<String, CustomRustError> {
+        log_info!("children_order_hash: parent_id={} for doc_id: {}", parent_id, self.doc_id);
+
+        let state = self.read_document_state()?;
+        if parent_id != "root" && !state.blocks.contains_key(&parent_id) {
+            return Err(DocError::BlockNotFound(format!("Block {} not found", parent_id)).into());
+        }
+
+        let children = state.children_map.get(&parent_id).cloned().unwrap_or_default();
+        let broken = ChainSorting::find_broken_links(&state.blocks, &children);
+        if !broken.is_empty() {
+            return Err(DocError::StateError(format!(
+                "Broken sibling chain under {}: {:?}", parent_id, broken
+            )).into());
+        }
+
+        let order_json = serde_json::to_string(&children)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+        Ok(sha256_hex(order_json.as_bytes()))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 삭제 기록(툼스톤) 없이 현재 내용만 담은 새 문서의 전체 상태 업데이트 생성 (서버 스냅샷 교체용)
+    ///
+    /// 블록, 메타데이터, 댓글, 루트 포인터를 새 Doc에 다시 써서 인코딩하므로 결과는 기존 기록과 이어지지 않음.
+    /// 주의: 예전 기록을 가진 피어의 업데이트는 압축된 문서와 병합되지 않고 내용이 중복/유실될 수 있으므로,
+    /// 모든 클라이언트가 압축본으로 다시 시작하도록 맞춘 뒤에만 사용해야 함.
+    /// 이 인스턴스는 바뀌지 않으며, 압축본은 새 DocumentService에 apply_updates로 불러와야 함
+    pub fn compact(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("compact: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let meta: JsonValue = serde_json::from_str(&self.get_all_meta()?)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let comments = Self::read_comments(&self.doc.transact());
+
+        let compacted = Doc::new();
+        let root = compacted.get_or_insert_map(ROOT_ID);
+        {
+            let mut txn = compacted.transact_mut();
+            let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+            for block in state.blocks.values() {
+                BlockOperations::write_block(&mut txn, &blocks_map, block)?;
+            }
+            let meta_map = root.get_or_init_map(&mut txn, META);
+            if let Some(fields) = meta.as_object() {
+                for (key, value) in fields {
+                    Self::write_meta_json(&mut txn, &meta_map, key, value);
+                }
+            }
+            if !state.root_id.is_empty() {
+                root.insert(&mut txn, ROOT_ID, state.root_id.clone());
+            }
+            // 텍스트가 새로 쓰였으므로 댓글 위치는 오프셋으로 풀었다가 새 텍스트에 다시 고정
+            if !comments.is_empty() {
+                let comments_map = root.get_or_init_map(&mut txn, COMMENTS);
+                for comment in &comments {
+                    Self::write_comment(&mut txn, &comments_map, &blocks_map, comment);
+                }
+            }
+        }
+
+        let update = compacted.transact().encode_state_as_update_v2(&StateVector::default());
+        log_info!("compact: {} blocks, {} bytes for doc_id: {}", state.blocks.len(), update.len(), self.doc_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 저장 용량 (노트별 용량 표시, 할당량 경고용)
+    ///
+    /// 반환: full_state_bytes는 encode_full_state 크기 (삭제 기록 포함), content_bytes는 현재 내용만 담은
+    /// compact 결과 크기. 삭제가 많이 쌓였거나 GC가 돌면 둘의 차이가 커짐. 문서는 변경하지 않음
+    pub fn get_document_size_bytes(&self) -> Result<DocSize, CustomRustError> {
+        log_info!("get_document_size_bytes: doc_id: {}", self.doc_id);
+
+        let size = DocSize {
+            full_state_bytes: self.encode_full_state()?.len() as u64,
+            content_bytes: self.compact()?.len() as u64,
+        };
+        log_info!("get_document_size_bytes: full={}, content={} for doc_id: {}", size.full_state_bytes, size.content_bytes, self.doc_id);
+        Ok(size)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 현재 내용을 그대로 복사한 독립 문서 생성 (노트 복제용)
+    ///
+    /// [new_doc_id] 복제된 문서의 id
+    ///
+    /// 전체 상태를 새 Doc에 적용하므로 이후 편집은 서로 영향을 주지 않음.
+    /// 복제본은 원본 기록에 없는 client id를 사용하므로 나중에 두 문서를 병합해도 항목이 겹치지 않음.
+    /// GC 설정은 원본을 따르고, 구독/undo/awareness 상태는 복사되지 않음
+    pub fn fork(&self, new_doc_id: String) -> Result<DocumentService, CustomRustError> {
+        log_info!("fork: Forking doc_id: {} into {}", self.doc_id, new_doc_id);
+
+        let (full_state, used_clients) = {
+            let txn = self.doc.transact();
+            (txn.encode_state_as_update_v2(&StateVector::default()), txn.state_vector())
+        };
+
+        let mut forked = Self::new_with_options(new_doc_id.clone(), self.gc_enabled());
+        while forked.doc.client_id() == self.doc.client_id() || used_clients.contains_client(&forked.doc.client_id()) {
+            forked = Self::new_with_options(new_doc_id.clone(), self.gc_enabled());
+        }
+
+        let (_, failed) = UpdateOperations::apply_updates_inner(&forked.doc, "fork", vec![full_state])?;
+        if !failed.failed_updates_ids.is_empty() {
+            return Err(DocError::StateError("Failed to copy document state into fork".into()).into());
+        }
+
+        log_info!("fork: Created {} with client id {}", new_doc_id, forked.doc.client_id());
+        Ok(forked)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 문서 내용(블록 트리와 메타데이터)의 SHA-256 해시 (복제본이 수렴했는지 빠르게 확인하기 위함)
+    ///
+    /// 업데이트 바이트가 아니라 문서 상태를 해시하므로 편집 순서나 툼스톤이 달라도 보이는 내용이 같으면 같은 값.
+    /// 댓글이 있으면 댓글도 포함하고, doc_id는 내용이 아니므로 제외
+    pub fn content_hash(&self) -> Result<String, CustomRustError> {
+        log_info!("content_hash: Starting for doc_id: {}", self.doc_id);
+
+        let state = self.read_document_state()?;
+        let meta: JsonValue = serde_json::from_str(&self.get_all_meta()?)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+
+        // serde_json 객체는 키 순으로 정렬되므로 HashMap 순서와 관계없이 같은 문자열이 됨
+        let mut content = json!({
+            "root_id": state.root_id,
+            "blocks": state.blocks,
+            "children_map": state.children_map,
+            "meta": meta,
+        });
+        // 댓글이 없는 문서는 이전과 같은 해시를 유지하도록 키를 넣지 않음
+        let comments: BTreeMap<String, CommentDoc> = Self::read_comments(&self.doc.transact())
+            .into_iter()
+            .map(|comment| (comment.comment_id.clone(), comment))
+            .collect();
+        if !comments.is_empty() {
+            content["comments"] = json!(comments);
+        }
+        let content_json = serde_json::to_string(&content)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+        Ok(sha256_hex(content_json.as_bytes()))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 전체 문서를 직렬화하지 않고 부모의 자식 id를 문서 순서대로 반환 (접힌 섹션을 펼칠 때 사용)
+    ///
+    /// [parent_id] 부모 블록 id (부모가 없는 최상위 블록은 "root")
+    ///
+    /// 자식이 없으면 빈 목록, 부모 블록이 없을 때만 에러 반환
+    pub fn get_children_ids(&self, parent_id: String) -> Result<Vec<String>, CustomRustError> {
+        log_info!("get_children_ids: parent_id={} for doc_id: {}", parent_id, self.doc_id);
+
+        let txn = self.doc.transact();
+        let blocks_map = match UpdateOperations::blocks_map(&txn) {
+            Some(map) => map,
+            None if parent_id == "root" => return Ok(Vec::new()),
+            None => return Err(DocError::BlockNotFound(format!("Block {} not found", parent_id)).into()),
+        };
+        if parent_id != "root" && blocks_map.get(&txn, &parent_id).is_none() {
+            return Err(DocError::BlockNotFound(format!("Block {} not found", parent_id)).into());
+        }
+
+        // 형제 블록만 읽어서 문서 상태와 같은 prevId 체인 정렬을 적용
+        let mut siblings = HashMap::new();
+        for (id, block) in blocks_map.iter(&txn) {
+            let yrs::Out::YMap(block_map) = block else { continue };
+            let block_parent = block_map.get(&txn, PARENT_ID).map(|out| out.to_string(&txn));
+            if block_parent.as_deref().unwrap_or("root") != parent_id {
+                continue;
+            }
+            if let Some(block) = UpdateOperations::extract_block(&txn, &blocks_map, id)? {
+                siblings.insert(id.to_string(), block);
+            }
+        }
+
+        let children = ChainSorting::sort_blocks_by_chain(&siblings)
+            .remove(&parent_id)
+            .unwrap_or_default();
+        log_info!("get_children_ids: Found {} children", children.len());
+        Ok(children)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 트리 구조 검사 (렌더링 전에 깨진 구조를 찾기 위한 읽기 전용 진단)
+    ///
+    /// 반환: 부모가 없는 블록, 부모-자식 순환에 속한 블록, 없는 블록을 prevId/nextId로 가리키는 블록,
+    /// 블록이 삭제되어 남은 댓글 목록
+    pub fn validate_document(&self) -> Result<DocumentValidation, CustomRustError> {
+        log_info!("validate_document: Starting for doc_id: {}", self.doc_id);
+
+        let txn = self.doc.transact();
+        let blocks = UpdateOperations::collect_blocks(&txn)?;
+        let mut validation = BlockOperations::check_structure(&blocks);
+
+        if let Some(yrs::Out::YMap(comments)) = txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, COMMENTS)) {
+            validation.orphaned_comment_ids = comments.iter(&txn)
+                .filter_map(|(id, comment)| match comment {
+                    yrs::Out::YMap(comment) => Some((id, comment.get(&txn, COMMENT_BLOCK_ID).map(|out| out.to_string(&txn)))),
+                    _ => None,
+                })
+                .filter(|(_, block_id)| !block_id.as_ref().is_some_and(|block_id| blocks.contains_key(block_id)))
+                .map(|(id, _)| id.to_string())
+                .collect();
+            validation.orphaned_comment_ids.sort();
+        }
+
+        log_info!(
+            "validate_document: {} orphaned, {} cyclic, {} dangling, {} orphaned comments for doc_id: {}",
+            validation.orphaned_block_ids.len(),
+            validation.cyclic_block_ids.len(),
+            validation.dangling_reference_ids.len(),
+            validation.orphaned_comment_ids.len(),
+            self.doc_id
+        );
+        Ok(validation)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록을 바로 앞 형제의 마지막 자식으로 들여쓰기 (Tab)
+    ///
+    /// [block_id] 들여쓸 블록 id
+    ///
+    /// 앞 형제가 없는 첫 번째 자식이면 아무것도 하지 않고 빈 업데이트 반환
+    pub fn indent_block(&mut self, block_id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("indent_block: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let block = self.get_block(block_id.clone())?
+            .ok_or_else(|| DocError::BlockNotFound(format!("Block {} not found", block_id)))?;
+        let Some(new_parent_id) = block.prev_id.clone() else {
+            log_info!("indent_block: Block {} has no previous sibling", block_id);
+            return Ok(Update::new().encode_v2());
+        };
+        let new_prev_id = self.get_children_ids(new_parent_id.clone())?.pop();
+        let old_parent_id = block.parent_id.unwrap_or_else(|| DEFAULT_PARENT.to_owned());
+
+        self.move_block_to(&block_id, &new_parent_id, &old_parent_id, new_prev_id)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록을 부모의 형제로 내어쓰기 (Shift-Tab), 부모 바로 뒤에 삽입
+    ///
+    /// [block_id] 내어쓸 블록 id
+    ///
+    /// 최상위 블록이나 루트 페이지의 자식처럼 더 나갈 곳이 없으면 아무것도 하지 않고 빈 업데이트 반환
+    pub fn outdent_block(&mut self, block_id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("outdent_block: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let block = self.get_block(block_id.clone())?
+            .ok_or_else(|| DocError::BlockNotFound(format!("Block {} not found", block_id)))?;
+        let parent = match &block.parent_id {
+            Some(parent_id) => self.get_block(parent_id.clone())?,
+            None => None,
+        };
+        let Some(new_parent_id) = parent.and_then(|parent| parent.parent_id) else {
+            log_info!("outdent_block: Block {} is already at the top level", block_id);
+            return Ok(Update::new().encode_v2());
+        };
+        let old_parent_id = block.parent_id.unwrap_or_else(|| DEFAULT_PARENT.to_owned());
+
+        self.move_block_to(&block_id, &new_parent_id, &old_parent_id, Some(old_parent_id.clone()))
+    }
+
+    /// move_block으로 블록을 새 부모의 prev_id 뒤로 옮기고 업데이트 반환
+    fn move_block_to(
+        &mut self,
+        block_id: &str,
+        parent_id: &str,
+        old_parent_id: &str,
+        prev_id: Option<String>,
+    ) -> Result<Vec<u8>, CustomRustError> {
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        BlockOperations::move_block(&mut txn, blocks_map, &[], &[], parent_id, old_parent_id, block_id, prev_id, None)?;
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("move_block_to: Moved {} under {}", block_id, parent_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록의 조상 id 목록을 최상위부터 순서대로 반환 (대상 블록은 제외)
+    ///
+    /// [block_id] 경로를 찾을 블록 id
+    ///
+    /// 부모가 없는 최상위 블록이면 빈 목록, 블록이 없으면 에러 반환
+    pub fn get_block_path(&self, block_id: String) -> Result<Vec<String>, CustomRustError> {
+        log_info!("get_block_path: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let txn = self.doc.transact();
+        let blocks_map = UpdateOperations::blocks_map(&txn)
+            .filter(|blocks_map| blocks_map.get(&txn, &block_id).is_some())
+            .ok_or_else(|| DocError::BlockNotFound(format!("Block {} not found", block_id)))?;
+
+        let mut path = BlockOperations::ancestors(&txn, &blocks_map, &block_id);
+        path.reverse();
+        Ok(path)
+    }
+
+    #[frb]
+    pub fn merge_updates(&self, updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("merge_updates: Merging {} updates", updates.len());
+        
+        match merge_updates_v2(updates) {
+            Ok(update) => {
+                log_info!("merge_updates: Successfully merged updates");
+                Ok(update)
+            },
+            Err(e) => {
+                log_error!("merge_updates: Failed to merge updates: {}", e);
+                Err(DocError::EncodingError(format!("Failed to merge updates: {}", e)).into())
+            }
+        }
+    }
+
+    #[frb]
+    /// v1 인코딩 업데이트 병합 (v1만 쓰는 서버/저장소와 호환용)
+    pub fn merge_updates_v1(&self, updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("merge_updates_v1: Merging {} updates", updates.len());
+
+        yrs::merge_updates_v1(updates).map_err(|e| {
+            log_error!("merge_updates_v1: Failed to merge updates: {}", e);
+            DocError::EncodingError(format!("Failed to merge updates: {}", e)).into()
+        })
+    }
+
+    #[frb]
+    /// 업데이트 목록을 중복 없이 하나로 합침 (서버의 업데이트 로그 정리용)
+    ///
+    /// [updates] v2 인코딩 업데이트 목록 (같은 업데이트가 여러 번 들어 있어도 됨)
+    ///
+    /// 새 Doc에 모두 적용한 뒤 전체 상태를 다시 인코딩하므로 겹치는 작업은 한 번만 남고,
+    /// merge_updates와 달리 삭제된 내용은 GC되어 길이만 남음.
+    /// compact와 달리 기존 기록과 이어지므로 예전 업데이트를 가진 피어와도 그대로 병합됨.
+    /// 선행 업데이트가 빠진 업데이트가 있으면 에러 (빈 구간이 생긴 결과는 나중에 빠진 업데이트를 받아도 적용되지 않음)
+    pub fn compact_updates(&self, updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("compact_updates: Compacting {} updates", updates.len());
+
+        let input_bytes: usize = updates.iter().map(Vec::len).sum();
+        let scratch = Doc::new();
+        {
+            let mut txn = scratch.transact_mut();
+            for (i, bytes) in updates.iter().enumerate() {
+                let update = UpdateOperations::decode_update_v2(bytes).map_err(|e| {
+                    log_error!("compact_updates: Failed to decode update {}: {}", i, e);
+                    DocError::EncodingError(format!("Failed to decode update {}: {}", i, e))
+                })?;
+                txn.apply_update(update).map_err(|e| {
+                    log_error!("compact_updates: Failed to apply update {}: {}", i, e);
+                    DocError::EncodingError(format!("Failed to apply update {}: {}", i, e))
+                })?;
+            }
+            if txn.store().pending_update().is_some() {
+                log_error!("compact_updates: Updates depend on operations missing from the list");
+                return Err(DocError::StateError("Updates depend on operations missing from the list".into()).into());
+            }
+        }
+
+        let update = scratch.transact().encode_state_as_update_v2(&StateVector::default());
+        log_info!("compact_updates: {} bytes -> {} bytes", input_bytes, update.len());
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Setting a root node id in the root map
+    pub fn set_root_node_id(&mut self, id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_root_node_id: Setting root node id to {}", id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        root.insert(&mut txn, ROOT_ID, id.clone());
+        log_info!("set_root_node_id: Successfully set root node id to {}", id);
+
+        // Encode the state as an update
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_root_node_id: Finished for doc_id: {}", self.doc_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// set_root_node_id로 저장된 루트 블록 id 읽기 (apply_updates로 불러온 문서의 루트 확인용)
+    ///
+    /// 반환: 루트 블록 id, 한 번도 설정되지 않았으면 None
+    pub fn get_root_node_id(&self) -> Result<Option<String>, CustomRustError> {
+        let txn = self.doc.transact();
+        let root_id = match txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, ROOT_ID)) {
+            Some(yrs::Out::Any(yrs::Any::String(id))) => Some(id.to_string()),
+            _ => None,
+        };
+        log_info!("get_root_node_id: {:?} for doc_id: {}", root_id, self.doc_id);
+        Ok(root_id)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 최상위 블록이 하나만 있도록 정규화 (잘못된 병합으로 최상위 블록이 여러 개가 된 경우)
+    ///
+    /// 루트 포인터(ROOT_ID)가 가리키는 최상위 블록이 있으면 나머지 최상위 블록을 그 아래로 옮기고,
+    /// 없으면 새 페이지 블록을 만들어 모두 그 아래로 옮긴 뒤 루트 포인터를 갱신함.
+    /// 이미 최상위 블록이 하나 이하이면 아무것도 변경하지 않음.
+    pub fn enforce_single_root(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("enforce_single_root: Starting for doc_id: {}", self.doc_id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        let blocks = UpdateOperations::collect_blocks(&txn)?;
+        let children_map = ChainSorting::sort_blocks_by_chain(&blocks);
+        let top_level = children_map.get("root").cloned().unwrap_or_default();
+
+        if top_level.len() > 1 {
+            let root_pointer = match root.get(&txn, ROOT_ID) {
+                Some(yrs::Out::Any(yrs::Any::String(id))) => Some(id.to_string()),
+                _ => None,
+            };
+
+            let root_block_id = match root_pointer.filter(|id| top_level.contains(id)) {
+                Some(id) => id,
+                None => {
+                    let id = self.id_generator.next_id();
+                    log_info!("enforce_single_root: Creating new root block {}", id);
+                    BlockOperations::write_block(&mut txn, &blocks_map, &BlockDoc {
+                        id: id.clone(),
+                        ty: ROOT_TYPE.to_string(),
+                        attributes: HashMap::new(),
+                        delta: None,
+                        parent_id: None,
+                        prev_id: None,
+                        next_id: None,
+                        old_parent_id: None,
+                        created_at: None,
+                        updated_at: None,
+                    })?;
+                    root.insert(&mut txn, ROOT_ID, id.clone());
+                    id
+                }
+            };
+
+            // 기존 자식들 뒤에 나머지 최상위 블록을 순서대로 이어 붙임
+            let mut prev_id = children_map.get(&root_block_id).and_then(|children| children.last().cloned());
+            for block_id in top_level.iter().filter(|id| **id != root_block_id) {
+                let node = blocks_map.get_or_init_map(&mut txn, block_id.as_str());
+                node.insert(&mut txn, PARENT_ID, root_block_id.clone());
+                match &prev_id {
+                    Some(prev) => { node.insert(&mut txn, PREV_ID, prev.clone()); }
+                    None => { node.remove(&mut txn, PREV_ID); }
+                }
+                prev_id = Some(block_id.clone());
+            }
+            log_info!("enforce_single_root: Moved {} blocks under {}", top_level.len() - 1, root_block_id);
+        } else {
+            log_info!("enforce_single_root: Document already has a single root");
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// validate_document가 찾는 구조 문제를 고쳐서 피어에 보낼 업데이트로 반환
+    ///
+    /// 없는 블록을 가리키는 prevId/nextId는 지우고, 부모가 없는 블록과 순환마다 id가 가장 작은 블록은
+    /// 최상위(DEFAULT_PARENT)로 옮겨 기존 최상위 블록 뒤에 이어 붙임.
+    /// 고칠 것이 없으면 아무것도 변경하지 않으므로 두 번 실행해도 결과가 같음.
+    pub fn repair_document(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("repair_document: Starting for doc_id: {}", self.doc_id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks = UpdateOperations::collect_blocks(&txn)?;
+        let validation = BlockOperations::check_structure(&blocks);
+
+        if validation != DocumentValidation::default() {
+            let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+            for block_id in &validation.dangling_reference_ids {
+                let block = &blocks[block_id];
+                let node = blocks_map.get_or_init_map(&mut txn, block_id.as_str());
+                for (key, target) in [(PREV_ID, &block.prev_id), (NEXT_ID, &block.next_id)] {
+                    if target.as_ref().is_some_and(|id| !id.is_empty() && !blocks.contains_key(id)) {
+                        log_info!("repair_document: Removing {} of {} pointing to missing block", key, block_id);
+                        node.remove(&mut txn, key);
+                    }
+                }
+            }
+
+            let mut detached = validation.orphaned_block_ids.clone();
+            detached.extend(Self::cycle_breakers(&blocks, &validation.cyclic_block_ids));
+
+            // 기존 최상위 블록 뒤에 순서대로 이어 붙임
+            let mut prev_id = ChainSorting::sort_blocks_by_chain(&blocks)
+                .get("root")
+                .and_then(|top_level| top_level.last().cloned());
+            for block_id in &detached {
+                log_info!("repair_document: Moving {} to the top level", block_id);
+                let node = blocks_map.get_or_init_map(&mut txn, block_id.as_str());
+                node.remove(&mut txn, PARENT_ID);
+                match &prev_id {
+                    Some(prev) => { node.insert(&mut txn, PREV_ID, prev.clone()); }
+                    None => { node.remove(&mut txn, PREV_ID); }
+                }
+                prev_id = Some(block_id.clone());
+            }
+        } else {
+            log_info!("repair_document: Nothing to repair");
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        Ok(update)
+    }
+
+    /// 순환마다 하나씩 떼어낼 블록을 고름 (id 순으로 보면서 아직 순환 안에 남은 블록만 선택)
+    fn cycle_breakers(blocks: &HashMap<String, BlockDoc>, cyclic_ids: &[String]) -> Vec<String> {
+        let mut breakers: Vec<String> = Vec::new();
+        for id in cyclic_ids {
+            let mut current = blocks.get(id).and_then(|block| block.parent_id.clone());
+            let mut steps = 0;
+            while let Some(parent_id) = current {
+                if parent_id == *id {
+                    breakers.push(id.clone());
+                    break;
+                }
+                if breakers.contains(&parent_id) || steps > blocks.len() {
+                    break;
+                }
+                steps += 1;
+                current = blocks.get(&parent_id).and_then(|block| block.parent_id.clone());
+            }
+        }
+        breakers
+    }
+
+    // ============================================
+    // Attachment API - 블록에 저장되는 바이너리 첨부
+    // ============================================
+
+    #[frb]
+    /// 문서 전체 첨부 용량 상한 설정 (None이면 제한 없음)
+    ///
+    /// [budget] 최대 바이트 수
+    pub fn set_attachment_budget(&mut self, budget: Option<u64>) {
+        log_info!("set_attachment_budget: {:?} for doc_id: {}", budget, self.doc_id);
+        self.attachment_budget = budget;
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 데이터에 저장된 모든 바이너리 첨부의 총 바이트 수
+    pub fn attachment_total_bytes(&self) -> Result<u64, CustomRustError> {
+        let txn = self.doc.transact();
+        let total = UpdateOperations::blocks_map(&txn)
+            .map(|blocks_map| BlockOperations::attachment_bytes(&txn, &blocks_map))
+            .unwrap_or(0);
+        Ok(total)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 데이터에 바이너리 첨부 저장
+    ///
+    /// [block_id] 대상 블록 id
+    /// [key] 블록 데이터 키
+    /// [bytes] 저장할 바이너리
+    ///
+    /// 첨부 용량 상한이 설정되어 있으면 (현재 총량 - 같은 키의 기존 첨부 + 새 첨부)가 상한을 넘을 때 에러 반환
+    pub fn set_block_attachment(&mut self, block_id: String, key: String, bytes: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_block_attachment: block_id={}, key={}, size={}", block_id, key, bytes.len());
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        let block = match blocks_map.get(&txn, &block_id) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        let attrs = block.get_or_init_map(&mut txn, ATTRIBUTES);
+
+        if let Some(budget) = self.attachment_budget {
+            let replaced = match attrs.get(&txn, &key) {
+                Some(yrs::Out::Any(yrs::Any::Buffer(buf))) => buf.len() as u64,
+                _ => 0,
+            };
+            let total = BlockOperations::attachment_bytes(&txn, &blocks_map) - replaced + bytes.len() as u64;
+            if total > budget {
+                log_error!("set_block_attachment: Budget exceeded ({} > {}) for doc_id: {}", total, budget, self.doc_id);
+                return Err(DocError::ValidationError(format!(
+                    "Attachment budget exceeded: {} bytes would be stored, limit is {} bytes", total, budget
+                )).into());
+            }
+        }
+
+        attrs.insert(&mut txn, key.clone(), yrs::Any::Buffer(bytes.into()));
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_block_attachment: Finished for block_id={}, key={}", block_id, key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 데이터의 필드 하나만 설정 (체크박스 checked 토글 등)
+    ///
+    /// [block_id] 대상 블록 id
+    /// [field] 필드 키, 점으로 구분하면 중첩 맵 경로 (예: "style.color")
+    /// [value_json] 저장할 값의 JSON 문자열 (apply_action의 attributes와 같은 형식으로 저장)
+    ///
+    /// 해당 키만 쓰므로 다른 클라이언트가 같은 블록의 다른 필드를 동시에 바꿔도 둘 다 유지됨.
+    /// 경로 중간에 맵이 아닌 값이 있으면 덮어쓰지 않고 에러 반환
+    pub fn set_block_data_field(&mut self, block_id: String, field: String, value_json: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_block_data_field: block_id={}, field={}", block_id, field);
+        log_content!("set_block_data_field: value={}", value_json);
+
+        serde_json::from_str::<JsonValue>(&value_json)
+            .map_err(|e| DocError::DecodingError(format!("Failed to parse value for field {}: {}", field, e)))?;
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        let block = match blocks_map.get(&txn, &block_id) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        BlockOperations::set_data_field(&mut txn, &block, &field, value_json)?;
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_block_data_field: Finished for block_id={}, field={}", block_id, field);
+        Ok(update)
+    }
+
+    // ============================================
+    // Undo API - yrs UndoManager 기반 실행 취소/다시 실행
+    // ============================================
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// BLOCKS 맵을 대상으로 UndoManager 활성화 (이미 활성화되어 있으면 스택을 비우고 새로 생성)
+    ///
+    /// [origins] 추적할 트랜잭션 origin 목록, 비어 있으면 origin 없는 로컬 편집만 추적
+    ///
+    /// apply_updates로 적용된 원격 업데이트는 REMOTE_ORIGIN으로 적용되므로 추적되지 않음
+    pub fn enable_undo_manager(&mut self, origins: Vec<String>) -> Result<(), CustomRustError> {
+        log_info!("enable_undo_manager: origins={:?} for doc_id: {}", origins, self.doc_id);
+
+        self.undo_manager = Some(Self::build_undo_manager(&self.doc, &origins)?);
+        Ok(())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 마지막 로컬 편집을 실행 취소하고 그 결과 업데이트를 반환
+    ///
+    /// 취소할 항목이 없으면 빈 변경의 업데이트 반환
+    pub fn undo(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("undo: Starting for doc_id: {}", self.doc_id);
+        self.run_undo_step(false)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 마지막으로 실행 취소한 편집을 다시 실행하고 그 결과 업데이트를 반환
+    ///
+    /// 다시 실행할 항목이 없으면 빈 변경의 업데이트 반환
+    pub fn redo(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("redo: Starting for doc_id: {}", self.doc_id);
+        self.run_undo_step(true)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 실행 취소 가능 여부 (툴바 버튼 활성화용, UndoManager가 없으면 false)
+    pub fn can_undo(&self) -> bool {
+        self.undo_manager.as_ref().is_some_and(|manager| manager.can_undo())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 다시 실행 가능 여부 (툴바 버튼 활성화용, UndoManager가 없으면 false)
+    pub fn can_redo(&self) -> bool {
+        self.undo_manager.as_ref().is_some_and(|manager| manager.can_redo())
+    }
+
+    fn build_undo_manager(doc: &Doc, origins: &[String]) -> Result<UndoManager, CustomRustError> {
+        let blocks = {
+            let txn = doc.transact();
+            UpdateOperations::blocks_map(&txn)
+                .ok_or_else(|| DocError::StateError("Blocks map not found in document".into()))?
+        };
+
+        let mut manager = UndoManager::new(doc, &blocks);
+        for origin in origins {
+            manager.include_origin(origin.as_str());
+        }
+        Ok(manager)
+    }
+
+    fn run_undo_step(&mut self, redo: bool) -> Result<Vec<u8>, CustomRustError> {
+        let manager = self.undo_manager.as_mut()
+            .ok_or_else(|| DocError::InvalidOperation("Undo manager is not enabled".into()))?;
+
+        // undo/redo는 트랜잭션 하나로 커밋되므로 그 트랜잭션의 업데이트만 받아 둠
+        // (변경이 없으면 옵저버가 호출되지 않으므로 디코딩 가능한 변경 없는 업데이트가 그대로 남음)
+        let captured = Arc::new(Mutex::new(Update::new().encode_v2()));
+        let sink = captured.clone();
+        let subscription = self.doc
+            .observe_update_v2(move |_, event| match sink.lock() {
+                Ok(mut update) => *update = event.update.clone(),
+                Err(_) => log_error!("run_undo_step: Update buffer lock poisoned, update dropped"),
+            })
+            .map_err(|e| DocError::StateError(format!("Failed to observe document updates: {}", e)))?;
+        let changed = if redo { manager.redo_blocking() } else { manager.undo_blocking() };
+        drop(subscription);
+        let update = std::mem::take(
+            &mut *captured.lock().map_err(|_| DocError::StateError("Update buffer lock poisoned".into()))?
+        );
+
+        log_info!("run_undo_step: redo={}, changed={} for doc_id: {}", redo, changed, self.doc_id);
+        Ok(update)
+    }
+
+    // ============================================
+    // Subdocument API - 블록에 붙는 하위 문서 (임베드된 보드/데이터베이스)
+    // ============================================
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록에 yrs 하위 문서를 붙이고 guid 반환 (임베드된 보드가 페이지 텍스트와 따로 동기화되도록 함)
+    ///
+    /// [block_id] 하위 문서를 붙일 블록 id
+    ///
+    /// 이미 하위 문서가 있으면 새로 만들지 않고 기존 guid 반환.
+    /// 블록에 하위 문서를 연결하는 부모 문서 변경은 subscribe_changes 스트림이나 encode_diff로 전파해야 하며,
+    /// 하위 문서 내용은 부모 문서 업데이트에 포함되지 않으므로 encode_subdoc_state/apply_subdoc_update로 따로 주고받음
+    pub fn create_subdoc(&mut self, block_id: String) -> Result<String, CustomRustError> {
+        log_info!("create_subdoc: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        let block = match blocks_map.get(&txn, &block_id) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        if let Some(yrs::Out::YDoc(existing)) = block.get(&txn, SUBDOC) {
+            return Ok(existing.guid().to_string());
+        }
+
+        let subdoc = Doc::with_options(yrs::Options { skip_gc: doc.skip_gc(), ..Default::default() });
+        let subdoc = block.insert(&mut txn, SUBDOC, subdoc);
+        let guid = subdoc.guid().to_string();
+        log_info!("create_subdoc: Attached subdoc {} to block {}", guid, block_id);
+        Ok(guid)
+    }
+
+    #[frb]
+    /// 문서에 연결된 모든 하위 문서 guid (정렬됨, 원격 업데이트로 받은 하위 문서 포함)
+    pub fn get_subdoc_guids(&self) -> Vec<String> {
+        let mut guids: Vec<String> = self.doc.transact().subdoc_guids().map(|guid| guid.to_string()).collect();
+        guids.sort();
+        guids
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 하위 문서의 전체 상태를 업데이트(v2)로 인코딩
+    ///
+    /// [guid] create_subdoc 또는 get_subdoc_guids로 얻은 하위 문서 guid
+    pub fn encode_subdoc_state(&self, guid: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_subdoc_state: guid={} for doc_id: {}", guid, self.doc_id);
+
+        let subdoc = self.find_subdoc(&guid)?;
+        let update = subdoc.transact().encode_state_as_update_v2(&StateVector::default());
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 원격 하위 문서 업데이트(v2)를 적용 (부모 문서 변경 스트림에는 나타나지 않음)
+    ///
+    /// [guid] 하위 문서 guid
+    /// [update] 적용할 업데이트
+    pub fn apply_subdoc_update(&mut self, guid: String, update: Vec<u8>) -> Result<(), CustomRustError> {
+        log_info!("apply_subdoc_update: guid={}, {} bytes for doc_id: {}", guid, update.len(), self.doc_id);
+
+        let subdoc = self.find_subdoc(&guid)?;
+        let decoded = UpdateOperations::decode_update_v2(&update)
+            .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to decode subdoc update: {}", e)))?;
+        subdoc.transact_mut_with(REMOTE_ORIGIN).apply_update(decoded)
+            .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to apply subdoc update: {}", e)))?;
+        Ok(())
+    }
+
+    #[frb]
+    /// 하위 문서에 커밋되는 업데이트(v2)를 Dart 스트림으로 전달 (부모 문서의 subscribe_changes와 별개)
+    ///
+    /// [guid] 하위 문서 guid
+    /// [sink] 업데이트 바이트를 받을 스트림, 같은 guid로 다시 호출하면 이전 스트림을 대체
+    pub fn subscribe_subdoc_changes(&mut self, guid: String, sink: StreamSink<Vec<u8>>) -> Result<(), CustomRustError> {
+        log_info!("subscribe_subdoc_changes: guid={} for doc_id: {}", guid, self.doc_id);
+
+        let subdoc = self.find_subdoc(&guid)?;
+        let subscription = subdoc
+            .observe_update_v2(move |_, event| {
+                if sink.add(event.update.clone()).is_err() {
+                    log_error!("subscribe_subdoc_changes: Failed to forward update to Dart");
+                }
+            })
+            .map_err(|e| DocError::StateError(format!("Failed to observe subdoc: {}", e)))?;
+        self.subdoc_subscriptions.insert(guid, subscription);
+        Ok(())
+    }
+
+    fn find_subdoc(&self, guid: &str) -> Result<Doc, CustomRustError> {
+        self.doc.transact()
+            .subdocs()
+            .find(|subdoc| subdoc.guid().to_string() == guid)
+            .cloned()
+            .ok_or_else(|| DocError::StateError(format!("Subdoc {} not found", guid)).into())
+    }
+
+    // ============================================
+    // Comment API - 블록에 붙는 인라인 댓글 (COMMENTS 맵, 일반 업데이트로 동기화)
+    // ============================================
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록에 댓글 추가
+    ///
+    /// [block_id] 댓글을 붙일 블록 id
+    /// [comment_id] 댓글 id (Dart에서 생성, 문서 안에서 고유해야 함)
+    /// [author] 작성자
+    /// [text] 댓글 내용
+    /// [anchor_offset] 블록 텍스트 안의 위치 (None이면 블록 전체에 대한 댓글).
+    /// 상대 위치로 저장되므로 앞쪽 텍스트가 편집되어도 같은 글자를 따라감
+    ///
+    /// 블록이 없거나, 같은 id의 댓글이 있거나, 위치가 텍스트 범위를 벗어나면 에러
+    pub fn add_comment(
+        &mut self,
+        block_id: String,
+        comment_id: String,
+        author: String,
+        text: String,
+        anchor_offset: Option<u32>,
+    ) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("add_comment: comment_id={} on block_id={} for doc_id: {}", comment_id, block_id, self.doc_id);
+        log_content!("add_comment: author={}, text={}", author, text);
+
+        if comment_id.is_empty() {
+            return Err(DocError::ValidationError("Comment id is empty".into()).into());
+        }
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        let comments = root.get_or_init_map(&mut txn, COMMENTS);
+
+        let block = match blocks_map.get(&txn, &block_id) {
+            Some(yrs::Out::YMap(block)) => block,
+            _ => return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into()),
+        };
+        if comments.get(&txn, &comment_id).is_some() {
+            return Err(DocError::ValidationError(format!("Comment {} already exists", comment_id)).into());
+        }
+        if let Some(offset) = anchor_offset {
+            let text_ref = match block.get(&txn, TEXT) {
+                Some(yrs::Out::YText(text_ref)) => text_ref,
+                _ => return Err(DocError::InvalidOperation(format!("Block {} has no text", block_id)).into()),
+            };
+            if offset > text_ref.len(&txn) {
+                return Err(DocError::InvalidOperation(format!("Offset {} is out of range for block {}", offset, block_id)).into());
+            }
+        }
+
+        Self::write_comment(&mut txn, &comments, &blocks_map, &CommentDoc {
+            comment_id: comment_id.clone(),
+            block_id,
+            author,
+            text,
+            resolved: false,
+            anchor_offset,
+        });
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("add_comment: Finished for comment_id={}", comment_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 댓글을 해결됨으로 표시 (댓글은 남아 있고 get_comments_for_block의 resolved가 true가 됨)
+    ///
+    /// [comment_id] 댓글 id
+    ///
+    /// 댓글이 없으면 에러
+    pub fn resolve_comment(&mut self, comment_id: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("resolve_comment: comment_id={} for doc_id: {}", comment_id, self.doc_id);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let comments = root.get_or_init_map(&mut txn, COMMENTS);
+
+        let comment = match comments.get(&txn, &comment_id) {
+            Some(yrs::Out::YMap(comment)) => comment,
+            _ => return Err(DocError::ValidationError(format!("Comment {} not found", comment_id)).into()),
+        };
+        comment.insert(&mut txn, COMMENT_RESOLVED, true);
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("resolve_comment: Finished for comment_id={}", comment_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록에 붙은 댓글 목록 (해결된 댓글 포함)
+    ///
+    /// [block_id] 블록 id
+    ///
+    /// 텍스트 위치 순으로 정렬되고 블록 전체 댓글은 맨 뒤, 같은 위치는 댓글 id 순.
+    /// 블록이 삭제되어도 에러 없이 남은 댓글을 반환하며, 이런 댓글은 validate_document의 orphaned_comment_ids로 확인
+    pub fn get_comments_for_block(&self, block_id: String) -> Result<Vec<CommentDoc>, CustomRustError> {
+        log_info!("get_comments_for_block: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        let mut result: Vec<CommentDoc> = Self::read_comments(&self.doc.transact())
+            .into_iter()
+            .filter(|comment| comment.block_id == block_id)
+            .collect();
+        result.sort_by(|a, b| {
+            (a.anchor_offset.is_none(), a.anchor_offset, &a.comment_id).cmp(&(b.anchor_offset.is_none(), b.anchor_offset, &b.comment_id))
+        });
+
+        log_info!("get_comments_for_block: {} comments for block_id={}", result.len(), block_id);
+        Ok(result)
+    }
+
+    /// COMMENTS 맵의 모든 댓글을 읽음 (텍스트 위치는 이 트랜잭션 기준 오프셋으로 풀어서 반환)
+    fn read_comments<T: ReadTxn>(txn: &T) -> Vec<CommentDoc> {
+        let comments = match txn.get_map(ROOT_ID).and_then(|root| root.get(txn, COMMENTS)) {
+            Some(yrs::Out::YMap(comments)) => comments,
+            _ => return Vec::new(),
+        };
+
+        let read = |comment: &yrs::MapRef, key: &str| comment.get(txn, key).map(|out| out.to_string(txn)).unwrap_or_default();
+        comments.iter(txn)
+            .filter_map(|(id, comment)| match comment {
+                yrs::Out::YMap(comment) => Some((id, comment)),
+                _ => None,
+            })
+            .map(|(id, comment)| {
+                let anchor_offset = match comment.get(txn, COMMENT_ANCHOR) {
+                    Some(yrs::Out::Any(yrs::Any::Buffer(bytes))) => StickyIndex::decode_v1(&bytes)
+                        .ok()
+                        .and_then(|index| index.get_offset(txn))
+                        .map(|offset| offset.index),
+                    _ => None,
+                };
+                CommentDoc {
+                    comment_id: id.to_string(),
+                    block_id: read(&comment, COMMENT_BLOCK_ID),
+                    author: read(&comment, COMMENT_AUTHOR),
+                    text: read(&comment, COMMENT_TEXT),
+                    resolved: matches!(comment.get(txn, COMMENT_RESOLVED), Some(yrs::Out::Any(yrs::Any::Bool(true)))),
+                    anchor_offset,
+                }
+            })
+            .collect()
+    }
+
+    /// 댓글 하나를 COMMENTS 맵에 기록 (같은 id가 있으면 교체)
+    ///
+    /// anchor_offset은 이 문서의 블록 텍스트에 상대 위치로 다시 고정함.
+    /// 블록이나 텍스트가 없거나 범위를 벗어나면 블록 전체 댓글로 기록
+    fn write_comment(txn: &mut yrs::TransactionMut, comments: &yrs::MapRef, blocks_map: &yrs::MapRef, comment: &CommentDoc) {
+        let text_ref = match blocks_map.get(txn, &comment.block_id) {
+            Some(yrs::Out::YMap(block)) => match block.get(txn, TEXT) {
+                Some(yrs::Out::YText(text_ref)) => Some(text_ref),
+                _ => None,
+            },
+            _ => None,
+        };
+        let anchor = comment.anchor_offset
+            .zip(text_ref)
+            .and_then(|(offset, text_ref)| text_ref.sticky_index(txn, offset, Assoc::After));
+
+        let entry = comments.insert(txn, comment.comment_id.clone(), yrs::MapPrelim::default());
+        entry.insert(txn, COMMENT_BLOCK_ID, comment.block_id.clone());
+        entry.insert(txn, COMMENT_AUTHOR, comment.author.clone());
+        entry.insert(txn, COMMENT_TEXT, comment.text.clone());
+        entry.insert(txn, COMMENT_RESOLVED, comment.resolved);
+        if let Some(anchor) = anchor {
+            entry.insert(txn, COMMENT_ANCHOR, yrs::Any::Buffer(anchor.encode_v1().into()));
+        }
+    }
+
+    // ============================================
+    // Batch API - 빠른 입력 중 업데이트를 하나로 묶어 전송
+    // ============================================
+
+    #[frb]
+    /// 업데이트 묶음 시작. 이후 commit_batch까지 이 문서에서 일어난 로컬 변경의 업데이트를 모아둠
+    ///
+    /// 각 편집 함수는 여전히 자신의 업데이트를 반환하지만, 묶음 중에는 전송하지 않고 commit_batch 결과만 보내면 됨.
+    /// apply_updates로 적용된 원격 업데이트(REMOTE_ORIGIN)는 다시 보낼 필요가 없으므로 모으지 않음.
+    /// 이미 묶음이 진행 중이면 에러
+    pub fn begin_batch(&mut self) -> Result<(), CustomRustError> {
+        log_info!("begin_batch: doc_id: {}", self.doc_id);
+
+        if self.batch.is_some() {
+            return Err(DocError::StateError("A batch is already in progress".into()).into());
+        }
+        let updates: Arc<Mutex<Vec<Vec<u8>>>> = Arc::default();
+        let collected = updates.clone();
+        let subscription = self.doc
+            .observe_update_v2(move |txn, event| {
+                let remote = txn.origin().is_some_and(|origin| origin.as_ref() == REMOTE_ORIGIN.as_bytes());
+                if remote {
+                    return;
+                }
+                match collected.lock() {
+                    Ok(mut updates) => updates.push(event.update.clone()),
+                    Err(_) => log_error!("begin_batch: Batch buffer lock poisoned, update dropped"),
+                }
+            })
+            .map_err(|e| DocError::StateError(format!("Failed to observe document updates: {}", e)))?;
+        self.batch = Some(UpdateBatch { updates, _subscription: subscription });
+        Ok(())
+    }
+
+    #[frb]
+    /// 묶음을 끝내고 모아둔 업데이트를 merge_updates_v2로 합친 업데이트 하나를 반환
+    ///
+    /// 묶음 중 변경이 없었으면 빈 업데이트 반환. begin_batch 없이 호출하면 에러
+    pub fn commit_batch(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        let batch = self.batch.take()
+            .ok_or_else(|| DocError::StateError("No batch in progress".into()))?;
+        let updates = std::mem::take(
+            &mut *batch.updates.lock().map_err(|_| DocError::StateError("Batch buffer lock poisoned".into()))?
+        );
+        log_info!("commit_batch: Merging {} updates for doc_id: {}", updates.len(), self.doc_id);
+
+        // 빈 Vec는 v2 업데이트로 디코딩되지 않으므로 변경 없는 업데이트를 인코딩해서 반환
+        if updates.is_empty() {
+            return Ok(Update::new().encode_v2());
+        }
+        merge_updates_v2(updates).map_err(|e| {
+            log_error!("commit_batch: Failed to merge updates: {}", e);
+            DocError::MergeError(format!("Failed to merge batched updates: {}", e)).into()
+        })
+    }
+
+    #[frb]
+    /// 묶음이 진행 중인지 여부
+    pub fn is_batching(&self) -> bool {
+        self.batch.is_some()
+    }
+
+    // ============================================
+    // Change API - 문서 변경 스트림
+    // ============================================
+
+    #[frb]
+    /// 문서에 커밋되는 모든 업데이트(v2)를 트랜잭션 origin과 함께 Dart 스트림으로 전달 (apply_updates로 받은 원격 변경 포함)
+    ///
+    /// [sink] 업데이트와 origin을 받을 스트림, 다시 호출하면 이전 스트림을 대체
+    ///
+    /// origin: apply_action_with_origin으로 준 값, apply_updates로 받은 원격 변경은 "remote", 그 외 로컬 편집은 None
+    /// (Dart 쪽에서 자기가 보낸 변경을 서버로 다시 보내지 않도록 걸러낼 때 사용)
+    pub fn subscribe_changes(&mut self, sink: StreamSink<OriginUpdate>) -> Result<(), CustomRustError> {
+        log_info!("subscribe_changes: Subscribing for doc_id: {}", self.doc_id);
+
+        self.set_change_listener(Arc::new(move |update, origin| {
+            if sink.add(OriginUpdate { update, origin }).is_err() {
+                log_error!("subscribe_changes: Failed to forward update to Dart");
+            }
+        }))
+    }
+
+    fn set_change_listener(&mut self, listener: ChangeListener) -> Result<(), CustomRustError> {
+        self.change_listener = Some(listener);
+        self.register_change_observer()
+    }
+
+    /// 현재 문서에 변경 리스너를 연결 (이전 구독은 해제됨)
+    fn register_change_observer(&mut self) -> Result<(), CustomRustError> {
+        let Some(listener) = self.change_listener.clone() else {
+            return Ok(());
+        };
+        let subscription = self.doc
+            .observe_update_v2(move |txn, event| {
+                let origin = txn.origin().map(|origin| String::from_utf8_lossy(origin.as_ref()).into_owned());
+                listener(event.update.clone(), origin)
+            })
+            .map_err(|e| DocError::StateError(format!("Failed to observe document updates: {}", e)))?;
+        self.change_subscription = Some(subscription);
+        Ok(())
+    }
+
+    #[frb]
+    /// 메타데이터가 바뀔 때마다 바뀐 키만 JSON으로 Dart 스트림에 전달 (apply_updates로 받은 원격 변경 포함)
+    ///
+    /// [sink] {"title": "새 제목", "status": null} 형식의 JSON을 받을 스트림 (null은 키 삭제)
+    ///
+    /// 중첩 메타가 바뀌면 해당 최상위 키의 현재 값 전체를 보냄. 다시 호출하면 이전 스트림을 대체
+    pub fn subscribe_meta_changes(&mut self, sink: StreamSink<String>) -> Result<(), CustomRustError> {
+        log_info!("subscribe_meta_changes: Subscribing for doc_id: {}", self.doc_id);
+
+        self.set_meta_listener(Arc::new(move |changes| {
+            if sink.add(changes).is_err() {
+                log_error!("subscribe_meta_changes: Failed to forward meta changes to Dart");
+            }
+        }));
+        Ok(())
+    }
+
+    /// root 맵을 깊게 관찰해서 META 아래 변경만 골라 리스너에 전달 (이전 구독은 해제됨)
+    ///
+    /// META 맵이 나중에 만들어지거나 원격에서 교체되어도 root에서 관찰하므로 계속 받음
+    fn set_meta_listener(&mut self, listener: MetaListener) {
+        let root = self.doc.get_or_insert_map(ROOT_ID);
+        let observed_root = root.clone();
+        let subscription = root.observe_deep(move |txn, events| {
+            let changes = Self::meta_changes_from_events(txn, &observed_root, events);
+            if changes.is_empty() {
+                return;
+            }
+            match serde_json::to_string(&JsonValue::Object(changes)) {
+                Ok(json) => listener(json),
+                Err(e) => log_error!("subscribe_meta_changes: JSON serialization failed: {}", e),
+            }
+        });
+        self.meta_subscription = Some(subscription);
+    }
+
+    /// 깊은 관찰 이벤트에서 바뀐 최상위 메타 키와 현재 값(삭제는 null)을 추림
+    fn meta_changes_from_events(
+        txn: &yrs::TransactionMut,
+        root: &yrs::MapRef,
+        events: &yrs::types::Events,
+    ) -> serde_json::Map<String, JsonValue> {
+        let meta = match root.get(txn, META) {
+            Some(yrs::Out::YMap(meta)) => Some(meta),
+            _ => None,
+        };
+        let current = |key: &str| match meta.as_ref().and_then(|m| m.get(txn, key)) {
+            Some(value) => Self::yrs_value_to_json(txn, value),
+            None => JsonValue::Null,
+        };
+
+        let mut changes = serde_json::Map::new();
+        for event in events.iter() {
+            let path = event.path();
+            match (path.front(), path.get(1), event) {
+                // META 맵 자체가 새로 생기거나 교체됨
+                (None, _, yrs::types::Event::Map(root_event)) if root_event.keys(txn).contains_key(META) => {
+                    if let Some(meta) = meta.as_ref() {
+                        for (key, value) in meta.iter(txn) {
+                            changes.insert(key.to_string(), Self::yrs_value_to_json(txn, value));
+                        }
+                    }
+                }
+                (Some(PathSegment::Key(first)), None, yrs::types::Event::Map(meta_event)) if first.as_ref() == META => {
+                    for key in meta_event.keys(txn).keys() {
+                        changes.insert(key.to_string(), current(key));
+                    }
+                }
+                (Some(PathSegment::Key(first)), Some(PathSegment::Key(key)), _) if first.as_ref() == META => {
+                    changes.insert(key.to_string(), current(key));
+                }
+                _ => {}
+            }
+        }
+        changes
+    }
+
+    #[frb]
+    /// 문서가 비어 있음/내용 있음 사이를 오갈 때만 Dart 스트림에 전달 (빈 문서 안내 문구 표시용)
+    ///
+    /// [sink] is_empty 결과를 받을 스트림, 다시 호출하면 이전 스트림을 대체
+    ///
+    /// 구독 즉시 현재 값을 한 번 보내고, 이후에는 값이 바뀐 트랜잭션에서만 보냄 (apply_updates로 받은 원격 변경 포함)
+    pub fn subscribe_emptiness(&mut self, sink: StreamSink<bool>) -> Result<(), CustomRustError> {
+        log_info!("subscribe_emptiness: Subscribing for doc_id: {}", self.doc_id);
+
+        self.set_emptiness_listener(Arc::new(move |empty| {
+            if sink.add(empty).is_err() {
+                log_error!("subscribe_emptiness: Failed to forward emptiness to Dart");
+            }
+        }))
+    }
+
+    /// 현재 값을 바로 전달하고 업데이트마다 is_empty를 다시 계산해 바뀐 경우만 전달 (이전 구독은 해제됨)
+    fn set_emptiness_listener(&mut self, listener: EmptinessListener) -> Result<(), CustomRustError> {
+        let initial = Self::is_empty_in(&self.doc.transact());
+        let last = Arc::new(AtomicBool::new(initial));
+        listener(initial);
+        let subscription = self.doc
+            .observe_update_v2(move |txn, _| {
+                let empty = Self::is_empty_in(txn);
+                if last.swap(empty, Ordering::SeqCst) != empty {
+                    listener(empty);
+                }
+            })
+            .map_err(|e| DocError::StateError(format!("Failed to observe document updates: {}", e)))?;
+        self.emptiness_subscription = Some(subscription);
+        Ok(())
+    }
+
+    #[frb]
+    /// 블록 하나의 변경만 JSON으로 Dart 스트림에 전달 (집중 편집 화면용, apply_updates로 받은 원격 변경 포함)
+    ///
+    /// [block_id] 관찰할 블록 id
+    /// [sink] 변경 JSON을 받을 스트림, 같은 블록으로 다시 호출하면 이전 스트림을 대체
+    ///
+    /// 변경: {"blockId":"a","event":"updated","fields":["attributes.level","type"],"delta":[...],"childrenReordered":false}
+    /// (delta는 텍스트가 바뀐 경우에만 포함)
+    /// 블록이 삭제되면 {"blockId":"a","event":"deleted"}를 마지막으로 보내고 구독이 끝남
+    pub fn subscribe_block_changes(&mut self, block_id: String, sink: StreamSink<String>) -> Result<(), CustomRustError> {
+        log_info!("subscribe_block_changes: block_id={} for doc_id: {}", block_id, self.doc_id);
+
+        self.set_block_listener(block_id, Arc::new(move |changes| {
+            if sink.add(changes).is_err() {
+                log_error!("subscribe_block_changes: Failed to forward block changes to Dart");
+            }
+        }))
+    }
+
+    #[frb]
+    /// subscribe_block_changes 구독 해제
+    ///
+    /// 반환: 구독 중이던 블록이었는지 여부
+    pub fn unsubscribe_block_changes(&mut self, block_id: String) -> bool {
+        log_info!("unsubscribe_block_changes: block_id={} for doc_id: {}", block_id, self.doc_id);
+        self.block_subscriptions.remove(&block_id).is_some()
+    }
+
+    /// root 맵을 깊게 관찰해서 block_id 블록에 해당하는 변경만 리스너에 전달 (이전 구독은 해제됨)
+    fn set_block_listener(&mut self, block_id: String, listener: BlockListener) -> Result<(), CustomRustError> {
+        if self.get_block(block_id.clone())?.is_none() {
+            return Err(DocError::BlockNotFound(format!("Block {} not found", block_id)).into());
+        }
+        // 삭제로 끝난 구독 정리
+        self.block_subscriptions.retain(|_, (_, ended)| !ended.load(Ordering::Acquire));
+
+        let root = self.doc.get_or_insert_map(ROOT_ID);
+        let observed_id = block_id.clone();
+        let ended = Arc::new(AtomicBool::new(false));
+        let observed_ended = ended.clone();
+        let subscription = root.observe_deep(move |txn, events| {
+            if observed_ended.load(Ordering::Acquire) {
+                return;
+            }
+            let Some(changes) = Self::block_changes_from_events(txn, &observed_id, events) else {
+                return;
+            };
+            if changes["event"] == "deleted" {
+                observed_ended.store(true, Ordering::Release);
+            }
+            listener(changes.to_string());
+        });
+        self.block_subscriptions.insert(block_id, (subscription, ended));
+        Ok(())
+    }
+
+    /// 깊은 관찰 이벤트에서 block_id 블록의 변경(필드, 텍스트 delta, 자식 순서, 삭제)을 추림
+    fn block_changes_from_events(
+        txn: &yrs::TransactionMut,
+        block_id: &str,
+        events: &yrs::types::Events,
+    ) -> Option<JsonValue> {
+        let as_str = |value: &yrs::Out| match value {
+            yrs::Out::Any(yrs::Any::String(s)) => Some(s.to_string()),
+            _ => None,
+        };
+        let mut fields = std::collections::BTreeSet::new();
+        let mut delta = Vec::new();
+        let mut children_reordered = false;
+
+        for event in events.iter() {
+            let path = event.path();
+            let segment = |i: usize| match path.get(i) {
+                Some(PathSegment::Key(key)) => Some(key.as_ref()),
+                _ => None,
+            };
+            if segment(0) != Some(BLOCKS) {
+                continue;
+            }
+            match (segment(1), segment(2), event) {
+                // BLOCKS 맵에서 블록이 추가/삭제됨
+                (None, _, yrs::types::Event::Map(blocks_event)) => {
+                    for (key, change) in blocks_event.keys(txn) {
+                        if key.as_ref() == block_id {
+                            if matches!(change, yrs::types::EntryChange::Removed(_)) {
+                                return Some(json!({ "blockId": block_id, "event": "deleted" }));
+                            }
+                        } else if let yrs::types::EntryChange::Inserted(yrs::Out::YMap(child)) = change {
+                            children_reordered |= child.get(txn, PARENT_ID).as_ref().and_then(as_str).as_deref() == Some(block_id);
+                        }
+                    }
+                }
+                (Some(id), None, yrs::types::Event::Map(block_event)) if id == block_id => {
+                    fields.extend(block_event.keys(txn).keys().map(|key| key.to_string()));
+                }
+                (Some(id), Some(ATTRIBUTES), yrs::types::Event::Map(attributes_event)) if id == block_id => {
+                    fields.extend(attributes_event.keys(txn).keys().map(|key| format!("{}.{}", ATTRIBUTES, key)));
+                }
+                (Some(id), Some(TEXT), yrs::types::Event::Text(text_event)) if id == block_id => {
+                    fields.insert(TEXT.to_string());
+                    delta.extend(text_event.delta(txn).iter().cloned());
+                }
+                // 다른 블록의 순서/부모가 바뀌었는데 이 블록의 자식이거나 자식이었던 경우
+                (Some(_), None, yrs::types::Event::Map(other_event)) => {
+                    let current_parent = other_event.target().get(txn, PARENT_ID).as_ref().and_then(as_str);
+                    for (key, change) in other_event.keys(txn) {
+                        if key.as_ref() != PARENT_ID && key.as_ref() != PREV_ID {
+                            continue;
+                        }
+                        let old_parent = match (key.as_ref(), change) {
+                            (PARENT_ID, yrs::types::EntryChange::Updated(old, _) | yrs::types::EntryChange::Removed(old)) => as_str(old),
+                            _ => None,
+                        };
+                        if current_parent.as_deref() == Some(block_id) || old_parent.as_deref() == Some(block_id) {
+                            children_reordered = true;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if fields.is_empty() && !children_reordered {
+            return None;
+        }
+        let mut changes = json!({
+            "blockId": block_id,
+            "event": "updated",
+            "fields": fields.into_iter().collect::<Vec<_>>(),
+            "childrenReordered": children_reordered,
+        });
+        if !delta.is_empty() {
+            changes["delta"] = Conversion::deltas_to_json(txn, delta).unwrap_or(JsonValue::Null);
+        }
+        Some(changes)
+    }
+
+    // ============================================
+    // Awareness API - 접속 중인 편집자 정보
+    // ============================================
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 최근 window_ms 동안 awareness 상태를 갱신한 편집자 수 ("3명 편집 중" 표시용)
+    ///
+    /// [window_ms] 활동으로 인정할 기간 (밀리초)
+    ///
+    /// AWARENESS_TIMEOUT_MS 동안 소식이 없는 원격 클라이언트는 먼저 오프라인 처리됨
+    pub fn active_editor_count(&self, window_ms: u64) -> u32 {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        self.expire_stale_awareness(now);
+
+        let count = self.awareness.iter()
+            .filter(|(_, state)| state.data.is_some() && now.saturating_sub(state.last_updated) <= window_ms)
+            .count() as u32;
+        log_info!("active_editor_count: {} editors within {}ms for doc_id: {}", count, window_ms, self.doc_id);
+        count
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 로컬 편집자의 awareness 상태(이름, 커서, 색상 등) 설정
+    ///
+    /// [state_json] 상태 JSON (예: {"user":"taeuk","color":"#ff0000","cursor":{"blockId":"a","offset":3}})
+    ///
+    /// 문서 내용과 무관하므로 문서 업데이트를 만들지 않음
+    pub fn set_local_awareness(&mut self, state_json: String) -> Result<(), CustomRustError> {
+        log_info!("set_local_awareness: doc_id: {}", self.doc_id);
+
+        serde_json::from_str::<JsonValue>(&state_json)
+            .map_err(|e| DocError::DecodingError(format!("Failed to parse awareness state: {}", e)))?;
+        self.awareness.set_local_state_raw(state_json);
+        Ok(())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 현재 알고 있는 모든 편집자의 awareness 상태를 인코딩 (v1, 다른 클라이언트로 전송용)
+    pub fn encode_awareness_update(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_awareness_update: doc_id: {}", self.doc_id);
+
+        let update = self.awareness.update()
+            .map_err(|e| DocError::EncodingError(format!("Failed to encode awareness update: {}", e)))?;
+        Ok(update.encode_v1())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 다른 클라이언트의 awareness 업데이트 적용
+    ///
+    /// [update] encode_awareness_update로 만든 바이트 (v1)
+    ///
+    /// 반환: 바뀐 클라이언트 id JSON (예: {"added":[12],"updated":[34],"removed":[]})
+    pub fn apply_awareness_update(&mut self, update: Vec<u8>) -> Result<String, CustomRustError> {
+        log_info!("apply_awareness_update: {} bytes for doc_id: {}", update.len(), self.doc_id);
+
+        let update = AwarenessUpdate::decode_v1(&update)
+            .map_err(|e| DocError::DecodingError(format!("Failed to decode awareness update: {}", e)))?;
+        let summary = self.awareness.apply_update_summary(update)
+            .map_err(|e| DocError::MergeError(format!("Failed to apply awareness update: {}", e)))?;
+
+        let (added, updated, removed) = match summary {
+            Some(summary) => (summary.added, summary.updated, summary.removed),
+            None => Default::default(),
+        };
+        Ok(json!({ "added": added, "updated": updated, "removed": removed }).to_string())
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 원격 클라이언트의 awareness 상태 제거 (Dart 쪽 타임아웃으로 연결이 끊긴 편집자 정리용)
+    ///
+    /// [client_id] 제거할 클라이언트 id
+    ///
+    /// 반환: 상태가 있던 클라이언트였는지 여부
+    pub fn remove_awareness_client(&mut self, client_id: u64) -> bool {
+        log_info!("remove_awareness_client: client_id={} for doc_id: {}", client_id, self.doc_id);
+
+        let known = self.awareness.iter().any(|(id, state)| id == client_id && state.data.is_some());
+        if known {
+            self.awareness.remove_state(client_id);
+        }
+        known
+    }
+
+    /// 타임아웃이 지난 원격 클라이언트의 awareness 상태 제거 (로컬 클라이언트는 유지)
+    fn expire_stale_awareness(&self, now: u64) {
+        let local_id = self.awareness.client_id();
+        let stale: Vec<u64> = self.awareness.iter()
+            .filter(|(client_id, state)| {
+                *client_id != local_id
+                    && state.data.is_some()
+                    && now.saturating_sub(state.last_updated) > AWARENESS_TIMEOUT_MS
+            })
+            .map(|(client_id, _)| client_id)
+            .collect();
+
+        for client_id in stale {
+            log_info!("expire_stale_awareness: Removing client {} for doc_id: {}", client_id, self.doc_id);
+            self.awareness.remove_state(client_id);
+        }
+    }
+
+    // ============================================
+    // Meta API - YDoc 메타데이터 조작
+    // ============================================
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터에 문자열 값 설정
+    ///
+    /// [key] 메타데이터 키
+    /// [value] 설정할 문자열 값
+    pub fn set_meta_string(&mut self, key: String, value: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_meta_string: key={}, len={}", key, value.len());
+        log_content!("set_meta_string: key={}, value={}", key, value);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        meta.insert(&mut txn, key.clone(), value);
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_meta_string: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 키 제거
+    ///
+    /// [key] 제거할 메타데이터 키
+    pub fn remove_meta_key(&mut self, key: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("remove_meta_key: key={}", key);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        meta.remove(&mut txn, &key);
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("remove_meta_key: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터에 정수 값 설정
+    ///
+    /// [key] 메타데이터 키
+    /// [value] 설정할 정수 값
+    pub fn set_meta_int(&mut self, key: String, value: i64) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_meta_int: key={}", key);
+        log_content!("set_meta_int: key={}, value={}", key, value);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        meta.insert(&mut txn, key.clone(), value);
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_meta_int: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터에 불리언 값 설정
+    ///
+    /// [key] 메타데이터 키
+    /// [value] 설정할 불리언 값
+    pub fn set_meta_bool(&mut self, key: String, value: bool) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_meta_bool: key={}", key);
+        log_content!("set_meta_bool: key={}, value={}", key, value);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        meta.insert(&mut txn, key.clone(), value);
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_meta_bool: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터에 문자열 배열 설정 (기존 배열 전체 교체)
+    ///
+    /// [key] 메타데이터 키
+    /// [values] 설정할 문자열 배열
+    pub fn set_meta_string_array(&mut self, key: String, values: Vec<String>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_meta_string_array: key={}, count={}", key, values.len());
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        // 기존 배열이 있으면 제거하고 새로 생성
+        meta.remove(&mut txn, &key);
+        let array = meta.get_or_init_array(&mut txn, key.clone());
+
+        for value in values {
+            array.push_back(&mut txn, value);
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_meta_string_array: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 배열에 문자열 항목 추가 (중복 체크)
+    ///
+    /// [key] 메타데이터 키
+    /// [value] 추가할 문자열 값
+    pub fn push_meta_array_item(&mut self, key: String, value: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("push_meta_array_item: key={}, len={}", key, value.len());
+        log_content!("push_meta_array_item: key={}, value={}", key, value);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        Self::apply_meta_op(&mut txn, &meta, MetaOp::PushArrayItem { key: key.clone(), value });
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("push_meta_array_item: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 배열에서 문자열 항목 제거
+    ///
+    /// [key] 메타데이터 키
+    /// [value] 제거할 문자열 값
+    pub fn remove_meta_array_item(&mut self, key: String, value: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("remove_meta_array_item: key={}, len={}", key, value.len());
+        log_content!("remove_meta_array_item: key={}, value={}", key, value);
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        Self::apply_meta_op(&mut txn, &meta, MetaOp::RemoveArrayItem { key: key.clone(), value });
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("remove_meta_array_item: Finished for key={}", key);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 여러 메타데이터 변경을 한 트랜잭션으로 적용 (apply_action처럼 업데이트 하나만 생성)
+    ///
+    /// [operations] 순서대로 적용할 메타 변경 목록
+    pub fn set_meta_batch(&mut self, operations: Vec<MetaOp>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_meta_batch: Applying {} operations", operations.len());
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        for operation in operations {
+            Self::apply_meta_op(&mut txn, &meta, operation);
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_meta_batch: Finished");
+        Ok(update)
+    }
+
+    /// META 맵에 메타 변경 하나를 적용
+    fn apply_meta_op(txn: &mut yrs::TransactionMut, meta: &yrs::MapRef, operation: MetaOp) {
+        match operation {
+            MetaOp::SetString { key, value } => {
+                meta.insert(txn, key, value);
+            }
+            MetaOp::SetInt { key, value } => {
+                meta.insert(txn, key, value);
+            }
+            MetaOp::SetBool { key, value } => {
+                meta.insert(txn, key, value);
+            }
+            MetaOp::SetStringArray { key, values } => {
+                // 기존 배열이 있으면 제거하고 새로 생성
+                meta.remove(txn, &key);
+                let array = meta.get_or_init_array(txn, key);
+                for value in values {
+                    array.push_back(txn, value);
+                }
+            }
+            MetaOp::Remove { key } => {
+                meta.remove(txn, &key);
+            }
+            MetaOp::PushArrayItem { key, value } => {
+                let array = meta.get_or_init_array(txn, key);
+                // 중복 체크
+                let exists = array.iter(txn).any(|v| {
+                    matches!(v, yrs::Out::Any(yrs::Any::String(s)) if s.as_ref() == value.as_str())
+                });
+                if !exists {
+                    array.push_back(txn, value);
+                }
+            }
+            MetaOp::RemoveArrayItem { key, value } => {
+                if let Some(yrs::Out::YArray(array)) = meta.get(txn, &key) {
+                    let index = array.iter(txn).position(|v| {
+                        matches!(v, yrs::Out::Any(yrs::Any::String(s)) if s.as_ref() == value.as_str())
+                    });
+                    if let Some(index) = index {
+                        array.remove(txn, index as u32);
+                    }
+                }
+            }
+        }
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 문자열 값 조회
+    ///
+    /// [key] 메타데이터 키
+    ///
+    /// 키가 없거나 문자열이 아니면 None
+    pub fn get_meta_string(&self, key: String) -> Result<Option<String>, CustomRustError> {
+        log_info!("get_meta_string: key={}", key);
+
+        Ok(self.read_meta_value(&key, |_, value| match value {
+            yrs::Out::Any(yrs::Any::String(s)) => Some(s.to_string()),
+            _ => None,
+        }))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 정수 값 조회
+    ///
+    /// [key] 메타데이터 키
+    ///
+    /// 키가 없거나 정수가 아니면 None (소수점이 있는 숫자도 None)
+    pub fn get_meta_int(&self, key: String) -> Result<Option<i64>, CustomRustError> {
+        log_info!("get_meta_int: key={}", key);
+
+        Ok(self.read_meta_value(&key, |_, value| match value {
+            // yrs는 안전한 정수 범위의 i64를 f64로 저장
+            yrs::Out::Any(yrs::Any::Number(n))
+                if n.fract() == 0.0 && n >= i64::MIN as f64 && n <= i64::MAX as f64 => Some(n as i64),
+            yrs::Out::Any(yrs::Any::BigInt(i)) => Some(i),
+            _ => None,
+        }))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 불리언 값 조회
+    ///
+    /// [key] 메타데이터 키
+    ///
+    /// 키가 없거나 불리언이 아니면 None
+    pub fn get_meta_bool(&self, key: String) -> Result<Option<bool>, CustomRustError> {
+        log_info!("get_meta_bool: key={}", key);
+
+        Ok(self.read_meta_value(&key, |_, value| match value {
+            yrs::Out::Any(yrs::Any::Bool(b)) => Some(b),
+            _ => None,
+        }))
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 문자열 배열 조회
+    ///
+    /// [key] 메타데이터 키
+    ///
+    /// 키가 없거나 배열이 아니면 None, 문자열이 아닌 항목이 섞여 있어도 None
+    pub fn get_meta_string_array(&self, key: String) -> Result<Option<Vec<String>>, CustomRustError> {
+        log_info!("get_meta_string_array: key={}", key);
+
+        Ok(self.read_meta_value(&key, |txn, value| match value {
+            yrs::Out::YArray(array) => array.iter(txn)
+                .map(|item| match item {
+                    yrs::Out::Any(yrs::Any::String(s)) => Some(s.to_string()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }))
+    }
+
+    /// 읽기 트랜잭션에서 META 맵의 값을 꺼내 변환 (루트나 META 맵, 키가 없으면 None)
+    fn read_meta_value<R>(
+        &self,
+        key: &str,
+        convert: impl FnOnce(&yrs::Transaction, yrs::Out) -> Option<R>,
+    ) -> Option<R> {
+        let txn = self.doc.transact();
+        let root = txn.get_map(ROOT_ID)?;
+        let meta = match root.get(&txn, META)? {
+            yrs::Out::YMap(meta) => meta,
+            _ => return None,
+        };
+        let value = meta.get(&txn, key)?;
+        convert(&txn, value)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 메타데이터 키 목록만 반환 (값은 직렬화하지 않음)
+    ///
+    /// 반환: 정렬된 키 목록, META 맵이 없으면 빈 목록
+    pub fn get_meta_keys(&self) -> Result<Vec<String>, CustomRustError> {
+        log_info!("get_meta_keys: Starting");
+
+        let txn = self.doc.transact();
+        let mut keys: Vec<String> = match txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, META)) {
+            Some(yrs::Out::YMap(meta)) => meta.keys(&txn).map(str::to_string).collect(),
+            _ => Vec::new(),
+        };
+        keys.sort();
+
+        log_info!("get_meta_keys: Found {} keys", keys.len());
+        Ok(keys)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 모든 메타데이터를 JSON 문자열로 반환
+    ///
+    /// 반환: JSON 형식의 메타데이터 (예: {"title": "노트", "color": 123, "status": "active"})
+    pub fn get_all_meta(&self) -> Result<String, CustomRustError> {
+        log_info!("get_all_meta: Starting");
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let txn = doc.transact();
+
+        let mut result = serde_json::Map::new();
+
+        if let Some(yrs::Value::YMap(meta)) = root.get(&txn, META) {
+            for (key, value) in meta.iter(&txn) {
+                let json_value = Self::yrs_value_to_json(&txn, value);
+                result.insert(key.to_string(), json_value);
+            }
+        }
+
+        let json_str = serde_json::to_string(&JsonValue::Object(result))
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)))?;
+
+        log_info!("get_all_meta: Finished");
+        Ok(json_str)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// JSON을 set_meta_from_json으로 적용했을 때 바뀔 메타데이터 미리보기
+    ///
+    /// [json] 비교할 메타데이터 JSON (set_meta_from_json 입력과 같은 형식)
+    ///
+    /// 반환: {"added": {"key": 값}, "removed": ["key"], "changed": {"key": {"from": 이전값, "to": 새값}}}
+    /// null 값은 키 제거를 의미하고, JSON에 없는 키는 변경되지 않음. 타입이 다르면 변경으로 취급.
+    pub fn meta_diff_against_json(&self, json: String) -> Result<String, CustomRustError> {
+        log_info!("meta_diff_against_json: Starting");
+
+        let incoming: JsonValue = serde_json::from_str(&json)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let incoming = incoming.as_object()
+            .ok_or_else(|| DocError::InvalidOperation("Expected JSON object".into()))?;
+
+        let current: JsonValue = serde_json::from_str(&self.get_all_meta()?)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+        let current = current.as_object().cloned().unwrap_or_default();
+
+        let mut added = serde_json::Map::new();
+        let mut removed = Vec::new();
+        let mut changed = serde_json::Map::new();
+
+        for (key, value) in incoming {
+            let stored = Self::meta_json_as_stored(value, current.get(key));
+            match (current.get(key), stored) {
+                (None, Some(new_value)) => { added.insert(key.clone(), new_value); }
+                (Some(_), None) => removed.push(JsonValue::String(key.clone())),
+                (Some(old_value), Some(new_value)) if *old_value != new_value => {
+                    changed.insert(key.clone(), json!({ "from": old_value, "to": new_value }));
+                }
+                _ => {}
+            }
+        }
+
+        let diff = json!({ "added": added, "removed": removed, "changed": changed });
+        log_info!("meta_diff_against_json: added={}, removed={}, changed={}", added.len(), removed.len(), changed.len());
+        serde_json::to_string(&diff)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)).into())
+    }
+
+    /// set_meta_from_json이 저장한 뒤 get_all_meta가 돌려줄 형태로 JSON 값을 변환 (null은 삭제라서 None)
+    ///
+    /// [current] 현재 저장된 값 (중첩 객체는 기존 값에 필드 단위로 병합되므로 필요)
+    fn meta_json_as_stored(value: &JsonValue, current: Option<&JsonValue>) -> Option<JsonValue> {
+        match value {
+            JsonValue::Null => None,
+            JsonValue::Object(fields) => {
+                let mut merged = current.and_then(|c| c.as_object()).cloned().unwrap_or_default();
+                for (field, field_value) in fields {
+                    match Self::meta_json_as_stored(field_value, merged.get(field)) {
+                        Some(stored) => { merged.insert(field.clone(), stored); }
+                        None => { merged.remove(field); }
+                    }
+                }
+                Some(JsonValue::Object(merged))
+            }
+            JsonValue::Array(items) => Some(JsonValue::Array(
+                items.iter()
+                    .map(|item| Self::meta_json_as_stored(item, None).unwrap_or(JsonValue::Null))
+                    .collect()
+            )),
+            scalar => Self::meta_json_scalar(scalar).map(Self::yrs_any_to_json),
+        }
+    }
+
+    /// 메타 JSON 값을 맵에 기록 (null은 제거, 객체는 기존 YMap에 필드 단위로 병합, 배열은 새 YArray)
+    fn write_meta_json(txn: &mut yrs::TransactionMut, map: &yrs::MapRef, key: &str, value: &JsonValue) {
+        match value {
+            JsonValue::Null => { map.remove(txn, key); }
+            JsonValue::Object(fields) => {
+                let nested = match map.get(txn, key) {
+                    Some(yrs::Out::YMap(nested)) => nested,
+                    _ => map.insert(txn, key, yrs::MapPrelim::default()),
+                };
+                for (field, field_value) in fields {
+                    Self::write_meta_json(txn, &nested, field, field_value);
+                }
+            }
+            JsonValue::Array(items) => {
+                let array = map.insert(txn, key, yrs::ArrayPrelim::default());
+                for item in items {
+                    Self::push_meta_json(txn, &array, item);
+                }
+            }
+            scalar => {
+                if let Some(any) = Self::meta_json_scalar(scalar) {
+                    map.insert(txn, key, any);
+                }
+            }
+        }
+    }
+
+    /// write_meta_json은 객체를 기존 YMap에 병합하므로, 되돌릴 값에 없는 중첩 필드를 먼저 제거
+    fn prune_meta_fields(txn: &mut yrs::TransactionMut, map: &yrs::MapRef, key: &str, value: &JsonValue) {
+        let (Some(yrs::Out::YMap(nested)), JsonValue::Object(fields)) = (map.get(txn, key), value) else {
+            return;
+        };
+        let stale: Vec<String> = nested.keys(txn).filter(|field| !fields.contains_key(*field)).map(str::to_string).collect();
+        for field in stale {
+            nested.remove(txn, &field);
+        }
+        for (field, field_value) in fields {
+            Self::prune_meta_fields(txn, &nested, field, field_value);
+        }
+    }
+
+    /// 메타 배열에 JSON 값을 추가 (중첩 객체/배열도 YMap/YArray로 저장)
+    fn push_meta_json(txn: &mut yrs::TransactionMut, array: &yrs::ArrayRef, value: &JsonValue) {
+        match value {
+            JsonValue::Object(fields) => {
+                let nested = array.push_back(txn, yrs::MapPrelim::default());
+                for (field, field_value) in fields {
+                    Self::write_meta_json(txn, &nested, field, field_value);
+                }
+            }
+            JsonValue::Array(items) => {
+                let nested = array.push_back(txn, yrs::ArrayPrelim::default());
+                for item in items {
+                    Self::push_meta_json(txn, &nested, item);
+                }
+            }
+            scalar => {
+                array.push_back(txn, Self::meta_json_scalar(scalar).unwrap_or(yrs::Any::Null));
+            }
+        }
+    }
+
+    /// 스칼라 JSON 값을 메타에 저장되는 yrs::Any로 변환 (정수는 i64로 저장)
+    fn meta_json_scalar(value: &JsonValue) -> Option<yrs::Any> {
+        match value {
+            JsonValue::Bool(b) => Some(yrs::Any::from(*b)),
+            JsonValue::Number(n) => n.as_i64().map(yrs::Any::from).or_else(|| n.as_f64().map(yrs::Any::from)),
+            JsonValue::String(s) => Some(yrs::Any::from(s.clone())),
+            _ => None,
+        }
+    }
+
+    /// yrs::Value를 serde_json::Value로 변환
+    fn yrs_value_to_json<T: ReadTxn>(txn: &T, value: yrs::Value) -> JsonValue {
+        match value {
+            yrs::Value::Any(any) => Self::yrs_any_to_json(any),
+            yrs::Value::YArray(array) => {
+                let items: Vec<JsonValue> = array.iter(txn)
+                    .map(|v| Self::yrs_value_to_json(txn, v))
+                    .collect();
+                JsonValue::Array(items)
+            }
+            yrs::Value::YMap(map) => {
+                let mut obj = serde_json::Map::new();
+                for (k, v) in map.iter(txn) {
+                    obj.insert(k.to_string(), Self::yrs_value_to_json(txn, v));
+                }
+                JsonValue::Object(obj)
+            }
+            _ => JsonValue::Null,
+        }
+    }
+
+    /// yrs::Any를 serde_json::Value로 변환
+    fn yrs_any_to_json(any: yrs::Any) -> JsonValue {
+        match any {
+            yrs::Any::Null => JsonValue::Null,
+            yrs::Any::Undefined => JsonValue::Null,
+            yrs::Any::Bool(b) => JsonValue::Bool(b),
+            // 정수로 저장된 값은 f64로 들어오므로 다시 정수로 돌려줌
+            yrs::Any::Number(n) if n.fract() == 0.0 && n.abs() <= MAX_SAFE_INTEGER => json!(n as i64),
+            yrs::Any::Number(n) => json!(n),
+            yrs::Any::BigInt(n) => json!(n),
+            yrs::Any::String(s) => JsonValue::String(s.to_string()),
+            yrs::Any::Array(arr) => {
+                let items: Vec<JsonValue> = arr.iter()
+                    .map(|v| Self::yrs_any_to_json(v.clone()))
+                    .collect();
+                JsonValue::Array(items)
+            }
+            yrs::Any::Map(map) => {
+                let mut obj = serde_json::Map::new();
+                for (k, v) in map.iter() {
+                    obj.insert(k.clone(), Self::yrs_any_to_json(v.clone()));
+                }
+                JsonValue::Object(obj)
+            }
+            yrs::Any::Buffer(buf) => {
+                // Base64 인코딩 또는 배열로 변환
+                JsonValue::Array(buf.iter().map(|b| json!(*b)).collect())
+            }
+        }
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 여러 메타데이터 필드를 한 번에 설정 (JSON 입력)
+    ///
+    /// [json_str] 설정할 메타데이터 JSON (예: {"title": "노트", "status": "active"})
+    ///
+    /// 지원 타입: string, number (int/double), boolean, array, object
+    /// 중첩 객체는 YMap으로 저장되어 필드 단위로 병합되고 (null 필드는 제거), 배열은 YArray로 통째로 교체됨
+    pub fn set_meta_from_json(&mut self, json_str: String) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("set_meta_from_json: {} bytes", json_str.len());
+        log_content!("set_meta_from_json: {}", json_str);
+
+        let json: JsonValue = serde_json::from_str(&json_str)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+
+        let obj = json.as_object()
+            .ok_or_else(|| DocError::InvalidOperation("Expected JSON object".into()))?;
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let meta = root.get_or_init_map(&mut txn, META);
+
+        for (key, value) in obj {
+            Self::write_meta_json(&mut txn, &meta, key, value);
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("set_meta_from_json: Finished");
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// 블록 없이 META 맵 내용만 담은 업데이트 생성 (노트 목록 화면의 제목/색상 동기화용)
+    ///
+    /// 반환: 현재 메타데이터만 새 Doc에 기록한 전체 상태. 원본 문서의 변경 기록이 아니므로
+    /// apply_updates가 아닌 apply_meta_only_update로 적용해야 하며, 적용하면 CRDT 병합이 아니라
+    /// 받는 쪽 META를 이 시점의 값으로 통째로 교체함 (apply_meta_only_update 주석 참고)
+    pub fn encode_meta_only_update(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_meta_only_update: Starting for doc_id: {}", self.doc_id);
+
+        let meta: JsonValue = serde_json::from_str(&self.get_all_meta()?)
+            .map_err(|e| DocError::EncodingError(format!("JSON parse failed: {}", e)))?;
+
+        let meta_doc = Doc::new();
+        let root = meta_doc.get_or_insert_map(ROOT_ID);
+        {
+            let mut txn = meta_doc.transact_mut();
+            let meta_map = root.get_or_init_map(&mut txn, META);
+            if let Some(fields) = meta.as_object() {
+                for (key, value) in fields {
+                    Self::write_meta_json(&mut txn, &meta_map, key, value);
+                }
+            }
+        }
+
+        let update = meta_doc.transact().encode_state_as_update_v2(&StateVector::default());
+        log_info!("encode_meta_only_update: {} bytes for doc_id: {}", update.len(), self.doc_id);
+        Ok(update)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// encode_meta_only_update로 만든 업데이트의 메타데이터를 이 문서의 META 맵에 반영
+    ///
+    /// [update] encode_meta_only_update 결과 (encode_full_state 같은 전체 상태도 가능)
+    ///
+    /// 업데이트에 블록 데이터가 있어도 무시하고 META만 반영. 업데이트에 없는 키는 제거되고 값이 다른 키만 다시 씀.
+    /// 업데이트에 META 맵이 없으면 (일부만 담긴 diff 등) 메타를 지우지 않도록 에러 반환
+    ///
+    /// 주의: CRDT 병합이 아니라 마지막에 적용한 쪽이 이기는 교체임. 업데이트를 만든 뒤에 이 문서에서 바꾼 메타도
+    /// 업데이트 시점의 값으로 덮어쓰거나 지우므로, 오래된 스냅샷을 적용하면 더 최신의 로컬 메타 변경이 사라짐.
+    /// 노트 목록처럼 메타를 읽기만 하는 복사본을 최신으로 맞추는 용도로만 쓰고, 편집 중인 문서의 메타 동기화는
+    /// encode_diff/apply_updates로 해야 함
+    ///
+    /// 반환: 이 문서에 적용된 변경의 업데이트 (로컬 저장용)
+    pub fn apply_meta_only_update(&mut self, update: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("apply_meta_only_update: {} bytes for doc_id: {}", update.len(), self.doc_id);
+
+        let scratch = Doc::new();
+        let (_, failed) = UpdateOperations::apply_updates_inner(&scratch, &self.doc_id, vec![update])?;
+        if !failed.failed_updates_ids.is_empty() {
+            return Err(DocError::UpdateDecodingFailed("Failed to decode meta update".into()).into());
+        }
+        let incoming: serde_json::Map<String, JsonValue> = {
+            let txn = scratch.transact();
+            match txn.get_map(ROOT_ID).and_then(|root| root.get(&txn, META)) {
+                Some(yrs::Out::YMap(meta)) => meta.iter(&txn)
+                    .map(|(key, value)| (key.to_string(), Self::yrs_value_to_json(&txn, value)))
+                    .collect(),
+                _ => {
+                    log_error!("apply_meta_only_update: Update has no META map for doc_id: {}", self.doc_id);
+                    return Err(DocError::ValidationError("Update contains no meta state".into()).into());
+                }
+            }
+        };
+
+        let doc = &self.doc;
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut_with(REMOTE_ORIGIN);
+        let meta_map = root.get_or_init_map(&mut txn, META);
+
+        let current: HashMap<String, JsonValue> = meta_map.iter(&txn)
+            .map(|(key, value)| (key.to_string(), Self::yrs_value_to_json(&txn, value)))
+            .collect();
+        for key in current.keys().filter(|key| !incoming.contains_key(*key)) {
+            meta_map.remove(&mut txn, key);
+        }
+        let mut changed = 0;
+        for (key, value) in &incoming {
+            if current.get(key) != Some(value) {
+                // 중첩 객체는 병합되므로 지운 뒤 다시 써서 업데이트와 같은 값이 되게 함
+                meta_map.remove(&mut txn, key);
+                Self::write_meta_json(&mut txn, &meta_map, key, value);
+                changed += 1;
+            }
+        }
+
+        let before_state = txn.before_state();
+        let update = txn.encode_diff_v2(before_state);
+        log_info!("apply_meta_only_update: {} keys changed for doc_id: {}", changed, self.doc_id);
+        Ok(update)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::constants::REMOTE_ORIGIN;
+
+    #[test]
+    fn test_meta_save_and_load() {
+        // 첫 번째 DocumentService에서 메타 저장
+        let mut doc_service1 = DocumentService::new();
+        doc_service1.init_empty_doc().unwrap();
+
+        let meta_json = r#"{"title":"테스트 노트","color":4294924083,"status":"pinned","labelIds":["label1","label2"]}"#;
+        let update = doc_service1.set_meta_from_json(meta_json.to_string()).unwrap();
+
+        println!("Update size: {} bytes", update.len());
+
+        // 메타데이터 로드
+        let loaded_meta = doc_service1.get_all_meta().unwrap();
         println!("Loaded meta: {}", loaded_meta);
 
-        assert!(loaded_meta.contains("테스트 노트"));
-        assert!(loaded_meta.contains("pinned"));
+        assert!(loaded_meta.contains("테스트 노트"));
+        assert!(loaded_meta.contains("pinned"));
+    }
+
+    fn block_action(id: &str, parent_id: Option<&str>, prev_id: Option<&str>, delta: Option<&str>) -> BlockActionDoc {
+        BlockActionDoc {
+            action: BlockActionTypeDoc::Insert,
+            block: BlockDoc {
+                id: id.to_string(),
+                ty: "paragraph".to_string(),
+                attributes: HashMap::new(),
+                delta: delta.map(|d| d.to_string()),
+                parent_id: parent_id.map(|p| p.to_string()),
+                prev_id: prev_id.map(|p| p.to_string()),
+                next_id: None,
+                old_parent_id: None,
+                created_at: None,
+                updated_at: None,
+            },
+            path: vec![],
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_update_remapped() {
+        let mut template = DocumentService::new();
+        template.init_empty_doc().unwrap();
+        template.apply_action(vec![
+            block_action("tpl-root", None, None, None),
+            block_action("tpl-1", Some("tpl-root"), None, Some(r#"[{"insert":"Hello"}]"#)),
+        ]).unwrap();
+        let template_state = template.encode_full_state().unwrap();
+
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+
+        let id_map = r#"{"tpl-root":"new-root","tpl-1":"new-1"}"#;
+        doc.apply_update_remapped(template_state.clone(), id_map.to_string()).unwrap();
+
+        let state = doc.get_document_state().unwrap();
+        assert!(state.blocks.contains_key("new-root"));
+        assert!(!state.blocks.contains_key("tpl-1"));
+        assert_eq!(state.blocks["new-1"].parent_id.as_deref(), Some("new-root"));
+        assert!(state.blocks["new-1"].delta.as_ref().unwrap().contains("Hello"));
+
+        // 같은 매핑으로 다시 적용하면 id가 겹치므로 실패해야 함
+        assert!(doc.apply_update_remapped(template_state, id_map.to_string()).is_err());
+        assert_eq!(doc.get_document_state().unwrap().blocks.len(), 3);
+    }
+
+    #[test]
+    fn test_attachment_budget() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![block_action("img", None, None, None)]).unwrap();
+
+        doc.set_attachment_budget(Some(10));
+        doc.set_block_attachment("img".to_string(), "thumb".to_string(), vec![0; 8]).unwrap();
+        assert_eq!(doc.attachment_total_bytes().unwrap(), 8);
+
+        // 같은 키를 교체하는 경우 기존 첨부 크기는 빼고 계산
+        doc.set_block_attachment("img".to_string(), "thumb".to_string(), vec![0; 10]).unwrap();
+        assert!(doc.set_block_attachment("img".to_string(), "full".to_string(), vec![0; 1]).is_err());
+        assert_eq!(doc.attachment_total_bytes().unwrap(), 10);
+    }
+
+    #[test]
+    fn test_state_as_of() {
+        let mut doc = DocumentService {
+            doc: Doc::with_options(yrs::Options { skip_gc: true, ..Default::default() }),
+            ..DocumentService::new()
+        };
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("gone", Some("page"), None, None),
+        ]).unwrap();
+        let recorded = doc.take_snapshot().unwrap();
+
+        doc.apply_action(vec![block_action("later", Some("page"), Some("gone"), None)]).unwrap();
+        doc.apply_action(vec![BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action("gone", Some("page"), None, None) }]).unwrap();
+
+        // 기록 이후의 추가는 빠지고, 기록 이후의 삭제는 되돌려진 상태로 보여야 함
+        let past = doc.state_as_of(recorded.clone()).unwrap();
+        assert!(past.blocks.contains_key("page"));
+        assert!(past.blocks.contains_key("gone"));
+        assert!(!past.blocks.contains_key("later"));
+        assert!(!doc.get_document_state().unwrap().blocks.contains_key("gone"));
+
+        // state vector는 스냅샷이 아니므로 받지 않음
+        let err = doc.state_as_of(doc.encode_state_vector().unwrap()).unwrap_err();
+        assert!(err.message.contains("not a state vector"), "{}", err.message);
+
+        // GC가 켜진 문서에서는 에러
+        let gc_doc = DocumentService::new();
+        assert!(gc_doc.state_as_of(recorded).is_err());
+    }
+
+    #[test]
+    fn test_find_oversized_blocks_counts_characters() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("ko", None, None, Some(r#"[{"insert":"안녕하세요"}]"#)),
+            block_action("en", None, Some("ko"), Some(r#"[{"insert":"hello world"}]"#)),
+        ]).unwrap();
+
+        // "안녕하세요"는 15바이트지만 5글자
+        assert_eq!(doc.find_oversized_blocks(5).unwrap(), vec!["en".to_string()]);
+        assert!(doc.find_oversized_blocks(20).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_text_ops() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("p".to_string()).unwrap();
+        doc.apply_action(vec![block_action("p", None, None, Some(r#"[{"insert":"Hello world"}]"#))]).unwrap();
+
+        doc.apply_text_ops("p".to_string(), r#"[{"retain":5},{"insert":","},{"retain":1},{"delete":5},{"insert":"there"}]"#.to_string()).unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.blocks["p"].delta.as_deref(), Some(r#"[{"insert":"Hello, there"}]"#));
+
+        // 범위를 벗어나는 delete가 있으면 앞의 연산도 적용되지 않음
+        assert!(doc.apply_text_ops("p".to_string(), r#"[{"insert":"X"},{"retain":3},{"delete":50}]"#.to_string()).is_err());
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.blocks["p"].delta.as_deref(), Some(r#"[{"insert":"Hello, there"}]"#));
+
+        // 텍스트가 없는 블록에 거부된 연산은 빈 텍스트도 만들지 않음
+        doc.apply_action(vec![block_action("q", Some("p"), None, None)]).unwrap();
+        let before = doc.encode_state_vector().unwrap();
+        assert!(doc.apply_text_ops("q".to_string(), r#"[{"retain":1}]"#.to_string()).is_err());
+        assert_eq!(doc.encode_state_vector().unwrap(), before);
+        assert_eq!(doc.get_block("q".to_string()).unwrap().unwrap().delta, None);
+    }
+
+    #[test]
+    fn test_can_merge_blocks() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"A"}]"#)),
+            block_action("b", Some("a"), None, Some(r#"[{"insert":"B"}]"#)),
+            block_action("divider", None, Some("a"), None),
+        ]).unwrap();
+
+        assert!(!doc.can_merge_blocks("b".to_string(), "a".to_string()).unwrap());
+        assert!(doc.can_merge_blocks("a".to_string(), "b".to_string()).unwrap());
+        assert!(!doc.can_merge_blocks("a".to_string(), "divider".to_string()).unwrap());
+        assert!(!doc.can_merge_blocks("a".to_string(), "missing".to_string()).unwrap());
+
+        // 타입 호환: 서식 있는 텍스트 블록끼리, code는 code끼리만
+        let typed = |id: &str, ty: &str, prev: &str| {
+            let mut action = block_action(id, None, Some(prev), Some(r#"[{"insert":"x"}]"#));
+            action.block.ty = ty.to_string();
+            action
+        };
+        doc.apply_action(vec![
+            typed("heading", "heading", "divider"),
+            typed("code", "code", "heading"),
+            typed("code2", "code", "code"),
+            typed("equation", "math_equation", "code2"),
+        ]).unwrap();
+        assert!(doc.can_merge_blocks("a".to_string(), "heading".to_string()).unwrap());
+        assert!(doc.can_merge_blocks("code".to_string(), "code2".to_string()).unwrap());
+        assert!(!doc.can_merge_blocks("heading".to_string(), "code".to_string()).unwrap());
+        assert!(!doc.can_merge_blocks("code2".to_string(), "a".to_string()).unwrap());
+        assert!(!doc.can_merge_blocks("a".to_string(), "equation".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_merge_blocks() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello "}]"#)),
+            block_action("a1", Some("a"), None, Some(r#"[{"insert":"a child"}]"#)),
+            block_action("b", Some("page"), Some("a"), Some(r#"[{"insert":"wor","attributes":{"bold":true}},{"insert":{"image":"cat.png"}},{"insert":"ld"}]"#)),
+            block_action("b1", Some("b"), None, None),
+            block_action("b2", Some("b"), Some("b1"), None),
+            block_action("c", Some("page"), Some("b"), Some(r#"[{"insert":"C"}]"#)),
+            block_action("divider", Some("page"), Some("c"), None),
+        ]).unwrap();
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+
+        let update = doc.merge_blocks("a".to_string(), "b".to_string()).unwrap();
+        assert_eq!(doc.get_block_plain_text("a".to_string()).unwrap(), "Hello wor\u{FFFC}ld");
+        let delta: JsonValue = serde_json::from_str(&doc.get_block("a".to_string()).unwrap().unwrap().delta.unwrap()).unwrap();
+        assert_eq!(delta[1], json!({"insert": "wor", "attributes": {"bold": true}}));
+        assert!(doc.get_block("b".to_string()).unwrap().is_none());
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.children_map["a"], vec!["a1".to_string(), "b1".to_string(), "b2".to_string()]);
+        assert_eq!(state.children_map["page"], vec!["a".to_string(), "c".to_string(), "divider".to_string()]);
+
+        // can_merge_blocks와 같은 검사로 거부하고 아무것도 바꾸지 않음
+        let before = doc.encode_state_vector().unwrap();
+        assert!(doc.merge_blocks("c".to_string(), "divider".to_string()).is_err());
+        assert!(doc.merge_blocks("a1".to_string(), "a".to_string()).is_err());
+        assert!(doc.merge_blocks("a".to_string(), "b".to_string()).is_err());
+        assert_eq!(doc.encode_state_vector().unwrap(), before);
+
+        peer.apply_updates(vec![update]).unwrap();
+        assert_eq!(peer.get_block_plain_text("a".to_string()).unwrap(), "Hello wor\u{FFFC}ld");
+        assert_eq!(peer.get_document_state().unwrap().children_map["a"], state.children_map["a"]);
+    }
+
+    #[test]
+    fn test_export_structure() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("h1", Some("page"), None, Some(r#"[{"insert":"Title"}]"#)),
+            block_action("p1", Some("page"), Some("h1"), Some(r#"[{"insert":"Body"}]"#)),
+            block_action("child", Some("p1"), None, None),
+        ]).unwrap();
+
+        let json: JsonValue = serde_json::from_str(&doc.export_structure().unwrap()).unwrap();
+        assert_eq!(json["id"], "page");
+        assert_eq!(json["children"][0]["id"], "h1");
+        assert_eq!(json["children"][1]["id"], "p1");
+        assert_eq!(json["children"][1]["children"][0]["id"], "child");
+        assert!(!doc.export_structure().unwrap().contains("Body"));
+    }
+
+    #[test]
+    fn test_apply_updates_with_changes() {
+        let mut local = DocumentService::new();
+        local.init_empty_doc().unwrap();
+        local.apply_action(vec![
+            block_action("keep", None, None, Some(r#"[{"insert":"a"}]"#)),
+            block_action("gone", None, Some("keep"), None),
+        ]).unwrap();
+        let base = local.encode_full_state().unwrap();
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![base.clone()]).unwrap();
+        remote.apply_text_ops("keep".to_string(), r#"[{"insert":"b"}]"#.to_string()).unwrap();
+        remote.apply_action(vec![
+            BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action("gone", None, None, None) },
+            block_action("new", None, Some("keep"), None),
+            block_action("temp", None, None, None),
+            BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action("temp", None, None, None) },
+        ]).unwrap();
+        let remote_state = remote.encode_full_state().unwrap();
+
+        let changes: JsonValue = serde_json::from_str(
+            &local.apply_updates_with_changes(vec![base, remote_state]).unwrap()
+        ).unwrap();
+        assert_eq!(changes["keep"], "text_changed");
+        assert_eq!(changes["gone"], "deleted");
+        assert_eq!(changes["new"], "inserted");
+        assert!(changes.get("temp").is_none());
+
+        // 속성 변경은 data_changed, 같은 값을 다시 쓰면 변경 없음
+        let set_level = || {
+            let mut action = block_action("new", None, None, None);
+            action.action = BlockActionTypeDoc::Update;
+            action.block.attributes.insert("level".to_string(), "2".to_string());
+            action
+        };
+        remote.apply_action(vec![set_level()]).unwrap();
+        let changes: JsonValue = serde_json::from_str(
+            &local.apply_updates_with_changes(vec![remote.encode_full_state().unwrap()]).unwrap()
+        ).unwrap();
+        assert_eq!(changes, json!({"new": "data_changed"}));
+
+        let again = remote.apply_action(vec![set_level()]).unwrap();
+        assert_eq!(local.apply_updates_with_changes(vec![again]).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_apply_updates_returns_affected_blocks() {
+        let mut local = DocumentService::new();
+        local.init_empty_doc().unwrap();
+        local.apply_action(vec![
+            block_action("keep", None, None, Some(r#"[{"insert":"a"}]"#)),
+            block_action("gone", None, Some("keep"), None),
+        ]).unwrap();
+        let base = local.encode_full_state().unwrap();
+
+        let mut remote = DocumentService::new();
+        let changes = remote.apply_updates(vec![base.clone()]).unwrap();
+        assert_eq!(changes.inserted_block_ids.len(), 2);
+
+        remote.apply_text_ops("keep".to_string(), r#"[{"insert":"b"}]"#.to_string()).unwrap();
+        remote.apply_action(vec![
+            BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action("gone", None, None, None) },
+            block_action("new", None, Some("keep"), None),
+        ]).unwrap();
+        let changes = local.apply_updates(vec![remote.encode_full_state().unwrap()]).unwrap();
+        assert_eq!(changes, AppliedBlockChanges {
+            inserted_block_ids: vec!["new".to_string()],
+            updated_block_ids: vec!["keep".to_string()],
+            deleted_block_ids: vec!["gone".to_string()],
+            failed_updates: FailedToDecodeUpdates::default(),
+        });
+
+        // 메타만 바뀌면 빈 목록
+        remote.set_meta_string("title".to_string(), "T".to_string()).unwrap();
+        let changes = local.apply_updates(vec![remote.encode_full_state().unwrap()]).unwrap();
+        assert_eq!(changes, AppliedBlockChanges::default());
+    }
+
+    #[test]
+    fn test_get_block() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"Hi"}]"#)),
+            block_action("b", Some("a"), None, None),
+        ]).unwrap();
+
+        let block = doc.get_block("b".to_string()).unwrap().unwrap();
+        assert_eq!(block.ty, "paragraph");
+        assert_eq!(block.parent_id.as_deref(), Some("a"));
+        assert_eq!(doc.get_block("a".to_string()).unwrap().unwrap().delta.as_deref(), Some(r#"[{"insert":"Hi"}]"#));
+        assert!(doc.get_block("missing".to_string()).unwrap().is_none());
+        assert!(DocumentService::new().get_block("a".to_string()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_detect_language() {
+        let detect = |texts: &[&str]| {
+            let mut doc = DocumentService::new();
+            doc.init_empty_doc().unwrap();
+            let actions = texts.iter().enumerate()
+                .map(|(i, text)| {
+                    let delta = serde_json::to_string(&json!([{ "insert": text }])).unwrap();
+                    block_action(&format!("b{}", i), None, None, Some(&delta))
+                })
+                .collect();
+            doc.apply_action(actions).unwrap();
+            doc.detect_language().unwrap()
+        };
+
+        assert_eq!(detect(&["안녕하세요, 오늘은", "문서 동기화 기능을 테스트하고 있습니다."]), "ko");
+        assert_eq!(detect(&["今日はドキュメントの同期機能をテストしています。"]), "ja");
+        assert_eq!(detect(&["今天我们正在测试文档同步功能和编辑器的表现。"]), "zh");
+        assert_eq!(detect(&["Сегодня мы тестируем синхронизацию документов."]), "ru");
+        assert_eq!(detect(&["This is a note about the editor", "and the sync plugin."]), "en");
+        assert_eq!(detect(&["Der Editor ist nicht mit dem Server verbunden und die Daten fehlen."]), "de");
+        assert_eq!(detect(&["La sincronización de los documentos es rápida y con pocos errores."]), "es");
+        assert_eq!(detect(&["Hi there"]), "und");
+        assert_eq!(detect(&[]), "und");
+    }
+
+    #[test]
+    fn test_extract_delete_set() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        let insert = doc.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"Hello"}]"#))]).unwrap();
+        let empty: JsonValue = serde_json::from_str(&DocumentService::extract_delete_set(insert).unwrap()).unwrap();
+        assert_eq!(empty["encoding"], "v2");
+        assert_eq!(empty["has_deletions"], false);
+        assert!(empty["clients"].as_object().unwrap().is_empty());
+
+        let delete = doc.apply_text_ops("a".to_string(), r#"[{"retain":1},{"delete":3}]"#.to_string()).unwrap();
+        let parsed: JsonValue = serde_json::from_str(&DocumentService::extract_delete_set(delete).unwrap()).unwrap();
+        assert_eq!(parsed["has_deletions"], true);
+        let client = doc.doc.client_id().to_string();
+        assert_eq!(parsed["clients"][&client].as_array().unwrap().len(), 1);
+
+        // v1 인코딩도 지원
+        let v1 = doc.doc.transact().encode_state_as_update_v1(&StateVector::default());
+        let parsed: JsonValue = serde_json::from_str(&DocumentService::extract_delete_set(v1).unwrap()).unwrap();
+        assert_eq!(parsed["encoding"], "v1");
+        assert_eq!(parsed["has_deletions"], true);
+
+        assert!(DocumentService::extract_delete_set(vec![0xff]).is_err());
+        assert!(DocumentService::extract_delete_set(vec![0xff; 20]).is_err());
+    }
+
+    #[test]
+    fn test_get_children_ids() {
+        let mut doc = DocumentService::new();
+        assert!(doc.get_children_ids("root".to_string()).unwrap().is_empty());
+        assert!(doc.get_children_ids("page".to_string()).is_err());
+
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("c", Some("page"), Some("a"), None),
+            block_action("b", Some("page"), Some("a"), None),
+            block_action("a1", Some("a"), None, None),
+        ]).unwrap();
+
+        doc.set_root_node_id("page".to_string()).unwrap();
+        let expected = doc.get_document_state().unwrap().children_map["page"].clone();
+        assert_eq!(expected.len(), 3);
+        assert_eq!(doc.get_children_ids("page".to_string()).unwrap(), expected);
+        assert_eq!(doc.get_children_ids("a".to_string()).unwrap(), vec!["a1".to_string()]);
+        assert_eq!(doc.get_children_ids("root".to_string()).unwrap(), vec!["page".to_string()]);
+        assert!(doc.get_children_ids("a1".to_string()).unwrap().is_empty());
+        assert!(doc.get_children_ids("missing".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_document_state_cache() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        let builds = |doc: &DocumentService| doc.state_cache.lock().unwrap().builds;
+
+        // 변경 없이 반복해서 읽으면 다시 만들지 않음
+        assert_eq!(doc.get_document_state().unwrap().blocks.len(), 1);
+        doc.get_document_state().unwrap();
+        assert_eq!(builds(&doc), 1);
+
+        doc.apply_action(vec![block_action("a", Some("page"), None, None)]).unwrap();
+        assert!(doc.get_document_state().unwrap().blocks.contains_key("a"));
+        doc.get_document_state().unwrap();
+        assert_eq!(builds(&doc), 2);
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        peer.apply_action(vec![block_action("b", Some("page"), Some("a"), None)]).unwrap();
+        doc.apply_updates(vec![peer.encode_full_state().unwrap()]).unwrap();
+        assert!(doc.get_document_state().unwrap().blocks.contains_key("b"));
+        assert_eq!(builds(&doc), 3);
+
+        // 메타만 바뀌어도 무효화됨
+        doc.set_meta_string("title".to_string(), "T".to_string()).unwrap();
+        doc.get_document_state().unwrap();
+        doc.set_meta_bool("pinned".to_string(), true).unwrap();
+        doc.get_document_state().unwrap();
+        assert_eq!(builds(&doc), 5);
+
+        // 같은 업데이트를 다시 적용하면 변경이 없으므로 캐시 유지
+        doc.apply_updates(vec![peer.encode_full_state().unwrap()]).unwrap();
+        doc.get_document_state().unwrap();
+        assert_eq!(builds(&doc), 5);
+    }
+
+    #[test]
+    fn test_apply_delta_merges_concurrent_inserts() {
+        let mut alice = DocumentService::new();
+        alice.init_empty_doc().unwrap();
+        alice.set_root_node_id("page".to_string()).unwrap();
+        alice.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("p", Some("page"), None, Some(r#"[{"insert":"ac"}]"#)),
+        ]).unwrap();
+        let mut bob = DocumentService::new();
+        bob.apply_updates(vec![alice.encode_full_state().unwrap()]).unwrap();
+
+        // 같은 위치에 동시에 삽입해도 둘 다 남아야 함
+        let from_alice = alice.apply_delta("p".to_string(), r#"[{"retain":1},{"insert":"X","attributes":{"bold":true}}]"#.to_string()).unwrap();
+        let from_bob = bob.apply_delta("p".to_string(), r#"[{"retain":1},{"insert":"Y"}]"#.to_string()).unwrap();
+        alice.apply_updates(vec![from_bob]).unwrap();
+        bob.apply_updates(vec![from_alice]).unwrap();
+
+        let text = |doc: &DocumentService| {
+            let deltas: Vec<JsonValue> = serde_json::from_str(doc.get_document_state().unwrap().blocks["p"].delta.as_deref().unwrap()).unwrap();
+            deltas.iter().map(|d| d["insert"].as_str().unwrap().to_string()).collect::<String>()
+        };
+        assert_eq!(text(&alice).len(), 4);
+        assert!(text(&alice).contains('X') && text(&alice).contains('Y'));
+        assert_eq!(text(&alice), text(&bob));
+
+        assert!(alice.apply_delta("missing".to_string(), r#"[{"insert":"a"}]"#.to_string()).is_err());
+        assert!(alice.apply_delta("page".to_string(), r#"[{"insert":"a"}]"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_get_block_plain_text() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"Hello ","attributes":{"bold":true}},{"insert":"world\nagain"}]"#)),
+            block_action("b", None, Some("a"), None),
+        ]).unwrap();
+
+        assert_eq!(doc.get_block_plain_text("a".to_string()).unwrap(), "Hello world\nagain");
+        assert_eq!(doc.get_block_plain_text("b".to_string()).unwrap(), "");
+        assert!(doc.get_block_plain_text("missing".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_inline_embed_in_delta() {
+        let mut doc = DocumentService::new();
+        let init = doc.init_empty_doc().unwrap();
+        let pointer = doc.set_root_node_id("page".to_string()).unwrap();
+        let setup = doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello "},{"insert":{"image":"https://example.com/cat.png","width":120}},{"insert":" world"}]"#)),
+        ]).unwrap();
+        let edit = doc.apply_text_ops("a".to_string(), r#"[{"retain":7},{"insert":"!"}]"#.to_string()).unwrap();
+
+        assert_eq!(doc.get_block_plain_text("a".to_string()).unwrap(), "Hello \u{FFFC}! world");
+        let delta: JsonValue = serde_json::from_str(&doc.get_block("a".to_string()).unwrap().unwrap().delta.unwrap()).unwrap();
+        assert_eq!(delta, json!([
+            {"insert": "Hello "},
+            {"insert": {"image": "https://example.com/cat.png", "width": 120.0}},
+            {"insert": "! world"},
+        ]));
+
+        // 병합 후에도 임베드가 유지되고 내보내기에 포함됨
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![init, pointer, setup, edit]).unwrap();
+        assert_eq!(peer.get_block_plain_text("a".to_string()).unwrap(), "Hello \u{FFFC}! world");
+        let exported: JsonValue = serde_json::from_str(&peer.export_appflowy_json().unwrap()).unwrap();
+        assert_eq!(exported["document"]["children"][0]["delta"][1]["insert"]["image"], json!("https://example.com/cat.png"));
+
+        assert!(doc.apply_text_ops("a".to_string(), r#"[{"insert":42}]"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_get_document_plain_text() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"First","attributes":{"bold":true}}]"#)),
+            block_action("divider", Some("page"), Some("a"), None),
+            block_action("b", Some("page"), Some("divider"), Some(r#"[{"insert":"Third"}]"#)),
+            block_action("a1", Some("a"), None, Some(r#"[{"insert":"Nested"}]"#)),
+        ]).unwrap();
+
+        assert_eq!(doc.get_document_plain_text().unwrap(), "First\nNested\nThird");
+
+        doc.apply_delta("b".to_string(), r#"[{"insert":{"formula":"x"}}]"#.to_string()).unwrap();
+        assert_eq!(doc.get_document_plain_text().unwrap(), "First\nNested\n\u{FFFC}Third");
+    }
+
+    #[test]
+    fn test_search_text() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Aaa ","attributes":{"bold":true}},{"insert":"b"}]"#)),
+            block_action("b", Some("page"), Some("a"), Some(r#"[{"insert":"한글 aa"}]"#)),
+        ]).unwrap();
+
+        let found = doc.search_text("aa".to_string(), false).unwrap();
+        assert_eq!(found, vec![
+            TextMatch { block_id: "a".to_string(), offset: 0, length: 2 },
+            TextMatch { block_id: "a".to_string(), offset: 1, length: 2 },
+            TextMatch { block_id: "b".to_string(), offset: 3, length: 2 },
+        ]);
+        assert_eq!(doc.search_text("aa".to_string(), true).unwrap().len(), 2);
+        assert!(doc.search_text("".to_string(), false).unwrap().is_empty());
+
+        // 임베드 뒤의 일치 위치는 임베드를 한 글자로 셈
+        doc.apply_action(vec![
+            block_action("c", Some("page"), Some("b"), Some(r#"[{"insert":{"image":"cat.png"}},{"insert":"aa"}]"#)),
+        ]).unwrap();
+        let found = doc.search_text("aa".to_string(), true).unwrap();
+        assert_eq!(found.last(), Some(&TextMatch { block_id: "c".to_string(), offset: 1, length: 2 }));
+        assert_eq!(doc.get_block_plain_text("c".to_string()).unwrap().chars().skip(1).take(2).collect::<String>(), "aa");
+        assert!(doc.search_text("zzz".to_string(), false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_replace_all() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello "},{"insert":"World","attributes":{"bold":true}},{"insert":" world"}]"#)),
+            block_action("b", Some("page"), Some("a"), Some(r#"[{"insert":"세계 aaa"}]"#)),
+        ]).unwrap();
+
+        let update = doc.replace_all("world".to_string(), "there".to_string(), false).unwrap();
+        assert!(!update.is_empty());
+        assert_eq!(doc.last_replace_count(), 2);
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(
+            state.blocks["a"].delta.as_deref(),
+            Some(r#"[{"insert":"Hello "},{"attributes":{"bold":true},"insert":"there"},{"insert":" there"}]"#)
+        );
+
+        // 겹치는 일치는 앞쪽 것만 바꿈
+        doc.replace_all("aa".to_string(), "b".to_string(), true).unwrap();
+        assert_eq!(doc.last_replace_count(), 1);
+        assert_eq!(doc.get_block_plain_text("b".to_string()).unwrap(), "세계 ba");
+
+        doc.replace_all("".to_string(), "x".to_string(), true).unwrap();
+        assert_eq!(doc.last_replace_count(), 0);
+    }
+
+    #[test]
+    fn test_doc_id() {
+        let mut doc = DocumentService::new_with_id("note-1".to_string());
+        assert_eq!(doc.doc_id(), "note-1");
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        assert_eq!(doc.get_document_state().unwrap().doc_id, "note-1");
+
+        let first = DocumentService::new().doc_id();
+        assert_eq!(first.len(), 36);
+        assert_ne!(first, DocumentService::new().doc_id());
+    }
+
+    #[test]
+    fn test_state_vector_sync() {
+        let mut server = DocumentService::new();
+        server.init_empty_doc().unwrap();
+        server.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"Hello"}]"#))]).unwrap();
+        let mut client = DocumentService::new();
+        client.apply_updates(vec![server.encode_full_state().unwrap()]).unwrap();
+
+        client.apply_action(vec![block_action("b", None, Some("a"), None)]).unwrap();
+        let diff = client.encode_diff_since(server.encode_state_vector().unwrap()).unwrap();
+        assert!(diff.len() < client.encode_full_state().unwrap().len());
+
+        server.apply_updates(vec![diff]).unwrap();
+        assert!(server.get_block("b".to_string()).unwrap().is_some());
+        assert_eq!(server.encode_state_vector().unwrap(), client.encode_state_vector().unwrap());
+
+        assert!(server.encode_diff_since(vec![0xff]).is_err());
+    }
+
+    #[test]
+    fn test_estimate_diff_size() {
+        let mut server = DocumentService::new();
+        server.init_empty_doc().unwrap();
+        server.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"Hello"}]"#))]).unwrap();
+        let mut client = DocumentService::new();
+        client.apply_updates(vec![server.encode_full_state().unwrap()]).unwrap();
+        assert_eq!(client.estimate_diff_size(server.encode_state_vector().unwrap()).unwrap(), 0);
+
+        client.apply_action(vec![block_action("b", None, Some("a"), None)]).unwrap();
+        let server_sv = server.encode_state_vector().unwrap();
+        let estimate = client.estimate_diff_size(server_sv.clone()).unwrap();
+        assert_eq!(estimate, client.encode_diff_since(server_sv.clone()).unwrap().len());
+        assert!(estimate > 0);
+
+        // 삭제만 빠진 상대는 state vector로 구분할 수 없으므로 0
+        server.apply_updates(vec![client.encode_diff_since(server_sv).unwrap()]).unwrap();
+        client.apply_text_ops("a".to_string(), r#"[{"delete":2}]"#.to_string()).unwrap();
+        assert_eq!(client.estimate_diff_size(server.encode_state_vector().unwrap()).unwrap(), 0);
+
+        assert!(client.estimate_diff_size(vec![0xff]).is_err());
+    }
+
+    #[test]
+    fn test_clear_document() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello"}]"#)),
+        ]).unwrap();
+        doc.set_meta_string("title".to_string(), "T".to_string()).unwrap();
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+
+        let update = doc.clear_document().unwrap();
+        assert!(doc.get_document_state().unwrap().blocks.is_empty());
+        assert_eq!(doc.get_meta_string("title".to_string()).unwrap(), None);
+
+        peer.apply_updates(vec![update]).unwrap();
+        assert!(peer.get_document_state().unwrap().blocks.is_empty());
+        assert_eq!(peer.get_meta_string("title".to_string()).unwrap(), None);
+
+        // 다시 초기화하지 않고 계속 편집 가능
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        assert_eq!(doc.get_document_state().unwrap().blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut doc = DocumentService::new_with_gc_disabled();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello"}]"#)),
+            block_action("gone", Some("page"), Some("a"), None),
+        ]).unwrap();
+        doc.set_meta_string("title".to_string(), "v1".to_string()).unwrap();
+        doc.set_meta_from_json(r#"{"settings":{"font":"serif","size":12}}"#.to_string()).unwrap();
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let snapshot = doc.take_snapshot().unwrap();
+
+        doc.apply_text_ops("a".to_string(), r#"[{"retain":5},{"insert":" world"}]"#.to_string()).unwrap();
+        doc.apply_action(vec![
+            BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action("gone", None, None, None) },
+            block_action("later", Some("page"), Some("a"), None),
+        ]).unwrap();
+        doc.set_meta_string("title".to_string(), "v2".to_string()).unwrap();
+        doc.set_meta_bool("pinned".to_string(), true).unwrap();
+        doc.set_meta_from_json(r#"{"settings":{"size":14,"theme":"dark"}}"#.to_string()).unwrap();
+
+        let update = doc.restore_snapshot(snapshot.clone()).unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.blocks.len(), 3);
+        assert!(state.blocks.contains_key("gone") && !state.blocks.contains_key("later"));
+        assert_eq!(state.blocks["a"].delta.as_deref(), Some(r#"[{"insert":"Hello"}]"#));
+        assert_eq!(doc.get_meta_string("title".to_string()).unwrap().as_deref(), Some("v1"));
+        assert_eq!(doc.get_meta_bool("pinned".to_string()).unwrap(), None);
+        // 중첩 메타는 필드 단위로 되돌려지고 YMap으로 남음
+        let meta: JsonValue = serde_json::from_str(&doc.get_all_meta().unwrap()).unwrap();
+        assert_eq!(meta["settings"], json!({"font": "serif", "size": 12}));
+        {
+            let txn = doc.doc.transact();
+            let meta_map = match txn.get_map(ROOT_ID).unwrap().get(&txn, META) {
+                Some(yrs::Out::YMap(meta_map)) => meta_map,
+                _ => panic!("meta map missing"),
+            };
+            assert!(matches!(meta_map.get(&txn, "settings"), Some(yrs::Out::YMap(_))));
+        }
+
+        // 스냅샷 이후 변경을 모두 받은 피어도 같은 상태가 됨
+        peer.apply_updates(vec![doc.encode_full_state().unwrap(), update]).unwrap();
+        let peer_state = peer.get_document_state().unwrap();
+        assert!(peer_state.blocks.contains_key("gone") && !peer_state.blocks.contains_key("later"));
+
+        // 다른 문서에서 만든 스냅샷은 현재 문서에 없는 항목을 가리키므로 에러
+        let mut other = DocumentService::new_with_gc_disabled();
+        other.init_empty_doc().unwrap();
+        assert!(other.restore_snapshot(snapshot).is_err());
+
+        assert!(DocumentService::new().take_snapshot().is_err());
+    }
+
+    #[test]
+    fn test_new_with_options() {
+        let gc_doc = DocumentService::new_with_options("note".to_string(), true);
+        assert!(gc_doc.gc_enabled());
+        assert_eq!(gc_doc.doc_id(), "note");
+        let err = gc_doc.take_snapshot().unwrap_err();
+        assert!(err.message.contains("GC disabled"));
+
+        let history_doc = DocumentService::new_with_options("note".to_string(), false);
+        assert!(!history_doc.gc_enabled());
+        assert!(history_doc.take_snapshot().is_ok());
+
+        assert!(DocumentService::new().gc_enabled());
+        assert!(!DocumentService::new_with_gc_disabled().gc_enabled());
+    }
+
+    #[test]
+    fn test_v1_variants() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"Hello"}]"#))]).unwrap();
+        let first = doc.encode_full_state_v1().unwrap();
+        let sv = doc.encode_state_vector().unwrap();
+        doc.apply_action(vec![block_action("b", None, Some("a"), None)]).unwrap();
+        let second = doc.doc.transact().encode_state_as_update_v1(&StateVector::decode_v1(&sv).unwrap());
+
+        let merged = doc.merge_updates_v1(vec![first, second]).unwrap();
+        let mut peer = DocumentService::new();
+        let changes = peer.apply_updates_v1(vec![merged, vec![0xff]]).unwrap();
+        assert_eq!(changes.inserted_block_ids.len(), 2);
+        assert_eq!(peer.encode_state_vector().unwrap(), doc.encode_state_vector().unwrap());
+        assert!(peer.get_block("b".to_string()).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_nested_meta_round_trip() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        let meta = json!({
+            "cover": { "type": "color", "value": "#fff", "offset": { "x": 1, "y": 2.5 } },
+            "sections": [{ "id": "s1", "tags": ["a", "b"] }, [1, true], "plain"],
+            "title": "노트"
+        });
+        doc.set_meta_from_json(meta.to_string()).unwrap();
+        let loaded: JsonValue = serde_json::from_str(&doc.get_all_meta().unwrap()).unwrap();
+        assert_eq!(loaded, meta);
+
+        // 중첩 객체는 필드 단위로 병합되어 동시 수정이 모두 남음
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let local = doc.set_meta_from_json(r##"{"cover": {"value": "#000"}}"##.to_string()).unwrap();
+        let remote = peer.set_meta_from_json(r#"{"cover": {"type": "image", "offset": null}}"#.to_string()).unwrap();
+        doc.apply_updates(vec![remote]).unwrap();
+        peer.apply_updates(vec![local]).unwrap();
+        let expected = json!({ "type": "image", "value": "#000" });
+        let merged: JsonValue = serde_json::from_str(&doc.get_all_meta().unwrap()).unwrap();
+        assert_eq!(merged["cover"], expected);
+        let peer_merged: JsonValue = serde_json::from_str(&peer.get_all_meta().unwrap()).unwrap();
+        assert_eq!(peer_merged["cover"], expected);
+
+        let diff: JsonValue = serde_json::from_str(
+            &doc.meta_diff_against_json(r#"{"cover": {"type": "image"}}"#.to_string()).unwrap()
+        ).unwrap();
+        assert!(diff["changed"].as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_meta_change_listener() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        let received: Arc<Mutex<Vec<JsonValue>>> = Arc::default();
+        let sink = received.clone();
+        doc.set_meta_listener(Arc::new(move |json| sink.lock().unwrap().push(serde_json::from_str(&json).unwrap())));
+
+        // META 맵이 처음 만들어지는 변경도 전달됨
+        doc.set_meta_string("title".to_string(), "노트".to_string()).unwrap();
+        doc.set_meta_from_json(r#"{"color": 3, "cover": {"type": "color"}}"#.to_string()).unwrap();
+        doc.set_meta_from_json(r##"{"cover": {"value": "#fff"}, "color": null}"##.to_string()).unwrap();
+        // 블록 변경은 무시
+        doc.apply_action(vec![block_action("a", None, None, None)]).unwrap();
+
+        // 원격에서 온 메타 변경도 전달됨
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let remote = peer.set_meta_bool("pinned".to_string(), true).unwrap();
+        doc.apply_updates(vec![remote]).unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(*received, vec![
+            json!({ "title": "노트" }),
+            json!({ "color": 3, "cover": { "type": "color" } }),
+            json!({ "color": null, "cover": { "type": "color", "value": "#fff" } }),
+            json!({ "pinned": true }),
+        ]);
+    }
+
+    #[test]
+    fn test_set_meta_batch() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_meta_string("draft".to_string(), "yes".to_string()).unwrap();
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+
+        let update = doc.set_meta_batch(vec![
+            MetaOp::SetString { key: "title".to_string(), value: "노트".to_string() },
+            MetaOp::SetInt { key: "color".to_string(), value: 7 },
+            MetaOp::SetBool { key: "pinned".to_string(), value: true },
+            MetaOp::SetStringArray { key: "labelIds".to_string(), values: vec!["a".to_string(), "b".to_string()] },
+            MetaOp::PushArrayItem { key: "labelIds".to_string(), value: "a".to_string() },
+            MetaOp::PushArrayItem { key: "labelIds".to_string(), value: "c".to_string() },
+            MetaOp::RemoveArrayItem { key: "labelIds".to_string(), value: "b".to_string() },
+            MetaOp::Remove { key: "draft".to_string() },
+        ]).unwrap();
+
+        let expected = json!({ "title": "노트", "color": 7, "pinned": true, "labelIds": ["a", "c"] });
+        assert_eq!(serde_json::from_str::<JsonValue>(&doc.get_all_meta().unwrap()).unwrap(), expected);
+
+        // 업데이트 하나에 모든 변경이 담김
+        peer.apply_updates(vec![update]).unwrap();
+        assert_eq!(serde_json::from_str::<JsonValue>(&peer.get_all_meta().unwrap()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_validate_document() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("b", Some("page"), Some("a"), None),
+        ]).unwrap();
+        assert_eq!(doc.validate_document().unwrap(), DocumentValidation::default());
+
+        // 동시 편집으로 깨진 구조를 직접 기록
+        {
+            let mut txn = doc.doc.transact_mut();
+            let blocks_map = UpdateOperations::blocks_map(&txn).unwrap();
+            let mut write = |id: &str, parent: Option<&str>, prev: Option<&str>| {
+                let block = block_action(id, parent, prev, None).block;
+                BlockOperations::write_block(&mut txn, &blocks_map, &block).unwrap();
+            };
+            write("orphan", Some("deleted"), None);
+            write("x", Some("y"), None);
+            write("y", Some("x"), None);
+            write("under_cycle", Some("x"), None);
+            write("c", Some("page"), Some("gone"));
+        }
+
+        let validation = doc.validate_document().unwrap();
+        assert_eq!(validation.orphaned_block_ids, vec!["orphan".to_string()]);
+        assert_eq!(validation.cyclic_block_ids, vec!["x".to_string(), "y".to_string()]);
+        assert_eq!(validation.dangling_reference_ids, vec!["c".to_string()]);
+    }
+
+    #[test]
+    fn test_repair_document() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+        ]).unwrap();
+        {
+            let mut txn = doc.doc.transact_mut();
+            let blocks_map = UpdateOperations::blocks_map(&txn).unwrap();
+            for (id, parent, prev) in [
+                ("orphan", Some("deleted"), None),
+                ("x", Some("y"), None),
+                ("y", Some("x"), None),
+                ("c", Some("page"), Some("gone")),
+            ] {
+                let block = block_action(id, parent, prev, None).block;
+                BlockOperations::write_block(&mut txn, &blocks_map, &block).unwrap();
+            }
+        }
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+
+        let update = doc.repair_document().unwrap();
+        assert_eq!(doc.validate_document().unwrap(), DocumentValidation::default());
+        let c = doc.get_block("c".to_string()).unwrap().unwrap();
+        assert_eq!((c.parent_id.as_deref(), c.prev_id), (Some("page"), None));
+        let x = doc.get_block("x".to_string()).unwrap().unwrap();
+        assert_eq!(x.parent_id, None);
+        let orphan = doc.get_block("orphan".to_string()).unwrap().unwrap();
+        assert_eq!((orphan.parent_id, orphan.prev_id.as_deref()), (None, Some("page")));
+
+        // 업데이트를 받은 피어도 같은 구조로 수렴
+        peer.apply_updates(vec![update]).unwrap();
+        assert_eq!(peer.validate_document().unwrap(), DocumentValidation::default());
+
+        // 두 번째 실행은 변경 없음
+        let before = doc.encode_full_state().unwrap();
+        doc.repair_document().unwrap();
+        assert_eq!(before, doc.encode_full_state().unwrap());
+    }
+
+    #[test]
+    fn test_diff_states_replays_changes() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"hello"}]"#)),
+            block_action("b", Some("page"), Some("a"), None),
+            block_action("c", Some("page"), Some("b"), None),
+            block_action("c1", Some("c"), None, None),
+            block_action("d", Some("page"), Some("c"), None),
+        ]).unwrap();
+        let old_state = doc.encode_full_state().unwrap();
+
+        let mut move_d = block_action("d", Some("page"), Some("a"), None);
+        move_d.action = BlockActionTypeDoc::Move;
+        move_d.block.old_parent_id = Some("page".to_string());
+        move_d.old_path = Some(vec![]);
+        let mut update_a = block_action("a", Some("page"), None, Some(r#"[{"retain":5},{"insert":" world"}]"#));
+        update_a.action = BlockActionTypeDoc::Update;
+        update_a.block.attributes.insert("checked".to_string(), "true".to_string());
+        let mut delete_c = block_action("c", Some("page"), None, None);
+        delete_c.action = BlockActionTypeDoc::Delete;
+        doc.apply_action(vec![
+            move_d,
+            update_a,
+            delete_c,
+            block_action("e", Some("page"), Some("b"), None),
+        ]).unwrap();
+        let new_state = doc.encode_full_state().unwrap();
+
+        let actions = DocumentService::diff_states(old_state.clone(), new_state).unwrap();
+        // a, b, d 중 순서가 바뀐 건 하나뿐이므로 Move는 하나만 생성
+        let summary: Vec<String> = actions.iter()
+            .map(|action| format!("{:?}", action.action))
+            .collect();
+        assert_eq!(summary, vec!["Move", "Insert", "Update", "Delete"]);
+        assert_eq!((actions[1].block.id.as_str(), actions[2].block.id.as_str(), actions[3].block.id.as_str()), ("e", "a", "c"));
+        assert_eq!(actions[2].block.attributes, HashMap::from([("checked".to_string(), "true".to_string())]));
+
+        // 이전 상태에 다시 적용하면 같은 문서가 됨
+        let mut replay = DocumentService::new();
+        replay.apply_updates(vec![old_state]).unwrap();
+        replay.apply_action(actions).unwrap();
+        let expected = doc.get_document_state().unwrap();
+        let replayed = replay.get_document_state().unwrap();
+        assert_eq!(replayed.children_map["page"], vec!["a", "d", "b", "e"]);
+        assert_eq!(replayed.children_map["page"], expected.children_map["page"]);
+        assert_eq!(replayed.blocks["a"].delta, expected.blocks["a"].delta);
+        assert!(!replayed.blocks.contains_key("c1"));
+    }
+
+    #[test]
+    fn test_content_hash_ignores_edit_order() {
+        let mut first = DocumentService::new();
+        first.init_empty_doc().unwrap();
+        first.set_root_node_id("page".to_string()).unwrap();
+        first.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        let base = first.encode_full_state().unwrap();
+        let mut second = DocumentService::new();
+        second.apply_updates(vec![base]).unwrap();
+
+        first.apply_action(vec![block_action("a", Some("page"), None, Some(r#"[{"insert":"hi"}]"#))]).unwrap();
+        first.set_meta_string("title".to_string(), "노트".to_string()).unwrap();
+
+        // 다른 순서로, 지웠다가 다시 쓰는 편집을 거쳐 같은 내용에 도달
+        second.set_meta_string("title".to_string(), "임시".to_string()).unwrap();
+        second.apply_action(vec![block_action("a", Some("page"), None, Some(r#"[{"insert":"hello"}]"#))]).unwrap();
+        second.apply_delta("a".to_string(), r#"[{"retain":1},{"delete":4},{"insert":"i"}]"#.to_string()).unwrap();
+        second.set_meta_string("title".to_string(), "노트".to_string()).unwrap();
+
+        assert_ne!(first.encode_full_state().unwrap(), second.encode_full_state().unwrap());
+        assert_eq!(first.content_hash().unwrap(), second.content_hash().unwrap());
+
+        second.set_meta_bool("pinned".to_string(), true).unwrap();
+        assert_ne!(first.content_hash().unwrap(), second.content_hash().unwrap());
+    }
+
+    #[test]
+    fn test_export_appflowy_json() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        let mut heading = block_action("h1", Some("page"), None, Some(r#"[{"insert":"Title","attributes":{"bold":true}}]"#));
+        heading.block.ty = "heading".to_string();
+        heading.block.attributes.insert("level".to_string(), "2".to_string());
+        let mut divider = block_action("div", Some("page"), Some("h1"), None);
+        divider.block.ty = "divider".to_string();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            heading,
+            divider,
+            block_action("child", Some("div"), None, Some(r#"[{"insert":"x"}]"#)),
+        ]).unwrap();
+
+        let json: JsonValue = serde_json::from_str(&doc.export_appflowy_json().unwrap()).unwrap();
+        assert_eq!(json["document"]["id"], "page");
+        assert_eq!(json["document"]["children"][0], json!({
+            "id": "h1",
+            "type": "heading",
+            "data": { "level": 2 },
+            "delta": [{ "insert": "Title", "attributes": { "bold": true } }],
+            "children": [],
+        }));
+        let divider = &json["document"]["children"][1];
+        assert!(divider.get("delta").is_none());
+        assert_eq!(divider["children"][0]["delta"], json!([{ "insert": "x" }]));
+        assert!(Conversion::validate_appflowy_document(&json).is_empty());
+    }
+
+    #[test]
+    fn test_import_appflowy_json_round_trip() {
+        let json = json!({
+            "document": {
+                "id": "page",
+                "type": "page",
+                "data": {},
+                "children": [
+                    {
+                        "id": "h1",
+                        "type": "heading",
+                        "data": { "level": 2 },
+                        "delta": [{ "insert": "Title", "attributes": { "bold": true } }],
+                        "children": [],
+                    },
+                    {
+                        "type": "todo_list",
+                        "data": { "checked": false },
+                        "delta": [{ "insert": "task" }],
+                        "children": [{ "id": "nested", "type": "paragraph", "data": {}, "delta": [], "children": [] }],
+                    },
+                ],
+            },
+        });
+
+        let mut doc = DocumentService::new();
+        let init = doc.init_empty_doc().unwrap();
+        let update = doc.import_appflowy_json(json.to_string()).unwrap();
+
+        let exported: JsonValue = serde_json::from_str(&doc.export_appflowy_json().unwrap()).unwrap();
+        let todo_id = exported["document"]["children"][1]["id"].as_str().unwrap().to_string();
+        let mut expected = json.clone();
+        expected["document"]["children"][1]["id"] = json!(todo_id);
+        assert_eq!(exported, expected);
+
+        // 업데이트만으로 피어에서도 같은 문서가 됨
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![init, update]).unwrap();
+        assert_eq!(peer.export_appflowy_json().unwrap(), doc.export_appflowy_json().unwrap());
+
+        // 이미 블록이 있는 문서와 루트 노드가 없는 JSON은 거부
+        assert!(doc.import_appflowy_json(json.to_string()).is_err());
+        let err = DocumentService::new().import_appflowy_json(r#"{"page": {}}"#.to_string()).unwrap_err();
+        assert!(err.message.contains("missing root node"));
+    }
+
+    #[test]
+    fn test_export_markdown() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        let typed = |id: &str, ty: &str, parent: &str, prev: Option<&str>, delta: &str, attrs: &[(&str, &str)]| {
+            let mut action = block_action(id, Some(parent), prev, Some(delta));
+            action.block.ty = ty.to_string();
+            for (k, v) in attrs {
+                action.block.attributes.insert(k.to_string(), v.to_string());
+            }
+            action
+        };
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            typed("h", "heading", "page", None, r#"[{"insert":"Plan"}]"#, &[("level", "2")]),
+            typed("p", "paragraph", "page", Some("h"), r#"[{"insert":"Mix "},{"insert":"bold","attributes":{"bold":true}},{"insert":" and "},{"insert":"code","attributes":{"code":true,"italic":true}}]"#, &[]),
+            typed("n1", "numbered_list", "page", Some("p"), r#"[{"insert":"one"}]"#, &[]),
+            typed("b1", "bulleted_list", "n1", None, r#"[{"insert":"nested"}]"#, &[]),
+            typed("n2", "numbered_list", "page", Some("n1"), r#"[{"insert":"two"}]"#, &[]),
+            typed("t", "todo_list", "page", Some("n2"), r#"[{"insert":"done"}]"#, &[("checked", "true")]),
+            typed("q", "quote", "page", Some("t"), r#"[{"insert":"said"}]"#, &[]),
+            typed("c", "code", "page", Some("q"), r#"[{"insert":"let x = 1;","attributes":{"bold":true}}]"#, &[("language", "rust")]),
+            typed("u", "callout", "page", Some("c"), r#"[{"insert":"plain"}]"#, &[]),
+        ]).unwrap();
+
+        assert_eq!(doc.export_markdown().unwrap(), [
+            "## Plan",
+            "",
+            "Mix **bold** and *`code`*",
+            "",
+            "1. one",
+            "  - nested",
+            "2. two",
+            "- [x] done",
+            "",
+            "> said",
+            "",
+            "```rust\nlet x = 1;\n```",
+            "",
+            "plain",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_import_markdown() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("existing", Some("page"), None, Some(r#"[{"insert":"before"}]"#)),
+        ]).unwrap();
+
+        let markdown = [
+            "## Plan",
+            "Mix **bold** and *it* with 2 * 3",
+            "",
+            "1. one",
+            "  - nested",
+            "2. two",
+            "- [x] done",
+            "",
+            "```rust",
+            "let x = 1;",
+            "```",
+        ].join("\n");
+        doc.import_markdown(markdown.clone()).unwrap();
+
+        let state = doc.get_document_state().unwrap();
+        let children = &state.children_map["page"];
+        assert_eq!(children.len(), 7);
+        assert_eq!(children[0], "existing");
+        let kinds: Vec<&str> = children.iter().map(|id| state.blocks[id].ty.as_str()).collect();
+        assert_eq!(kinds, vec!["paragraph", "heading", "paragraph", "numbered_list", "numbered_list", "todo_list", "code"]);
+        assert_eq!(state.blocks[&children[1]].attributes["level"], "2");
+        let delta: JsonValue = serde_json::from_str(state.blocks[&children[2]].delta.as_ref().unwrap()).unwrap();
+        assert_eq!(delta, json!([
+            { "insert": "Mix " },
+            { "attributes": { "bold": true }, "insert": "bold" },
+            { "insert": " and " },
+            { "attributes": { "italic": true }, "insert": "it" },
+            { "insert": " with 2 * 3" },
+        ]));
+        let nested = &state.children_map[&children[3]];
+        assert_eq!(state.blocks[&nested[0]].ty, "bulleted_list");
+
+        // 내보낸 Markdown이 원문과 같은 구조가 됨
+        let exported = doc.export_markdown().unwrap();
+        assert!(exported.ends_with(&markdown.replace("## Plan\nMix", "## Plan\n\nMix")));
+    }
+
+    #[test]
+    fn test_move_into_own_descendant_is_rejected() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("a1", Some("a"), None, None),
+            block_action("a2", Some("a1"), None, None),
+        ]).unwrap();
+        let before = doc.encode_full_state().unwrap();
+
+        for target in ["a", "a2"] {
+            let mut action = block_action("a", Some(target), None, None);
+            action.action = BlockActionTypeDoc::Move;
+            action.block.old_parent_id = Some("page".to_string());
+            action.old_path = Some(vec![0]);
+            let err = doc.apply_action(vec![action]).unwrap_err();
+            assert!(err.message.contains("cannot move block into its own descendant"));
+        }
+
+        // move_block 자체도 검사함
+        {
+            let mut txn = doc.doc.transact_mut();
+            let blocks_map = UpdateOperations::blocks_map(&txn).unwrap();
+            let err = BlockOperations::move_block(&mut txn, blocks_map, &[0], &[0, 0, 0], "a2", "page", "a", None, None).unwrap_err();
+            assert!(err.message.contains("cannot move block into its own descendant"));
+        }
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+    }
+
+    #[test]
+    fn test_duplicate_block() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        let mut section = block_action("s", Some("page"), None, Some(r#"[{"insert":"Sec","attributes":{"bold":true}},{"insert":"tion"}]"#));
+        section.block.attributes.insert("level".to_string(), "2".to_string());
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            section,
+            block_action("s1", Some("s"), None, Some(r#"[{"insert":"one"}]"#)),
+            block_action("s2", Some("s"), Some("s1"), None),
+            block_action("s2a", Some("s2"), None, None),
+            block_action("after", Some("page"), Some("s"), None),
+        ]).unwrap();
+
+        let result = doc.duplicate_block("s".to_string()).unwrap();
+        assert_eq!(result.id_map.len(), 4);
+        let copy = &result.id_map["s"];
+
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["s".to_string(), copy.clone(), "after".to_string()]);
+        assert_eq!(state.blocks[copy].delta, state.blocks["s"].delta);
+        assert_eq!(state.blocks[copy].attributes["level"], "2");
+        assert_eq!(state.children_map[copy], vec![result.id_map["s1"].clone(), result.id_map["s2"].clone()]);
+        assert_eq!(state.children_map[&result.id_map["s2"]], vec![result.id_map["s2a"].clone()]);
+
+        // 복제본을 고쳐도 원본은 그대로
+        doc.apply_delta(result.id_map["s1"].clone(), r#"[{"retain":3},{"insert":"!"}]"#.to_string()).unwrap();
+        assert_eq!(doc.get_block_plain_text("s1".to_string()).unwrap(), "one");
+        assert_eq!(doc.get_block_plain_text(result.id_map["s1"].clone()).unwrap(), "one!");
+        assert!(doc.duplicate_block("missing".to_string()).is_err());
+
+        // 부모가 순환하는 블록도 스택 오버플로 없이 한 번씩만 복제
+        {
+            let mut txn = doc.doc.transact_mut();
+            let blocks_map = UpdateOperations::blocks_map(&txn).unwrap();
+            for (id, parent) in [("x", "y"), ("y", "x")] {
+                let block = block_action(id, Some(parent), None, None).block;
+                BlockOperations::write_block(&mut txn, &blocks_map, &block).unwrap();
+            }
+        }
+        let result = doc.duplicate_block("x".to_string()).unwrap();
+        assert_eq!(result.id_map.len(), 2);
+    }
+
+    #[test]
+    fn test_get_block_path() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("a1", Some("a"), None, None),
+        ]).unwrap();
+
+        assert_eq!(doc.get_block_path("a1".to_string()).unwrap(), vec!["page".to_string(), "a".to_string()]);
+        assert_eq!(doc.get_block_path("a".to_string()).unwrap(), vec!["page".to_string()]);
+        assert!(doc.get_block_path("page".to_string()).unwrap().is_empty());
+        assert!(doc.get_block_path("missing".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_indent_and_outdent() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("a1", Some("a"), None, None),
+            block_action("b", Some("page"), Some("a"), None),
+            block_action("b1", Some("b"), None, None),
+            block_action("c", Some("page"), Some("b"), None),
+        ]).unwrap();
+
+        // 자식을 데리고 앞 형제의 마지막 자식이 됨
+        doc.indent_block("b".to_string()).unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["a".to_string(), "c".to_string()]);
+        assert_eq!(state.children_map["a"], vec!["a1".to_string(), "b".to_string()]);
+        assert_eq!(state.children_map["b"], vec!["b1".to_string()]);
+
+        // 부모 바로 뒤로 나감
+        doc.outdent_block("b".to_string()).unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(state.children_map["a"], vec!["a1".to_string()]);
+
+        // 첫 번째 자식과 루트 페이지의 자식은 변경 없음
+        assert_eq!(doc.indent_block("a1".to_string()).unwrap(), Update::new().encode_v2());
+        assert_eq!(doc.outdent_block("a".to_string()).unwrap(), Update::new().encode_v2());
+        assert_eq!(doc.outdent_block("page".to_string()).unwrap(), Update::new().encode_v2());
+        assert!(doc.indent_block("missing".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_file() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("doc-{}.bin", uuid::Uuid::new_v4())).to_string_lossy().to_string();
+
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"saved"}]"#))]).unwrap();
+        doc.set_meta_string("title".to_string(), "노트".to_string()).unwrap();
+        doc.save_to_file(path.clone()).unwrap();
+
+        let mut loaded = DocumentService::new();
+        loaded.load_from_file(path.clone()).unwrap();
+        assert_eq!(loaded.get_block_plain_text("a".to_string()).unwrap(), "saved");
+        assert_eq!(loaded.get_meta_string("title".to_string()).unwrap(), Some("노트".to_string()));
+
+        std::fs::write(&path, [0xff]).unwrap();
+        let err = DocumentService::new().load_from_file(path.clone()).unwrap_err();
+        assert!(err.message.contains("not a valid document update"));
+        std::fs::write(&path, [0xff; 20]).unwrap();
+        let err = DocumentService::new().load_from_file(path.clone()).unwrap_err();
+        assert!(err.message.contains("not a valid document update"));
+        std::fs::remove_file(&path).unwrap();
+
+        let err = DocumentService::new().load_from_file(path).unwrap_err();
+        assert!(err.message.starts_with("IO error"));
+    }
+
+    #[test]
+    fn test_apply_updates_skips_undecodable_updates() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let update = local.apply_action(vec![block_action("a", None, None, None)]).unwrap();
+
+        let mut remote = DocumentService::new();
+        let changes = remote.apply_updates(vec![init, vec![0xff, 0xff, 0xff], update]).unwrap();
+        assert_eq!(changes.inserted_block_ids, vec!["a".to_string()]);
+        assert_eq!(changes.failed_updates.failed_updates_ids, vec!["1".to_string()]);
+        assert_eq!(changes.failed_updates.errors.len(), 1);
+        assert!(!changes.failed_updates.errors[0].is_empty());
+
+        // v1 배치도 호출자 기준 인덱스로 보고
+        let mut v1_remote = DocumentService::new();
+        let full_v1 = local.doc.transact().encode_state_as_update_v1(&StateVector::default());
+        let changes = v1_remote.apply_updates_v1(vec![vec![0xff], full_v1]).unwrap();
+        assert_eq!(changes.inserted_block_ids, vec!["a".to_string()]);
+        assert_eq!(changes.failed_updates.failed_updates_ids, vec!["0".to_string()]);
+    }
+
+    #[test]
+    fn test_block_count_and_is_empty() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        assert_eq!(doc.get_block_count().unwrap(), 0);
+        assert!(doc.is_empty().unwrap());
+
+        // 루트 페이지 블록과 메타만 있으면 비어 있음
+        doc.apply_action(vec![BlockActionDoc {
+            block: BlockDoc { ty: ROOT_TYPE.to_string(), ..block_action("page", None, None, None).block },
+            ..block_action("page", None, None, None)
+        }]).unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.set_meta_string("title".to_string(), "T".to_string()).unwrap();
+        assert_eq!(doc.get_block_count().unwrap(), 1);
+        assert!(doc.is_empty().unwrap());
+
+        doc.apply_action(vec![block_action("p", Some("page"), None, None)]).unwrap();
+        assert_eq!(doc.get_block_count().unwrap(), 2);
+        assert!(!doc.is_empty().unwrap());
+    }
+
+    #[test]
+    fn test_get_all_block_ids() {
+        let mut doc = DocumentService::new();
+        assert!(doc.get_all_block_ids().unwrap().is_empty());
+
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("a1", Some("a"), None, None),
+        ]).unwrap();
+        let mut ids = doc.get_all_block_ids().unwrap();
+        ids.sort();
+        assert_eq!(ids, vec!["a".to_string(), "a1".to_string(), "page".to_string()]);
+        assert_eq!(ids.len(), doc.get_block_count().unwrap());
+    }
+
+    #[test]
+    fn test_get_blocks_in_order() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("b", Some("page"), Some("a"), None),
+            block_action("a1", Some("a"), None, None),
+            block_action("a1x", Some("a1"), None, None),
+            block_action("lost", None, None, None),
+        ]).unwrap();
+
+        let ordered: Vec<(String, i32)> = doc.get_blocks_in_order().unwrap()
+            .into_iter()
+            .map(|ordered| (ordered.block.id, ordered.depth))
+            .collect();
+        assert_eq!(ordered, vec![
+            ("page".to_string(), 0),
+            ("a".to_string(), 1),
+            ("a1".to_string(), 2),
+            ("a1x".to_string(), 3),
+            ("b".to_string(), 1),
+            ("lost".to_string(), -1),
+        ]);
+    }
+
+    #[test]
+    fn test_relative_position_survives_remote_edits() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let update = local.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"Hello world"}]"#)),
+            block_action("empty", None, Some("a"), None),
+        ]).unwrap();
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![init, update]).unwrap();
+
+        // "world" 앞에 커서
+        let caret = local.encode_relative_position("a".to_string(), 6).unwrap();
+        let edit = remote.apply_text_ops("a".to_string(), r#"[{"insert":">> "}]"#.to_string()).unwrap();
+        local.apply_updates(vec![edit]).unwrap();
+
+        let pos = local.decode_relative_position(caret.clone()).unwrap();
+        assert_eq!(pos, RelativePos { block_id: "a".to_string(), offset: 9 });
+        assert_eq!(remote.decode_relative_position(caret).unwrap().offset, 9);
+
+        assert!(local.encode_relative_position("a".to_string(), 100).is_err());
+        assert!(local.encode_relative_position("missing".to_string(), 0).is_err());
+        assert!(local.decode_relative_position(vec![0xff]).is_err());
+    }
+
+    #[test]
+    fn test_block_change_listener() {
+        let mut remote = DocumentService::new();
+        let init = remote.init_empty_doc().unwrap();
+        let setup = remote.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"Hi"}]"#)),
+            block_action("b", None, Some("a"), None),
+        ]).unwrap();
+        let mut doc = DocumentService::new();
+        doc.apply_updates(vec![init, setup]).unwrap();
+
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::<JsonValue>::new()));
+        let sink = received.clone();
+        doc.set_block_listener("a".to_string(), Arc::new(move |json| sink.lock().unwrap().push(serde_json::from_str(&json).unwrap()))).unwrap();
+        assert!(doc.set_block_listener("missing".to_string(), Arc::new(|_| {})).is_err());
+
+        // 다른 블록 변경은 전달되지 않음
+        doc.apply_text_ops("b".to_string(), r#"[{"insert":"x"}]"#.to_string()).unwrap();
+        assert!(received.lock().unwrap().is_empty());
+
+        // 원격 텍스트/속성 변경
+        let edit = remote.apply_text_ops("a".to_string(), r#"[{"retain":2},{"insert":"!"}]"#.to_string()).unwrap();
+        doc.apply_updates(vec![edit]).unwrap();
+        let mut update = block_action("a", None, None, None);
+        update.action = BlockActionTypeDoc::Update;
+        update.block.attributes.insert("level".to_string(), "1".to_string());
+        doc.apply_action(vec![update]).unwrap();
+        doc.apply_action(vec![block_action("c", Some("a"), None, None)]).unwrap();
+        doc.apply_action(vec![BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action("a", None, None, None) }]).unwrap();
+        doc.apply_action(vec![block_action("a", None, Some("b"), None)]).unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 4);
+        assert_eq!(received[0]["fields"], json!(["text"]));
+        assert_eq!(received[0]["delta"], json!([{"retain": 2}, {"insert": "!"}]));
+        assert!(received[1]["fields"].as_array().unwrap().contains(&json!("attributes.level")));
+        assert_eq!(received[2]["childrenReordered"], json!(true));
+        // 삭제 이후 같은 id로 다시 만들어도 끝난 구독은 전달하지 않음
+        assert_eq!(received[3], json!({ "blockId": "a", "event": "deleted" }));
+    }
+
+    #[test]
+    fn test_insert_blocks_bulk() {
+        let mut doc = DocumentService::new();
+        let init = doc.init_empty_doc().unwrap();
+        let setup = doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("first", Some("page"), None, None),
+        ]).unwrap();
+
+        let blocks: Vec<BlockDoc> = (0..10_000)
+            .map(|i| block_action(&format!("b{}", i), None, None, Some(r#"[{"insert":"x"}]"#)).block)
+            .collect();
+        let update = doc.insert_blocks_bulk(blocks, "page".to_string()).unwrap();
+
+        let children = doc.get_children_ids("page".to_string()).unwrap();
+        assert_eq!(children.len(), 10_001);
+        assert_eq!(children[0], "first");
+        assert_eq!(children[1], "b0");
+        assert_eq!(children[10_000], "b9999");
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![init, setup, update]).unwrap();
+        assert_eq!(peer.get_children_ids("page".to_string()).unwrap(), children);
+
+        // 중복 id, 없는 부모는 아무것도 적용하지 않음
+        let before = doc.encode_full_state().unwrap();
+        let duplicate = vec![block_action("new", None, None, None).block, block_action("b5", None, None, None).block];
+        assert!(doc.insert_blocks_bulk(duplicate, "page".to_string()).is_err());
+        assert!(doc.insert_blocks_bulk(vec![block_action("new", None, None, None).block], "missing".to_string()).is_err());
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+    }
+
+    #[test]
+    fn test_apply_action_large_batch() {
+        let mut doc = DocumentService::new();
+        let init = doc.init_empty_doc().unwrap();
+        let mut actions = vec![block_action("page", None, None, None)];
+        actions.extend((0..500).map(|i| {
+            let prev = (i > 0).then(|| format!("b{}", i - 1));
+            block_action(&format!("b{}", i), Some("page"), prev.as_deref(), Some(r#"[{"insert":"x"}]"#))
+        }));
+        let update = doc.apply_action(actions).unwrap();
+
+        let expected: Vec<String> = (0..500).map(|i| format!("b{}", i)).collect();
+        assert_eq!(doc.get_children_ids("page".to_string()).unwrap(), expected);
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![init, update]).unwrap();
+        assert_eq!(peer.get_children_ids("page".to_string()).unwrap(), expected);
+    }
+
+    /// apply_action이 배치마다 blocks 맵을 한 번만 가져올 때 절약되는 시간 측정 (큰 붙여넣기 배치 기준)
+    /// cargo test --release -- --ignored --nocapture bench_apply_action_large_batch
+    #[test]
+    #[ignore]
+    fn bench_apply_action_large_batch() {
+        logging::set_max_level(log::LevelFilter::Warn);
+        let count = 5_000;
+        let actions = || {
+            let mut actions = vec![block_action("page", None, None, None)];
+            actions.extend((0..count).map(|i| {
+                let prev = (i > 0).then(|| format!("b{}", i - 1));
+                block_action(&format!("b{}", i), Some("page"), prev.as_deref(), Some(r#"[{"insert":"x"}]"#))
+            }));
+            actions
+        };
+
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        let start = std::time::Instant::now();
+        doc.apply_action(actions()).unwrap();
+        let batch = start.elapsed();
+
+        // 같은 트랜잭션에서 액션마다 맵을 찾던 이전 방식과 한 번 찾은 핸들을 재사용하는 방식 비교
+        let root = doc.doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.doc.transact_mut();
+        let start = std::time::Instant::now();
+        for _ in 0..=count {
+            std::hint::black_box(root.get_or_init_map(&mut txn, BLOCKS));
+        }
+        let per_action_lookup = start.elapsed();
+        let start = std::time::Instant::now();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        for _ in 0..=count {
+            std::hint::black_box(blocks_map.clone());
+        }
+        let hoisted = start.elapsed();
+
+        println!(
+            "{} inserts in one batch: {:?}; blocks map per action {:?}, fetched once {:?}",
+            count, batch, per_action_lookup, hoisted
+        );
+        assert!(hoisted < per_action_lookup);
+    }
+
+    #[test]
+    fn test_get_document_state_does_not_mutate_empty_doc() {
+        let doc = DocumentService::new();
+        let before = doc.encode_full_state().unwrap();
+
+        let state = doc.get_document_state().unwrap();
+        assert!(state.blocks.is_empty());
+        assert!(state.children_map.is_empty());
+        assert_eq!(state.root_id, "");
+        assert!(doc.read_document_state().unwrap().blocks.is_empty());
+        // 상태 기반 export도 같은 리더를 쓰므로 root 맵이 없어도 실패하지 않아야 함
+        assert!(doc.get_blocks_in_order().unwrap().is_empty());
+        assert_eq!(doc.export_structure().unwrap(), "null");
+        assert_eq!(doc.export_markdown().unwrap(), "");
+
+        assert!(doc.doc.transact().get_map(ROOT_ID).is_none());
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+    }
+
+    #[test]
+    fn test_get_document_json() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hi","attributes":{"bold":true}}]"#)),
+        ]).unwrap();
+
+        let json: JsonValue = serde_json::from_str(&doc.get_document_json().unwrap()).unwrap();
+        assert_eq!(json["root_id"], json!("page"));
+        assert_eq!(json["children_map"]["page"], json!(["a"]));
+        assert_eq!(json["blocks"]["a"]["delta"], json!([{"insert": "Hi", "attributes": {"bold": true}}]));
+        assert_eq!(json["blocks"]["a"]["parentId"], json!("page"));
+        assert_eq!(json["blocks"]["page"]["delta"], JsonValue::Null);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut doc = DocumentService::new_with_options("note".to_string(), false);
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"keep","attributes":{"bold":true}}]"#)),
+        ]).unwrap();
+        doc.set_meta_string("title".to_string(), "노트".to_string()).unwrap();
+        doc.set_meta_from_json(r#"{"cover": {"type": "color", "value": "red"}}"#.to_string()).unwrap();
+        for i in 0..50 {
+            let id = format!("tmp{}", i);
+            doc.apply_action(vec![block_action(&id, Some("page"), Some("a"), Some(r#"[{"insert":"scratch text"}]"#))]).unwrap();
+            doc.apply_action(vec![BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action(&id, Some("page"), None, None) }]).unwrap();
+        }
+
+        let compacted = doc.compact().unwrap();
+        assert!(compacted.len() < doc.encode_full_state().unwrap().len());
+
+        let mut fresh = DocumentService::new_with_id("note".to_string());
+        fresh.apply_updates(vec![compacted]).unwrap();
+        assert_eq!(fresh.content_hash().unwrap(), doc.content_hash().unwrap());
+        assert_eq!(fresh.get_children_ids("page".to_string()).unwrap(), vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_merge_three_way() {
+        let mut base = DocumentService::new();
+        base.init_empty_doc().unwrap();
+        base.set_root_node_id("page".to_string()).unwrap();
+        base.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"hello world"}]"#)),
+        ]).unwrap();
+        let base_state = base.encode_full_state().unwrap();
+
+        let mut left = DocumentService::new();
+        left.apply_updates(vec![base_state.clone()]).unwrap();
+        left.apply_text_ops("a".to_string(), r#"[{"insert":"Oh, "}]"#.to_string()).unwrap();
+        let mut right = DocumentService::new();
+        right.apply_updates(vec![base_state.clone()]).unwrap();
+        right.apply_text_ops("a".to_string(), r#"[{"retain":11},{"insert":"!"}]"#.to_string()).unwrap();
+
+        let merged = DocumentService::merge_three_way(
+            base_state.clone(),
+            left.encode_full_state().unwrap(),
+            right.encode_full_state().unwrap(),
+        ).unwrap();
+        let mut result = DocumentService::new();
+        result.apply_updates(vec![merged]).unwrap();
+        assert_eq!(result.get_block_plain_text("a".to_string()).unwrap(), "Oh, hello world!");
+
+        let mut unrelated = DocumentService::new();
+        unrelated.init_empty_doc().unwrap();
+        let err = DocumentService::merge_three_way(
+            base_state,
+            left.encode_full_state().unwrap(),
+            unrelated.encode_full_state().unwrap(),
+        ).unwrap_err();
+        assert!(err.message.contains("right"));
+    }
+
+    #[test]
+    fn test_fork_is_independent() {
+        let mut doc = DocumentService::new_with_id("original".to_string());
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"hello"}]"#)),
+        ]).unwrap();
+
+        let mut forked = doc.fork("copy".to_string()).unwrap();
+        assert_eq!(forked.doc_id(), "copy");
+        assert_ne!(forked.doc.client_id(), doc.doc.client_id());
+        assert_eq!(forked.content_hash().unwrap(), doc.content_hash().unwrap());
+
+        forked.apply_action(vec![block_action("b", Some("page"), Some("a"), None)]).unwrap();
+        doc.apply_text_ops("a".to_string(), r#"[{"retain":5},{"insert":"!"}]"#.to_string()).unwrap();
+        assert!(doc.get_block("b".to_string()).unwrap().is_none());
+        assert_eq!(forked.get_block_plain_text("a".to_string()).unwrap(), "hello");
+        assert_eq!(doc.get_block_plain_text("a".to_string()).unwrap(), "hello!");
+    }
+
+    #[test]
+    fn test_block_timestamps() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let insert = local.apply_action_with_timestamp(vec![block_action("a", None, None, Some(r#"[{"insert":"hi"}]"#))], 1_000).unwrap();
+        let untimed = local.apply_action(vec![block_action("b", None, Some("a"), None)]).unwrap();
+        let block = local.get_block("a".to_string()).unwrap().unwrap();
+        assert_eq!((block.created_at, block.updated_at), (Some(1_000), Some(1_000)));
+        let block = local.get_block("b".to_string()).unwrap().unwrap();
+        assert_eq!((block.created_at, block.updated_at), (None, None));
+
+        let update_a = || BlockActionDoc { action: BlockActionTypeDoc::Update, ..block_action("a", None, None, None) };
+        local.apply_action_with_timestamp(vec![update_a()], 2_000).unwrap();
+        // 시계가 뒤로 간 클라이언트의 편집은 수정 시각을 되돌리지 않음
+        local.apply_action_with_timestamp(vec![update_a()], 1_500).unwrap();
+        let block = local.get_block("a".to_string()).unwrap().unwrap();
+        assert_eq!((block.created_at, block.updated_at), (Some(1_000), Some(2_000)));
+
+        // 동시 편집은 둘 중 더 늦은 시각으로 수렴
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![init, insert, untimed]).unwrap();
+        remote.apply_updates(vec![local.encode_full_state().unwrap()]).unwrap();
+        let from_local = local.apply_action_with_timestamp(vec![update_a()], 3_000).unwrap();
+        let from_remote = remote.apply_action_with_timestamp(vec![update_a()], 2_500).unwrap();
+        local.apply_updates(vec![from_remote]).unwrap();
+        remote.apply_updates(vec![from_local]).unwrap();
+        for doc in [&local, &remote] {
+            let block = doc.get_block("a".to_string()).unwrap().unwrap();
+            assert_eq!(block.updated_at, Some(3_000));
+        }
+        local.set_root_node_id("a".to_string()).unwrap();
+        let state = local.get_document_state().unwrap();
+        assert_eq!((state.blocks["a"].created_at, state.blocks["a"].updated_at), (Some(1_000), Some(3_000)));
+    }
+
+    #[test]
+    fn test_strict_block_types() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        let typo = BlockDoc { ty: "paragrah".to_string(), ..block_action("typo", None, None, None).block };
+
+        // 기본은 허용 모드라 어떤 type이든 저장됨
+        doc.apply_action(vec![BlockActionDoc { block: typo.clone(), ..block_action("typo", None, None, None) }]).unwrap();
+
+        doc.set_strict_types(true);
+        let err = doc.apply_action(vec![
+            block_action("a", None, None, None),
+            BlockActionDoc { block: BlockDoc { id: "b".to_string(), ..typo.clone() }, ..block_action("b", None, None, None) },
+        ]).unwrap_err();
+        assert!(err.message.contains("paragrah"));
+        assert!(doc.get_block("a".to_string()).unwrap().is_none());
+        assert!(doc.insert_blocks_bulk(vec![BlockDoc { id: "c".to_string(), ..typo.clone() }], "root".to_string()).is_err());
+
+        let mut update = block_action("typo", None, None, Some(r#"[{"insert":"x"}]"#));
+        update.action = BlockActionTypeDoc::Update;
+        update.block.ty = String::new();
+        doc.apply_action(vec![update]).unwrap();
+
+        doc.set_allowed_block_types(vec!["paragraph".to_string(), "kanban".to_string()]);
+        doc.apply_action(vec![BlockActionDoc {
+            block: BlockDoc { ty: "kanban".to_string(), ..block_action("k", None, None, None).block },
+            ..block_action("k", None, None, None)
+        }]).unwrap();
+        doc.set_strict_types(false);
+        doc.apply_action(vec![BlockActionDoc { block: BlockDoc { id: "d".to_string(), ..typo }, ..block_action("d", None, None, None) }]).unwrap();
+    }
+
+    #[test]
+    fn test_strict_block_types_cover_imports() {
+        let strict = || {
+            let mut doc = DocumentService::new();
+            doc.init_empty_doc().unwrap();
+            doc.set_strict_types(true);
+            doc
+        };
+
+        let mut doc = strict();
+        let appflowy = r#"{"document": {"id": "page", "type": "page", "children": [{"id": "p", "type": "paragrah", "delta": [{"insert": "x"}]}]}}"#;
+        assert!(doc.import_appflowy_json(appflowy.to_string()).unwrap_err().message.contains("paragrah"));
+        assert!(doc.is_empty().unwrap());
+
+        let tree = r#"{"id": "page", "type": "page", "data": {}, "delta": null, "children": [{"id": "p", "type": "paragrah", "data": {}, "delta": null, "children": []}]}"#;
+        assert!(doc.import_tree_json(tree.to_string()).is_err());
+        assert!(doc.is_empty().unwrap());
+
+        let mut template = DocumentService::new();
+        template.init_empty_doc().unwrap();
+        let typo = BlockDoc { ty: "paragrah".to_string(), ..block_action("tpl", None, None, None).block };
+        template.apply_action(vec![BlockActionDoc { block: typo, ..block_action("tpl", None, None, None) }]).unwrap();
+        assert!(doc.apply_update_remapped(template.encode_full_state().unwrap(), r#"{"tpl": "copy"}"#.to_string()).is_err());
+        assert!(doc.is_empty().unwrap());
+
+        // 허용 목록에 없는 type이 들어 있는 Markdown 가져오기는 새 루트 블록도 만들지 않아야 함
+        doc.set_allowed_block_types(vec!["page".to_string(), "paragraph".to_string()]);
+        assert!(doc.import_markdown("# Title".to_string()).is_err());
+        assert!(doc.is_empty().unwrap());
+        doc.import_markdown("plain".to_string()).unwrap();
+
+        // 올바른 type이면 그대로 가져옴
+        let mut ok = strict();
+        ok.import_appflowy_json(appflowy.replace("paragrah", "paragraph")).unwrap();
+        assert_eq!(ok.get_document_state().unwrap().blocks["p"].ty, "paragraph");
+    }
+
+    #[test]
+    fn test_copy_paste_blocks_across_documents() {
+        let mut source = DocumentService::new();
+        source.init_empty_doc().unwrap();
+        source.set_root_node_id("page".to_string()).unwrap();
+        source.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"bold","attributes":{"bold":true}},{"insert":" plain"}]"#)),
+            block_action("a1", Some("a"), None, Some(r#"[{"insert":"child"}]"#)),
+            block_action("b", Some("page"), Some("a"), None),
+            block_action("c", Some("page"), Some("b"), Some(r#"[{"insert":"third"}]"#)),
+        ]).unwrap();
+        let payload = source.copy_blocks(vec!["c".to_string(), "a1".to_string(), "a".to_string()]).unwrap();
+        assert!(!payload.contains(&source.doc.client_id().to_string()));
+
+        let mut target = DocumentService::new();
+        target.init_empty_doc().unwrap();
+        target.set_root_node_id("page".to_string()).unwrap();
+        target.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("x", Some("page"), None, None),
+            block_action("y", Some("page"), Some("x"), None),
+        ]).unwrap();
+        target.paste_blocks(payload.clone(), "page".to_string(), Some("x".to_string())).unwrap();
+
+        let children = target.get_children_ids("page".to_string()).unwrap();
+        assert_eq!(children.len(), 4);
+        assert_eq!((children[0].as_str(), children[3].as_str()), ("x", "y"));
+        let pasted_a = target.get_block(children[1].clone()).unwrap().unwrap();
+        assert_ne!(pasted_a.id, "a");
+        assert_eq!(pasted_a.delta, source.get_block("a".to_string()).unwrap().unwrap().delta);
+        let pasted_a1 = target.get_children_ids(pasted_a.id.clone()).unwrap();
+        assert_eq!(target.get_block_plain_text(pasted_a1[0].clone()).unwrap(), "child");
+        assert_eq!(target.get_block_plain_text(children[2].clone()).unwrap(), "third");
+
+        // 같은 클립보드를 다시 붙여넣으면 새 id로 맨 앞에 들어감
+        target.paste_blocks(payload, "page".to_string(), None).unwrap();
+        let children = target.get_children_ids("page".to_string()).unwrap();
+        assert_eq!(children.len(), 6);
+        assert_eq!(children[2], "x");
+        assert!(target.paste_blocks(r#"{"blocks":[]}"#.to_string(), "page".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_set_log_level() {
+        let previous = logging::max_level();
+        assert!(DocumentService::set_log_level("verbose".to_string()).is_err());
+        assert_eq!(logging::max_level(), previous);
+
+        DocumentService::set_log_level("WARN".to_string()).unwrap();
+        assert!(logging::enabled(log::Level::Error));
+        assert!(!logging::enabled(log::Level::Info));
+        DocumentService::set_log_level("debug".to_string()).unwrap();
+        assert!(logging::enabled(log::Level::Debug));
+        logging::set_max_level(previous);
+    }
+
+    #[test]
+    fn test_apply_update_reports_progress() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let insert = local.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"hi"}]"#))]).unwrap();
+
+        let mut remote = DocumentService::new();
+        assert!(remote.apply_update(init.clone()).unwrap());
+        assert!(remote.apply_update(insert.clone()).unwrap());
+        assert!(!remote.apply_update(insert).unwrap());
+        assert!(!remote.apply_update(init).unwrap());
+        assert_eq!(remote.get_block_plain_text("a".to_string()).unwrap(), "hi");
+        assert!(remote.apply_update(vec![0xff, 0xff, 0xff]).is_err());
+    }
+
+    #[test]
+    fn test_state_vector_comparison() {
+        let mut a = DocumentService::new();
+        let init = a.init_empty_doc().unwrap();
+        let mut b = DocumentService::new();
+        b.apply_updates(vec![init]).unwrap();
+        assert!(DocumentService::state_vectors_equal(a.encode_state_vector().unwrap(), b.encode_state_vector().unwrap()).unwrap());
+        assert!(DocumentService::missing_from(b.encode_state_vector().unwrap(), a.encode_state_vector().unwrap()).unwrap().is_empty());
+
+        a.apply_action(vec![block_action("x", None, None, Some(r#"[{"insert":"abc"}]"#))]).unwrap();
+        let (a_sv, b_sv) = (a.encode_state_vector().unwrap(), b.encode_state_vector().unwrap());
+        assert!(!DocumentService::state_vectors_equal(a_sv.clone(), b_sv.clone()).unwrap());
+        let missing = DocumentService::missing_from(b_sv.clone(), a_sv.clone()).unwrap();
+        assert_eq!(missing, vec![(a.doc.client_id(), a.doc.transact().state_vector().get(&a.doc.client_id()))]);
+        assert!(DocumentService::missing_from(a_sv, b_sv).unwrap().is_empty());
+
+        let err = DocumentService::state_vectors_equal(vec![0xff], vec![]).unwrap_err();
+        assert_eq!(err.code, "encoding_error");
+    }
+
+    #[test]
+    fn test_subdoc_syncs_separately() {
+        let mut local = DocumentService::new();
+        local.init_empty_doc().unwrap();
+        local.apply_action(vec![block_action("board", None, None, None)]).unwrap();
+        let guid = local.create_subdoc("board".to_string()).unwrap();
+        assert_eq!(local.create_subdoc("board".to_string()).unwrap(), guid);
+        assert_eq!(local.get_subdoc_guids(), vec![guid.clone()]);
+        assert!(local.create_subdoc("missing".to_string()).is_err());
+
+        // 하위 문서 편집은 부모 문서의 state vector를 바꾸지 않음
+        let parent_sv = local.encode_state_vector().unwrap();
+        {
+            let subdoc = local.find_subdoc(&guid).unwrap();
+            let cards = subdoc.get_or_insert_text("cards");
+            cards.push(&mut subdoc.transact_mut(), "todo");
+        }
+        assert_eq!(local.encode_state_vector().unwrap(), parent_sv);
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![local.encode_full_state().unwrap()]).unwrap();
+        assert_eq!(remote.get_subdoc_guids(), vec![guid.clone()]);
+        remote.apply_subdoc_update(guid.clone(), local.encode_subdoc_state(guid.clone()).unwrap()).unwrap();
+        let subdoc = remote.find_subdoc(&guid).unwrap();
+        let cards = subdoc.get_or_insert_text("cards");
+        assert_eq!(cards.get_string(&subdoc.transact()), "todo");
+
+        assert!(remote.apply_subdoc_update(guid.clone(), vec![0xff; 20]).is_err());
+        assert!(remote.encode_subdoc_state("unknown".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_statistics() {
+        let empty = DocumentService::new();
+        let stats = empty.get_statistics().unwrap();
+        assert_eq!((stats.word_count, stats.char_count, stats.block_count), (0, 0, 0));
+
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("en", Some("page"), None, Some(r#"[{"insert":"Hello, "},{"insert":"world - again","attributes":{"bold":true}}]"#)),
+            block_action("ko", Some("page"), Some("en"), Some(r#"[{"insert":"안녕하세요 세계"}]"#)),
+            block_action("zh", Some("page"), Some("ko"), Some(r#"[{"insert":"你好世界"}]"#)),
+            block_action("divider", Some("page"), Some("zh"), None),
+        ]).unwrap();
+
+        let stats = doc.get_statistics_with_blocks().unwrap();
+        assert_eq!(stats.block_count, 4);
+        assert_eq!(stats.word_count, 3 + 2 + 4);
+        assert_eq!(stats.char_count, 20 + 8 + 4);
+        assert_eq!(stats.char_count_no_spaces, 17 + 7 + 4);
+        let blocks = stats.blocks.unwrap();
+        let ids: Vec<&str> = blocks.iter().map(|block| block.block_id.as_str()).collect();
+        assert_eq!(ids, vec!["en", "ko", "zh"]);
+        assert_eq!((blocks[1].word_count, blocks[1].char_count), (2, 8));
+        assert!(doc.get_statistics().unwrap().blocks.is_none());
+
+        // 임베드는 글자 수에 넣지 않음
+        doc.apply_delta("zh".to_string(), r#"[{"insert":{"image":"cat.png"}}]"#.to_string()).unwrap();
+        assert_eq!(doc.get_statistics().unwrap().char_count, 20 + 8 + 4);
+    }
+
+    #[test]
+    fn test_set_block_data_field_merges_concurrent_fields() {
+        let mut doc_a = DocumentService::new();
+        doc_a.init_empty_doc().unwrap();
+        let mut todo = block_action("todo", None, None, None);
+        todo.block.attributes.insert("checked".to_string(), "false".to_string());
+        todo.block.attributes.insert("color".to_string(), "\"red\"".to_string());
+        doc_a.apply_action(vec![todo]).unwrap();
+
+        let mut doc_b = DocumentService::new();
+        doc_b.apply_updates(vec![doc_a.encode_full_state().unwrap()]).unwrap();
+
+        let update_a = doc_a.set_block_data_field("todo".to_string(), "checked".to_string(), "true".to_string()).unwrap();
+        let update_b = doc_b.set_block_data_field("todo".to_string(), "color".to_string(), "\"blue\"".to_string()).unwrap();
+        doc_a.apply_updates(vec![update_b]).unwrap();
+        doc_b.apply_updates(vec![update_a]).unwrap();
+
+        for doc in [&doc_a, &doc_b] {
+            let block = doc.get_block("todo".to_string()).unwrap().unwrap();
+            assert_eq!(block.attributes.get("checked").unwrap(), "true");
+            assert_eq!(block.attributes.get("color").unwrap(), "\"blue\"");
+        }
+
+        doc_a.set_block_data_field("todo".to_string(), "style.align".to_string(), "\"center\"".to_string()).unwrap();
+        let block = doc_a.get_block("todo".to_string()).unwrap().unwrap();
+        assert!(block.attributes.get("style").unwrap().contains("center"));
+
+        assert!(doc_a.set_block_data_field("todo".to_string(), "checked.nested".to_string(), "1".to_string()).is_err());
+        assert!(doc_a.set_block_data_field("todo".to_string(), "style..align".to_string(), "1".to_string()).is_err());
+        assert!(doc_a.set_block_data_field("todo".to_string(), "checked".to_string(), "not json".to_string()).is_err());
+        assert!(doc_a.set_block_data_field("missing".to_string(), "checked".to_string(), "true".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_delete_block_recursive() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("section", Some("page"), None, None),
+            block_action("child_a", Some("section"), None, None),
+            block_action("grandchild", Some("child_a"), None, None),
+            block_action("child_b", Some("section"), Some("child_a"), None),
+            block_action("after", Some("page"), Some("section"), None),
+        ]).unwrap();
+        let before = doc.get_all_block_ids().unwrap().len();
+
+        doc.delete_block_recursive("section".to_string()).unwrap();
+
+        let mut ids = doc.get_all_block_ids().unwrap();
+        ids.sort();
+        assert_eq!(ids.len(), before - 4);
+        assert_eq!(ids, vec!["after".to_string(), "page".to_string()]);
+        let after = doc.get_block("after".to_string()).unwrap().unwrap();
+        assert_eq!(after.parent_id.as_deref(), Some("page"));
+        assert_eq!(after.prev_id, None);
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.children_map.get("page").unwrap(), &vec!["after".to_string()]);
+
+        assert!(doc.delete_block_recursive("section".to_string()).is_err());
+        assert!(doc.delete_block_recursive("page".to_string()).is_err());
+
+        // 부모가 순환하는 하위 트리도 스택 오버플로 없이 모두 삭제
+        doc.apply_action(vec![block_action("cycle", Some("page"), Some("after"), None)]).unwrap();
+        {
+            let mut txn = doc.doc.transact_mut();
+            let blocks_map = UpdateOperations::blocks_map(&txn).unwrap();
+            for (id, parent) in [("x", "cycle"), ("y", "x"), ("z", "y")] {
+                let block = block_action(id, Some(parent), None, None).block;
+                BlockOperations::write_block(&mut txn, &blocks_map, &block).unwrap();
+            }
+            // x의 부모를 z로 바꿔 x -> y -> z -> x 순환을 만듦
+            let block = block_action("x", Some("z"), None, None).block;
+            BlockOperations::write_block(&mut txn, &blocks_map, &block).unwrap();
+            let block = block_action("w", Some("x"), None, None).block;
+            BlockOperations::write_block(&mut txn, &blocks_map, &block).unwrap();
+        }
+        doc.delete_block_recursive("y".to_string()).unwrap();
+        let mut ids = doc.get_all_block_ids().unwrap();
+        ids.sort();
+        assert_eq!(ids, vec!["after".to_string(), "cycle".to_string(), "page".to_string()]);
+    }
+
+    #[test]
+    fn test_move_with_stale_siblings() {
+        let move_into_page = |prev_id: Option<&str>, next_id: Option<&str>| {
+            let mut doc = DocumentService::new();
+            doc.init_empty_doc().unwrap();
+            doc.set_root_node_id("page".to_string()).unwrap();
+            doc.apply_action(vec![
+                block_action("page", None, None, None),
+                block_action("a", Some("page"), None, None),
+                block_action("b", Some("page"), Some("a"), None),
+                block_action("c", Some("page"), Some("b"), None),
+                block_action("other", Some("page"), Some("c"), None),
+                block_action("x1", Some("other"), None, None),
+                block_action("m", Some("other"), Some("x1"), None),
+            ]).unwrap();
+
+            let mut action = block_action("m", Some("page"), prev_id, None);
+            action.action = BlockActionTypeDoc::Move;
+            action.block.next_id = next_id.map(|id| id.to_string());
+            action.block.old_parent_id = Some("other".to_string());
+            action.old_path = Some(vec![3, 1]);
+            doc.apply_action(vec![action]).unwrap();
+
+            assert_eq!(doc.get_children_ids("other".to_string()).unwrap(), vec!["x1".to_string()]);
+            let children = doc.get_children_ids("page".to_string()).unwrap();
+            assert_eq!(children.iter().filter(|id| *id == "m").count(), 1);
+            assert_eq!(children.len(), 5);
+            children
+        };
+
+        // prev_id가 사라졌으면 next_id 바로 앞
+        assert_eq!(move_into_page(Some("gone"), Some("b")), vec!["a", "m", "b", "c", "other"]);
+        // prev_id, next_id 둘 다 사라졌으면 끝에 추가
+        assert_eq!(move_into_page(Some("gone"), Some("also-gone")), vec!["a", "b", "c", "other", "m"]);
+        // 다른 부모로 옮겨진 형제도 사라진 것으로 취급
+        assert_eq!(move_into_page(Some("x1"), None), vec!["a", "b", "c", "other", "m"]);
+        assert_eq!(move_into_page(None, Some("x1")), vec!["a", "b", "c", "other", "m"]);
+        // 유효한 prev_id는 그대로 사용하고, 맞지 않는 next_id는 무시
+        assert_eq!(move_into_page(Some("a"), Some("c")), vec!["a", "m", "b", "c", "other"]);
+        // 이웃이 없으면 첫 번째 자식
+        assert_eq!(move_into_page(None, None), vec!["m", "a", "b", "c", "other"]);
+    }
+
+    #[test]
+    fn test_meta_only_update() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"body text"}]"#))]).unwrap();
+        doc.set_meta_from_json(r#"{"title": "Note", "color": 3, "labels": ["x"], "style": {"font": "serif"}}"#.to_string()).unwrap();
+
+        let meta_update = doc.encode_meta_only_update().unwrap();
+        let mut listing = DocumentService::new();
+        listing.apply_meta_only_update(meta_update).unwrap();
+        assert_eq!(listing.get_all_meta().unwrap(), doc.get_all_meta().unwrap());
+        assert!(listing.get_all_block_ids().unwrap().is_empty());
+
+        // 키 제거와 중첩 필드 제거도 반영되고, 블록이 담긴 전체 상태를 받아도 META만 반영
+        doc.set_meta_from_json(r#"{"color": null, "title": "Renamed", "style": {"font": null, "size": 12}}"#.to_string()).unwrap();
+        listing.apply_meta_only_update(doc.encode_full_state().unwrap()).unwrap();
+        assert_eq!(listing.get_all_meta().unwrap(), doc.get_all_meta().unwrap());
+        assert!(listing.get_all_block_ids().unwrap().is_empty());
+
+        assert!(listing.apply_meta_only_update(vec![1, 2, 3]).is_err());
+        assert!(listing.apply_meta_only_update(DocumentService::new().encode_full_state().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_document_size_bytes() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"kept"}]"#)),
+        ]).unwrap();
+        for i in 0..20 {
+            doc.apply_action(vec![block_action(&format!("tmp{}", i), Some("page"), Some("a"), Some(r#"[{"insert":"scratch text that gets deleted"}]"#))]).unwrap();
+            let mut delete = block_action(&format!("tmp{}", i), Some("page"), None, None);
+            delete.action = BlockActionTypeDoc::Delete;
+            doc.apply_action(vec![delete]).unwrap();
+        }
+        let before = doc.encode_full_state().unwrap();
+
+        let size = doc.get_document_size_bytes().unwrap();
+        assert_eq!(size.full_state_bytes, before.len() as u64);
+        assert!(size.content_bytes > 0);
+        assert!(size.content_bytes < size.full_state_bytes);
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+    }
+
+    #[test]
+    fn test_update_batch() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"x"}]"#))]).unwrap();
+        let base = doc.encode_full_state().unwrap();
+        assert!(doc.commit_batch().is_err());
+
+        doc.begin_batch().unwrap();
+        assert!(doc.begin_batch().is_err());
+        let mut returned = Vec::new();
+        for (i, c) in "hello".chars().enumerate() {
+            let delta = format!(r#"[{{"retain":{}}},{{"insert":"{}"}}]"#, i + 1, c);
+            returned.push(doc.apply_delta("a".to_string(), delta).unwrap());
+        }
+        returned.push(doc.set_meta_string("title".to_string(), "typed".to_string()).unwrap());
+
+        // 원격 업데이트는 묶음에 들어가지 않음
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![base.clone()]).unwrap();
+        let remote_update = remote.apply_action(vec![block_action("b", None, Some("a"), None)]).unwrap();
+        doc.apply_updates(vec![remote_update]).unwrap();
+
+        let batched = doc.commit_batch().unwrap();
+        assert!(!doc.is_batching());
+        assert_eq!(batched, merge_updates_v2(returned).unwrap());
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![base, batched]).unwrap();
+        assert_eq!(peer.get_block("a".to_string()).unwrap().unwrap().delta, doc.get_block("a".to_string()).unwrap().unwrap().delta);
+        assert!(peer.get_block("b".to_string()).unwrap().is_none());
+
+        // 변경 없는 묶음도 피어가 디코딩할 수 있는 업데이트를 반환
+        doc.begin_batch().unwrap();
+        let empty = doc.commit_batch().unwrap();
+        assert!(!empty.is_empty());
+        assert!(peer.apply_updates(vec![empty]).unwrap().failed_updates.failed_updates_ids.is_empty());
+    }
+
+    #[test]
+    fn test_get_root_node_id() {
+        let mut doc = DocumentService::new();
+        assert_eq!(doc.get_root_node_id().unwrap(), None);
+        doc.init_empty_doc().unwrap();
+        assert_eq!(doc.get_root_node_id().unwrap(), None);
+        doc.set_root_node_id("page".to_string()).unwrap();
+        assert_eq!(doc.get_root_node_id().unwrap(), Some("page".to_string()));
+
+        let mut loaded = DocumentService::new();
+        loaded.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        assert_eq!(loaded.get_root_node_id().unwrap(), Some("page".to_string()));
+    }
+
+    #[test]
+    fn test_export_tree_json() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        let mut todo = block_action("todo", Some("page"), Some("intro"), Some(r#"[{"insert":"Buy milk"}]"#));
+        todo.block.ty = "todo_list".to_string();
+        todo.block.attributes.insert("checked".to_string(), "true".to_string());
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("intro", Some("page"), None, Some(r#"[{"insert":"Hi ","attributes":{"bold":true}},{"insert":"there"}]"#)),
+            todo,
+            block_action("nested", Some("todo"), None, Some(r#"[{"insert":"2L"}]"#)),
+        ]).unwrap();
+
+        let tree: JsonValue = serde_json::from_str(&doc.export_tree_json().unwrap()).unwrap();
+        assert_eq!(tree["id"], "page");
+        assert_eq!(tree["delta"], JsonValue::Null);
+        let children = tree["children"].as_array().unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0]["delta"], json!([{"insert": "Hi ", "attributes": {"bold": true}}, {"insert": "there"}]));
+        assert_eq!(children[1]["type"], "todo_list");
+        assert_eq!(children[1]["data"]["checked"], true);
+        assert_eq!(children[1]["children"][0]["id"], "nested");
+        assert_eq!(children[1]["children"][0]["children"], json!([]));
+
+        // 순환은 표시 노드로 끊음
+        let mut state = doc.get_document_state().unwrap();
+        state.children_map.insert("nested".to_string(), vec!["page".to_string()]);
+        let tree = Conversion::tree_to_json(&state).unwrap();
+        assert_eq!(tree["children"][1]["children"][0]["children"][0], json!({"id": "page", "cycle": true}));
+
+        assert!(DocumentService::new().export_tree_json().is_err());
+    }
+
+    #[test]
+    fn test_import_tree_json() {
+        let mut source = DocumentService::new();
+        source.init_empty_doc().unwrap();
+        source.set_root_node_id("page".to_string()).unwrap();
+        source.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"one","attributes":{"bold":true}}]"#)),
+            block_action("b", Some("page"), Some("a"), Some(r#"[{"insert":"two"}]"#)),
+            block_action("b1", Some("b"), None, Some(r#"[{"insert":"three"}]"#)),
+        ]).unwrap();
+        let exported = source.export_tree_json().unwrap();
+
+        let mut imported = DocumentService::new();
+        imported.import_tree_json(exported.clone()).unwrap();
+        assert_eq!(imported.get_root_node_id().unwrap(), Some("page".to_string()));
+        assert_eq!(imported.get_children_ids("page".to_string()).unwrap(), vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(imported.export_tree_json().unwrap(), exported);
+        assert!(imported.get_block("page".to_string()).unwrap().unwrap().delta.is_none());
+        assert!(imported.import_tree_json(exported).is_err());
+
+        // id 없는 노드는 새 id, 순환 표시 노드는 건너뜀
+        let mut generated = DocumentService::new();
+        generated.import_tree_json(r#"{"type":"page","children":[
+            {"type":"paragraph","delta":[{"insert":"x"}],"children":[]},
+            {"id":"page","cycle":true}
+        ]}"#.to_string()).unwrap();
+        let root_id = generated.get_root_node_id().unwrap().unwrap();
+        assert_eq!(generated.get_children_ids(root_id).unwrap().len(), 1);
+
+        let mut deep = json!({"type": "paragraph", "children": []});
+        for _ in 0..5 {
+            deep = json!({"type": "paragraph", "children": [deep]});
+        }
+        let mut limited = DocumentService::new();
+        limited.set_max_tree_depth(4);
+        let err = limited.import_tree_json(deep.to_string()).unwrap_err();
+        assert!(err.message.contains("deeper than the limit"));
+        limited.set_max_tree_depth(5);
+        limited.import_tree_json(deep.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_apply_action_with_ids() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+
+        let result = doc.apply_action_with_ids(vec![
+            block_action("", Some("page"), None, Some(r#"[{"insert":"first"}]"#)),
+            block_action("named", Some("page"), None, None),
+            block_action("", Some("page"), None, None),
+        ]).unwrap();
+        assert_eq!(result.generated_ids.len(), 2);
+        assert_ne!(result.generated_ids[0], result.generated_ids[1]);
+        assert!(doc.get_block("named".to_string()).unwrap().is_some());
+        let first = doc.get_block(result.generated_ids[0].clone()).unwrap().unwrap();
+        assert_eq!(first.id, result.generated_ids[0]);
+        assert_eq!(first.parent_id.as_deref(), Some("page"));
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        assert!(peer.get_block(result.generated_ids[1].clone()).unwrap().is_some());
+
+        // Insert가 아닌 작업의 빈 id는 여전히 거부
+        let mut update = block_action("", None, None, None);
+        update.action = BlockActionTypeDoc::Update;
+        assert!(doc.apply_action_with_ids(vec![update]).is_err());
+    }
+
+    #[test]
+    fn test_validate_action_dry_run() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("a1", Some("a"), None, None),
+        ]).unwrap();
+        let before = doc.encode_full_state().unwrap();
+
+        let move_action = |id: &str, parent: &str| {
+            let mut action = block_action(id, Some(parent), None, None);
+            action.action = BlockActionTypeDoc::Move;
+            action.block.old_parent_id = Some("page".to_string());
+            action.old_path = Some(vec![0]);
+            action
+        };
+
+        doc.validate_action(vec![block_action("", Some("a1"), None, None), move_action("a1", "page")]).unwrap();
+        assert!(doc.validate_action(vec![move_action("a", "a1")]).unwrap_err().message.contains("own descendant"));
+        assert!(doc.validate_action(vec![block_action("b", Some("missing"), None, None)]).is_err());
+        let mut incomplete = move_action("a1", "page");
+        incomplete.old_path = None;
+        assert!(doc.validate_action(vec![incomplete]).is_err());
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+
+        // 빈 문서에서도 동작
+        assert!(DocumentService::new().validate_action(vec![block_action("x", None, None, None)]).is_ok());
+
+        // 검사는 시드된 id 순서를 소비하지 않음
+        let seeded_ids = |validate_first: bool| {
+            let mut doc = DocumentService::new();
+            doc.set_id_generator_seed(3);
+            doc.init_empty_doc().unwrap();
+            if validate_first {
+                doc.validate_action(vec![block_action("", None, None, None)]).unwrap();
+            }
+            doc.apply_action_with_ids(vec![block_action("", None, None, None)]).unwrap().generated_ids
+        };
+        assert_eq!(seeded_ids(true), seeded_ids(false));
+    }
+
+    /// 페이지 아래에 텍스트 블록 count개가 있는 문서의 전체 상태
+    fn large_doc_state(count: usize) -> Vec<u8> {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        let blocks = (0..count)
+            .map(|i| block_action(&format!("b{}", i), None, None, Some(&format!(r#"[{{"insert":"line {}"}}]"#, i))).block)
+            .collect();
+        doc.insert_blocks_bulk(blocks, "page".to_string()).unwrap();
+        doc.encode_full_state().unwrap()
+    }
+
+    #[test]
+    fn test_load_and_get_state() {
+        let full_state = large_doc_state(300);
+
+        let mut two_calls = DocumentService::new_with_id("same".to_string());
+        two_calls.apply_updates(vec![full_state.clone()]).unwrap();
+        let expected = two_calls.get_document_state().unwrap();
+
+        let mut loaded = DocumentService::new_with_id("same".to_string());
+        let state = loaded.load_and_get_state(vec![full_state, vec![1, 2, 3]]).unwrap();
+        assert_eq!(serde_json::to_value(&state).unwrap(), serde_json::to_value(&expected).unwrap());
+        assert_eq!(state.children_map["page"].len(), 300);
+
+        // 바로 이어지는 get_document_state는 캐시에서 반환되고, 이후 변경은 캐시를 무효화함
+        loaded.get_document_state().unwrap();
+        assert_eq!(loaded.state_cache.lock().unwrap().builds, 1);
+        loaded.apply_action(vec![block_action("extra", Some("page"), Some("b299"), None)]).unwrap();
+        assert!(loaded.get_document_state().unwrap().blocks.contains_key("extra"));
+
+        let empty = DocumentService::new().load_and_get_state(vec![]).unwrap();
+        assert!(empty.blocks.is_empty());
+    }
+
+    /// cargo test --release -- --ignored --nocapture bench_load_and_get_state
+    #[test]
+    #[ignore]
+    fn bench_load_and_get_state() {
+        logging::set_max_level(log::LevelFilter::Warn);
+        let full_state = large_doc_state(5_000);
+        let runs = 5;
+
+        let start = std::time::Instant::now();
+        for _ in 0..runs {
+            let mut doc = DocumentService::new();
+            doc.apply_updates(vec![full_state.clone()]).unwrap();
+            doc.get_document_state().unwrap();
+        }
+        let two_calls = start.elapsed() / runs;
+
+        let start = std::time::Instant::now();
+        for _ in 0..runs {
+            let mut doc = DocumentService::new();
+            doc.load_and_get_state(vec![full_state.clone()]).unwrap();
+        }
+        let one_call = start.elapsed() / runs;
+
+        println!("5000 blocks: apply_updates + get_document_state {:?}, load_and_get_state {:?}", two_calls, one_call);
+        assert!(one_call < two_calls);
+    }
+
+    #[test]
+    fn test_get_blocks_page() {
+        let mut doc = DocumentService::new();
+        doc.apply_updates(vec![large_doc_state(25)]).unwrap();
+        doc.apply_action(vec![block_action("child", Some("b3"), None, None)]).unwrap();
+        let all: Vec<String> = doc.get_blocks_in_order().unwrap().into_iter().map(|ordered| ordered.block.id).collect();
+        assert_eq!(all.len(), 27);
+
+        let mut paged = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = doc.get_blocks_page(offset, 10).unwrap();
+            assert_eq!(page.total, 27);
+            offset += page.blocks.len();
+            paged.extend(page.blocks.into_iter().map(|block| block.id));
+            if !page.has_more {
+                break;
+            }
+        }
+        assert_eq!(paged, all);
+
+        let past_end = doc.get_blocks_page(100, 10).unwrap();
+        assert!(past_end.blocks.is_empty() && !past_end.has_more);
+        assert!(!doc.get_blocks_page(17, 10).unwrap().has_more);
+        assert!(DocumentService::new().get_blocks_page(0, 10).unwrap().blocks.is_empty());
+    }
+
+    #[test]
+    fn test_comments() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let setup = local.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"Hello world"}]"#)),
+            block_action("b", None, Some("a"), None),
+        ]).unwrap();
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![init, setup]).unwrap();
+
+        // "world"에 붙인 댓글과 블록 전체 댓글
+        let c1 = local.add_comment("a".to_string(), "c1".to_string(), "kim".to_string(), "typo?".to_string(), Some(6)).unwrap();
+        let c2 = local.add_comment("a".to_string(), "c2".to_string(), "lee".to_string(), "nice".to_string(), None).unwrap();
+        let c0 = local.add_comment("a".to_string(), "c0".to_string(), "lee".to_string(), "hi".to_string(), Some(0)).unwrap();
+        assert!(local.add_comment("a".to_string(), "c1".to_string(), "kim".to_string(), "dup".to_string(), None).is_err());
+        assert!(local.add_comment("missing".to_string(), "c3".to_string(), "kim".to_string(), "x".to_string(), None).is_err());
+        assert!(local.add_comment("a".to_string(), "c4".to_string(), "kim".to_string(), "x".to_string(), Some(100)).is_err());
+        assert!(local.add_comment("a".to_string(), String::new(), "kim".to_string(), "x".to_string(), None).is_err());
+        let resolved = local.resolve_comment("c2".to_string()).unwrap();
+        assert_eq!(local.resolve_comment("missing".to_string()).unwrap_err().code, "validation_error");
+        remote.apply_updates(vec![c1, c2, c0, resolved]).unwrap();
+
+        // 앞쪽 텍스트가 편집되어도 위치를 따라감
+        let edit = remote.apply_text_ops("a".to_string(), r#"[{"insert":">> "}]"#.to_string()).unwrap();
+        local.apply_updates(vec![edit]).unwrap();
+        for doc in [&local, &remote] {
+            let comments = doc.get_comments_for_block("a".to_string()).unwrap();
+            let ids: Vec<(&str, Option<u32>, bool)> = comments.iter()
+                .map(|c| (c.comment_id.as_str(), c.anchor_offset, c.resolved))
+                .collect();
+            assert_eq!(ids, vec![("c0", Some(3), false), ("c1", Some(9), false), ("c2", None, true)]);
+            assert_eq!(comments[1].author, "kim");
+            assert_eq!(comments[1].text, "typo?");
+        }
+        assert!(local.get_comments_for_block("b".to_string()).unwrap().is_empty());
+        assert!(local.validate_document().unwrap().orphaned_comment_ids.is_empty());
+
+        // 블록 삭제 후에도 댓글은 남고 검사에서 드러남
+        let delete = remote.delete_block_recursive("a".to_string()).unwrap();
+        local.apply_updates(vec![delete]).unwrap();
+        assert_eq!(
+            local.validate_document().unwrap().orphaned_comment_ids,
+            vec!["c0".to_string(), "c1".to_string(), "c2".to_string()]
+        );
+        let orphaned = local.get_comments_for_block("a".to_string()).unwrap();
+        assert_eq!(orphaned.len(), 3);
+    }
+
+    #[test]
+    fn test_compact_preserves_comments() {
+        let mut doc = DocumentService::new_with_options("note".to_string(), false);
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello world"}]"#)),
+        ]).unwrap();
+        doc.add_comment("a".to_string(), "c1".to_string(), "kim".to_string(), "typo?".to_string(), Some(6)).unwrap();
+        doc.add_comment("a".to_string(), "c2".to_string(), "lee".to_string(), "nice".to_string(), None).unwrap();
+        doc.resolve_comment("c2".to_string()).unwrap();
+        let hash_with_comments = doc.content_hash().unwrap();
+
+        let mut fresh = DocumentService::new_with_id("note".to_string());
+        fresh.apply_updates(vec![doc.compact().unwrap()]).unwrap();
+        assert_eq!(fresh.get_comments_for_block("a".to_string()).unwrap(), doc.get_comments_for_block("a".to_string()).unwrap());
+        assert_eq!(fresh.content_hash().unwrap(), hash_with_comments);
+
+        // 압축본에서도 위치는 상대 위치라 앞쪽 편집을 따라감
+        fresh.apply_text_ops("a".to_string(), r#"[{"insert":">> "}]"#.to_string()).unwrap();
+        assert_eq!(fresh.get_comments_for_block("a".to_string()).unwrap()[0].anchor_offset, Some(9));
+
+        // 댓글은 해시와 용량 계산에 포함됨
+        let mut without = DocumentService::new_with_options("note".to_string(), false);
+        without.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        without.resolve_comment("c1".to_string()).unwrap();
+        assert_ne!(without.content_hash().unwrap(), hash_with_comments);
+        let content_bytes = doc.get_document_size_bytes().unwrap().content_bytes;
+        doc.clear_document().unwrap();
+        assert!(doc.validate_document().unwrap().orphaned_comment_ids.is_empty());
+        assert!(doc.get_comments_for_block("a".to_string()).unwrap().is_empty());
+        assert!(doc.get_document_size_bytes().unwrap().content_bytes < content_bytes);
+    }
+
+    #[test]
+    fn test_restore_snapshot_restores_comments() {
+        let mut doc = DocumentService::new_with_gc_disabled();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello world"}]"#)),
+        ]).unwrap();
+        doc.add_comment("a".to_string(), "kept".to_string(), "kim".to_string(), "typo?".to_string(), Some(6)).unwrap();
+        let snapshot = doc.take_snapshot().unwrap();
+        let before = doc.get_comments_for_block("a".to_string()).unwrap();
+
+        doc.resolve_comment("kept".to_string()).unwrap();
+        doc.add_comment("a".to_string(), "later".to_string(), "lee".to_string(), "x".to_string(), None).unwrap();
+        doc.apply_text_ops("a".to_string(), r#"[{"retain":5},{"insert":","}]"#.to_string()).unwrap();
+
+        let edited = doc.encode_full_state().unwrap();
+        let restore = doc.restore_snapshot(snapshot).unwrap();
+        assert_eq!(doc.get_comments_for_block("a".to_string()).unwrap(), before);
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![edited]).unwrap();
+        assert_eq!(peer.get_comments_for_block("a".to_string()).unwrap().len(), 2);
+        peer.apply_updates(vec![restore]).unwrap();
+        assert_eq!(peer.get_comments_for_block("a".to_string()).unwrap(), before);
+    }
+
+    #[test]
+    fn test_id_generator_seed() {
+        let run = |seed: Option<u64>| {
+            let mut doc = DocumentService::new();
+            if let Some(seed) = seed {
+                doc.set_id_generator_seed(seed);
+            }
+            doc.init_empty_doc().unwrap();
+            doc.import_markdown("# Title\n\nBody".to_string()).unwrap();
+            let root = doc.get_root_node_id().unwrap();
+            let inserted = doc.apply_action_with_ids(vec![block_action("", root.as_deref(), None, None)]).unwrap();
+            let copy = doc.duplicate_block(inserted.generated_ids[0].clone()).unwrap();
+            let mut ids: Vec<String> = doc.get_document_state().unwrap().blocks.keys().cloned().collect();
+            ids.sort();
+            (ids, inserted.generated_ids, copy.id_map)
+        };
+
+        let first = run(Some(7));
+        assert_eq!(first, run(Some(7)));
+        assert_ne!(first.0, run(Some(8)).0);
+        assert_ne!(run(None).0, run(None).0);
+        assert!(first.0.iter().all(|id| uuid::Uuid::parse_str(id).is_ok()));
+
+        // 시드를 다시 설정하면 처음부터 같은 순서
+        let doc = DocumentService::new();
+        doc.id_generator.set_seed(1);
+        let a = doc.id_generator.next_id();
+        doc.id_generator.set_seed(1);
+        assert_eq!(doc.id_generator.next_id(), a);
+        assert_ne!(doc.id_generator.next_id(), a);
+    }
+
+    #[test]
+    fn test_compact_updates() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let setup = local.apply_action(vec![
+            block_action("a", None, None, Some(r#"[{"insert":"Hello"}]"#)),
+            block_action("b", None, Some("a"), None),
+        ]).unwrap();
+        // 한 글자씩 입력한 뒤 지운 편집 기록
+        let mut session = vec![init.clone(), setup.clone()];
+        for i in 0..40 {
+            session.push(local.apply_text_ops("a".to_string(), format!(r#"[{{"retain":{}}},{{"insert":"x"}}]"#, 5 + i)).unwrap());
+        }
+        session.push(local.apply_text_ops("a".to_string(), r#"[{"retain":5},{"delete":40}]"#.to_string()).unwrap());
+
+        // 클라이언트가 같은 업데이트를 세 번 재전송한 로그
+        let log: Vec<Vec<u8>> = session.iter().cycle().take(session.len() * 3).cloned().collect();
+        let merged = local.merge_updates(log.clone()).unwrap();
+        let compacted = local.compact_updates(log).unwrap();
+        assert!(compacted.len() < merged.len(), "{} >= {}", compacted.len(), merged.len());
+
+        // 기존 기록과 이어지므로 원래 업데이트를 가진 피어에도 그대로 적용됨
+        let mut peer = DocumentService::new();
+        peer.apply_updates(session[..10].to_vec()).unwrap();
+        peer.apply_updates(vec![compacted.clone()]).unwrap();
+        let mut fresh = DocumentService::new();
+        fresh.apply_updates(vec![compacted]).unwrap();
+        for doc in [&mut peer, &mut fresh] {
+            assert_eq!(doc.get_block("a".to_string()).unwrap().unwrap().delta.as_deref(), Some(r#"[{"insert":"Hello"}]"#));
+            assert!(doc.get_block("b".to_string()).unwrap().is_some());
+        }
+
+        // 선행 업데이트가 빠진 로그는 합치지 않음
+        assert!(local.compact_updates(vec![init, session[2].clone()]).is_err());
+        assert!(local.compact_updates(vec![vec![0xff, 0x01]]).is_err());
+        assert!(local.compact_updates(vec![vec![0xff; 20]]).is_err());
+        assert!(local.compact_updates(vec![]).is_ok());
+    }
+
+    #[test]
+    fn test_emptiness_listener() {
+        let mut remote = DocumentService::new();
+        let init = remote.init_empty_doc().unwrap();
+        let mut doc = DocumentService::new();
+        doc.apply_updates(vec![init]).unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+
+        let received = Arc::new(Mutex::new(Vec::<bool>::new()));
+        let sink = received.clone();
+        doc.set_emptiness_listener(Arc::new(move |empty| sink.lock().unwrap().push(empty))).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![true]);
+
+        // 경계를 넘을 때만 전달, 그 사이 편집은 무시
+        doc.apply_action(vec![block_action("a", Some("page"), None, None)]).unwrap();
+        doc.apply_text_ops("a".to_string(), r#"[{"insert":"Hi"}]"#.to_string()).unwrap();
+        doc.apply_action(vec![block_action("b", Some("page"), Some("a"), None)]).unwrap();
+        doc.set_meta_string("title".to_string(), "x".to_string()).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![true, false]);
+
+        doc.delete_block_recursive("a".to_string()).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![true, false]);
+        doc.delete_block_recursive("b".to_string()).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![true, false, true]);
+
+        // 원격 변경도 반영
+        remote.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let update = remote.apply_action(vec![block_action("c", Some("page"), None, None)]).unwrap();
+        doc.apply_updates(vec![update]).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn test_enforce_single_root() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("child", Some("page"), None, None),
+            block_action("stray", None, Some("page"), None),
+        ]).unwrap();
+
+        doc.enforce_single_root().unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.children_map["root"], vec!["page".to_string()]);
+        assert_eq!(state.children_map["page"], vec!["child".to_string(), "stray".to_string()]);
+
+        // 이미 정규화된 문서에서는 변경 없음
+        let before = doc.encode_full_state().unwrap();
+        doc.enforce_single_root().unwrap();
+        assert_eq!(before, doc.encode_full_state().unwrap());
+    }
+
+    #[test]
+    fn test_meta_diff_against_json() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_meta_from_json(r#"{"title":"노트","color":1,"status":"active","pinned":true}"#.to_string()).unwrap();
+
+        let diff: JsonValue = serde_json::from_str(&doc.meta_diff_against_json(
+            r#"{"title":"노트","color":"1","status":null,"labelIds":["a"],"pinned":true}"#.to_string()
+        ).unwrap()).unwrap();
+
+        assert_eq!(diff["added"], json!({"labelIds": ["a"]}));
+        assert_eq!(diff["removed"], json!(["status"]));
+        assert_eq!(diff["changed"]["color"]["to"], "1");
+        assert!(diff["changed"].get("title").is_none());
+        assert!(diff["changed"].get("pinned").is_none());
+    }
+
+    #[test]
+    fn test_typed_meta_getters() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_meta_string("title".to_string(), "노트".to_string()).unwrap();
+        doc.set_meta_int("color".to_string(), 4294924083).unwrap();
+        doc.set_meta_bool("pinned".to_string(), true).unwrap();
+        doc.set_meta_string_array("labelIds".to_string(), vec!["a".to_string(), "b".to_string()]).unwrap();
+
+        assert_eq!(doc.get_meta_string("title".to_string()).unwrap(), Some("노트".to_string()));
+        assert_eq!(doc.get_meta_int("color".to_string()).unwrap(), Some(4294924083));
+        assert_eq!(doc.get_meta_bool("pinned".to_string()).unwrap(), Some(true));
+        assert_eq!(
+            doc.get_meta_string_array("labelIds".to_string()).unwrap(),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+
+        // 없는 키와 타입 불일치는 None
+        assert_eq!(doc.get_meta_string("missing".to_string()).unwrap(), None);
+        assert_eq!(doc.get_meta_bool("title".to_string()).unwrap(), None);
+        assert_eq!(doc.get_meta_string("color".to_string()).unwrap(), None);
+        assert_eq!(doc.get_meta_string_array("pinned".to_string()).unwrap(), None);
+
+        doc.set_meta_from_json(r#"{"ratio":0.5}"#.to_string()).unwrap();
+        assert_eq!(doc.get_meta_int("ratio".to_string()).unwrap(), None);
+
+        assert_eq!(doc.get_meta_keys().unwrap(), vec!["color", "labelIds", "pinned", "ratio", "title"]);
+        assert!(DocumentService::new().get_meta_keys().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stream_blocks_in_document_order() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("b", Some("page"), Some("a"), None),
+            block_action("a1", Some("a"), None, None),
+        ]).unwrap();
+
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = received.clone();
+        futures::executor::block_on(doc.stream_blocks(move |json: String| -> DartFnFuture<()> {
+            let block: JsonValue = serde_json::from_str(&json).unwrap();
+            sink.lock().unwrap().push(block["id"].as_str().unwrap().to_string());
+            Box::pin(async {})
+        })).unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec!["page", "a", "a1", "b"]);
+    }
+
+    #[test]
+    fn test_validate_appflowy_json() {
+        let valid = r#"{"document": {"type": "page", "id": "p", "children": [
+            {"type": "heading", "data": {"level": 1}, "delta": [{"insert": "Title"}]},
+            {"type": "paragraph", "id": "a", "children": []}
+        ]}}"#;
+        assert!(DocumentService::validate_appflowy_json(valid.to_string()).unwrap().is_empty());
+
+        let invalid = r#"{"document": {"type": "page", "id": "a", "children": [
+            {"data": []},
+            {"type": "paragraph", "id": "a", "delta": [{"bold": true}]},
+            {"type": "paragraph", "children": {}}
+        ]}}"#;
+        let problems = DocumentService::validate_appflowy_json(invalid.to_string()).unwrap();
+        assert_eq!(problems, vec![
+            "document.children[0]: missing \"type\"",
+            "document.children[0]: \"data\" must be an object",
+            "document.children[1]: duplicate id \"a\"",
+            "document.children[1].delta[0]: expected an insert, retain or delete operation",
+            "document.children[2]: \"children\" must be an array",
+        ]);
+
+        assert_eq!(
+            DocumentService::validate_appflowy_json("{}".to_string()).unwrap(),
+            vec!["missing root node \"document\""]
+        );
+        assert!(DocumentService::validate_appflowy_json("not json".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("a".to_string()).unwrap();
+        assert!(doc.undo().is_err());
+        doc.enable_undo_manager(vec![]).unwrap();
+        assert!(!doc.can_undo());
+
+        doc.apply_action(vec![block_action("a", None, None, Some(r#"[{"insert":"Hello"}]"#))]).unwrap();
+        assert!(doc.can_undo());
+
+        // undo 결과 업데이트를 받은 피어도 같은 상태가 되어야 함
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let undo_update = doc.undo().unwrap();
+        assert!(!doc.get_document_state().unwrap().blocks.contains_key("a"));
+        assert!(doc.can_redo());
+        peer.apply_updates(vec![peer.encode_full_state().unwrap(), undo_update]).unwrap();
+        assert!(!peer.get_document_state().unwrap().blocks.contains_key("a"));
+
+        doc.redo().unwrap();
+        assert!(doc.get_document_state().unwrap().blocks.contains_key("a"));
+        assert!(!doc.can_redo());
+        // 되돌릴 것이 없으면 변경 없는 업데이트 (피어에 그대로 보내도 디코딩됨)
+        let noop = doc.redo().unwrap();
+        assert_eq!(noop, Update::new().encode_v2());
+        assert!(peer.apply_updates(vec![noop]).unwrap().failed_updates.failed_updates_ids.is_empty());
+    }
+
+    #[test]
+    fn test_undo_update_carries_only_its_own_deletes() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        let ids: Vec<String> = (0..20).map(|i| format!("old{}", i)).collect();
+        for id in &ids {
+            doc.apply_action(vec![block_action(id, Some("page"), None, Some(r#"[{"insert":"tombstone me"}]"#))]).unwrap();
+        }
+        for id in &ids {
+            doc.apply_action(vec![BlockActionDoc { action: BlockActionTypeDoc::Delete, ..block_action(id, Some("page"), None, None) }]).unwrap();
+        }
+
+        doc.enable_undo_manager(vec![]).unwrap();
+        doc.apply_action(vec![block_action("a", Some("page"), None, None)]).unwrap();
+        let before = doc.encode_full_state().unwrap();
+        let old_tombstones = doc.doc.transact().snapshot().delete_set;
+        let undo_update = doc.undo().unwrap();
+
+        // 이전 툼스톤이 아니라 이번 undo의 삭제만 담겨야 함
+        let undo_deletes = Update::decode_v2(&undo_update).unwrap().delete_set().clone();
+        assert!(!undo_deletes.is_empty());
+        for (client, ranges) in old_tombstones.iter() {
+            for range in ranges.iter() {
+                assert!(range.clone().all(|clock| !undo_deletes.is_deleted(&yrs::ID::new(*client, clock))));
+            }
+        }
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![before, undo_update]).unwrap();
+        assert!(!peer.get_document_state().unwrap().blocks.contains_key("a"));
+        assert!(peer.get_document_state().unwrap().blocks.contains_key("page"));
+    }
+
+    #[test]
+    fn test_undo_ignores_remote_updates() {
+        let mut remote = DocumentService::new();
+        remote.init_empty_doc().unwrap();
+        remote.set_root_node_id("remote".to_string()).unwrap();
+        remote.apply_action(vec![block_action("remote", None, None, None)]).unwrap();
+
+        let mut doc = DocumentService::new();
+        doc.apply_updates(vec![remote.encode_full_state().unwrap()]).unwrap();
+        doc.enable_undo_manager(vec![]).unwrap();
+
+        let remote_edit = remote.apply_action(vec![block_action("remote2", None, Some("remote"), None)]).unwrap();
+        doc.apply_updates(vec![doc.encode_full_state().unwrap(), remote_edit]).unwrap();
+
+        assert!(!doc.can_undo());
+        assert!(doc.get_document_state().unwrap().blocks.contains_key("remote2"));
+    }
+
+    #[test]
+    fn test_apply_action_is_atomic() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+        let before = doc.encode_full_state().unwrap();
+
+        // 세 번째 액션의 retain이 앞에서 넣은 텍스트 길이를 넘으므로 배치 전체가 거부되어야 함
+        let mut update = block_action("a", Some("page"), None, Some(r#"[{"retain":10},{"insert":"!"}]"#));
+        update.action = BlockActionTypeDoc::Update;
+        let result = doc.apply_action(vec![
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello"}]"#)),
+            block_action("b", Some("page"), Some("a"), None),
+            update,
+        ]);
+        assert!(result.is_err());
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+
+        // 없는 부모 아래 삽입도 거부
+        assert!(doc.apply_action(vec![block_action("c", Some("missing"), None, None)]).is_err());
+
+        // 같은 배치에서 먼저 만든 블록은 부모와 텍스트 길이 모두 반영됨
+        let mut update = block_action("a", Some("page"), None, Some(r#"[{"retain":5},{"insert":"!"}]"#));
+        update.action = BlockActionTypeDoc::Update;
+        doc.apply_action(vec![
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello"}]"#)),
+            block_action("a1", Some("a"), None, None),
+            update,
+        ]).unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.blocks["a"].delta.as_deref(), Some(r#"[{"insert":"Hello!"}]"#));
+        assert!(state.blocks.contains_key("a1"));
+
+        // 앞에서 지운 블록의 하위 블록은 다시 지워도 되지만, 그 아래에 삽입할 수는 없음
+        let delete = |id: &str| {
+            let mut action = block_action(id, Some("page"), None, None);
+            action.action = BlockActionTypeDoc::Delete;
+            action
+        };
+        let before = doc.encode_full_state().unwrap();
+        assert!(doc.apply_action(vec![delete("a"), block_action("a2", Some("a1"), None, None)]).is_err());
+        assert_eq!(doc.encode_full_state().unwrap(), before);
+        assert!(doc.validate_action(vec![delete("a"), delete("a1")]).is_ok());
+    }
+
+    #[test]
+    fn test_active_editor_count() {
+        let doc = DocumentService::new();
+        assert_eq!(doc.active_editor_count(60_000), 0);
+
+        doc.awareness.set_local_state_raw(r#"{"user":"local"}"#);
+        let remote = Awareness::new(Doc::new());
+        remote.set_local_state_raw(r#"{"user":"remote"}"#);
+        doc.awareness.apply_update(remote.update().unwrap()).unwrap();
+        assert_eq!(doc.active_editor_count(60_000), 2);
+
+        // 연결을 끊은 클라이언트는 세지 않음
+        doc.awareness.remove_state(remote.client_id());
+        assert_eq!(doc.active_editor_count(60_000), 1);
+    }
+
+    #[test]
+    fn test_awareness_update_roundtrip() {
+        let mut local = DocumentService::new();
+        let mut remote = DocumentService::new();
+        remote.set_local_awareness(r##"{"user":"remote","color":"#00ff00"}"##.to_string()).unwrap();
+        assert!(remote.set_local_awareness("not json".to_string()).is_err());
+
+        let before = local.encode_state_vector().unwrap();
+        let remote_id = remote.awareness.client_id();
+        let changes: JsonValue = serde_json::from_str(
+            &local.apply_awareness_update(remote.encode_awareness_update().unwrap()).unwrap()
+        ).unwrap();
+        assert_eq!(changes["added"], json!([remote_id]));
+        assert_eq!(local.active_editor_count(60_000), 1);
+        // awareness는 문서 내용을 바꾸지 않음
+        assert_eq!(local.encode_state_vector().unwrap(), before);
+
+        assert!(local.remove_awareness_client(remote_id));
+        assert!(!local.remove_awareness_client(remote_id));
+        assert_eq!(local.active_editor_count(60_000), 0);
+        assert!(local.apply_awareness_update(vec![0xff]).is_err());
+    }
+
+    #[test]
+    fn test_additive_update_for_keeps_peer_content() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hello"}]"#)),
+        ]).unwrap();
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let peer_sv = peer.doc.transact().state_vector().encode_v1();
+
+        // 로컬에서 a를 지우고 b를 추가
+        let mut delete = block_action("a", Some("page"), None, None);
+        delete.action = BlockActionTypeDoc::Delete;
+        doc.apply_action(vec![delete]).unwrap();
+        doc.apply_action(vec![block_action("b", Some("page"), None, Some(r#"[{"insert":"New"}]"#))]).unwrap();
+
+        let additive = doc.additive_update_for(peer_sv).unwrap();
+        peer.apply_updates(vec![peer.encode_full_state().unwrap(), additive]).unwrap();
+
+        let state = peer.get_document_state().unwrap();
+        assert!(state.blocks.contains_key("a"));
+        assert_eq!(state.blocks["a"].delta.as_deref(), Some(r#"[{"insert":"Hello"}]"#));
+        assert!(state.blocks.contains_key("b"));
+    }
+
+    #[test]
+    fn test_change_listener_survives_apply_updates() {
+        let mut remote = DocumentService::new();
+        remote.init_empty_doc().unwrap();
+        remote.set_root_node_id("page".to_string()).unwrap();
+        remote.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let mut doc = DocumentService::new();
+        doc.set_change_listener(Arc::new(move |update, _| sink.lock().unwrap().push(update))).unwrap();
+
+        // 원격 업데이트 적용 후 로컬 편집도 계속 전달되어야 함
+        doc.apply_updates(vec![remote.encode_full_state().unwrap()]).unwrap();
+        doc.apply_action(vec![block_action("a", Some("page"), None, None)]).unwrap();
+        assert_eq!(received.lock().unwrap().len(), 2);
+
+        let mut mirror = DocumentService::new();
+        mirror.apply_updates(received.lock().unwrap().clone()).unwrap();
+        let state = mirror.get_document_state().unwrap();
+        assert!(state.blocks.contains_key("page"));
+        assert!(state.blocks.contains_key("a"));
+    }
+
+    #[test]
+    fn test_change_listener_receives_transaction_origin() {
+        let mut remote = DocumentService::new();
+        let init = remote.init_empty_doc().unwrap();
+        let remote_update = remote.apply_action(vec![block_action("r", None, None, None)]).unwrap();
+
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let mut doc = DocumentService::new();
+        doc.set_change_listener(Arc::new(move |_, origin| sink.lock().unwrap().push(origin))).unwrap();
+
+        doc.apply_updates(vec![init, remote_update]).unwrap();
+        doc.apply_action_with_origin(vec![block_action("a", None, Some("r"), None)], "client-1".to_string()).unwrap();
+        doc.apply_action(vec![block_action("b", None, Some("a"), None)]).unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![
+            Some(REMOTE_ORIGIN.to_string()),
+            Some("client-1".to_string()),
+            None,
+        ]);
+        assert!(doc.get_block("a".to_string()).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_apply_updates_keeps_doc_and_skips_bad_updates() {
+        let mut remote = DocumentService::new();
+        remote.init_empty_doc().unwrap();
+        remote.set_root_node_id("page".to_string()).unwrap();
+        remote.apply_action(vec![block_action("page", None, None, None)]).unwrap();
+
+        let mut doc = DocumentService::new();
+        doc.apply_updates(vec![remote.encode_full_state().unwrap()]).unwrap();
+        doc.enable_undo_manager(vec![]).unwrap();
+        doc.apply_action(vec![block_action("local", Some("page"), None, None)]).unwrap();
+
+        // 깨진 업데이트가 섞여 있어도 나머지는 적용되고, 로컬 undo 스택도 유지됨
+        let remote_edit = remote.apply_action(vec![block_action("remote", Some("page"), None, None)]).unwrap();
+        doc.apply_updates(vec![vec![0xff, 0x01, 0x02], remote_edit]).unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert!(state.blocks.contains_key("remote"));
+        assert!(state.blocks.contains_key("local"));
+        assert!(doc.can_undo());
+
+        doc.undo().unwrap();
+        let state = doc.get_document_state().unwrap();
+        assert!(!state.blocks.contains_key("local"));
+        assert!(state.blocks.contains_key("remote"));
+    }
+
+    #[test]
+    fn test_children_order_hash() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, None),
+            block_action("b", Some("page"), Some("a"), None),
+        ]).unwrap();
+
+        let mut peer = DocumentService::new();
+        peer.apply_updates(vec![doc.encode_full_state().unwrap()]).unwrap();
+        let hash = doc.children_order_hash("page".to_string()).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(peer.children_order_hash("page".to_string()).unwrap(), hash);
+        assert_ne!(doc.children_order_hash("a".to_string()).unwrap(), hash);
+        assert!(doc.children_order_hash("missing".to_string()).is_err());
+
+        // prevId가 형제가 아닌 블록을 가리키면 체인이 끊긴 것으로 봄
+        doc.apply_action(vec![block_action("c", Some("page"), Some("elsewhere"), None)]).unwrap();
+        assert!(doc.children_order_hash("page".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_format_blocks() {
+        let mut doc = DocumentService::new();
+        doc.init_empty_doc().unwrap();
+        doc.set_root_node_id("page".to_string()).unwrap();
+        doc.apply_action(vec![
+            block_action("page", None, None, None),
+            block_action("a", Some("page"), None, Some(r#"[{"insert":"Hi"}]"#)),
+            block_action("b", Some("page"), Some("a"), Some(r#"[{"insert":"Yo","attributes":{"italic":true}}]"#)),
+        ]).unwrap();
+
+        let update = doc.format_blocks(
+            vec!["a".to_string(), "missing".to_string(), "b".to_string()],
+            r#"{"bold":true,"italic":null}"#.to_string(),
+        ).unwrap();
+        assert!(!update.is_empty());
+
+        let state = doc.get_document_state().unwrap();
+        assert_eq!(state.blocks["a"].delta.as_deref(), Some(r#"[{"attributes":{"bold":true},"insert":"Hi"}]"#));
+        assert_eq!(state.blocks["b"].delta.as_deref(), Some(r#"[{"attributes":{"bold":true},"insert":"Yo"}]"#));
+
+        assert!(doc.format_blocks(vec!["a".to_string()], "[]".to_string()).is_err());
     }
 
     #[test]
@@ -13,7 +13,7 @@ pub enum BlockActionTypeDoc {
 }
 
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BlockDoc {
     pub id: String,
     pub ty: String,
@@ -27,6 +27,11 @@ pub struct BlockDoc {
     pub next_id: Option<String>,
     #[serde(rename = "oldParentId")]
     pub old_parent_id: Option<String>, //For Move action
+    // Milliseconds since epoch as passed by the editing client (None for blocks written without a timestamp)
+    #[serde(rename = "createdAt", default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+    #[serde(rename = "updatedAt", default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<i64>,
 }
 
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
@@ -40,16 +45,161 @@ pub struct BlockActionDoc {
 }
 
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
 pub struct FailedToDecodeUpdates {
     pub failed_updates_ids: Vec<String>,
+    // Decoder error for each entry of failed_updates_ids, in the same order
+    pub errors: Vec<String>,
+}
+
+// Blocks touched by a remote update, so Flutter can repaint only what changed
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct AppliedBlockChanges {
+    pub inserted_block_ids: Vec<String>,
+    pub updated_block_ids: Vec<String>,
+    pub deleted_block_ids: Vec<String>,
+    // Updates in the batch that could not be decoded and were skipped
+    pub failed_updates: FailedToDecodeUpdates,
+}
+
+
+// One occurrence found by search_text; offset and length count characters in the block's plain text
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TextMatch {
+    pub block_id: String,
+    pub offset: u32,
+    pub length: u32,
+}
+
+
+// Storage weight of a document; full_state_bytes includes deletion history that content_bytes leaves out
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DocSize {
+    pub full_state_bytes: u64,
+    pub content_bytes: u64,
+}
+
+// Word/character counts of one block, for counts shown in the margin
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BlockStats {
+    pub block_id: String,
+    pub word_count: u32,
+    pub char_count: u32,
+    pub char_count_no_spaces: u32,
+}
+
+// Document totals for writing goals; blocks is only filled by get_statistics_with_blocks
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DocStats {
+    pub word_count: u32,
+    pub char_count: u32,
+    pub char_count_no_spaces: u32,
+    pub block_count: u32,
+    pub blocks: Option<Vec<BlockStats>>,
+}
+
+// One meta write for set_meta_batch; mirrors the individual set_meta_* / remove / array item calls
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MetaOp {
+    SetString { key: String, value: String },
+    SetInt { key: String, value: i64 },
+    SetBool { key: String, value: bool },
+    SetStringArray { key: String, values: Vec<String> },
+    Remove { key: String },
+    PushArrayItem { key: String, value: String },
+    RemoveArrayItem { key: String, value: String },
+}
+
+
+// Structural problems found by validate_document; every list is sorted and empty when the tree is sound
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct DocumentValidation {
+    // Blocks whose parentId names a block that doesn't exist
+    pub orphaned_block_ids: Vec<String>,
+    // Blocks that are part of a parent/child cycle
+    pub cyclic_block_ids: Vec<String>,
+    // Blocks whose prevId or nextId names a block that doesn't exist
+    pub dangling_reference_ids: Vec<String>,
+    // Comments whose block has been deleted
+    pub orphaned_comment_ids: Vec<String>,
 }
 
 
+// An inline comment on a block; anchor_offset is where in the block's text it was placed, tracked
+// through later edits, and None for whole-block comments or when the anchor can't be resolved
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CommentDoc {
+    pub comment_id: String,
+    pub block_id: String,
+    pub author: String,
+    pub text: String,
+    pub resolved: bool,
+    pub anchor_offset: Option<u32>,
+}
+
+// A caret position resolved from an encoded relative position; offset uses the same units as delta retain
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RelativePos {
+    pub block_id: String,
+    pub offset: u32,
+}
+
+// A block in document order with its nesting depth (root page 0, its children 1, ...; -1 for blocks unreachable from the root)
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrderedBlock {
+    pub block: BlockDoc,
+    pub depth: i32,
+}
+
+// One window of get_blocks_in_order for streaming huge documents; total counts every block in the document
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockPage {
+    pub blocks: Vec<BlockDoc>,
+    pub total: usize,
+    pub has_more: bool,
+}
+
+// Result of apply_action_with_ids: the update and the ids assigned to inserts that came without one, in action order
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ActionResult {
+    pub update: Vec<u8>,
+    pub generated_ids: Vec<String>,
+}
+
+// Result of duplicate_block: the update to broadcast and which new id each copied block got
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DuplicateResult {
+    pub update: Vec<u8>,
+    pub id_map: HashMap<String, String>,
+}
+
+
+// One committed document update together with the origin of the transaction that produced it
+// (None for untagged local edits, "remote" for apply_updates)
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OriginUpdate {
+    pub update: Vec<u8>,
+    pub origin: Option<String>,
+}
+
 
 // Define the document structure for Flutter
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DocumentState {
     pub doc_id: String,
     pub blocks: HashMap<String, BlockDoc>,
@@ -62,12 +212,15 @@ pub struct DocumentState {
 #[frb]
 #[derive(Debug)]
 pub struct CustomRustError {
+    /// 에러 종류 코드 (DocError에서 변환된 경우 변형별 코드, 그 외 "unknown")
+    pub code: String,
     pub message: String,
 }
 
 impl CustomRustError {
     pub fn new(message: &str) -> Self {
         CustomRustError {
+            code: "unknown".to_string(),
             message: message.to_string(),
         }
     }
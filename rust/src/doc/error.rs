@@ -12,6 +12,7 @@ pub enum DocError {
     MergeError(String),
     UpdateDecodingFailed(String),
     StateEncodingFailed(String),
+    Io(String),
 }
 
 impl fmt::Display for DocError {
@@ -26,12 +27,56 @@ impl fmt::Display for DocError {
             Self::MergeError(msg) => write!(f, "Merge error: {}", msg),
             Self::UpdateDecodingFailed(msg) => write!(f, "Update decoding failed: {}", msg),
             Self::StateEncodingFailed(msg) => write!(f, "State encoding failed: {}", msg),
+            Self::Io(msg) => write!(f, "IO error: {}", msg),
+        }
+    }
+}
+
+impl DocError {
+    /// Dart 쪽에서 에러 종류를 구분할 때 쓰는 고정 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidOperation(_) => "invalid_operation",
+            Self::DecodingError(_) => "decoding_error",
+            Self::EncodingError(_) => "encoding_error",
+            Self::ValidationError(_) => "validation_error",
+            Self::StateError(_) => "state_error",
+            Self::BlockNotFound(_) => "block_not_found",
+            Self::MergeError(_) => "merge_error",
+            Self::UpdateDecodingFailed(_) => "update_decoding_failed",
+            Self::StateEncodingFailed(_) => "state_encoding_failed",
+            Self::Io(_) => "io",
         }
     }
 }
 
 impl From<DocError> for CustomRustError {
     fn from(error: DocError) -> Self {
-        CustomRustError::new(&error.to_string())
+        CustomRustError {
+            code: error.code().to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_is_carried_into_custom_error() {
+        let io: CustomRustError = DocError::Io("disk full".to_string()).into();
+        assert_eq!(io.code, "io");
+        assert_eq!(io.message, "IO error: disk full");
+
+        let validation: CustomRustError = DocError::ValidationError("cycle".to_string()).into();
+        assert_eq!(validation.code, "validation_error");
+        assert_eq!(validation.message, "Validation error: cycle");
+
+        let legacy: CustomRustError = DocError::EncodingError("bad".to_string()).into();
+        assert_eq!(legacy.code, "encoding_error");
+        assert_eq!(legacy.message, "Encoding error: bad");
+
+        assert_eq!(CustomRustError::new("plain").code, "unknown");
     }
-}
\ No newline at end of file
+}
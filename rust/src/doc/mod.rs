@@ -1,7 +1,9 @@
 pub mod document_service;
+pub mod document_registry;
 /// flutter_rust_bridge:ignore
 pub mod utils;
 pub mod document_types;
+/// flutter_rust_bridge:ignore
 pub mod error;
 /// flutter_rust_bridge:ignore
 pub mod operations;
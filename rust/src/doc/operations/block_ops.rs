@@ -1,25 +1,28 @@
 use flutter_rust_bridge::DartFnFuture;
 use log::info;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use yrs::{ Array, ArrayRef, Map, MapPrelim, MapRef, ReadTxn, TextRef, TransactionMut };
+use yrs::{ Array, ArrayRef, Map, MapPrelim, MapRef, ReadTxn, Text, TextRef, TransactionMut };
 
-use crate::doc::constants::{ ATTRIBUTES, DEFAULT_PARENT, ID, PARENT_ID, PREV_ID, TEXT, TYPE };
-use crate::doc::document_types::{ BlockActionDoc, CustomRustError };
+use crate::doc::constants::{ ATTRIBUTES, CODE_TYPE, CREATED_AT, DEFAULT_PARENT, ID, MERGEABLE_TEXT_TYPES, PARENT_ID, PREV_ID, TEXT, TYPE, UPDATED_AT };
+use crate::doc::document_types::{ BlockActionDoc, BlockActionTypeDoc, BlockDoc, CustomRustError, DocumentValidation };
 use crate::doc::error::DocError;
 use crate::doc::operations::delta_ops::DeltaOperations;
-use crate::doc::utils::util::MapExt;
+use crate::doc::utils::sorting::ChainSorting;
+use crate::doc::utils::util::{IdGenerator, MapExt};
 
 use crate::{ log_info, log_error };
 
 pub struct BlockOperations;
 
 impl BlockOperations {
-    /// Insert a new block node into the document
+    /// Insert a new block node into the document.
+    /// With a timestamp (milliseconds from the editing client) the block records it as created and updated time.
     pub fn insert_node(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
         action: BlockActionDoc,
+        timestamp: Option<i64>,
     ) -> Result<MapRef, CustomRustError> {
         let block_id = action.block.id.clone();
         log_info!("insert_node: Starting for block_id: {}", block_id);
@@ -35,6 +38,11 @@ impl BlockOperations {
             node_ref.insert(txn, Arc::from(PARENT_ID), parent_id.clone());
         }
 
+        if let Some(timestamp) = timestamp {
+            node_ref.insert(txn, Arc::from(CREATED_AT), timestamp);
+            Self::touch_updated_at(txn, &node_ref, timestamp);
+        }
+
         // Set attributes
         let mut attr_map = MapPrelim::default();
         for (k, v) in action.block.attributes {
@@ -75,16 +83,75 @@ impl BlockOperations {
         Ok(node_ref)
     }
 
-    /// Update an existing block node in the document
+    /// Give every Insert action without a block id a fresh one, returning the generated ids
+    /// in action order. Caller-provided ids are left as they are.
+    pub fn assign_missing_ids(actions: &mut [BlockActionDoc], ids: &IdGenerator) -> Vec<String> {
+        let mut generated = Vec::new();
+        for action in actions.iter_mut() {
+            if matches!(action.action, BlockActionTypeDoc::Insert) && action.block.id.is_empty() {
+                action.block.id = ids.next_id();
+                log_info!("assign_missing_ids: Generated id {} for insert", action.block.id);
+                generated.push(action.block.id.clone());
+            }
+        }
+        generated
+    }
+
+    /// Write a block into the blocks map exactly as described, replacing any existing
+    /// entry with the same id. Unlike `insert_node` this doesn't rewire sibling prev_id
+    /// chains, so the caller is responsible for passing consistent parent/prev ids.
+    pub fn write_block(
+        txn: &mut TransactionMut,
+        blocks_map: &MapRef,
+        block: &BlockDoc,
+    ) -> Result<MapRef, CustomRustError> {
+        log_info!("write_block: Writing block_id: {}", block.id);
+
+        let node_ref = blocks_map.insert(txn, block.id.clone(), MapPrelim::default());
+        node_ref.insert(txn, Arc::from(ID), block.id.clone());
+        node_ref.insert(txn, Arc::from(TYPE), block.ty.clone());
+
+        if let Some(parent_id) = &block.parent_id {
+            node_ref.insert(txn, Arc::from(PARENT_ID), parent_id.clone());
+        }
+        if let Some(prev_id) = &block.prev_id {
+            node_ref.insert(txn, Arc::from(PREV_ID), prev_id.clone());
+        }
+        if let Some(created_at) = block.created_at {
+            node_ref.insert(txn, Arc::from(CREATED_AT), created_at);
+        }
+        if let Some(updated_at) = block.updated_at {
+            Self::touch_updated_at(txn, &node_ref, updated_at);
+        }
+
+        let mut attr_map = MapPrelim::default();
+        for (k, v) in &block.attributes {
+            attr_map.insert(k.as_str().into(), v.clone().into());
+        }
+        node_ref.insert(txn, Arc::from(ATTRIBUTES), attr_map);
+
+        if let Some(delta_json) = &block.delta {
+            let text = node_ref.get_or_init_text(txn, TEXT);
+            DeltaOperations::apply_delta_to_text(txn, text, delta_json.clone())?;
+        }
+
+        Ok(node_ref)
+    }
+
+    /// Update an existing block node in the document, recording the timestamp as updated time when given
     pub fn update_node(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
         action: BlockActionDoc,
+        timestamp: Option<i64>,
     ) -> Result<(), CustomRustError> {
         let block_id = action.block.id.clone();
         log_info!("update_node: Updating block_id: {}", block_id);
 
         let node = blocks_map.get_or_init_map(txn, block_id.clone());
+        if let Some(timestamp) = timestamp {
+            Self::touch_updated_at(txn, &node, timestamp);
+        }
 
         // Update attributes if any
         if !action.block.attributes.is_empty() {
@@ -103,6 +170,37 @@ impl BlockOperations {
         log_info!("update_node: Updated block_id: {}", block_id);
         Ok(())
     }
+
+    /// Record an edit time under this client's own key of the block's UPDATED_AT map.
+    /// Each client only raises its own entry, so concurrent edits merge without a
+    /// last-writer-wins overwrite and the max over all entries never goes backwards.
+    fn touch_updated_at(txn: &mut TransactionMut, node: &MapRef, timestamp: i64) {
+        let clocks = node.get_or_init_map(txn, UPDATED_AT);
+        let client = txn.doc().client_id().to_string();
+        let latest = clocks.get(txn, &client)
+            .and_then(|out| Self::timestamp_value(&out))
+            .map_or(timestamp, |current| current.max(timestamp));
+        clocks.insert(txn, client, latest);
+    }
+
+    /// Created and last updated time of a block (max over all clients), when recorded
+    pub fn block_timestamps<T: ReadTxn>(txn: &T, node: &MapRef) -> (Option<i64>, Option<i64>) {
+        let created_at = node.get(txn, CREATED_AT).and_then(|out| Self::timestamp_value(&out));
+        let updated_at = match node.get(txn, UPDATED_AT) {
+            Some(yrs::Out::YMap(clocks)) => clocks.iter(txn).filter_map(|(_, out)| Self::timestamp_value(&out)).max(),
+            _ => None,
+        };
+        (created_at, updated_at)
+    }
+
+    fn timestamp_value(out: &yrs::Out) -> Option<i64> {
+        match out {
+            yrs::Out::Any(yrs::Any::Number(n)) if n.fract() == 0.0 => Some(*n as i64),
+            yrs::Out::Any(yrs::Any::BigInt(n)) => Some(*n),
+            _ => None,
+        }
+    }
+
     /// Delete a block node and its descendants from the document
     pub fn delete_node(
         txn: &mut TransactionMut,
@@ -159,9 +257,20 @@ impl BlockOperations {
             old_parent_id,
             parent_id
         );
+
+        // Reparenting under itself or a descendant would make the tree cyclic
+        if parent_id == block_id || Self::ancestors(txn, &blocks_map, parent_id).iter().any(|id| id == block_id) {
+            log_error!("move_block: Block {} cannot be moved into its own descendant {}", block_id, parent_id);
+            return Err(DocError::InvalidOperation("cannot move block into its own descendant".into()).into());
+        }
     
         // Update the prev_id chain
         Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), block_id)?;
+
+        // A concurrent move can leave prev_id/next_id pointing at blocks that are gone
+        // or now live elsewhere, so check them against the target parent's children
+        let siblings = Self::children_in_order(txn, &blocks_map, parent_id, block_id)?;
+        let (prev_id, next_id) = Self::resolve_insertion_point(&siblings, prev_id, next_id);
     
         Self::handle_following_connection(
             txn,
@@ -193,6 +302,94 @@ impl BlockOperations {
         Ok(())
     }
 
+    /// Ids of the children of `parent_id` in sibling order, leaving out `exclude`.
+    /// DEFAULT_PARENT selects the top-level blocks.
+    /// Only the fields chain sorting reads (parent, prev and the device/timestamp
+    /// attributes) are copied out, so sibling text is never serialized.
+    pub fn children_in_order<T: ReadTxn>(
+        txn: &T,
+        blocks_map: &MapRef,
+        parent_id: &str,
+        exclude: &str,
+    ) -> Result<Vec<String>, CustomRustError> {
+        let parent_id = if parent_id == DEFAULT_PARENT { "root" } else { parent_id };
+        let read_string = |map: &MapRef, key: &str| match map.get(txn, key) {
+            Some(yrs::Out::Any(yrs::Any::String(s))) => Some(s.to_string()),
+            _ => None,
+        };
+
+        let mut siblings = HashMap::new();
+        for (id, block) in blocks_map.iter(txn) {
+            let yrs::Out::YMap(block_map) = block else { continue };
+            if id == exclude {
+                continue;
+            }
+            let block_parent = read_string(&block_map, PARENT_ID);
+            if block_parent.as_deref().unwrap_or("root") != parent_id {
+                continue;
+            }
+            // The excluded block is being moved, so a sibling still pointing at it is relinked
+            let prev_id = read_string(&block_map, PREV_ID).filter(|prev| prev != exclude);
+            let mut attributes = HashMap::new();
+            if let Some(yrs::Out::YMap(attrs)) = block_map.get(txn, ATTRIBUTES) {
+                for key in ["device", "timestamp"] {
+                    if let Some(value) = attrs.get(txn, key) {
+                        attributes.insert(key.to_string(), value.to_string(txn));
+                    }
+                }
+            }
+            siblings.insert(id.to_string(), BlockDoc {
+                id: id.to_string(),
+                ty: String::new(),
+                attributes,
+                delta: None,
+                parent_id: block_parent,
+                prev_id,
+                next_id: None,
+                old_parent_id: None,
+                created_at: None,
+                updated_at: None,
+            });
+        }
+
+        Ok(ChainSorting::sort_blocks_by_chain(&siblings)
+            .remove(parent_id)
+            .unwrap_or_default())
+    }
+
+    /// Pick the prev_id/next_id a moved block is linked with, given the target parent's
+    /// current children. A valid prev_id wins; otherwise the block goes right before a
+    /// valid next_id; if the caller named neighbours but none of them is a child of the
+    /// target parent anymore, the block is appended. With no neighbours at all the block
+    /// becomes the first child, ahead of the existing one.
+    fn resolve_insertion_point(
+        siblings: &[String],
+        prev_id: Option<String>,
+        next_id: Option<String>,
+    ) -> (Option<String>, Option<String>) {
+        let position = |id: &Option<String>| id.as_ref().and_then(|id| siblings.iter().position(|sibling| sibling == id));
+
+        match (position(&prev_id), position(&next_id)) {
+            (Some(prev_index), next_index) => {
+                // next_id only helps when it really is the block right after prev_id
+                let next_id = next_index.filter(|index| *index == prev_index + 1).and(next_id);
+                (prev_id, next_id)
+            }
+            (None, Some(next_index)) => {
+                if prev_id.is_some() {
+                    log_info!("resolve_insertion_point: prev_id {:?} is stale, placing before {:?}", prev_id, next_id);
+                }
+                let prev_id = next_index.checked_sub(1).map(|index| siblings[index].clone());
+                (prev_id, next_id)
+            }
+            (None, None) if prev_id.is_none() && next_id.is_none() => (None, siblings.first().cloned()),
+            (None, None) => {
+                log_info!("resolve_insertion_point: prev_id {:?} and next_id {:?} are stale, appending", prev_id, next_id);
+                (siblings.last().cloned(), None)
+            }
+        }
+    }
+
     /// Find all blocks that reference a given prev_id
     fn find_block_referencing_prev_id(
         txn: &mut TransactionMut,
@@ -288,7 +485,7 @@ impl BlockOperations {
     }
 
     /// Remove a block from the prev_id chain
-    fn remove_block_from_prev_id_chain(
+    pub fn remove_block_from_prev_id_chain(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
         block_id: &str
@@ -445,6 +642,311 @@ impl BlockOperations {
         Ok(())
     }
 
+    /// Dry-run a batch of actions against the current blocks so `apply_action` can reject it
+    /// before mutating anything. Tracks inserts, deletes and text lengths across the batch,
+    /// so later actions are checked against the state left by earlier ones.
+    pub fn validate_actions<T: ReadTxn>(
+        txn: &T,
+        blocks_map: Option<&MapRef>,
+        actions: &[BlockActionDoc],
+        allowed_types: Option<&HashSet<String>>,
+    ) -> Result<(), CustomRustError> {
+        // Only the blocks the batch mentions are read, with earlier actions layered on top
+        let mut view = BatchView::new(txn, blocks_map);
+        let mut text_lens: HashMap<String, u32> = HashMap::new();
+
+        for (index, action) in actions.iter().enumerate() {
+            let block = &action.block;
+            if block.id.is_empty() {
+                return Err(DocError::ValidationError(format!("Action {}: block id is empty", index)).into());
+            }
+            let exists = view.exists(&block.id);
+
+            match action.action {
+                BlockActionTypeDoc::Insert => {
+                    Self::check_block_type(index, &block.ty, allowed_types)?;
+                    Self::check_parent_present(index, block.parent_id.as_deref(), &view)?;
+                    if !exists {
+                        text_lens.insert(block.id.clone(), 0);
+                    }
+                    view.set_parent(&block.id, block.parent_id.clone().filter(|p| p != DEFAULT_PARENT));
+                }
+                BlockActionTypeDoc::Update => {
+                    if !exists {
+                        return Err(DocError::ValidationError(format!(
+                            "Action {}: cannot update missing block {}", index, block.id
+                        )).into());
+                    }
+                    // Updates don't rewrite the type, so an empty one just means "unchanged"
+                    if !block.ty.is_empty() {
+                        Self::check_block_type(index, &block.ty, allowed_types)?;
+                    }
+                }
+                BlockActionTypeDoc::Delete => {
+                    if !exists {
+                        // Already removed as a descendant of an earlier delete in this batch
+                        if view.was_deleted(&block.id) {
+                            continue;
+                        }
+                        return Err(DocError::ValidationError(format!(
+                            "Action {}: cannot delete missing block {}", index, block.id
+                        )).into());
+                    }
+                    for id in view.delete_subtree(&block.id) {
+                        text_lens.insert(id, 0);
+                    }
+                    continue;
+                }
+                BlockActionTypeDoc::Move => {
+                    if action.old_path.is_none() || block.parent_id.is_none() || block.old_parent_id.is_none() {
+                        return Err(DocError::InvalidOperation("Missing required fields for move operation".into()).into());
+                    }
+                    if !exists {
+                        return Err(DocError::ValidationError(format!(
+                            "Action {}: cannot move missing block {}", index, block.id
+                        )).into());
+                    }
+                    Self::check_parent_present(index, block.parent_id.as_deref(), &view)?;
+                    let mut ancestor = block.parent_id.clone().filter(|p| p != DEFAULT_PARENT);
+                    let mut visited = HashSet::new();
+                    while let Some(id) = ancestor {
+                        if id == block.id {
+                            return Err(DocError::InvalidOperation("cannot move block into its own descendant".into()).into());
+                        }
+                        if !visited.insert(id.clone()) {
+                            break;
+                        }
+                        ancestor = view.parent(&id).flatten();
+                    }
+                    view.set_parent(&block.id, block.parent_id.clone().filter(|p| p != DEFAULT_PARENT));
+                }
+            }
+
+            if let Some(delta_json) = &block.delta {
+                let current_len = match text_lens.get(&block.id) {
+                    Some(len) => *len,
+                    None => blocks_map
+                        .and_then(|blocks_map| match blocks_map.get(txn, &block.id) {
+                            Some(yrs::Out::YMap(node)) => match node.get(txn, TEXT) {
+                                Some(yrs::Out::YText(text)) => Some(text.len(txn)),
+                                _ => None,
+                            },
+                            _ => None,
+                        })
+                        .unwrap_or(0),
+                };
+                let new_len = DeltaOperations::delta_len_after(current_len, delta_json)
+                    .map_err(|e| DocError::ValidationError(format!("Action {}: {}", index, e.message)))?;
+                text_lens.insert(block.id.clone(), new_len);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reject a block type outside `allowed_types`; `None` accepts any type
+    pub fn check_block_type(
+        index: usize,
+        ty: &str,
+        allowed_types: Option<&HashSet<String>>,
+    ) -> Result<(), CustomRustError> {
+        match allowed_types {
+            Some(allowed) if !allowed.contains(ty) => Err(DocError::ValidationError(format!(
+                "Action {}: unknown block type \"{}\"", index, ty
+            )).into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_parent_present(
+        index: usize,
+        parent_id: Option<&str>,
+        view: &BatchView<'_, impl ReadTxn>
+    ) -> Result<(), CustomRustError> {
+        match parent_id {
+            Some(parent_id) if parent_id != DEFAULT_PARENT && !view.exists(parent_id) => {
+                Err(DocError::ValidationError(format!(
+                    "Action {}: parent block {} does not exist", index, parent_id
+                )).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Collect the ancestors of a block by following parent pointers, nearest first.
+    /// Stops at the first missing parent or repeated id, so corrupted trees can't loop.
+    pub fn ancestors<T: ReadTxn>(txn: &T, blocks_map: &MapRef, block_id: &str) -> Vec<String> {
+        let mut ancestors = Vec::new();
+        let mut visited = std::collections::HashSet::new();
+        visited.insert(block_id.to_string());
+
+        let mut current = block_id.to_string();
+        while let Some(yrs::Out::YMap(block)) = blocks_map.get(txn, &current) {
+            let parent_id = match block.get(txn, PARENT_ID) {
+                Some(yrs::Out::Any(yrs::Any::String(s))) => s.to_string(),
+                _ => break,
+            };
+            if !visited.insert(parent_id.clone()) {
+                log_error!("ancestors: Cycle detected at {} while walking up from {}", parent_id, block_id);
+                break;
+            }
+            ancestors.push(parent_id.clone());
+            current = parent_id;
+        }
+
+        ancestors
+    }
+
+    /// Find orphaned blocks, parent cycles and prev/next ids that point to missing blocks.
+    /// A missing or DEFAULT_PARENT parent id means a top-level block, which is fine.
+    pub fn check_structure(blocks: &HashMap<String, BlockDoc>) -> DocumentValidation {
+        let parent_of = |id: &str| {
+            blocks.get(id)
+                .and_then(|block| block.parent_id.as_deref())
+                .filter(|parent_id| *parent_id != DEFAULT_PARENT)
+        };
+
+        let mut validation = DocumentValidation::default();
+        for (id, block) in blocks {
+            if parent_of(id).is_some_and(|parent_id| !blocks.contains_key(parent_id)) {
+                validation.orphaned_block_ids.push(id.clone());
+            }
+            let dangling = [&block.prev_id, &block.next_id]
+                .into_iter()
+                .flatten()
+                .any(|sibling_id| !sibling_id.is_empty() && !blocks.contains_key(sibling_id));
+            if dangling {
+                validation.dangling_reference_ids.push(id.clone());
+            }
+        }
+
+        // Walk up from every block; reaching a block already on the current walk closes a cycle
+        let mut finished: std::collections::HashSet<&str> = std::collections::HashSet::new();
+        let mut cyclic = std::collections::BTreeSet::new();
+        for start in blocks.keys() {
+            let mut walk: Vec<&str> = Vec::new();
+            let mut current = Some(start.as_str());
+            while let Some(id) = current {
+                if finished.contains(id) || !blocks.contains_key(id) {
+                    break;
+                }
+                if let Some(position) = walk.iter().position(|visited| *visited == id) {
+                    log_error!("check_structure: Parent cycle through {:?}", &walk[position..]);
+                    cyclic.extend(walk[position..].iter().map(|id| id.to_string()));
+                    break;
+                }
+                walk.push(id);
+                current = parent_of(id);
+            }
+            finished.extend(walk);
+        }
+        validation.cyclic_block_ids = cyclic.into_iter().collect();
+
+        validation.orphaned_block_ids.sort();
+        validation.dangling_reference_ids.sort();
+        validation
+    }
+
+    /// Check whether `second_id` can be merged into `first_id`: both blocks must exist, be
+    /// distinct, carry text, have compatible types, and `second_id` must not be an ancestor
+    /// of `first_id`. This is the single eligibility rule: `can_merge_blocks` reports it, and
+    /// `merge_blocks` calls it inside its write transaction before moving any text.
+    pub fn check_merge<T: ReadTxn>(
+        txn: &T,
+        blocks_map: &MapRef,
+        first_id: &str,
+        second_id: &str
+    ) -> Result<(), DocError> {
+        if first_id == second_id {
+            return Err(DocError::InvalidOperation("Cannot merge a block with itself".into()));
+        }
+
+        let mut types = Vec::with_capacity(2);
+        for id in [first_id, second_id] {
+            match blocks_map.get(txn, id) {
+                Some(yrs::Out::YMap(block)) => {
+                    if !matches!(block.get(txn, TEXT), Some(yrs::Out::YText(_))) {
+                        return Err(DocError::InvalidOperation(format!("Block {} has no text to merge", id)));
+                    }
+                    types.push(match block.get(txn, TYPE) {
+                        Some(yrs::Out::Any(yrs::Any::String(ty))) => ty.to_string(),
+                        _ => String::new(),
+                    });
+                }
+                _ => return Err(DocError::BlockNotFound(format!("Block {} not found", id))),
+            }
+        }
+        if !Self::merge_compatible_types(&types[0], &types[1]) {
+            return Err(DocError::InvalidOperation(format!(
+                "Cannot merge a {} block into a {} block", types[1], types[0]
+            )));
+        }
+
+        if Self::ancestors(txn, blocks_map, first_id).iter().any(|id| id == second_id) {
+            return Err(DocError::InvalidOperation(format!(
+                "Block {} is an ancestor of {}", second_id, first_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Whether text from a `second_ty` block can flow into a `first_ty` block: rich-text types
+    /// merge with each other, code only with code, and every other type not at all
+    pub fn merge_compatible_types(first_ty: &str, second_ty: &str) -> bool {
+        let rich_text = |ty: &str| MERGEABLE_TEXT_TYPES.contains(&ty);
+        (rich_text(first_ty) && rich_text(second_ty)) || (first_ty == CODE_TYPE && second_ty == CODE_TYPE)
+    }
+
+    /// Write a single key of a block's attributes map, leaving sibling keys untouched so
+    /// concurrent edits to other fields of the same block merge. A dotted path such as
+    /// "style.color" walks nested maps and creates missing ones; an intermediate key that
+    /// already holds a plain value is rejected instead of being overwritten.
+    pub fn set_data_field(
+        txn: &mut TransactionMut,
+        block: &MapRef,
+        path: &str,
+        value: String,
+    ) -> Result<(), CustomRustError> {
+        let segments: Vec<&str> = path.split('.').collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(DocError::ValidationError(format!("Invalid data field path: {:?}", path)).into());
+        }
+
+        let (leaf, parents) = segments.split_last().expect("split always yields a segment");
+        let mut map = block.get_or_init_map(txn, ATTRIBUTES);
+        for segment in parents {
+            map = match map.get(txn, segment) {
+                Some(yrs::Out::YMap(nested)) => nested,
+                None => map.insert(txn, *segment, MapPrelim::default()),
+                Some(_) => {
+                    return Err(DocError::ValidationError(format!(
+                        "Data field {} in path {} is not a nested map", segment, path
+                    )).into());
+                }
+            };
+        }
+        map.insert(txn, *leaf, value);
+        Ok(())
+    }
+
+    /// Sum the sizes of all binary buffers stored in block attributes
+    pub fn attachment_bytes<T: ReadTxn>(txn: &T, blocks_map: &MapRef) -> u64 {
+        let mut total = 0u64;
+        for (_, block) in blocks_map.iter(txn) {
+            if let yrs::Out::YMap(block_map) = block {
+                if let Some(yrs::Out::YMap(attrs)) = block_map.get(txn, ATTRIBUTES) {
+                    for (_, value) in attrs.iter(txn) {
+                        if let yrs::Out::Any(yrs::Any::Buffer(buf)) = value {
+                            total += buf.len() as u64;
+                        }
+                    }
+                }
+            }
+        }
+        total
+    }
+
     /// Build a mapping of parents to their children by analyzing all blocks in the map
     pub fn build_parent_child_structure(
         txn: &mut TransactionMut, // Changed from 'mut txn: &TransactionMut'
@@ -485,24 +987,128 @@ impl BlockOperations {
         blocks_by_parent
     }
 
-    /// Find all descendants of a block (recursive)
+    /// Find all descendants of a block, parents before their children.
+    /// Walks with an explicit stack and a visited set, so a parent cycle ends the walk
+    /// instead of recursing until the stack overflows.
     pub fn find_descendants(
         block_id: &str,
         blocks_by_parent: &HashMap<String, Vec<String>>
     ) -> Vec<String> {
         let mut descendants = Vec::new();
+        let mut visited = HashSet::from([block_id.to_string()]);
 
-        // Get direct children
-        if let Some(children) = blocks_by_parent.get(block_id) {
-            for child_id in children {
-                descendants.push(child_id.clone());
-
-                // Recursively get children of children
-                let child_descendants = Self::find_descendants(child_id, blocks_by_parent);
-                descendants.extend(child_descendants);
+        // Children are pushed in reverse so they pop in sibling order
+        let mut stack: Vec<&String> = blocks_by_parent.get(block_id)
+            .map(|children| children.iter().rev().collect())
+            .unwrap_or_default();
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id.clone()) {
+                continue;
+            }
+            descendants.push(id.clone());
+            if let Some(children) = blocks_by_parent.get(id) {
+                stack.extend(children.iter().rev());
             }
         }
 
         descendants
     }
 }
+
+/// Block parents as `validate_actions` sees them partway through a batch: blocks are read
+/// from the document on demand, with the inserts, moves and deletes of earlier actions on top.
+struct BatchView<'a, T: ReadTxn> {
+    txn: &'a T,
+    blocks_map: Option<&'a MapRef>,
+    /// Blocks changed earlier in the batch: `Some(parent)` when present, `None` when deleted
+    overlay: HashMap<String, Option<Option<String>>>,
+    /// Parent -> children index, built on the first delete that needs to walk a subtree
+    children: Option<HashMap<String, HashSet<String>>>,
+}
+
+impl<'a, T: ReadTxn> BatchView<'a, T> {
+    fn new(txn: &'a T, blocks_map: Option<&'a MapRef>) -> Self {
+        Self { txn, blocks_map, overlay: HashMap::new(), children: None }
+    }
+
+    /// Parent of a present block (`Some(None)` for a root), or `None` when the block is absent
+    fn parent(&self, id: &str) -> Option<Option<String>> {
+        match self.overlay.get(id) {
+            Some(entry) => entry.clone(),
+            None => Self::stored_parent(self.txn, self.blocks_map, id),
+        }
+    }
+
+    fn exists(&self, id: &str) -> bool {
+        self.parent(id).is_some()
+    }
+
+    fn was_deleted(&self, id: &str) -> bool {
+        matches!(self.overlay.get(id), Some(None))
+    }
+
+    fn set_parent(&mut self, id: &str, parent: Option<String>) {
+        let old_parent = self.parent(id).flatten();
+        if let Some(children) = &mut self.children {
+            if let Some(siblings) = old_parent.and_then(|old_parent| children.get_mut(&old_parent)) {
+                siblings.remove(id);
+            }
+            if let Some(parent) = &parent {
+                children.entry(parent.clone()).or_default().insert(id.to_string());
+            }
+        }
+        self.overlay.insert(id.to_string(), Some(parent));
+    }
+
+    /// Remove a block and all of its descendants, returning the removed ids
+    fn delete_subtree(&mut self, id: &str) -> Vec<String> {
+        if self.children.is_none() {
+            self.children = Some(self.build_children());
+        }
+        let children = self.children.as_mut().expect("children index was just built");
+
+        let mut removed = vec![id.to_string()];
+        let mut i = 0;
+        while i < removed.len() {
+            if let Some(kids) = children.remove(&removed[i]) {
+                removed.extend(kids);
+            }
+            i += 1;
+        }
+        for removed_id in &removed {
+            self.overlay.insert(removed_id.clone(), None);
+        }
+        removed
+    }
+
+    fn stored_parent(txn: &T, blocks_map: Option<&MapRef>, id: &str) -> Option<Option<String>> {
+        match blocks_map?.get(txn, id) {
+            Some(yrs::Out::YMap(block)) => Some(match block.get(txn, PARENT_ID) {
+                Some(yrs::Out::Any(yrs::Any::String(s))) => Some(s.to_string()),
+                _ => None,
+            }),
+            Some(_) => Some(None),
+            None => None,
+        }
+    }
+
+    fn build_children(&self) -> HashMap<String, HashSet<String>> {
+        let mut children: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut add = |id: &str, parent: Option<Option<String>>| {
+            if let Some(Some(parent)) = parent {
+                children.entry(parent).or_default().insert(id.to_string());
+            }
+        };
+        if let Some(blocks_map) = self.blocks_map {
+            for (id, _) in blocks_map.iter(self.txn) {
+                if !self.overlay.contains_key(id) {
+                    add(id, Self::stored_parent(self.txn, self.blocks_map, id));
+                }
+            }
+        }
+        for (id, parent) in &self.overlay {
+            add(id, parent.clone());
+        }
+        children
+    }
+}
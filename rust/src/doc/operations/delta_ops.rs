@@ -6,12 +6,12 @@ use yrs::Text;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use yrs::{types::Delta, ReadTxn, TextRef, TransactionMut};
+use yrs::{types::{Attrs, Delta}, In, MapPrelim, OffsetKind, ReadTxn, TextRef, TransactionMut};
 
 use crate::doc::conversions::conversion::Conversion;
 use crate::doc::document_types::CustomRustError;
 use crate::doc::error::DocError;
-use crate::doc::utils::util::TextExt;
+use crate::doc::utils::util::{find_text_matches, TextExt};
 use crate::{log_info, log_error};
 
 // Constants for delta operations
@@ -20,6 +20,9 @@ pub const RETAIN: &str = "retain";
 pub const DELETE: &str = "delete";
 pub const ATTRIBUTES: &str = "attributes";
 
+/// Stands in for an inline embed in plain text so character offsets keep counting it
+pub const EMBED_PLACEHOLDER: char = '\u{FFFC}';
+
 pub struct DeltaOperations;
 
 impl DeltaOperations {
@@ -51,6 +54,114 @@ impl DeltaOperations {
         Self::apply_delta_diff_to_text(txn, text, &parsed_delta)
     }
 
+    /// Concatenate the string inserts of a YText, dropping attributes and embeds.
+    pub fn plain_text<T: ReadTxn>(txn: &T, text: &TextRef) -> String {
+        text.delta(txn)
+            .into_iter()
+            .filter_map(|delta| match delta {
+                Delta::Inserted(yrs::Out::Any(yrs::Any::String(s)), _) => Some(s),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .concat()
+    }
+
+    /// Like `plain_text`, but every embed (image, formula, ...) becomes `EMBED_PLACEHOLDER`
+    /// so offsets into the result line up with offsets into the YText.
+    pub fn plain_text_with_embeds<T: ReadTxn>(txn: &T, text: &TextRef) -> String {
+        text.delta(txn)
+            .into_iter()
+            .filter_map(|delta| match delta {
+                Delta::Inserted(yrs::Out::Any(yrs::Any::String(s)), _) => Some(s.to_string()),
+                Delta::Inserted(_, _) => Some(EMBED_PLACEHOLDER.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .concat()
+    }
+
+    /// Replace every non-overlapping occurrence of `query` in a YText with `replacement`.
+    /// Matches are found on the plain text and replaced right-to-left so earlier offsets stay
+    /// valid; each replacement keeps the formatting of the first character it replaces.
+    /// Matches spanning an embed are skipped. Returns the number of replacements.
+    pub fn replace_matches(
+        txn: &mut TransactionMut,
+        text: &TextRef,
+        query: &str,
+        replacement: &str,
+        case_sensitive: bool,
+    ) -> u32 {
+        let offset_kind = txn.doc().offset_kind();
+        let unit_len = |c: char| match offset_kind {
+            OffsetKind::Bytes => c.len_utf8() as u32,
+            OffsetKind::Utf16 => c.len_utf16() as u32,
+        };
+
+        // Index of every plain-text character inside the YText, plus the formatting it carries
+        let mut plain = String::new();
+        let mut positions: Vec<(u32, u32, usize)> = Vec::new();
+        let mut chunk_attrs: Vec<Attrs> = Vec::new();
+        let mut index = 0u32;
+        for delta in text.delta(txn) {
+            if let Delta::Inserted(value, attrs) = delta {
+                match value {
+                    yrs::Out::Any(yrs::Any::String(chunk)) => {
+                        for c in chunk.chars() {
+                            positions.push((index, unit_len(c), chunk_attrs.len()));
+                            plain.push(c);
+                            index += unit_len(c);
+                        }
+                        chunk_attrs.push(attrs.map(|a| *a).unwrap_or_default());
+                    }
+                    _ => index += 1,
+                }
+            }
+        }
+
+        let query_len = query.chars().count();
+        let mut next_free = 0;
+        let matches: Vec<usize> = find_text_matches(&plain, query, case_sensitive)
+            .into_iter()
+            .map(|offset| offset as usize)
+            .filter(|&offset| {
+                let free = offset >= next_free;
+                if free {
+                    next_free = offset + query_len;
+                }
+                free
+            })
+            .collect();
+
+        let mut replaced = 0;
+        for &offset in matches.iter().rev() {
+            let span = &positions[offset..offset + query_len];
+            let start = span[0].0;
+            let len: u32 = span.iter().map(|(_, unit, _)| unit).sum();
+            let (last_index, last_unit, _) = span[span.len() - 1];
+            if last_index + last_unit - start != len {
+                continue;
+            }
+            text.remove_range(txn, start, len);
+            text.insert_with_attributes(txn, start, replacement, chunk_attrs[span[0].2].clone());
+            replaced += 1;
+        }
+        replaced
+    }
+
+    /// Check a delta against a text of `current_len` without applying it, using the same
+    /// parsing and bounds rules as `apply_delta_to_text`. Returns the resulting text length.
+    pub fn delta_len_after(current_len: u32, delta_json: &str) -> Result<u32, CustomRustError> {
+        let parsed_delta: Vec<HashMap<String, Value>> = serde_json::from_str(delta_json)
+            .map_err(|e| DocError::DecodingError(format!("Failed to parse delta diff: {}", e)))?;
+
+        let mut current_len = current_len;
+        let mut cursor_pos = 0;
+        for d in &parsed_delta {
+            Self::parse_delta_operation(d, &mut cursor_pos, &mut current_len)?;
+        }
+        Ok(current_len)
+    }
+
     /// Apply a delta diff to a YText object
     pub fn apply_delta_diff_to_text(
         txn: &mut TransactionMut,
@@ -79,14 +190,22 @@ impl DeltaOperations {
         d: &HashMap<String, Value>, 
         cursor_pos: &mut u32,
         current_len: &mut u32
-    ) -> Result<Delta<String>, CustomRustError> {
+    ) -> Result<Delta<In>, CustomRustError> {
         if d.contains_key(INSERT) {
-            // Handle insert operation
-            let insert = d.get(INSERT)
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| DocError::InvalidOperation("Insert value must be a string".into()))?;
-                
-            let insert_len = insert.encode_utf16().count() as u32;
+            // Handle insert operation: a string, or an object for an inline embed stored as a YMap
+            let (value, insert_len) = match d.get(INSERT) {
+                Some(Value::String(insert)) => {
+                    (In::Any(yrs::Any::String(insert.as_str().into())), insert.encode_utf16().count() as u32)
+                }
+                Some(Value::Object(embed)) => {
+                    let embed: MapPrelim = embed
+                        .iter()
+                        .map(|(k, v)| (k.as_str(), In::Any(Conversion::json_value_to_yrs_any(v))))
+                        .collect();
+                    (In::Map(embed), 1)
+                }
+                _ => return Err(DocError::InvalidOperation("Insert value must be a string or an embed object".into()).into()),
+            };
             if insert_len == 0 {
                 return Ok(Delta::Retain(0, None));
             }
@@ -96,7 +215,7 @@ impl DeltaOperations {
             *current_len += insert_len;
             *cursor_pos += insert_len;
             
-            Ok(Delta::Inserted(insert.to_string().into(), attributes))
+            Ok(Delta::Inserted(value, attributes))
             
         } else if d.contains_key(RETAIN) {
             // Handle retain operation
@@ -123,7 +242,8 @@ impl DeltaOperations {
                 .and_then(|v| v.as_u64())
                 .ok_or_else(|| DocError::InvalidOperation("Delete value must be a number".into()))? as u32;
                 
-            if delete > *current_len {
+            // A delete removes characters after the cursor, so it is bounded by what's left
+            if delete > *current_len - *cursor_pos {
                 return Err(DocError::InvalidOperation("Delete exceeds text length".into()).into());
             }
             
@@ -132,7 +252,6 @@ impl DeltaOperations {
             }
             
             *current_len -= delete;
-            *cursor_pos = (*cursor_pos).saturating_sub(delete);
             
             Ok(Delta::Deleted(delete))
         } else {
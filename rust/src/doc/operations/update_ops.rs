@@ -1,73 +1,133 @@
+use yrs::types::{EntryChange, Event, Events, PathSegment};
 use yrs::updates::decoder::Decode;
-use yrs::{merge_updates_v2, Array, Doc, Map, ReadTxn, StateVector, Transact, Update};
-use std::collections::HashMap;
+use yrs::{merge_updates_v2, Array, Doc, Map, ReadTxn, StateVector, Transact, TransactionMut, Update};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::doc::conversions::conversion::Conversion;
-use crate::doc::document_types::{BlockDoc, CustomRustError, DocumentState, FailedToDecodeUpdates};
+use crate::doc::document_types::{BlockActionDoc, BlockActionTypeDoc, BlockDoc, CustomRustError, DocumentState, FailedToDecodeUpdates};
 use crate::doc::error::DocError;
+use crate::doc::operations::block_ops::BlockOperations;
+use crate::doc::operations::delta_ops::DeltaOperations;
 use crate::doc::utils::sorting::ChainSorting;
 // In other files
 use crate::{log_info, log_error};
-use crate::doc::constants::{ATTRIBUTES, BLOCKS, ID, NEXT_ID, PARENT_ID, PREV_ID, ROOT_ID, TEXT, TYPE};
+use crate::doc::constants::{ATTRIBUTES, BLOCKS, DEFAULT_PARENT, ID, NEXT_ID, PARENT_ID, PREV_ID, REMOTE_ORIGIN, ROOT_ID, TEXT, TYPE};
 use crate::doc::utils::util::TextExt;
 
+/// How a single block differs between two document states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockChangeKind {
+    Inserted,
+    Deleted,
+    TextChanged,
+    DataChanged,
+    TextAndDataChanged,
+}
+
+impl BlockChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Inserted => "inserted",
+            Self::Deleted => "deleted",
+            Self::TextChanged => "text_changed",
+            Self::DataChanged => "data_changed",
+            Self::TextAndDataChanged => "text_and_data_changed",
+        }
+    }
+
+    /// Combine two changes seen for the same block within one update.
+    /// Inserts and deletes win; text and data changes add up.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Inserted, _) | (_, Self::Inserted) => Self::Inserted,
+            (Self::Deleted, _) | (_, Self::Deleted) => Self::Deleted,
+            (a, b) if a == b => a,
+            _ => Self::TextAndDataChanged,
+        }
+    }
+}
+
 pub struct UpdateOperations;
 
 impl UpdateOperations {
-    /// Apply a list of updates to a document
+    /// Apply a list of updates directly onto `doc` in one transaction and return the resulting
+    /// diff. Updates that fail to decode are skipped and reported by index, so one bad update
+    /// doesn't drop the rest of the batch.
     pub fn apply_updates_inner(
-        doc: Doc,
+        doc: &Doc,
         doc_id: &str,
         updates: Vec<Vec<u8>>
-    ) -> Result<(), CustomRustError> {
+    ) -> Result<(Vec<u8>, FailedToDecodeUpdates), CustomRustError> {
         log_info!("apply_updates: Starting with {} updates for doc_id: {}", updates.len(), doc_id);
 
-        // Extract just the binary updates
-        let updates_only = updates;
+        let (merged_update, failed) = Self::decode_and_merge(doc_id, &updates);
 
-        // Merge all the updates together
-        let merged_update = merge_updates_v2(updates_only)
-            .map_err(|e| DocError::MergeError(format!("Failed to merge updates: {}", e)))?;
+        // Apply the merged update to the document
+        let diff = {
+            // Remote origin keeps these changes out of the local undo stack
+            let mut txn = doc.transact_mut_with(REMOTE_ORIGIN);
+            Self::apply_decoded(&mut txn, doc_id, merged_update);
 
-        log_info!("apply_updates: Merged update size = {} bytes", merged_update.len());
+            let before_state = txn.before_state();
+            txn.encode_diff_v2(before_state)
+            // txn is committed when dropped here
+        };
 
-        // Apply the merged update to the document
-        {
-            let mut txn = doc.transact_mut();
-
-            match Update::decode_v2(&merged_update) {
-                Ok(decoded_update) => {
-                    log_info!("apply_updates: Applying update for doc_id: {}", doc_id);
-                    match txn.apply_update(decoded_update) {
-                        Ok(_) => {
-                            log_info!("apply_updates: Update applied successfully for doc_id: {}", doc_id);
-                        },
-                        Err(e) => {
-                            log_error!("apply_updates: Failed to apply update for doc_id: {}: {:?}", doc_id, e);
-                        }
-                    }
-                },
+        log_info!("apply_updates: Finished for doc_id: {}", doc_id);
+        Ok((diff, failed))
+    }
+
+    /// Decode v2 updates and merge them into one. Updates that fail to decode are skipped
+    /// and reported by index.
+    pub fn decode_and_merge(doc_id: &str, updates: &[Vec<u8>]) -> (Update, FailedToDecodeUpdates) {
+        // Decode each update on its own so a corrupt one can be reported instead of failing the merge
+        let mut decoded = Vec::with_capacity(updates.len());
+        let mut failed_updates_ids = Vec::new();
+        let mut errors = Vec::new();
+        for (index, update) in updates.iter().enumerate() {
+            match Self::decode_update_v2(update) {
+                Ok(update) => decoded.push(update),
                 Err(e) => {
-                    log_error!("Failed to decode update for doc_id: {}: {}", doc_id, e);
-                    return Err(DocError::UpdateDecodingFailed(format!("Failed to decode update: {}", e)).into());
+                    log_error!("Failed to decode update {} for doc_id: {}: {}", index, doc_id, e);
+                    failed_updates_ids.push(index.to_string());
+                    errors.push(e);
                 }
-            };
-            // txn is committed when dropped here
+            }
         }
 
-        // Debug: verify update was applied
-        {
-            let txn = doc.transact();
-            if let Some(yrs::Value::YMap(root)) = txn.get_map(ROOT_ID).map(|m| yrs::Value::YMap(m)) {
-                let keys: Vec<String> = root.keys(&txn).map(|k| k.to_string()).collect();
-                log_info!("apply_updates_inner: root keys after commit = {:?}", keys);
-            } else {
-                log_info!("apply_updates_inner: ROOT map not found after update!");
+        (Update::merge_updates(decoded), FailedToDecodeUpdates { failed_updates_ids, errors })
+    }
+
+    /// Apply a decoded update inside an open transaction; a failure is logged, not returned,
+    /// matching how apply_updates treats updates it cannot integrate
+    pub fn apply_decoded(txn: &mut TransactionMut, doc_id: &str, update: Update) {
+        log_info!("apply_updates: Applying update for doc_id: {}", doc_id);
+        match txn.apply_update(update) {
+            Ok(_) => {
+                log_info!("apply_updates: Update applied successfully for doc_id: {}", doc_id);
+            },
+            Err(e) => {
+                log_error!("apply_updates: Failed to apply update for doc_id: {}: {:?}", doc_id, e);
             }
         }
+    }
 
-        log_info!("apply_updates: Finished for doc_id: {}", doc_id);
-        Ok(())
+    /// Decode a v2 update, turning decoder panics on truncated input into an error
+    pub(crate) fn decode_update_v2(update: &[u8]) -> Result<Update, String> {
+        match std::panic::catch_unwind(|| Update::decode_v2(update)) {
+            Ok(Ok(update)) => Ok(update),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(_) => Err("malformed update".to_string()),
+        }
+    }
+
+    /// Decode a v1 update with the same panic guard as decode_update_v2
+    pub(crate) fn decode_update_v1(update: &[u8]) -> Result<Update, String> {
+        match std::panic::catch_unwind(|| Update::decode_v1(update)) {
+            Ok(Ok(update)) => Ok(update),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(_) => Err("malformed update".to_string()),
+        }
     }
 
     /// Extract the current document state
@@ -116,8 +176,161 @@ impl UpdateOperations {
         })
     }
 
-    /// Extract a single block from the document
-    fn extract_block<T: ReadTxn>(
+    /// Look up the blocks map without creating it, for read-only callers
+    pub fn blocks_map<T: ReadTxn>(txn: &T) -> Option<yrs::MapRef> {
+        match txn.get_map(ROOT_ID).and_then(|root| root.get(txn, BLOCKS)) {
+            Some(yrs::Out::YMap(map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Walk the block tree depth-first from the root, children in `children_map` order, and yield
+    /// `(block id, depth)` with the root at depth 0. A block reached twice (cycle) is skipped.
+    /// Blocks unreachable from the root follow at the end, sorted by id, with depth -1.
+    pub fn blocks_in_order(doc_state: &DocumentState) -> impl Iterator<Item = (String, i32)> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![(doc_state.root_id.clone(), 0)];
+
+        while let Some((id, depth)) = stack.pop() {
+            if !doc_state.blocks.contains_key(&id) || !visited.insert(id.clone()) {
+                continue;
+            }
+            if let Some(children) = doc_state.children_map.get(&id) {
+                stack.extend(children.iter().rev().map(|child| (child.clone(), depth + 1)));
+            }
+            order.push((id, depth));
+        }
+
+        let mut orphans: Vec<&String> = doc_state.blocks.keys().filter(|id| !visited.contains(*id)).collect();
+        orphans.sort();
+        order.extend(orphans.into_iter().map(|id| (id.clone(), -1)));
+        order.into_iter()
+    }
+
+    /// Collect every block stored in the document, keyed by block id
+    pub fn collect_blocks<T: ReadTxn>(
+        txn: &T
+    ) -> Result<HashMap<String, BlockDoc>, CustomRustError> {
+        let blocks_map = match Self::blocks_map(txn) {
+            Some(map) => map,
+            None => return Ok(HashMap::new()),
+        };
+
+        let mut blocks = HashMap::new();
+        let block_keys: Vec<String> = blocks_map.keys(txn).map(|k| k.to_string()).collect();
+        for id in block_keys {
+            if let Some(block) = Self::extract_block(txn, &blocks_map, &id)? {
+                blocks.insert(id, block);
+            }
+        }
+
+        log_info!("collect_blocks: Collected {} blocks", blocks.len());
+        Ok(blocks)
+    }
+
+    /// Compare two block collections and classify every block that differs.
+    /// A block inserted and deleted between the two states doesn't appear at all.
+    pub fn diff_blocks(
+        before: &HashMap<String, BlockDoc>,
+        after: &HashMap<String, BlockDoc>
+    ) -> BTreeMap<String, BlockChangeKind> {
+        let mut changes = BTreeMap::new();
+
+        for (id, new_block) in after {
+            let kind = match before.get(id) {
+                None => Some(BlockChangeKind::Inserted),
+                Some(old_block) => {
+                    let text_changed = old_block.delta != new_block.delta;
+                    let data_changed = old_block.ty != new_block.ty
+                        || old_block.attributes != new_block.attributes
+                        || old_block.parent_id != new_block.parent_id
+                        || old_block.prev_id != new_block.prev_id;
+                    match (text_changed, data_changed) {
+                        (true, true) => Some(BlockChangeKind::TextAndDataChanged),
+                        (true, false) => Some(BlockChangeKind::TextChanged),
+                        (false, true) => Some(BlockChangeKind::DataChanged),
+                        (false, false) => None,
+                    }
+                }
+            };
+            if let Some(kind) = kind {
+                changes.insert(id.clone(), kind);
+            }
+        }
+
+        for id in before.keys() {
+            if !after.contains_key(id) {
+                changes.insert(id.clone(), BlockChangeKind::Deleted);
+            }
+        }
+
+        changes
+    }
+
+    /// Classify the blocks touched by one transaction from the deep events observed on the root
+    /// map and fold them into `changes`. Counts the same fields as diff_blocks (type, attributes,
+    /// parent/prev links and text), so timestamp and next-link bookkeeping alone is no change.
+    /// A block inserted and deleted within the transaction produces no event and doesn't appear.
+    pub fn classify_block_events(
+        txn: &TransactionMut,
+        events: &Events,
+        changes: &mut BTreeMap<String, BlockChangeKind>
+    ) {
+        let mut record = |id: &str, kind: BlockChangeKind| {
+            changes.entry(id.to_string())
+                .and_modify(|existing| *existing = existing.merge(kind))
+                .or_insert(kind);
+        };
+        // Setting a key to the value it already holds still reports an update
+        let changed = |change: &EntryChange| !matches!(change, EntryChange::Updated(old, new) if old == new);
+
+        for event in events.iter() {
+            let path = event.path();
+            let segment = |i: usize| match path.get(i) {
+                Some(PathSegment::Key(key)) => Some(key.as_ref()),
+                _ => None,
+            };
+            match (segment(0), segment(1), segment(2), event) {
+                // BLOCKS map created or replaced: every block in it is new
+                (None, _, _, Event::Map(root_event)) if root_event.keys(txn).contains_key(BLOCKS) => {
+                    if let Some(blocks_map) = Self::blocks_map(txn) {
+                        for id in blocks_map.keys(txn) {
+                            record(id, BlockChangeKind::Inserted);
+                        }
+                    }
+                }
+                (Some(BLOCKS), None, _, Event::Map(blocks_event)) => {
+                    for (id, change) in blocks_event.keys(txn) {
+                        let kind = match change {
+                            EntryChange::Inserted(_) => BlockChangeKind::Inserted,
+                            EntryChange::Removed(_) => BlockChangeKind::Deleted,
+                            EntryChange::Updated(..) => BlockChangeKind::TextAndDataChanged,
+                        };
+                        record(id, kind);
+                    }
+                }
+                (Some(BLOCKS), Some(id), None, Event::Map(block_event)) => {
+                    let keys = block_event.keys(txn);
+                    let touched = |key: &str| keys.get(key).is_some_and(changed);
+                    if touched(TEXT) {
+                        record(id, BlockChangeKind::TextChanged);
+                    }
+                    if [TYPE, ATTRIBUTES, PARENT_ID, PREV_ID].into_iter().any(touched) {
+                        record(id, BlockChangeKind::DataChanged);
+                    }
+                }
+                (Some(BLOCKS), Some(id), Some(TEXT), _) => record(id, BlockChangeKind::TextChanged),
+                (Some(BLOCKS), Some(_), Some(ATTRIBUTES), Event::Map(attrs_event))
+                    if path.len() == 3 && !attrs_event.keys(txn).values().any(changed) => {}
+                (Some(BLOCKS), Some(id), Some(ATTRIBUTES), _) => record(id, BlockChangeKind::DataChanged),
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract a single block from the document, or None when the id is absent
+    pub fn extract_block<T: ReadTxn>(
         txn: &T, 
         blocks_map: &yrs::MapRef, 
         id: &str
@@ -159,6 +372,8 @@ impl UpdateOperations {
                 HashMap::new()
             };
 
+            let (created_at, updated_at) = BlockOperations::block_timestamps(txn, &block_map);
+
             // Build the block object
             let block = BlockDoc {
                 id: match block_map.get(txn, ID) {
@@ -175,6 +390,8 @@ impl UpdateOperations {
                 prev_id: block_map.get(txn, PREV_ID)
                     .map(|out| out.to_string(txn)),
                 old_parent_id: None,
+                created_at,
+                updated_at,
                 next_id: block_map.get(txn, NEXT_ID)
                     .map(|out| out.to_string(txn)),
             };
@@ -185,6 +402,188 @@ impl UpdateOperations {
         }
     }
     
+    /// Describe how to turn `before` into `after` as block actions that `apply_action` can replay.
+    /// Inserts and moves follow the new document order, so every parent and prev block exists
+    /// by the time it is referenced. A sibling counts as moved only when it falls outside the
+    /// longest run of survivors that kept their relative order. Updates carry only changed
+    /// attributes plus a delta that replaces the whole text; removed attributes and type
+    /// changes can't be expressed by an Update and are left out. Deletes name only the
+    /// top-most removed block, since deleting a block removes its descendants.
+    pub fn diff_to_actions(
+        before: &HashMap<String, BlockDoc>,
+        after: &HashMap<String, BlockDoc>
+    ) -> Result<Vec<BlockActionDoc>, CustomRustError> {
+        let parent_of = |block: &BlockDoc| block.parent_id.clone().unwrap_or_else(|| "root".to_string());
+        let before_children = ChainSorting::sort_blocks_by_chain(before);
+        let after_children = ChainSorting::sort_blocks_by_chain(after);
+
+        let mut moved = HashSet::new();
+        for (parent_id, children) in &after_children {
+            let stayed = |id: &&String| before.get(*id).is_some_and(|old| parent_of(old) == *parent_id);
+            let new_order: Vec<&String> = children.iter().filter(stayed).collect();
+            let survivors: HashSet<&String> = new_order.iter().copied().collect();
+            let old_order: Vec<&String> = before_children.get(parent_id)
+                .map(|old| old.iter().filter(|id| survivors.contains(id)).collect())
+                .unwrap_or_default();
+            let kept = Self::longest_common_subsequence(&old_order, &new_order);
+            moved.extend(new_order.into_iter().filter(|id| !kept.contains(*id)).cloned());
+            moved.extend(children.iter().filter(|id| before.contains_key(*id) && !stayed(id)).cloned());
+        }
+
+        // Walk the new tree depth first; blocks outside it (orphans) go last in id order
+        let mut order = Vec::new();
+        let mut stack: Vec<&String> = after_children.get("root").map(|c| c.iter().rev().collect()).unwrap_or_default();
+        let mut visited = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if visited.insert(id.clone()) {
+                order.push(id.clone());
+                if let Some(children) = after_children.get(id) {
+                    stack.extend(children.iter().rev());
+                }
+            }
+        }
+        let mut rest: Vec<&String> = after.keys().filter(|id| !visited.contains(*id)).collect();
+        rest.sort();
+        order.extend(rest.into_iter().cloned());
+
+        let next_sibling = |id: &str, block: &BlockDoc| {
+            after_children.get(&parent_of(block))
+                .and_then(|siblings| siblings.iter().skip_while(|s| *s != id).nth(1))
+                .filter(|next| before.contains_key(*next))
+                .cloned()
+        };
+
+        let mut actions = Vec::new();
+        for id in &order {
+            let block = &after[id];
+            if !before.contains_key(id) {
+                actions.push(BlockActionDoc {
+                    action: BlockActionTypeDoc::Insert,
+                    block: BlockDoc { next_id: next_sibling(id, block), old_parent_id: None, ..block.clone() },
+                    path: vec![],
+                    old_path: None,
+                });
+            } else if moved.contains(id) {
+                let old = &before[id];
+                actions.push(BlockActionDoc {
+                    action: BlockActionTypeDoc::Move,
+                    block: BlockDoc {
+                        id: id.clone(),
+                        ty: block.ty.clone(),
+                        attributes: HashMap::new(),
+                        delta: None,
+                        parent_id: Some(block.parent_id.clone().unwrap_or_else(|| DEFAULT_PARENT.to_string())),
+                        prev_id: block.prev_id.clone(),
+                        next_id: next_sibling(id, block),
+                        old_parent_id: Some(old.parent_id.clone().unwrap_or_else(|| DEFAULT_PARENT.to_string())),
+                        created_at: None,
+                        updated_at: None,
+                    },
+                    path: vec![],
+                    old_path: Some(vec![]),
+                });
+            }
+        }
+
+        for id in &order {
+            let (Some(old), block) = (before.get(id), &after[id]) else { continue };
+            let attributes: HashMap<String, String> = block.attributes.iter()
+                .filter(|(key, value)| old.attributes.get(*key) != Some(*value))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            let delta = if old.delta != block.delta {
+                Some(Self::replacement_delta(old.delta.as_deref(), block.delta.as_deref())?)
+            } else {
+                None
+            };
+            if attributes.is_empty() && delta.is_none() {
+                continue;
+            }
+            actions.push(BlockActionDoc {
+                action: BlockActionTypeDoc::Update,
+                block: BlockDoc {
+                    id: id.clone(),
+                    ty: block.ty.clone(),
+                    attributes,
+                    delta,
+                    parent_id: block.parent_id.clone(),
+                    prev_id: None,
+                    next_id: None,
+                    old_parent_id: None,
+                    created_at: None,
+                    updated_at: None,
+                },
+                path: vec![],
+                old_path: None,
+            });
+        }
+
+        let mut deleted: Vec<&BlockDoc> = before.values()
+            .filter(|old| !after.contains_key(&old.id))
+            .filter(|old| old.parent_id.as_ref().is_none_or(|parent_id| after.contains_key(parent_id) || !before.contains_key(parent_id)))
+            .collect();
+        deleted.sort_by(|a, b| a.id.cmp(&b.id));
+        for old in deleted {
+            actions.push(BlockActionDoc {
+                action: BlockActionTypeDoc::Delete,
+                block: BlockDoc { delta: None, next_id: None, old_parent_id: None, ..old.clone() },
+                path: vec![],
+                old_path: None,
+            });
+        }
+
+        log_info!("diff_to_actions: Produced {} actions", actions.len());
+        Ok(actions)
+    }
+
+    /// Delta that deletes the whole old text and inserts the new one
+    fn replacement_delta(old_delta: Option<&str>, new_delta: Option<&str>) -> Result<String, CustomRustError> {
+        let old_len = match old_delta {
+            Some(delta) => DeltaOperations::delta_len_after(0, delta)?,
+            None => 0,
+        };
+        let mut ops: Vec<serde_json::Value> = Vec::new();
+        if old_len > 0 {
+            ops.push(serde_json::json!({ "delete": old_len }));
+        }
+        if let Some(delta) = new_delta {
+            let inserts: Vec<serde_json::Value> = serde_json::from_str(delta)
+                .map_err(|e| DocError::DecodingError(format!("Failed to parse delta: {}", e)))?;
+            ops.extend(inserts);
+        }
+        serde_json::to_string(&ops)
+            .map_err(|e| DocError::EncodingError(format!("JSON serialization failed: {}", e)).into())
+    }
+
+    /// Items of the longest subsequence shared by both sequences
+    fn longest_common_subsequence(a: &[&String], b: &[&String]) -> HashSet<String> {
+        let mut lengths = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                lengths[i][j] = if a[i] == b[j] {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+
+        let mut common = HashSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            if a[i] == b[j] {
+                common.insert(a[i].clone());
+                i += 1;
+                j += 1;
+            } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        common
+    }
+
     /// Merge multiple document updates into one
     pub fn merge_updates(updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
         log_info!("merge_updates: Merging {} updates", updates.len());
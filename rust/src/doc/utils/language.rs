@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+/// Best-effort language guess from character scripts and common words.
+/// Not NLP: it only looks at which scripts dominate, and for Latin text
+/// at which language's most frequent words show up.
+pub struct LanguageDetection;
+
+/// Below this many letters the text is too short to decide
+const MIN_LETTERS: usize = 20;
+
+/// Frequent short words per Latin-script language
+const LATIN_STOPWORDS: &[(&str, &[&str])] = &[
+    ("en", &["the", "and", "is", "of", "to", "in", "that", "it", "with", "for", "this", "are"]),
+    ("es", &["el", "la", "de", "que", "y", "los", "las", "en", "por", "una", "es", "con"]),
+    ("fr", &["le", "la", "les", "de", "et", "est", "des", "une", "que", "pas", "pour", "dans"]),
+    ("de", &["der", "die", "das", "und", "ist", "nicht", "ein", "eine", "zu", "mit", "den", "ich"]),
+    ("pt", &["o", "a", "de", "que", "e", "os", "as", "um", "uma", "para", "com", "não"]),
+    ("it", &["il", "di", "che", "e", "la", "per", "un", "una", "sono", "non", "gli", "con"]),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Script {
+    Latin,
+    Hangul,
+    Kana,
+    Han,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Thai,
+    Devanagari,
+}
+
+impl LanguageDetection {
+    /// Return a BCP-47 code such as "en", "ko" or "ja", or "und" when undecided
+    pub fn detect(text: &str) -> String {
+        let mut counts: HashMap<Script, usize> = HashMap::new();
+        for c in text.chars() {
+            if let Some(script) = Self::script_of(c) {
+                *counts.entry(script).or_default() += 1;
+            }
+        }
+
+        let letters: usize = counts.values().sum();
+        if letters < MIN_LETTERS {
+            return "und".to_string();
+        }
+        let count = |script: Script| counts.get(&script).copied().unwrap_or(0);
+
+        // Japanese mixes kana with Han, so any meaningful kana share decides it
+        if count(Script::Kana) * 10 >= letters {
+            return "ja".to_string();
+        }
+
+        let dominant = counts
+            .iter()
+            .max_by_key(|(_, n)| **n)
+            .map(|(script, _)| *script)
+            .unwrap_or(Script::Latin);
+
+        let code = match dominant {
+            Script::Latin => return Self::detect_latin(text),
+            Script::Hangul => "ko",
+            Script::Kana => "ja",
+            Script::Han => "zh",
+            Script::Cyrillic => "ru",
+            Script::Greek => "el",
+            Script::Arabic => "ar",
+            Script::Hebrew => "he",
+            Script::Thai => "th",
+            Script::Devanagari => "hi",
+        };
+        code.to_string()
+    }
+
+    /// Pick the Latin-script language whose common words appear most often
+    fn detect_latin(text: &str) -> String {
+        let lowered = text.to_lowercase();
+        let words: Vec<&str> = lowered
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let best = LATIN_STOPWORDS
+            .iter()
+            .map(|(code, stopwords)| {
+                let hits = words.iter().filter(|w| stopwords.contains(w)).count();
+                (*code, hits)
+            })
+            .fold(None, |best: Option<(&str, usize)>, (code, hits)| match best {
+                Some((_, best_hits)) if best_hits >= hits => best,
+                _ => Some((code, hits)),
+            });
+
+        match best {
+            Some((code, hits)) if hits > 0 => code.to_string(),
+            _ => "und".to_string(),
+        }
+    }
+
+    fn script_of(c: char) -> Option<Script> {
+        let script = match c as u32 {
+            0x0041..=0x005A | 0x0061..=0x007A | 0x00C0..=0x024F => Script::Latin,
+            0x0370..=0x03FF => Script::Greek,
+            0x0400..=0x04FF => Script::Cyrillic,
+            0x0590..=0x05FF => Script::Hebrew,
+            0x0600..=0x06FF => Script::Arabic,
+            0x0900..=0x097F => Script::Devanagari,
+            0x0E00..=0x0E7F => Script::Thai,
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Script::Hangul,
+            0x3040..=0x30FF => Script::Kana,
+            0x4E00..=0x9FFF | 0x3400..=0x4DBF => Script::Han,
+            _ => return None,
+        };
+        Some(script)
+    }
+}
+
@@ -1,10 +1,40 @@
-use log;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use log::{Level, LevelFilter};
+
+/// Info and above in debug builds, warn and above in release builds
+const DEFAULT_LEVEL: LevelFilter = if cfg!(debug_assertions) { LevelFilter::Info } else { LevelFilter::Warn };
+
+/// Most verbose level the log_* macros print, changed at runtime with `set_max_level`
+static MAX_LEVEL: AtomicUsize = AtomicUsize::new(DEFAULT_LEVEL as usize);
+
+/// Set the most verbose level the log_* macros print, and the `log` crate's max level with it
+pub fn set_max_level(level: LevelFilter) {
+    MAX_LEVEL.store(level as usize, Ordering::Relaxed);
+    log::set_max_level(level);
+}
+
+pub fn max_level() -> LevelFilter {
+    match MAX_LEVEL.load(Ordering::Relaxed) {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Error,
+        2 => LevelFilter::Warn,
+        3 => LevelFilter::Info,
+        4 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Whether a message at `level` gets printed; checked before the message is formatted
+pub fn enabled(level: Level) -> bool {
+    level <= max_level()
+}
 
 // Define a macro for combined println and info logging
 #[macro_export]
 macro_rules! log_info {
     ($($arg:tt)*) => {
-        {
+        if $crate::doc::utils::logging::enabled(log::Level::Info) {
         let message = format!($($arg)*);
         log::info!("{}", message);
         println!("{}", message);
@@ -12,14 +42,35 @@ macro_rules! log_info {
     };
 }
 
+// Define a macro for combined println and debug logging, for messages that carry note content
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        if $crate::doc::utils::logging::enabled(log::Level::Debug) {
+        let message = format!($($arg)*);
+        log::debug!("{}", message);
+        println!("{}", message);
+        }
+    };
+}
+
+// Debug logging for messages that carry note content; compiled out unless the log_content feature is on
+#[macro_export]
+macro_rules! log_content {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log_content")]
+        $crate::log_debug!($($arg)*);
+    };
+}
+
 // Define a macro for combined println and error logging
 #[macro_export]
 macro_rules! log_error {
     ($($arg:tt)*) => {
-        {
+        if $crate::doc::utils::logging::enabled(log::Level::Error) {
         let message = format!($($arg)*);
         log::error!("{}", message);
         println!("{}", message);
         }
     };
-}
\ No newline at end of file
+}
@@ -15,4 +15,10 @@ pub mod sorting;
 
 
 /// flutter_rust_bridge:ignore
-pub mod logging;
\ No newline at end of file
+pub mod logging;
+
+/// flutter_rust_bridge:ignore
+pub mod language;
+
+/// flutter_rust_bridge:ignore
+pub mod text_stats;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{doc::document_types::BlockDoc, log_info};
 
@@ -239,4 +239,46 @@ impl ChainSorting {
         
         sorted_children
     }
+
+    /// Find siblings whose prev_id chain is broken: the prev_id points outside the sibling
+    /// group, or the block can't be reached by walking from a chain start (a cycle).
+    pub fn find_broken_links(
+        blocks: &HashMap<String, BlockDoc>,
+        child_ids: &[String]
+    ) -> Vec<String> {
+        let siblings: HashSet<&str> = child_ids.iter().map(|id| id.as_str()).collect();
+        let mut next_blocks: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut broken = Vec::new();
+        let mut stack = Vec::new();
+
+        for id in child_ids {
+            match blocks.get(id).and_then(|block| block.prev_id.as_deref()) {
+                Some(prev) if siblings.contains(prev) => next_blocks.entry(prev).or_default().push(id),
+                Some(prev) => {
+                    log_info!("find_broken_links: Block {} points to missing sibling {}", id, prev);
+                    broken.push(id.clone());
+                }
+                None => stack.push(id.as_str()),
+            }
+        }
+
+        let mut reachable = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if reachable.insert(id) {
+                if let Some(nexts) = next_blocks.get(id) {
+                    stack.extend(nexts.iter().copied());
+                }
+            }
+        }
+
+        for id in child_ids {
+            if !reachable.contains(id.as_str()) && !broken.contains(id) {
+                log_info!("find_broken_links: Block {} is not reachable from a chain start", id);
+                broken.push(id.clone());
+            }
+        }
+
+        broken.sort();
+        broken
+    }
 }
\ No newline at end of file
@@ -29,6 +29,8 @@ mod tests {
             parent_id: parent_id.map(|s| s.to_string()),
             prev_id: prev_id.map(|s| s.to_string()),
             old_parent_id: None,
+            created_at: None,
+            updated_at: None,
             next_id: None,
         }
     }
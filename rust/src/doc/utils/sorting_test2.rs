@@ -37,6 +37,8 @@ mod tests {
             parent_id: None,
             prev_id: None,
             old_parent_id: None,
+            created_at: None,
+            updated_at: None,
             next_id: None,
         });
 
@@ -65,6 +67,8 @@ mod tests {
                 parent_id: Some("j62VDi".to_string()),
                 prev_id: prev_id.map(|s| s.to_string()),
                 old_parent_id: None,
+                created_at: None,
+                updated_at: None,
                 next_id: None,
             });
         }
@@ -0,0 +1,48 @@
+/// Word and character counts for writing goals.
+/// Words are whitespace-delimited runs containing at least one letter or digit, except that
+/// every Han or kana character counts as a word of its own, since Chinese and Japanese
+/// aren't written with spaces. Hangul is spaced by words and counted like Latin text.
+pub struct TextStatistics;
+
+/// Counts for one piece of text
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextCounts {
+    pub words: u32,
+    pub chars: u32,
+    pub chars_no_spaces: u32,
+}
+
+impl TextStatistics {
+    pub fn count(text: &str) -> TextCounts {
+        let mut counts = TextCounts::default();
+        let mut in_word = false;
+
+        for c in text.chars() {
+            counts.chars += 1;
+            if c.is_whitespace() {
+                in_word = false;
+                continue;
+            }
+            counts.chars_no_spaces += 1;
+
+            if Self::is_cjk(c) {
+                counts.words += 1;
+                in_word = false;
+            } else if c.is_alphanumeric() && !in_word {
+                counts.words += 1;
+                in_word = true;
+            }
+        }
+
+        counts
+    }
+
+    fn is_cjk(c: char) -> bool {
+        matches!(c as u32,
+            0x3040..=0x30FF            // Hiragana, Katakana
+            | 0x3400..=0x4DBF          // CJK Extension A
+            | 0x4E00..=0x9FFF          // CJK Unified Ideographs
+            | 0xF900..=0xFAFF          // CJK Compatibility Ideographs
+            | 0x20000..=0x2FA1F)       // CJK Extensions B and later
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use flutter_rust_bridge::frb;
+use sha2::{Digest, Sha256};
 use yrs::branch::BranchPtr;
 use yrs::types::text::YChange;
 use yrs::types::{DefaultPrelim, Delta};
@@ -14,6 +15,76 @@ use yrs::{
 
 
 
+/// Source of new block ids. Random UUID v4 by default; once seeded, a reproducible sequence
+/// of UUID-shaped ids so tests can assert on generated ids. Seeding is for tests only: seeded
+/// ids repeat across documents and peers, so production code must keep the UUID default.
+#[frb(ignore)]
+#[derive(Default)]
+pub struct IdGenerator {
+  /// splitmix64 state while seeded, None for random UUIDs
+  seeded: Mutex<Option<u64>>,
+}
+
+
+impl IdGenerator {
+  /// Restart the deterministic sequence from `seed`
+  pub fn set_seed(&self, seed: u64) {
+    *self.state() = Some(seed);
+  }
+
+  /// Generate the next block id
+  pub fn next_id(&self) -> String {
+    match self.state().as_mut() {
+      Some(state) => {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&Self::splitmix64(state).to_be_bytes());
+        bytes[8..].copy_from_slice(&Self::splitmix64(state).to_be_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid().to_string()
+      }
+      None => uuid::Uuid::new_v4().to_string(),
+    }
+  }
+
+  /// The guarded value is a plain u64 that a panicking holder cannot leave half-written,
+  /// so a poisoned lock is recovered instead of panicking across FFI
+  fn state(&self) -> MutexGuard<'_, Option<u64>> {
+    self.seeded.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+  }
+}
+
+/// SHA-256 of `bytes` as a lowercase hex string
+#[frb(ignore)]
+pub fn sha256_hex(bytes: &[u8]) -> String {
+  format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Character offsets of every occurrence of `query` in `text`, overlapping ones included.
+/// Case-insensitive matching compares lowercased characters one by one, so a match is
+/// always exactly as many characters long as the query.
+#[frb(ignore)]
+pub fn find_text_matches(text: &str, query: &str, case_sensitive: bool) -> Vec<u32> {
+  let fold = |c: char| -> Vec<char> {
+    if case_sensitive { vec![c] } else { c.to_lowercase().collect() }
+  };
+  let text: Vec<Vec<char>> = text.chars().map(fold).collect();
+  let query: Vec<Vec<char>> = query.chars().map(fold).collect();
+  if query.is_empty() || query.len() > text.len() {
+    return Vec::new();
+  }
+  (0..=text.len() - query.len())
+    .filter(|&start| text[start..start + query.len()] == query[..])
+    .map(|start| start as u32)
+    .collect()
+}
+
 #[frb(ignore)]
 pub trait MapExt: Map {
   // Get or insert a [YMap] with the given key
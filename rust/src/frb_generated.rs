@@ -25,6 +25,7 @@
 
 // Section: imports
 
+use crate::doc::document_registry::*;
 use crate::doc::document_service::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
@@ -38,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1752453603;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 12287034;
 
 // Section: executor
 
@@ -46,595 +47,1964 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__doc__document_registry__DocumentRegistry_apply_action_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_apply_action", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+let api_actions = <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_registry::DocumentRegistry::apply_action(&mut *api_that_guard, api_doc_id, api_actions)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_apply_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_apply_updates", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_registry::DocumentRegistry::apply_updates(&mut *api_that_guard, api_doc_id, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_close_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_close", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_registry::DocumentRegistry::close(&mut *api_that_guard, api_doc_id))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_encode_full_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_encode_full_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_registry::DocumentRegistry::encode_full_state(&mut *api_that_guard, api_doc_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_get_document_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_get_document_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_registry::DocumentRegistry::get_document_state(&mut *api_that_guard, api_doc_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_init_empty_doc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_init_empty_doc", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_registry::DocumentRegistry::init_empty_doc(&mut *api_that_guard, api_doc_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_list_open_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_list_open", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_registry::DocumentRegistry::list_open(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_new", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let output_ok = Result::<_, ()>::Ok(crate::doc::document_registry::DocumentRegistry::new())?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_registry__DocumentRegistry_open_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentRegistry_open", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(&mut deserializer);
+let api_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok({ crate::doc::document_registry::DocumentRegistry::open(&mut *api_that_guard, api_doc_id); })?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_active_editor_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_active_editor_count", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_window_ms = <u64>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::active_editor_count(&*api_that_guard, api_window_ms))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_add_comment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_add_comment", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_comment_id = <String>::sse_decode(&mut deserializer);
+let api_author = <String>::sse_decode(&mut deserializer);
+let api_text = <String>::sse_decode(&mut deserializer);
+let api_anchor_offset = <Option<u32>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::add_comment(&mut *api_that_guard, api_block_id, api_comment_id, api_author, api_text, api_anchor_offset)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_additive_update_for_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_additive_update_for", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_peer_state_vector = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::additive_update_for(&*api_that_guard, api_peer_state_vector)?; Ok(output_ok) })()) }
+    })
+}
 fn wire__crate__doc__document_service__DocumentService_apply_action_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_apply_action",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_actions =
-                <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::apply_action(
-                                &mut *api_that_guard,
-                                api_actions,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_apply_updates_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_action", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_actions = <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_action(&mut *api_that_guard, api_actions)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_action_with_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_action_with_ids", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_actions = <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_action_with_ids(&mut *api_that_guard, api_actions)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_action_with_origin_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_action_with_origin", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_actions = <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
+let api_origin = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_action_with_origin(&mut *api_that_guard, api_actions, api_origin)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_action_with_timestamp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_action_with_timestamp", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_actions = <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
+let api_timestamp_ms = <i64>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_action_with_timestamp(&mut *api_that_guard, api_actions, api_timestamp_ms)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_awareness_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_awareness_update", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_update = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_awareness_update(&mut *api_that_guard, api_update)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_delta_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_delta", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_delta_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_delta(&mut *api_that_guard, api_block_id, api_delta_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_meta_only_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_meta_only_update", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_update = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_meta_only_update(&mut *api_that_guard, api_update)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_subdoc_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_subdoc_update", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_guid = <String>::sse_decode(&mut deserializer);
+let api_update = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_subdoc_update(&mut *api_that_guard, api_guid, api_update)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_text_ops_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_text_ops", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_ops_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_text_ops(&mut *api_that_guard, api_block_id, api_ops_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_update", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_update = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_update(&mut *api_that_guard, api_update)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_update_remapped_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_update_remapped", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_update = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_id_map_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_update_remapped(&mut *api_that_guard, api_update, api_id_map_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_updates", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_updates(&mut *api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_updates_v1_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_updates_v1", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_updates_v1(&mut *api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_apply_updates_with_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_apply_updates_with_changes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::apply_updates_with_changes(&mut *api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_attachment_total_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_attachment_total_bytes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::attachment_total_bytes(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_begin_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_begin_batch", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::begin_batch(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_can_merge_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_can_merge_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_first_id = <String>::sse_decode(&mut deserializer);
+let api_second_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::can_merge_blocks(&*api_that_guard, api_first_id, api_second_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_can_redo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_can_redo", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::can_redo(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_can_undo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_can_undo", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::can_undo(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_children_order_hash_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_children_order_hash", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_parent_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::children_order_hash(&*api_that_guard, api_parent_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_clear_document_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_clear_document", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::clear_document(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_commit_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_commit_batch", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::commit_batch(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_compact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_compact", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::compact(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_compact_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_compact_updates", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::compact_updates(&*api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_content_hash_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_content_hash", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::content_hash(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_copy_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_copy_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_ids = <Vec<String>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::copy_blocks(&*api_that_guard, api_block_ids)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_create_subdoc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_create_subdoc", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::create_subdoc(&mut *api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_decode_relative_position_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_decode_relative_position", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_relative_position = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::decode_relative_position(&*api_that_guard, api_relative_position)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_delete_block_recursive_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_delete_block_recursive", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::delete_block_recursive(&mut *api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_detect_language_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_detect_language", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::detect_language(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_diff_states_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_diff_states", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_old_update = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_new_update = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::diff_states(api_old_update, api_new_update)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_doc_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_doc_id", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::doc_id(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_duplicate_block_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_duplicate_block", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::duplicate_block(&mut *api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_enable_undo_manager_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_enable_undo_manager", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_origins = <Vec<String>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::enable_undo_manager(&mut *api_that_guard, api_origins)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_awareness_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_awareness_update", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_awareness_update(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_diff_since_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_diff_since", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_remote_state_vector = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_diff_since(&*api_that_guard, api_remote_state_vector)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_full_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_full_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_full_state(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_full_state_v1_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_full_state_v1", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_full_state_v1(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_meta_only_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_meta_only_update", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_meta_only_update(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_relative_position_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_relative_position", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_offset = <u32>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_relative_position(&*api_that_guard, api_block_id, api_offset)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_state_vector_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_state_vector", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_state_vector(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_encode_subdoc_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_encode_subdoc_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_guid = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::encode_subdoc_state(&*api_that_guard, api_guid)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_enforce_single_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_enforce_single_root", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::enforce_single_root(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_estimate_diff_size_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_estimate_diff_size", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_remote_state_vector = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::estimate_diff_size(&*api_that_guard, api_remote_state_vector)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_export_appflowy_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_export_appflowy_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::export_appflowy_json(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_export_markdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_export_markdown", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::export_markdown(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_export_structure_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_export_structure", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::export_structure(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_export_tree_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_export_tree_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::export_tree_json(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_extract_delete_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_extract_delete_set", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_update = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::extract_delete_set(api_update)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_find_oversized_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_find_oversized_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_max_chars = <u32>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::find_oversized_blocks(&*api_that_guard, api_max_chars)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_fork_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_fork", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_new_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::fork(&*api_that_guard, api_new_doc_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_format_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_format_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_ids = <Vec<String>>::sse_decode(&mut deserializer);
+let api_attributes_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::format_blocks(&mut *api_that_guard, api_block_ids, api_attributes_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_gc_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_gc_enabled", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::gc_enabled(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_all_block_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_all_block_ids", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_all_block_ids(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_all_meta_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_all_meta", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_all_meta(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_block_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_block", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_block(&*api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_block_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_block_count", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_block_count(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_block_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_block_path", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_block_path(&*api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_block_plain_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_block_plain_text", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_block_plain_text(&*api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_blocks_in_order_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_blocks_in_order", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_blocks_in_order(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_blocks_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_blocks_page", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_offset = <usize>::sse_decode(&mut deserializer);
+let api_limit = <usize>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_blocks_page(&*api_that_guard, api_offset, api_limit)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_children_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_children_ids", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_parent_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_children_ids(&*api_that_guard, api_parent_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_comments_for_block_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_comments_for_block", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_comments_for_block(&*api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_document_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_document_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_document_json(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_document_plain_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_document_plain_text", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_document_plain_text(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_document_size_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_document_size_bytes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_document_size_bytes(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_document_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_document_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_document_state(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_meta_bool_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_meta_bool", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_meta_bool(&*api_that_guard, api_key)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_meta_int_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_meta_int", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_meta_int(&*api_that_guard, api_key)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_meta_keys_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_meta_keys", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_meta_keys(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_meta_string_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_meta_string", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_meta_string(&*api_that_guard, api_key)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_meta_string_array_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_meta_string_array", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_meta_string_array(&*api_that_guard, api_key)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_root_node_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_root_node_id", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_root_node_id(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_statistics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_statistics", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_statistics(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_statistics_with_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_statistics_with_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::get_statistics_with_blocks(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_get_subdoc_guids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_get_subdoc_guids", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::get_subdoc_guids(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_import_appflowy_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_import_appflowy_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::import_appflowy_json(&mut *api_that_guard, api_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_import_markdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_import_markdown", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_markdown = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::import_markdown(&mut *api_that_guard, api_markdown)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_import_tree_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_import_tree_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::import_tree_json(&mut *api_that_guard, api_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_indent_block_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_indent_block", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::indent_block(&mut *api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_init_empty_doc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_init_empty_doc", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::init_empty_doc(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_insert_blocks_bulk_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_insert_blocks_bulk", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_blocks = <Vec<crate::doc::document_types::BlockDoc>>::sse_decode(&mut deserializer);
+let api_parent_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::insert_blocks_bulk(&mut *api_that_guard, api_blocks, api_parent_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_is_batching_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_is_batching", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::is_batching(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_is_empty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_is_empty", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::is_empty(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_last_replace_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_last_replace_count", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::last_replace_count(&*api_that_guard))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_load_and_get_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_load_and_get_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::load_and_get_state(&mut *api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_load_from_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_load_from_file", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_path = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::load_from_file(&mut *api_that_guard, api_path)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_merge_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_merge_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_first_id = <String>::sse_decode(&mut deserializer);
+let api_second_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::merge_blocks(&mut *api_that_guard, api_first_id, api_second_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_merge_three_way_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_merge_three_way", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_base = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_left = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_right = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::merge_three_way(api_base, api_left, api_right)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_merge_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_merge_updates", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::merge_updates(&*api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_merge_updates_v1_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_merge_updates_v1", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::merge_updates_v1(&*api_that_guard, api_updates)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_meta_diff_against_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_meta_diff_against_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::meta_diff_against_json(&*api_that_guard, api_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_missing_from_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_missing_from", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_local_sv = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_remote_sv = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::missing_from(api_local_sv, api_remote_sv)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_new", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::new())?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_new_with_gc_disabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_new_with_gc_disabled", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::new_with_gc_disabled())?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_new_with_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_new_with_id", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_doc_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::new_with_id(api_doc_id))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_new_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_new_with_options", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_doc_id = <String>::sse_decode(&mut deserializer);
+let api_gc = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::new_with_options(api_doc_id, api_gc))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_outdent_block_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_outdent_block", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::outdent_block(&mut *api_that_guard, api_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_paste_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_paste_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_payload = <String>::sse_decode(&mut deserializer);
+let api_target_parent_id = <String>::sse_decode(&mut deserializer);
+let api_after_block_id = <Option<String>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::paste_blocks(&mut *api_that_guard, api_payload, api_target_parent_id, api_after_block_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_push_meta_array_item_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_push_meta_array_item", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_value = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::push_meta_array_item(&mut *api_that_guard, api_key, api_value)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_read_document_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_apply_updates",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::apply_updates(
-                                &mut *api_that_guard,
-                                api_updates,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_encode_full_state_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_read_document_state", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::read_document_state(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_redo_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_encode_full_state",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::encode_full_state(
-                                &*api_that_guard,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_get_all_meta_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_redo", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::redo(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_remove_awareness_client_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_get_all_meta",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::get_all_meta(
-                                &*api_that_guard,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_remove_awareness_client", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_client_id = <u64>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::remove_awareness_client(&mut *api_that_guard, api_client_id))?; Ok(output_ok) })()) }
+    })
 }
-fn wire__crate__doc__document_service__DocumentService_get_document_state_impl(
+fn wire__crate__doc__document_service__DocumentService_remove_meta_array_item_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_get_document_state",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::get_document_state(
-                                &*api_that_guard,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_remove_meta_array_item", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_value = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::remove_meta_array_item(&mut *api_that_guard, api_key, api_value)?; Ok(output_ok) })()) }
+    })
 }
-fn wire__crate__doc__document_service__DocumentService_init_empty_doc_impl(
+fn wire__crate__doc__document_service__DocumentService_remove_meta_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_init_empty_doc",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::init_empty_doc(
-                                &mut *api_that_guard,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_merge_updates_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_remove_meta_key", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::remove_meta_key(&mut *api_that_guard, api_key)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_repair_document_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_merge_updates",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_updates = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::merge_updates(
-                                &*api_that_guard,
-                                api_updates,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_new_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_repair_document", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::repair_document(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_replace_all_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_new",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::new())?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_push_meta_array_item_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_replace_all", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_query = <String>::sse_decode(&mut deserializer);
+let api_replacement = <String>::sse_decode(&mut deserializer);
+let api_case_sensitive = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::replace_all(&mut *api_that_guard, api_query, api_replacement, api_case_sensitive)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_resolve_comment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_push_meta_array_item",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::push_meta_array_item(
-                                &mut *api_that_guard,
-                                api_key,
-                                api_value,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_remove_meta_array_item_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_resolve_comment", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_comment_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::resolve_comment(&mut *api_that_guard, api_comment_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_restore_snapshot_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_remove_meta_array_item",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::remove_meta_array_item(
-                                &mut *api_that_guard,
-                                api_key,
-                                api_value,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__doc__document_service__DocumentService_remove_meta_key_impl(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_restore_snapshot", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_snapshot = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::restore_snapshot(&mut *api_that_guard, api_snapshot)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_save_to_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_save_to_file", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_path = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::save_to_file(&*api_that_guard, api_path)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_search_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_search_text", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_query = <String>::sse_decode(&mut deserializer);
+let api_case_sensitive = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::search_text(&*api_that_guard, api_query, api_case_sensitive)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_allowed_block_types_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_allowed_block_types", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_types = <Vec<String>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok({ crate::doc::document_service::DocumentService::set_allowed_block_types(&mut *api_that_guard, api_types); })?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_attachment_budget_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_attachment_budget", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_budget = <Option<u64>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok({ crate::doc::document_service::DocumentService::set_attachment_budget(&mut *api_that_guard, api_budget); })?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_block_attachment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_block_attachment", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_block_attachment(&mut *api_that_guard, api_block_id, api_key, api_bytes)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_block_data_field_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_block_data_field", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_field = <String>::sse_decode(&mut deserializer);
+let api_value_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_block_data_field(&mut *api_that_guard, api_block_id, api_field, api_value_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_id_generator_seed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_id_generator_seed", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_seed = <u64>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok({ crate::doc::document_service::DocumentService::set_id_generator_seed(&mut *api_that_guard, api_seed); })?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_local_awareness_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_local_awareness", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_state_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_local_awareness(&mut *api_that_guard, api_state_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_log_level_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_remove_meta_key",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::remove_meta_key(
-                                &mut *api_that_guard,
-                                api_key,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_log_level", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_level = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::set_log_level(api_level)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_max_tree_depth_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_max_tree_depth", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_depth = <u32>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok({ crate::doc::document_service::DocumentService::set_max_tree_depth(&mut *api_that_guard, api_depth); })?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_meta_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_meta_batch", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_operations = <Vec<crate::doc::document_types::MetaOp>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_meta_batch(&mut *api_that_guard, api_operations)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_service__DocumentService_set_meta_bool_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -642,57 +2012,15 @@ fn wire__crate__doc__document_service__DocumentService_set_meta_bool_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_set_meta_bool",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <bool>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::set_meta_bool(
-                                &mut *api_that_guard,
-                                api_key,
-                                api_value,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_meta_bool", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_value = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_meta_bool(&mut *api_that_guard, api_key, api_value)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_service__DocumentService_set_meta_from_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -700,55 +2028,14 @@ fn wire__crate__doc__document_service__DocumentService_set_meta_from_json_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_set_meta_from_json",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_json_str = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::set_meta_from_json(
-                                &mut *api_that_guard,
-                                api_json_str,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_meta_from_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_json_str = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_meta_from_json(&mut *api_that_guard, api_json_str)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_service__DocumentService_set_meta_int_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -756,57 +2043,15 @@ fn wire__crate__doc__document_service__DocumentService_set_meta_int_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_set_meta_int",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <i64>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::set_meta_int(
-                                &mut *api_that_guard,
-                                api_key,
-                                api_value,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_meta_int", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_value = <i64>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_meta_int(&mut *api_that_guard, api_key, api_value)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_service__DocumentService_set_meta_string_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -814,57 +2059,15 @@ fn wire__crate__doc__document_service__DocumentService_set_meta_string_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_set_meta_string",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::set_meta_string(
-                                &mut *api_that_guard,
-                                api_key,
-                                api_value,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_meta_string", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_value = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_meta_string(&mut *api_that_guard, api_key, api_value)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_service__DocumentService_set_meta_string_array_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -872,57 +2075,15 @@ fn wire__crate__doc__document_service__DocumentService_set_meta_string_array_imp
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_set_meta_string_array",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_values = <Vec<String>>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::set_meta_string_array(
-                                &mut *api_that_guard,
-                                api_key,
-                                api_values,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_meta_string_array", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_key = <String>::sse_decode(&mut deserializer);
+let api_values = <Vec<String>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_meta_string_array(&mut *api_that_guard, api_key, api_values)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_service__DocumentService_set_root_node_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -930,55 +2091,237 @@ fn wire__crate__doc__document_service__DocumentService_set_root_node_id_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DocumentService_set_root_node_id",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-            >>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::doc::document_types::CustomRustError>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, true,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                                _ => unreachable!(),
-                            }
-                        }
-                        let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::doc::document_service::DocumentService::set_root_node_id(
-                                &mut *api_that_guard,
-                                api_id,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_root_node_id", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::set_root_node_id(&mut *api_that_guard, api_id)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_set_strict_types_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_set_strict_types", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_strict = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok({ crate::doc::document_service::DocumentService::set_strict_types(&mut *api_that_guard, api_strict); })?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_state_as_of_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_state_as_of", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_snapshot = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::state_as_of(&*api_that_guard, api_snapshot)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_state_vectors_equal_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_state_vectors_equal", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_a = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_b = <Vec<u8>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::state_vectors_equal(api_a, api_b)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_stream_blocks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_stream_blocks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_callback = decode_DartFn_Inputs_String_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));
+deserializer.end();
+        move |context| async move { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || async move { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_async_ref().await), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::stream_blocks(&*api_that_guard, api_callback).await?; Ok(output_ok) })().await) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_subscribe_block_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_subscribe_block_changes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::subscribe_block_changes(&mut *api_that_guard, api_block_id, api_sink)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_subscribe_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_subscribe_changes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<crate::doc::document_types::OriginUpdate, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::subscribe_changes(&mut *api_that_guard, api_sink)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_subscribe_emptiness_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_subscribe_emptiness", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<bool, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::subscribe_emptiness(&mut *api_that_guard, api_sink)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_subscribe_meta_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_subscribe_meta_changes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::subscribe_meta_changes(&mut *api_that_guard, api_sink)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_subscribe_subdoc_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_subscribe_subdoc_changes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_guid = <String>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::subscribe_subdoc_changes(&mut *api_that_guard, api_guid, api_sink)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_take_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_take_snapshot", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::take_snapshot(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_undo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_undo", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::undo(&mut *api_that_guard)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_unsubscribe_block_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_unsubscribe_block_changes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_block_id = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()), _ => unreachable!(), } } let mut api_that_guard = api_that_guard.unwrap(); let output_ok = Result::<_, ()>::Ok(crate::doc::document_service::DocumentService::unsubscribe_block_changes(&mut *api_that_guard, api_block_id))?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_validate_action_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_validate_action", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+let api_actions = <Vec<crate::doc::document_types::BlockActionDoc>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::validate_action(&*api_that_guard, api_actions)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_validate_appflowy_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_validate_appflowy_json", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_json = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let output_ok = crate::doc::document_service::DocumentService::validate_appflowy_json(api_json)?; Ok(output_ok) })()) }
+    })
+}
+fn wire__crate__doc__document_service__DocumentService_validate_document_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "DocumentService_validate_document", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, crate::doc::document_types::CustomRustError>((move || { let mut api_that_guard = None; let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]); for i in decode_indices_ { match i { 0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()), _ => unreachable!(), } } let api_that_guard = api_that_guard.unwrap(); let output_ok = crate::doc::document_service::DocumentService::validate_document(&*api_that_guard)?; Ok(output_ok) })()) }
+    })
 }
 fn wire__crate__doc__document_types__custom_rust_error_new_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -986,385 +2329,393 @@ fn wire__crate__doc__document_types__custom_rust_error_new_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "custom_rust_error_new",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::doc::document_types::CustomRustError::new(&api_message),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "custom_rust_error_new", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_message = <String>::sse_decode(&mut deserializer);
+deserializer.end();
+        move |context| { transform_result_sse::<_, ()>((move || { let output_ok = Result::<_, ()>::Ok(crate::doc::document_types::CustomRustError::new(&api_message))?; Ok(output_ok) })()) }
+    })
 }
 
 // Section: related_funcs
 
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>
-);
+fn decode_DartFn_Inputs_String_Output_unit_AnyhowException(dart_opaque: flutter_rust_bridge::DartOpaque) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER.dart_fn_invoke(dart_opaque, args).await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(<flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer)),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(dart_opaque.clone(), arg0))
+    }
+}
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>);
 
 // Section: dart2rust
 
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer); return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);}
+}
+
+impl SseDecode for DocumentRegistry {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_decode(deserializer); return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);}
+}
+
 impl SseDecode for DocumentService {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_decode(deserializer); return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);}
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <usize>::sse_decode(deserializer); return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };}
 }
 
 impl SseDecode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <Vec<(String, String)>>::sse_decode(deserializer); return inner.into_iter().collect();}
 }
 
 impl SseDecode for std::collections::HashMap<String, crate::doc::document_types::BlockDoc> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner =
-            <Vec<(String, crate::doc::document_types::BlockDoc)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <Vec<(String, crate::doc::document_types::BlockDoc)>>::sse_decode(deserializer); return inner.into_iter().collect();}
 }
 
 impl SseDecode for std::collections::HashMap<String, Vec<String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, Vec<String>)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <Vec<(String, Vec<String>)>>::sse_decode(deserializer); return inner.into_iter().collect();}
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
+impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <usize>::sse_decode(deserializer); return decode_rust_opaque_moi(inner);}
+}
+
+impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <usize>::sse_decode(deserializer); return decode_rust_opaque_moi(inner);}
+}
+
+impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer); return StreamSink::deserialize(inner);}
+}
+
+impl SseDecode for StreamSink<bool, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer); return StreamSink::deserialize(inner);}
+}
+
+impl SseDecode for StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer); return StreamSink::deserialize(inner);}
+}
+
+impl SseDecode for StreamSink<crate::doc::document_types::OriginUpdate, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer); return StreamSink::deserialize(inner);}
 }
 
 impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <Vec<u8>>::sse_decode(deserializer); return String::from_utf8(inner).unwrap();}
+}
+
+impl SseDecode for crate::doc::document_types::ActionResult {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_update = <Vec<u8>>::sse_decode(deserializer);let mut var_generatedIds = <Vec<String>>::sse_decode(deserializer);return crate::doc::document_types::ActionResult { update: var_update, generated_ids: var_generatedIds };}
+}
+
+impl SseDecode for crate::doc::document_types::AppliedBlockChanges {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_insertedBlockIds = <Vec<String>>::sse_decode(deserializer);let mut var_updatedBlockIds = <Vec<String>>::sse_decode(deserializer);let mut var_deletedBlockIds = <Vec<String>>::sse_decode(deserializer);let mut var_failedUpdates = <crate::doc::document_types::FailedToDecodeUpdates>::sse_decode(deserializer);return crate::doc::document_types::AppliedBlockChanges { inserted_block_ids: var_insertedBlockIds, updated_block_ids: var_updatedBlockIds, deleted_block_ids: var_deletedBlockIds, failed_updates: var_failedUpdates };}
 }
 
 impl SseDecode for crate::doc::document_types::BlockActionDoc {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_action =
-            <crate::doc::document_types::BlockActionTypeDoc>::sse_decode(deserializer);
-        let mut var_block = <crate::doc::document_types::BlockDoc>::sse_decode(deserializer);
-        let mut var_path = <Vec<u32>>::sse_decode(deserializer);
-        let mut var_oldPath = <Option<Vec<u32>>>::sse_decode(deserializer);
-        return crate::doc::document_types::BlockActionDoc {
-            action: var_action,
-            block: var_block,
-            path: var_path,
-            old_path: var_oldPath,
-        };
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_action = <crate::doc::document_types::BlockActionTypeDoc>::sse_decode(deserializer);let mut var_block = <crate::doc::document_types::BlockDoc>::sse_decode(deserializer);let mut var_path = <Vec<u32>>::sse_decode(deserializer);let mut var_oldPath = <Option<Vec<u32>>>::sse_decode(deserializer);return crate::doc::document_types::BlockActionDoc { action: var_action, block: var_block, path: var_path, old_path: var_oldPath };}
 }
 
 impl SseDecode for crate::doc::document_types::BlockActionTypeDoc {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::doc::document_types::BlockActionTypeDoc::Insert,
-            1 => crate::doc::document_types::BlockActionTypeDoc::Update,
-            2 => crate::doc::document_types::BlockActionTypeDoc::Delete,
-            3 => crate::doc::document_types::BlockActionTypeDoc::Move,
-            _ => unreachable!("Invalid variant for BlockActionTypeDoc: {}", inner),
-        };
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer); return match inner { 0 => crate::doc::document_types::BlockActionTypeDoc::Insert, 1 => crate::doc::document_types::BlockActionTypeDoc::Update, 2 => crate::doc::document_types::BlockActionTypeDoc::Delete, 3 => crate::doc::document_types::BlockActionTypeDoc::Move, _ => unreachable!("Invalid variant for BlockActionTypeDoc: {}", inner), };}
 }
 
 impl SseDecode for crate::doc::document_types::BlockDoc {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_ty = <String>::sse_decode(deserializer);
-        let mut var_attributes =
-            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        let mut var_delta = <Option<String>>::sse_decode(deserializer);
-        let mut var_parentId = <Option<String>>::sse_decode(deserializer);
-        let mut var_prevId = <Option<String>>::sse_decode(deserializer);
-        let mut var_nextId = <Option<String>>::sse_decode(deserializer);
-        let mut var_oldParentId = <Option<String>>::sse_decode(deserializer);
-        return crate::doc::document_types::BlockDoc {
-            id: var_id,
-            ty: var_ty,
-            attributes: var_attributes,
-            delta: var_delta,
-            parent_id: var_parentId,
-            prev_id: var_prevId,
-            next_id: var_nextId,
-            old_parent_id: var_oldParentId,
-        };
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_id = <String>::sse_decode(deserializer);let mut var_ty = <String>::sse_decode(deserializer);let mut var_attributes = <std::collections::HashMap<String, String>>::sse_decode(deserializer);let mut var_delta = <Option<String>>::sse_decode(deserializer);let mut var_parentId = <Option<String>>::sse_decode(deserializer);let mut var_prevId = <Option<String>>::sse_decode(deserializer);let mut var_nextId = <Option<String>>::sse_decode(deserializer);let mut var_oldParentId = <Option<String>>::sse_decode(deserializer);let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);return crate::doc::document_types::BlockDoc { id: var_id, ty: var_ty, attributes: var_attributes, delta: var_delta, parent_id: var_parentId, prev_id: var_prevId, next_id: var_nextId, old_parent_id: var_oldParentId, created_at: var_createdAt, updated_at: var_updatedAt };}
+}
+
+impl SseDecode for crate::doc::document_types::BlockPage {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_blocks = <Vec<crate::doc::document_types::BlockDoc>>::sse_decode(deserializer);let mut var_total = <usize>::sse_decode(deserializer);let mut var_hasMore = <bool>::sse_decode(deserializer);return crate::doc::document_types::BlockPage { blocks: var_blocks, total: var_total, has_more: var_hasMore };}
+}
+
+impl SseDecode for crate::doc::document_types::BlockStats {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_blockId = <String>::sse_decode(deserializer);let mut var_wordCount = <u32>::sse_decode(deserializer);let mut var_charCount = <u32>::sse_decode(deserializer);let mut var_charCountNoSpaces = <u32>::sse_decode(deserializer);return crate::doc::document_types::BlockStats { block_id: var_blockId, word_count: var_wordCount, char_count: var_charCount, char_count_no_spaces: var_charCountNoSpaces };}
 }
 
 impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u8().unwrap() != 0}
+}
+
+impl SseDecode for crate::doc::document_types::CommentDoc {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_commentId = <String>::sse_decode(deserializer);let mut var_blockId = <String>::sse_decode(deserializer);let mut var_author = <String>::sse_decode(deserializer);let mut var_text = <String>::sse_decode(deserializer);let mut var_resolved = <bool>::sse_decode(deserializer);let mut var_anchorOffset = <Option<u32>>::sse_decode(deserializer);return crate::doc::document_types::CommentDoc { comment_id: var_commentId, block_id: var_blockId, author: var_author, text: var_text, resolved: var_resolved, anchor_offset: var_anchorOffset };}
 }
 
 impl SseDecode for crate::doc::document_types::CustomRustError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::doc::document_types::CustomRustError {
-            message: var_message,
-        };
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_code = <String>::sse_decode(deserializer);let mut var_message = <String>::sse_decode(deserializer);return crate::doc::document_types::CustomRustError { code: var_code, message: var_message };}
+}
+
+impl SseDecode for crate::doc::document_types::DocSize {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_fullStateBytes = <u64>::sse_decode(deserializer);let mut var_contentBytes = <u64>::sse_decode(deserializer);return crate::doc::document_types::DocSize { full_state_bytes: var_fullStateBytes, content_bytes: var_contentBytes };}
+}
+
+impl SseDecode for crate::doc::document_types::DocStats {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_wordCount = <u32>::sse_decode(deserializer);let mut var_charCount = <u32>::sse_decode(deserializer);let mut var_charCountNoSpaces = <u32>::sse_decode(deserializer);let mut var_blockCount = <u32>::sse_decode(deserializer);let mut var_blocks = <Option<Vec<crate::doc::document_types::BlockStats>>>::sse_decode(deserializer);return crate::doc::document_types::DocStats { word_count: var_wordCount, char_count: var_charCount, char_count_no_spaces: var_charCountNoSpaces, block_count: var_blockCount, blocks: var_blocks };}
 }
 
 impl SseDecode for crate::doc::document_types::DocumentState {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_docId = <String>::sse_decode(deserializer);
-        let mut var_blocks = <std::collections::HashMap<
-            String,
-            crate::doc::document_types::BlockDoc,
-        >>::sse_decode(deserializer);
-        let mut var_childrenMap =
-            <std::collections::HashMap<String, Vec<String>>>::sse_decode(deserializer);
-        let mut var_rootId = <String>::sse_decode(deserializer);
-        return crate::doc::document_types::DocumentState {
-            doc_id: var_docId,
-            blocks: var_blocks,
-            children_map: var_childrenMap,
-            root_id: var_rootId,
-        };
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_docId = <String>::sse_decode(deserializer);let mut var_blocks = <std::collections::HashMap<String, crate::doc::document_types::BlockDoc>>::sse_decode(deserializer);let mut var_childrenMap = <std::collections::HashMap<String, Vec<String>>>::sse_decode(deserializer);let mut var_rootId = <String>::sse_decode(deserializer);return crate::doc::document_types::DocumentState { doc_id: var_docId, blocks: var_blocks, children_map: var_childrenMap, root_id: var_rootId };}
+}
+
+impl SseDecode for crate::doc::document_types::DocumentValidation {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_orphanedBlockIds = <Vec<String>>::sse_decode(deserializer);let mut var_cyclicBlockIds = <Vec<String>>::sse_decode(deserializer);let mut var_danglingReferenceIds = <Vec<String>>::sse_decode(deserializer);let mut var_orphanedCommentIds = <Vec<String>>::sse_decode(deserializer);return crate::doc::document_types::DocumentValidation { orphaned_block_ids: var_orphanedBlockIds, cyclic_block_ids: var_cyclicBlockIds, dangling_reference_ids: var_danglingReferenceIds, orphaned_comment_ids: var_orphanedCommentIds };}
+}
+
+impl SseDecode for crate::doc::document_types::DuplicateResult {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_update = <Vec<u8>>::sse_decode(deserializer);let mut var_idMap = <std::collections::HashMap<String, String>>::sse_decode(deserializer);return crate::doc::document_types::DuplicateResult { update: var_update, id_map: var_idMap };}
 }
 
 impl SseDecode for crate::doc::document_types::FailedToDecodeUpdates {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_failedUpdatesIds = <Vec<String>>::sse_decode(deserializer);
-        return crate::doc::document_types::FailedToDecodeUpdates {
-            failed_updates_ids: var_failedUpdatesIds,
-        };
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_failedUpdatesIds = <Vec<String>>::sse_decode(deserializer);let mut var_errors = <Vec<String>>::sse_decode(deserializer);return crate::doc::document_types::FailedToDecodeUpdates { failed_updates_ids: var_failedUpdatesIds, errors: var_errors };}
 }
 
 impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_i32::<NativeEndian>().unwrap()}
 }
 
 impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_i64::<NativeEndian>().unwrap()}
 }
 
 impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<String>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<crate::doc::document_types::BlockActionDoc> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::doc::document_types::BlockActionDoc>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::BlockActionDoc>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<crate::doc::document_types::BlockDoc> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::BlockDoc>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<crate::doc::document_types::BlockStats> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::BlockStats>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<crate::doc::document_types::CommentDoc> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::CommentDoc>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<Vec<u8>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Vec<u8>>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<Vec<u8>>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<crate::doc::document_types::MetaOp> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::MetaOp>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<crate::doc::document_types::OrderedBlock> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::OrderedBlock>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u32>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<u32>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<u8>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<(String, crate::doc::document_types::BlockDoc)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, crate::doc::document_types::BlockDoc)>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<(String, crate::doc::document_types::BlockDoc)>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<(String, Vec<String>)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, Vec<String>)>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<(String, Vec<String>)>::sse_decode(deserializer)); } return ans_;}
 }
 
 impl SseDecode for Vec<(String, String)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<(String, String)>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<(u64, u32)> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<(u64, u32)>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for Vec<crate::doc::document_types::TextMatch> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer); let mut ans_ = vec![]; for idx_ in 0..len_ { ans_.push(<crate::doc::document_types::TextMatch>::sse_decode(deserializer)); } return ans_;}
+}
+
+impl SseDecode for crate::doc::document_types::MetaOp {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut tag_ = <i32>::sse_decode(deserializer); match tag_ { 0 => {let mut var_key = <String>::sse_decode(deserializer);let mut var_value = <String>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::SetString { key: var_key, value: var_value }; }1 => {let mut var_key = <String>::sse_decode(deserializer);let mut var_value = <i64>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::SetInt { key: var_key, value: var_value }; }2 => {let mut var_key = <String>::sse_decode(deserializer);let mut var_value = <bool>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::SetBool { key: var_key, value: var_value }; }3 => {let mut var_key = <String>::sse_decode(deserializer);let mut var_values = <Vec<String>>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::SetStringArray { key: var_key, values: var_values }; }4 => {let mut var_key = <String>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::Remove { key: var_key }; }5 => {let mut var_key = <String>::sse_decode(deserializer);let mut var_value = <String>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::PushArrayItem { key: var_key, value: var_value }; }6 => {let mut var_key = <String>::sse_decode(deserializer);let mut var_value = <String>::sse_decode(deserializer);return crate::doc::document_types::MetaOp::RemoveArrayItem { key: var_key, value: var_value }; } _ => { unimplemented!(""); } }}
 }
 
 impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<String>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<crate::doc::document_types::BlockDoc> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<crate::doc::document_types::BlockDoc>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<bool> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<bool>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<i64> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<i64>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<u32> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<u32>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<u64> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<u64>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<Vec<String>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<Vec<String>>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for Option<Vec<crate::doc::document_types::BlockStats>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<Vec<crate::doc::document_types::BlockStats>>::sse_decode(deserializer)); } else { return None; }}
 }
 
 impl SseDecode for Option<Vec<u32>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<u32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) { return Some(<Vec<u32>>::sse_decode(deserializer)); } else { return None; }}
+}
+
+impl SseDecode for crate::doc::document_types::OrderedBlock {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_block = <crate::doc::document_types::BlockDoc>::sse_decode(deserializer);let mut var_depth = <i32>::sse_decode(deserializer);return crate::doc::document_types::OrderedBlock { block: var_block, depth: var_depth };}
+}
+
+impl SseDecode for crate::doc::document_types::OriginUpdate {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_update = <Vec<u8>>::sse_decode(deserializer);let mut var_origin = <Option<String>>::sse_decode(deserializer);return crate::doc::document_types::OriginUpdate { update: var_update, origin: var_origin };}
 }
 
 impl SseDecode for (String, crate::doc::document_types::BlockDoc) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <crate::doc::document_types::BlockDoc>::sse_decode(deserializer);
-        return (var_field0, var_field1);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_field0 = <String>::sse_decode(deserializer);let mut var_field1 = <crate::doc::document_types::BlockDoc>::sse_decode(deserializer);return (var_field0, var_field1);}
 }
 
 impl SseDecode for (String, Vec<String>) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <Vec<String>>::sse_decode(deserializer);
-        return (var_field0, var_field1);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_field0 = <String>::sse_decode(deserializer);let mut var_field1 = <Vec<String>>::sse_decode(deserializer);return (var_field0, var_field1);}
 }
 
 impl SseDecode for (String, String) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_field0 = <String>::sse_decode(deserializer);let mut var_field1 = <String>::sse_decode(deserializer);return (var_field0, var_field1);}
+}
+
+impl SseDecode for (u64, u32) {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_field0 = <u64>::sse_decode(deserializer);let mut var_field1 = <u32>::sse_decode(deserializer);return (var_field0, var_field1);}
+}
+
+impl SseDecode for crate::doc::document_types::RelativePos {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_blockId = <String>::sse_decode(deserializer);let mut var_offset = <u32>::sse_decode(deserializer);return crate::doc::document_types::RelativePos { block_id: var_blockId, offset: var_offset };}
+}
+
+impl SseDecode for crate::doc::document_types::TextMatch {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_blockId = <String>::sse_decode(deserializer);let mut var_offset = <u32>::sse_decode(deserializer);let mut var_length = <u32>::sse_decode(deserializer);return crate::doc::document_types::TextMatch { block_id: var_blockId, offset: var_offset, length: var_length };}
 }
 
 impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u32::<NativeEndian>().unwrap()}
+}
+
+impl SseDecode for u64 {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u64::<NativeEndian>().unwrap()}
 }
 
 impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u8().unwrap()}
 }
 
 impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
 impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _}
 }
 
 fn pde_ffi_dispatcher_primary_impl(
@@ -1376,114 +2727,160 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        1 => wire__crate__doc__document_service__DocumentService_apply_action_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__doc__document_service__DocumentService_apply_updates_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__doc__document_service__DocumentService_encode_full_state_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__doc__document_service__DocumentService_get_all_meta_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__doc__document_service__DocumentService_get_document_state_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__doc__document_service__DocumentService_init_empty_doc_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__doc__document_service__DocumentService_merge_updates_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__doc__document_service__DocumentService_new_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__doc__document_service__DocumentService_push_meta_array_item_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__doc__document_service__DocumentService_remove_meta_array_item_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__doc__document_service__DocumentService_remove_meta_key_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__doc__document_service__DocumentService_set_meta_bool_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__doc__document_service__DocumentService_set_meta_from_json_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__doc__document_service__DocumentService_set_meta_int_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        15 => wire__crate__doc__document_service__DocumentService_set_meta_string_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        16 => wire__crate__doc__document_service__DocumentService_set_meta_string_array_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__doc__document_service__DocumentService_set_root_node_id_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => wire__crate__doc__document_types__custom_rust_error_new_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
+1 => wire__crate__doc__document_registry__DocumentRegistry_apply_action_impl(port, ptr, rust_vec_len, data_len),
+2 => wire__crate__doc__document_registry__DocumentRegistry_apply_updates_impl(port, ptr, rust_vec_len, data_len),
+3 => wire__crate__doc__document_registry__DocumentRegistry_close_impl(port, ptr, rust_vec_len, data_len),
+4 => wire__crate__doc__document_registry__DocumentRegistry_encode_full_state_impl(port, ptr, rust_vec_len, data_len),
+5 => wire__crate__doc__document_registry__DocumentRegistry_get_document_state_impl(port, ptr, rust_vec_len, data_len),
+6 => wire__crate__doc__document_registry__DocumentRegistry_init_empty_doc_impl(port, ptr, rust_vec_len, data_len),
+7 => wire__crate__doc__document_registry__DocumentRegistry_list_open_impl(port, ptr, rust_vec_len, data_len),
+8 => wire__crate__doc__document_registry__DocumentRegistry_new_impl(port, ptr, rust_vec_len, data_len),
+9 => wire__crate__doc__document_registry__DocumentRegistry_open_impl(port, ptr, rust_vec_len, data_len),
+10 => wire__crate__doc__document_service__DocumentService_active_editor_count_impl(port, ptr, rust_vec_len, data_len),
+11 => wire__crate__doc__document_service__DocumentService_add_comment_impl(port, ptr, rust_vec_len, data_len),
+12 => wire__crate__doc__document_service__DocumentService_additive_update_for_impl(port, ptr, rust_vec_len, data_len),
+13 => wire__crate__doc__document_service__DocumentService_apply_action_impl(port, ptr, rust_vec_len, data_len),
+14 => wire__crate__doc__document_service__DocumentService_apply_action_with_ids_impl(port, ptr, rust_vec_len, data_len),
+15 => wire__crate__doc__document_service__DocumentService_apply_action_with_origin_impl(port, ptr, rust_vec_len, data_len),
+16 => wire__crate__doc__document_service__DocumentService_apply_action_with_timestamp_impl(port, ptr, rust_vec_len, data_len),
+17 => wire__crate__doc__document_service__DocumentService_apply_awareness_update_impl(port, ptr, rust_vec_len, data_len),
+18 => wire__crate__doc__document_service__DocumentService_apply_delta_impl(port, ptr, rust_vec_len, data_len),
+19 => wire__crate__doc__document_service__DocumentService_apply_meta_only_update_impl(port, ptr, rust_vec_len, data_len),
+20 => wire__crate__doc__document_service__DocumentService_apply_subdoc_update_impl(port, ptr, rust_vec_len, data_len),
+21 => wire__crate__doc__document_service__DocumentService_apply_text_ops_impl(port, ptr, rust_vec_len, data_len),
+22 => wire__crate__doc__document_service__DocumentService_apply_update_impl(port, ptr, rust_vec_len, data_len),
+23 => wire__crate__doc__document_service__DocumentService_apply_update_remapped_impl(port, ptr, rust_vec_len, data_len),
+24 => wire__crate__doc__document_service__DocumentService_apply_updates_impl(port, ptr, rust_vec_len, data_len),
+25 => wire__crate__doc__document_service__DocumentService_apply_updates_v1_impl(port, ptr, rust_vec_len, data_len),
+26 => wire__crate__doc__document_service__DocumentService_apply_updates_with_changes_impl(port, ptr, rust_vec_len, data_len),
+27 => wire__crate__doc__document_service__DocumentService_attachment_total_bytes_impl(port, ptr, rust_vec_len, data_len),
+28 => wire__crate__doc__document_service__DocumentService_begin_batch_impl(port, ptr, rust_vec_len, data_len),
+29 => wire__crate__doc__document_service__DocumentService_can_merge_blocks_impl(port, ptr, rust_vec_len, data_len),
+30 => wire__crate__doc__document_service__DocumentService_can_redo_impl(port, ptr, rust_vec_len, data_len),
+31 => wire__crate__doc__document_service__DocumentService_can_undo_impl(port, ptr, rust_vec_len, data_len),
+32 => wire__crate__doc__document_service__DocumentService_children_order_hash_impl(port, ptr, rust_vec_len, data_len),
+33 => wire__crate__doc__document_service__DocumentService_clear_document_impl(port, ptr, rust_vec_len, data_len),
+34 => wire__crate__doc__document_service__DocumentService_commit_batch_impl(port, ptr, rust_vec_len, data_len),
+35 => wire__crate__doc__document_service__DocumentService_compact_impl(port, ptr, rust_vec_len, data_len),
+36 => wire__crate__doc__document_service__DocumentService_compact_updates_impl(port, ptr, rust_vec_len, data_len),
+37 => wire__crate__doc__document_service__DocumentService_content_hash_impl(port, ptr, rust_vec_len, data_len),
+38 => wire__crate__doc__document_service__DocumentService_copy_blocks_impl(port, ptr, rust_vec_len, data_len),
+39 => wire__crate__doc__document_service__DocumentService_create_subdoc_impl(port, ptr, rust_vec_len, data_len),
+40 => wire__crate__doc__document_service__DocumentService_decode_relative_position_impl(port, ptr, rust_vec_len, data_len),
+41 => wire__crate__doc__document_service__DocumentService_delete_block_recursive_impl(port, ptr, rust_vec_len, data_len),
+42 => wire__crate__doc__document_service__DocumentService_detect_language_impl(port, ptr, rust_vec_len, data_len),
+43 => wire__crate__doc__document_service__DocumentService_diff_states_impl(port, ptr, rust_vec_len, data_len),
+44 => wire__crate__doc__document_service__DocumentService_doc_id_impl(port, ptr, rust_vec_len, data_len),
+45 => wire__crate__doc__document_service__DocumentService_duplicate_block_impl(port, ptr, rust_vec_len, data_len),
+46 => wire__crate__doc__document_service__DocumentService_enable_undo_manager_impl(port, ptr, rust_vec_len, data_len),
+47 => wire__crate__doc__document_service__DocumentService_encode_awareness_update_impl(port, ptr, rust_vec_len, data_len),
+48 => wire__crate__doc__document_service__DocumentService_encode_diff_since_impl(port, ptr, rust_vec_len, data_len),
+49 => wire__crate__doc__document_service__DocumentService_encode_full_state_impl(port, ptr, rust_vec_len, data_len),
+50 => wire__crate__doc__document_service__DocumentService_encode_full_state_v1_impl(port, ptr, rust_vec_len, data_len),
+51 => wire__crate__doc__document_service__DocumentService_encode_meta_only_update_impl(port, ptr, rust_vec_len, data_len),
+52 => wire__crate__doc__document_service__DocumentService_encode_relative_position_impl(port, ptr, rust_vec_len, data_len),
+53 => wire__crate__doc__document_service__DocumentService_encode_state_vector_impl(port, ptr, rust_vec_len, data_len),
+54 => wire__crate__doc__document_service__DocumentService_encode_subdoc_state_impl(port, ptr, rust_vec_len, data_len),
+55 => wire__crate__doc__document_service__DocumentService_enforce_single_root_impl(port, ptr, rust_vec_len, data_len),
+56 => wire__crate__doc__document_service__DocumentService_estimate_diff_size_impl(port, ptr, rust_vec_len, data_len),
+57 => wire__crate__doc__document_service__DocumentService_export_appflowy_json_impl(port, ptr, rust_vec_len, data_len),
+58 => wire__crate__doc__document_service__DocumentService_export_markdown_impl(port, ptr, rust_vec_len, data_len),
+59 => wire__crate__doc__document_service__DocumentService_export_structure_impl(port, ptr, rust_vec_len, data_len),
+60 => wire__crate__doc__document_service__DocumentService_export_tree_json_impl(port, ptr, rust_vec_len, data_len),
+61 => wire__crate__doc__document_service__DocumentService_extract_delete_set_impl(port, ptr, rust_vec_len, data_len),
+62 => wire__crate__doc__document_service__DocumentService_find_oversized_blocks_impl(port, ptr, rust_vec_len, data_len),
+63 => wire__crate__doc__document_service__DocumentService_fork_impl(port, ptr, rust_vec_len, data_len),
+64 => wire__crate__doc__document_service__DocumentService_format_blocks_impl(port, ptr, rust_vec_len, data_len),
+65 => wire__crate__doc__document_service__DocumentService_gc_enabled_impl(port, ptr, rust_vec_len, data_len),
+66 => wire__crate__doc__document_service__DocumentService_get_all_block_ids_impl(port, ptr, rust_vec_len, data_len),
+67 => wire__crate__doc__document_service__DocumentService_get_all_meta_impl(port, ptr, rust_vec_len, data_len),
+68 => wire__crate__doc__document_service__DocumentService_get_block_impl(port, ptr, rust_vec_len, data_len),
+69 => wire__crate__doc__document_service__DocumentService_get_block_count_impl(port, ptr, rust_vec_len, data_len),
+70 => wire__crate__doc__document_service__DocumentService_get_block_path_impl(port, ptr, rust_vec_len, data_len),
+71 => wire__crate__doc__document_service__DocumentService_get_block_plain_text_impl(port, ptr, rust_vec_len, data_len),
+72 => wire__crate__doc__document_service__DocumentService_get_blocks_in_order_impl(port, ptr, rust_vec_len, data_len),
+73 => wire__crate__doc__document_service__DocumentService_get_blocks_page_impl(port, ptr, rust_vec_len, data_len),
+74 => wire__crate__doc__document_service__DocumentService_get_children_ids_impl(port, ptr, rust_vec_len, data_len),
+75 => wire__crate__doc__document_service__DocumentService_get_comments_for_block_impl(port, ptr, rust_vec_len, data_len),
+76 => wire__crate__doc__document_service__DocumentService_get_document_json_impl(port, ptr, rust_vec_len, data_len),
+77 => wire__crate__doc__document_service__DocumentService_get_document_plain_text_impl(port, ptr, rust_vec_len, data_len),
+78 => wire__crate__doc__document_service__DocumentService_get_document_size_bytes_impl(port, ptr, rust_vec_len, data_len),
+79 => wire__crate__doc__document_service__DocumentService_get_document_state_impl(port, ptr, rust_vec_len, data_len),
+80 => wire__crate__doc__document_service__DocumentService_get_meta_bool_impl(port, ptr, rust_vec_len, data_len),
+81 => wire__crate__doc__document_service__DocumentService_get_meta_int_impl(port, ptr, rust_vec_len, data_len),
+82 => wire__crate__doc__document_service__DocumentService_get_meta_keys_impl(port, ptr, rust_vec_len, data_len),
+83 => wire__crate__doc__document_service__DocumentService_get_meta_string_impl(port, ptr, rust_vec_len, data_len),
+84 => wire__crate__doc__document_service__DocumentService_get_meta_string_array_impl(port, ptr, rust_vec_len, data_len),
+85 => wire__crate__doc__document_service__DocumentService_get_root_node_id_impl(port, ptr, rust_vec_len, data_len),
+86 => wire__crate__doc__document_service__DocumentService_get_statistics_impl(port, ptr, rust_vec_len, data_len),
+87 => wire__crate__doc__document_service__DocumentService_get_statistics_with_blocks_impl(port, ptr, rust_vec_len, data_len),
+88 => wire__crate__doc__document_service__DocumentService_get_subdoc_guids_impl(port, ptr, rust_vec_len, data_len),
+89 => wire__crate__doc__document_service__DocumentService_import_appflowy_json_impl(port, ptr, rust_vec_len, data_len),
+90 => wire__crate__doc__document_service__DocumentService_import_markdown_impl(port, ptr, rust_vec_len, data_len),
+91 => wire__crate__doc__document_service__DocumentService_import_tree_json_impl(port, ptr, rust_vec_len, data_len),
+92 => wire__crate__doc__document_service__DocumentService_indent_block_impl(port, ptr, rust_vec_len, data_len),
+93 => wire__crate__doc__document_service__DocumentService_init_empty_doc_impl(port, ptr, rust_vec_len, data_len),
+94 => wire__crate__doc__document_service__DocumentService_insert_blocks_bulk_impl(port, ptr, rust_vec_len, data_len),
+95 => wire__crate__doc__document_service__DocumentService_is_batching_impl(port, ptr, rust_vec_len, data_len),
+96 => wire__crate__doc__document_service__DocumentService_is_empty_impl(port, ptr, rust_vec_len, data_len),
+97 => wire__crate__doc__document_service__DocumentService_last_replace_count_impl(port, ptr, rust_vec_len, data_len),
+98 => wire__crate__doc__document_service__DocumentService_load_and_get_state_impl(port, ptr, rust_vec_len, data_len),
+99 => wire__crate__doc__document_service__DocumentService_load_from_file_impl(port, ptr, rust_vec_len, data_len),
+100 => wire__crate__doc__document_service__DocumentService_merge_blocks_impl(port, ptr, rust_vec_len, data_len),
+101 => wire__crate__doc__document_service__DocumentService_merge_three_way_impl(port, ptr, rust_vec_len, data_len),
+102 => wire__crate__doc__document_service__DocumentService_merge_updates_impl(port, ptr, rust_vec_len, data_len),
+103 => wire__crate__doc__document_service__DocumentService_merge_updates_v1_impl(port, ptr, rust_vec_len, data_len),
+104 => wire__crate__doc__document_service__DocumentService_meta_diff_against_json_impl(port, ptr, rust_vec_len, data_len),
+105 => wire__crate__doc__document_service__DocumentService_missing_from_impl(port, ptr, rust_vec_len, data_len),
+106 => wire__crate__doc__document_service__DocumentService_new_impl(port, ptr, rust_vec_len, data_len),
+107 => wire__crate__doc__document_service__DocumentService_new_with_gc_disabled_impl(port, ptr, rust_vec_len, data_len),
+108 => wire__crate__doc__document_service__DocumentService_new_with_id_impl(port, ptr, rust_vec_len, data_len),
+109 => wire__crate__doc__document_service__DocumentService_new_with_options_impl(port, ptr, rust_vec_len, data_len),
+110 => wire__crate__doc__document_service__DocumentService_outdent_block_impl(port, ptr, rust_vec_len, data_len),
+111 => wire__crate__doc__document_service__DocumentService_paste_blocks_impl(port, ptr, rust_vec_len, data_len),
+112 => wire__crate__doc__document_service__DocumentService_push_meta_array_item_impl(port, ptr, rust_vec_len, data_len),
+113 => wire__crate__doc__document_service__DocumentService_read_document_state_impl(port, ptr, rust_vec_len, data_len),
+114 => wire__crate__doc__document_service__DocumentService_redo_impl(port, ptr, rust_vec_len, data_len),
+115 => wire__crate__doc__document_service__DocumentService_remove_awareness_client_impl(port, ptr, rust_vec_len, data_len),
+116 => wire__crate__doc__document_service__DocumentService_remove_meta_array_item_impl(port, ptr, rust_vec_len, data_len),
+117 => wire__crate__doc__document_service__DocumentService_remove_meta_key_impl(port, ptr, rust_vec_len, data_len),
+118 => wire__crate__doc__document_service__DocumentService_repair_document_impl(port, ptr, rust_vec_len, data_len),
+119 => wire__crate__doc__document_service__DocumentService_replace_all_impl(port, ptr, rust_vec_len, data_len),
+120 => wire__crate__doc__document_service__DocumentService_resolve_comment_impl(port, ptr, rust_vec_len, data_len),
+121 => wire__crate__doc__document_service__DocumentService_restore_snapshot_impl(port, ptr, rust_vec_len, data_len),
+122 => wire__crate__doc__document_service__DocumentService_save_to_file_impl(port, ptr, rust_vec_len, data_len),
+123 => wire__crate__doc__document_service__DocumentService_search_text_impl(port, ptr, rust_vec_len, data_len),
+124 => wire__crate__doc__document_service__DocumentService_set_allowed_block_types_impl(port, ptr, rust_vec_len, data_len),
+125 => wire__crate__doc__document_service__DocumentService_set_attachment_budget_impl(port, ptr, rust_vec_len, data_len),
+126 => wire__crate__doc__document_service__DocumentService_set_block_attachment_impl(port, ptr, rust_vec_len, data_len),
+127 => wire__crate__doc__document_service__DocumentService_set_block_data_field_impl(port, ptr, rust_vec_len, data_len),
+128 => wire__crate__doc__document_service__DocumentService_set_id_generator_seed_impl(port, ptr, rust_vec_len, data_len),
+129 => wire__crate__doc__document_service__DocumentService_set_local_awareness_impl(port, ptr, rust_vec_len, data_len),
+130 => wire__crate__doc__document_service__DocumentService_set_log_level_impl(port, ptr, rust_vec_len, data_len),
+131 => wire__crate__doc__document_service__DocumentService_set_max_tree_depth_impl(port, ptr, rust_vec_len, data_len),
+132 => wire__crate__doc__document_service__DocumentService_set_meta_batch_impl(port, ptr, rust_vec_len, data_len),
+133 => wire__crate__doc__document_service__DocumentService_set_meta_bool_impl(port, ptr, rust_vec_len, data_len),
+134 => wire__crate__doc__document_service__DocumentService_set_meta_from_json_impl(port, ptr, rust_vec_len, data_len),
+135 => wire__crate__doc__document_service__DocumentService_set_meta_int_impl(port, ptr, rust_vec_len, data_len),
+136 => wire__crate__doc__document_service__DocumentService_set_meta_string_impl(port, ptr, rust_vec_len, data_len),
+137 => wire__crate__doc__document_service__DocumentService_set_meta_string_array_impl(port, ptr, rust_vec_len, data_len),
+138 => wire__crate__doc__document_service__DocumentService_set_root_node_id_impl(port, ptr, rust_vec_len, data_len),
+139 => wire__crate__doc__document_service__DocumentService_set_strict_types_impl(port, ptr, rust_vec_len, data_len),
+140 => wire__crate__doc__document_service__DocumentService_state_as_of_impl(port, ptr, rust_vec_len, data_len),
+141 => wire__crate__doc__document_service__DocumentService_state_vectors_equal_impl(port, ptr, rust_vec_len, data_len),
+142 => wire__crate__doc__document_service__DocumentService_stream_blocks_impl(port, ptr, rust_vec_len, data_len),
+143 => wire__crate__doc__document_service__DocumentService_subscribe_block_changes_impl(port, ptr, rust_vec_len, data_len),
+144 => wire__crate__doc__document_service__DocumentService_subscribe_changes_impl(port, ptr, rust_vec_len, data_len),
+145 => wire__crate__doc__document_service__DocumentService_subscribe_emptiness_impl(port, ptr, rust_vec_len, data_len),
+146 => wire__crate__doc__document_service__DocumentService_subscribe_meta_changes_impl(port, ptr, rust_vec_len, data_len),
+147 => wire__crate__doc__document_service__DocumentService_subscribe_subdoc_changes_impl(port, ptr, rust_vec_len, data_len),
+148 => wire__crate__doc__document_service__DocumentService_take_snapshot_impl(port, ptr, rust_vec_len, data_len),
+149 => wire__crate__doc__document_service__DocumentService_undo_impl(port, ptr, rust_vec_len, data_len),
+150 => wire__crate__doc__document_service__DocumentService_unsubscribe_block_changes_impl(port, ptr, rust_vec_len, data_len),
+151 => wire__crate__doc__document_service__DocumentService_validate_action_impl(port, ptr, rust_vec_len, data_len),
+152 => wire__crate__doc__document_service__DocumentService_validate_appflowy_json_impl(port, ptr, rust_vec_len, data_len),
+153 => wire__crate__doc__document_service__DocumentService_validate_document_impl(port, ptr, rust_vec_len, data_len),
+154 => wire__crate__doc__document_types__custom_rust_error_new_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -1502,11 +2899,24 @@ fn pde_ffi_dispatcher_sync_impl(
 
 // Section: rust2dart
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<DocumentRegistry> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0).into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<DocumentRegistry> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<DocumentRegistry>> for DocumentRegistry {
+    fn into_into_dart(self) -> FrbWrapper<DocumentRegistry> {
+        self.into()
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<DocumentService> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0).into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<DocumentService> {}
@@ -1518,24 +2928,37 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<DocumentService>> for Document
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::doc::document_types::BlockActionDoc {
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::ActionResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.update.into_into_dart().into_dart(), self.generated_ids.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::ActionResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::ActionResult> for crate::doc::document_types::ActionResult {
+    fn into_into_dart(self) -> crate::doc::document_types::ActionResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::AppliedBlockChanges {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.action.into_into_dart().into_dart(),
-            self.block.into_into_dart().into_dart(),
-            self.path.into_into_dart().into_dart(),
-            self.old_path.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        [self.inserted_block_ids.into_into_dart().into_dart(), self.updated_block_ids.into_into_dart().into_dart(), self.deleted_block_ids.into_into_dart().into_dart(), self.failed_updates.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::doc::document_types::BlockActionDoc
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::AppliedBlockChanges {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::AppliedBlockChanges> for crate::doc::document_types::AppliedBlockChanges {
+    fn into_into_dart(self) -> crate::doc::document_types::AppliedBlockChanges {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::BlockActionDoc {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.action.into_into_dart().into_dart(), self.block.into_into_dart().into_dart(), self.path.into_into_dart().into_dart(), self.old_path.into_into_dart().into_dart()].into_dart()
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockActionDoc>
-    for crate::doc::document_types::BlockActionDoc
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::BlockActionDoc {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockActionDoc> for crate::doc::document_types::BlockActionDoc {
     fn into_into_dart(self) -> crate::doc::document_types::BlockActionDoc {
         self
     }
@@ -1543,22 +2966,11 @@ impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockActionDo
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::doc::document_types::BlockActionTypeDoc {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Insert => 0.into_dart(),
-            Self::Update => 1.into_dart(),
-            Self::Delete => 2.into_dart(),
-            Self::Move => 3.into_dart(),
-            _ => unreachable!(),
-        }
+        match self { Self::Insert => 0.into_dart(), Self::Update => 1.into_dart(), Self::Delete => 2.into_dart(), Self::Move => 3.into_dart(), _ => unreachable!(), }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::doc::document_types::BlockActionTypeDoc
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockActionTypeDoc>
-    for crate::doc::document_types::BlockActionTypeDoc
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::BlockActionTypeDoc {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockActionTypeDoc> for crate::doc::document_types::BlockActionTypeDoc {
     fn into_into_dart(self) -> crate::doc::document_types::BlockActionTypeDoc {
         self
     }
@@ -1566,378 +2978,544 @@ impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockActionTy
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::doc::document_types::BlockDoc {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.ty.into_into_dart().into_dart(),
-            self.attributes.into_into_dart().into_dart(),
-            self.delta.into_into_dart().into_dart(),
-            self.parent_id.into_into_dart().into_dart(),
-            self.prev_id.into_into_dart().into_dart(),
-            self.next_id.into_into_dart().into_dart(),
-            self.old_parent_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        [self.id.into_into_dart().into_dart(), self.ty.into_into_dart().into_dart(), self.attributes.into_into_dart().into_dart(), self.delta.into_into_dart().into_dart(), self.parent_id.into_into_dart().into_dart(), self.prev_id.into_into_dart().into_dart(), self.next_id.into_into_dart().into_dart(), self.old_parent_id.into_into_dart().into_dart(), self.created_at.into_into_dart().into_dart(), self.updated_at.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::doc::document_types::BlockDoc
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockDoc>
-    for crate::doc::document_types::BlockDoc
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::BlockDoc {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockDoc> for crate::doc::document_types::BlockDoc {
     fn into_into_dart(self) -> crate::doc::document_types::BlockDoc {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::doc::document_types::CustomRustError {
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::BlockPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.blocks.into_into_dart().into_dart(), self.total.into_into_dart().into_dart(), self.has_more.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::BlockPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockPage> for crate::doc::document_types::BlockPage {
+    fn into_into_dart(self) -> crate::doc::document_types::BlockPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::BlockStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.block_id.into_into_dart().into_dart(), self.word_count.into_into_dart().into_dart(), self.char_count.into_into_dart().into_dart(), self.char_count_no_spaces.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::BlockStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::BlockStats> for crate::doc::document_types::BlockStats {
+    fn into_into_dart(self) -> crate::doc::document_types::BlockStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::CommentDoc {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.message.into_into_dart().into_dart()].into_dart()
+        [self.comment_id.into_into_dart().into_dart(), self.block_id.into_into_dart().into_dart(), self.author.into_into_dart().into_dart(), self.text.into_into_dart().into_dart(), self.resolved.into_into_dart().into_dart(), self.anchor_offset.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::CommentDoc {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::CommentDoc> for crate::doc::document_types::CommentDoc {
+    fn into_into_dart(self) -> crate::doc::document_types::CommentDoc {
+        self
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::doc::document_types::CustomRustError
-{
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::CustomRustError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.code.into_into_dart().into_dart(), self.message.into_into_dart().into_dart()].into_dart()
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::CustomRustError>
-    for crate::doc::document_types::CustomRustError
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::CustomRustError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::CustomRustError> for crate::doc::document_types::CustomRustError {
     fn into_into_dart(self) -> crate::doc::document_types::CustomRustError {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::doc::document_types::DocumentState {
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::DocSize {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.full_state_bytes.into_into_dart().into_dart(), self.content_bytes.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::DocSize {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::DocSize> for crate::doc::document_types::DocSize {
+    fn into_into_dart(self) -> crate::doc::document_types::DocSize {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::DocStats {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.doc_id.into_into_dart().into_dart(),
-            self.blocks.into_into_dart().into_dart(),
-            self.children_map.into_into_dart().into_dart(),
-            self.root_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        [self.word_count.into_into_dart().into_dart(), self.char_count.into_into_dart().into_dart(), self.char_count_no_spaces.into_into_dart().into_dart(), self.block_count.into_into_dart().into_dart(), self.blocks.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::doc::document_types::DocumentState
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::DocStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::DocStats> for crate::doc::document_types::DocStats {
+    fn into_into_dart(self) -> crate::doc::document_types::DocStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::DocumentState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.doc_id.into_into_dart().into_dart(), self.blocks.into_into_dart().into_dart(), self.children_map.into_into_dart().into_dart(), self.root_id.into_into_dart().into_dart()].into_dart()
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::DocumentState>
-    for crate::doc::document_types::DocumentState
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::DocumentState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::DocumentState> for crate::doc::document_types::DocumentState {
     fn into_into_dart(self) -> crate::doc::document_types::DocumentState {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::doc::document_types::FailedToDecodeUpdates {
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::DocumentValidation {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.failed_updates_ids.into_into_dart().into_dart()].into_dart()
+        [self.orphaned_block_ids.into_into_dart().into_dart(), self.cyclic_block_ids.into_into_dart().into_dart(), self.dangling_reference_ids.into_into_dart().into_dart(), self.orphaned_comment_ids.into_into_dart().into_dart()].into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::doc::document_types::FailedToDecodeUpdates
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::DocumentValidation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::DocumentValidation> for crate::doc::document_types::DocumentValidation {
+    fn into_into_dart(self) -> crate::doc::document_types::DocumentValidation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::DuplicateResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.update.into_into_dart().into_dart(), self.id_map.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::DuplicateResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::DuplicateResult> for crate::doc::document_types::DuplicateResult {
+    fn into_into_dart(self) -> crate::doc::document_types::DuplicateResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::FailedToDecodeUpdates {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.failed_updates_ids.into_into_dart().into_dart(), self.errors.into_into_dart().into_dart()].into_dart()
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::FailedToDecodeUpdates>
-    for crate::doc::document_types::FailedToDecodeUpdates
-{
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::FailedToDecodeUpdates {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::FailedToDecodeUpdates> for crate::doc::document_types::FailedToDecodeUpdates {
     fn into_into_dart(self) -> crate::doc::document_types::FailedToDecodeUpdates {
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::MetaOp {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self { crate::doc::document_types::MetaOp::SetString { key, value } => [0.into_dart(), key.into_into_dart().into_dart(), value.into_into_dart().into_dart()].into_dart(),crate::doc::document_types::MetaOp::SetInt { key, value } => [1.into_dart(), key.into_into_dart().into_dart(), value.into_into_dart().into_dart()].into_dart(),crate::doc::document_types::MetaOp::SetBool { key, value } => [2.into_dart(), key.into_into_dart().into_dart(), value.into_into_dart().into_dart()].into_dart(),crate::doc::document_types::MetaOp::SetStringArray { key, values } => [3.into_dart(), key.into_into_dart().into_dart(), values.into_into_dart().into_dart()].into_dart(),crate::doc::document_types::MetaOp::Remove { key } => [4.into_dart(), key.into_into_dart().into_dart()].into_dart(),crate::doc::document_types::MetaOp::PushArrayItem { key, value } => [5.into_dart(), key.into_into_dart().into_dart(), value.into_into_dart().into_dart()].into_dart(),crate::doc::document_types::MetaOp::RemoveArrayItem { key, value } => [6.into_dart(), key.into_into_dart().into_dart(), value.into_into_dart().into_dart()].into_dart(), _ => { unimplemented!(""); } }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::MetaOp {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::MetaOp> for crate::doc::document_types::MetaOp {
+    fn into_into_dart(self) -> crate::doc::document_types::MetaOp {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::OrderedBlock {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.block.into_into_dart().into_dart(), self.depth.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::OrderedBlock {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::OrderedBlock> for crate::doc::document_types::OrderedBlock {
+    fn into_into_dart(self) -> crate::doc::document_types::OrderedBlock {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::OriginUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.update.into_into_dart().into_dart(), self.origin.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::OriginUpdate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::OriginUpdate> for crate::doc::document_types::OriginUpdate {
+    fn into_into_dart(self) -> crate::doc::document_types::OriginUpdate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::RelativePos {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.block_id.into_into_dart().into_dart(), self.offset.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::RelativePos {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::RelativePos> for crate::doc::document_types::RelativePos {
+    fn into_into_dart(self) -> crate::doc::document_types::RelativePos {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::doc::document_types::TextMatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.block_id.into_into_dart().into_dart(), self.offset.into_into_dart().into_dart(), self.length.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::doc::document_types::TextMatch {}
+impl flutter_rust_bridge::IntoIntoDart<crate::doc::document_types::TextMatch> for crate::doc::document_types::TextMatch {
+    fn into_into_dart(self) -> crate::doc::document_types::TextMatch {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(format!("{:?}", self), serializer);}
+}
+
+impl SseEncode for DocumentRegistry {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);}
+}
 
 impl SseEncode for DocumentService {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);}
+}
+
+impl SseEncode for flutter_rust_bridge::DartOpaque {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<usize>::sse_encode(self.encode(), serializer);}
 }
 
 impl SseEncode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);}
 }
 
 impl SseEncode for std::collections::HashMap<String, crate::doc::document_types::BlockDoc> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, crate::doc::document_types::BlockDoc)>>::sse_encode(
-            self.into_iter().collect(),
-            serializer,
-        );
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<(String, crate::doc::document_types::BlockDoc)>>::sse_encode(self.into_iter().collect(), serializer);}
 }
 
 impl SseEncode for std::collections::HashMap<String, Vec<String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, Vec<String>)>>::sse_encode(self.into_iter().collect(), serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<(String, Vec<String>)>>::sse_encode(self.into_iter().collect(), serializer);}
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
-    }
+impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {let (ptr, size) = self.sse_encode_raw(); <usize>::sse_encode(ptr, serializer); <i32>::sse_encode(size, serializer);}
+}
+
+impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {let (ptr, size) = self.sse_encode_raw(); <usize>::sse_encode(ptr, serializer); <i32>::sse_encode(size, serializer);}
+}
+
+impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {unimplemented!("")}
+}
+
+impl SseEncode for StreamSink<bool, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {unimplemented!("")}
+}
+
+impl SseEncode for StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {unimplemented!("")}
+}
+
+impl SseEncode for StreamSink<crate::doc::document_types::OriginUpdate, flutter_rust_bridge::for_generated::SseCodec> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {unimplemented!("")}
 }
 
 impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<u8>>::sse_encode(self.into_bytes(), serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::ActionResult {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<u8>>::sse_encode(self.update, serializer); <Vec<String>>::sse_encode(self.generated_ids, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::AppliedBlockChanges {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<String>>::sse_encode(self.inserted_block_ids, serializer); <Vec<String>>::sse_encode(self.updated_block_ids, serializer); <Vec<String>>::sse_encode(self.deleted_block_ids, serializer); <crate::doc::document_types::FailedToDecodeUpdates>::sse_encode(self.failed_updates, serializer);}
 }
 
 impl SseEncode for crate::doc::document_types::BlockActionDoc {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::doc::document_types::BlockActionTypeDoc>::sse_encode(self.action, serializer);
-        <crate::doc::document_types::BlockDoc>::sse_encode(self.block, serializer);
-        <Vec<u32>>::sse_encode(self.path, serializer);
-        <Option<Vec<u32>>>::sse_encode(self.old_path, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::doc::document_types::BlockActionTypeDoc>::sse_encode(self.action, serializer); <crate::doc::document_types::BlockDoc>::sse_encode(self.block, serializer); <Vec<u32>>::sse_encode(self.path, serializer); <Option<Vec<u32>>>::sse_encode(self.old_path, serializer);}
 }
 
 impl SseEncode for crate::doc::document_types::BlockActionTypeDoc {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::doc::document_types::BlockActionTypeDoc::Insert => 0,
-                crate::doc::document_types::BlockActionTypeDoc::Update => 1,
-                crate::doc::document_types::BlockActionTypeDoc::Delete => 2,
-                crate::doc::document_types::BlockActionTypeDoc::Move => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self { crate::doc::document_types::BlockActionTypeDoc::Insert => 0, crate::doc::document_types::BlockActionTypeDoc::Update => 1, crate::doc::document_types::BlockActionTypeDoc::Delete => 2, crate::doc::document_types::BlockActionTypeDoc::Move => 3, _ => { unimplemented!(""); } }, serializer);}
 }
 
 impl SseEncode for crate::doc::document_types::BlockDoc {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.ty, serializer);
-        <std::collections::HashMap<String, String>>::sse_encode(self.attributes, serializer);
-        <Option<String>>::sse_encode(self.delta, serializer);
-        <Option<String>>::sse_encode(self.parent_id, serializer);
-        <Option<String>>::sse_encode(self.prev_id, serializer);
-        <Option<String>>::sse_encode(self.next_id, serializer);
-        <Option<String>>::sse_encode(self.old_parent_id, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.id, serializer); <String>::sse_encode(self.ty, serializer); <std::collections::HashMap<String, String>>::sse_encode(self.attributes, serializer); <Option<String>>::sse_encode(self.delta, serializer); <Option<String>>::sse_encode(self.parent_id, serializer); <Option<String>>::sse_encode(self.prev_id, serializer); <Option<String>>::sse_encode(self.next_id, serializer); <Option<String>>::sse_encode(self.old_parent_id, serializer); <Option<i64>>::sse_encode(self.created_at, serializer); <Option<i64>>::sse_encode(self.updated_at, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::BlockPage {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<crate::doc::document_types::BlockDoc>>::sse_encode(self.blocks, serializer); <usize>::sse_encode(self.total, serializer); <bool>::sse_encode(self.has_more, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::BlockStats {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.block_id, serializer); <u32>::sse_encode(self.word_count, serializer); <u32>::sse_encode(self.char_count, serializer); <u32>::sse_encode(self.char_count_no_spaces, serializer);}
 }
 
 impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u8(self as _).unwrap();}
+}
+
+impl SseEncode for crate::doc::document_types::CommentDoc {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.comment_id, serializer); <String>::sse_encode(self.block_id, serializer); <String>::sse_encode(self.author, serializer); <String>::sse_encode(self.text, serializer); <bool>::sse_encode(self.resolved, serializer); <Option<u32>>::sse_encode(self.anchor_offset, serializer);}
 }
 
 impl SseEncode for crate::doc::document_types::CustomRustError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.message, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.code, serializer); <String>::sse_encode(self.message, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::DocSize {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.full_state_bytes, serializer); <u64>::sse_encode(self.content_bytes, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::DocStats {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u32>::sse_encode(self.word_count, serializer); <u32>::sse_encode(self.char_count, serializer); <u32>::sse_encode(self.char_count_no_spaces, serializer); <u32>::sse_encode(self.block_count, serializer); <Option<Vec<crate::doc::document_types::BlockStats>>>::sse_encode(self.blocks, serializer);}
 }
 
 impl SseEncode for crate::doc::document_types::DocumentState {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.doc_id, serializer);
-        <std::collections::HashMap<String, crate::doc::document_types::BlockDoc>>::sse_encode(
-            self.blocks,
-            serializer,
-        );
-        <std::collections::HashMap<String, Vec<String>>>::sse_encode(self.children_map, serializer);
-        <String>::sse_encode(self.root_id, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.doc_id, serializer); <std::collections::HashMap<String, crate::doc::document_types::BlockDoc>>::sse_encode(self.blocks, serializer); <std::collections::HashMap<String, Vec<String>>>::sse_encode(self.children_map, serializer); <String>::sse_encode(self.root_id, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::DocumentValidation {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<String>>::sse_encode(self.orphaned_block_ids, serializer); <Vec<String>>::sse_encode(self.cyclic_block_ids, serializer); <Vec<String>>::sse_encode(self.dangling_reference_ids, serializer); <Vec<String>>::sse_encode(self.orphaned_comment_ids, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::DuplicateResult {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<u8>>::sse_encode(self.update, serializer); <std::collections::HashMap<String, String>>::sse_encode(self.id_map, serializer);}
 }
 
 impl SseEncode for crate::doc::document_types::FailedToDecodeUpdates {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<String>>::sse_encode(self.failed_updates_ids, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<String>>::sse_encode(self.failed_updates_ids, serializer); <Vec<String>>::sse_encode(self.errors, serializer);}
 }
 
 impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_i32::<NativeEndian>(self).unwrap();}
 }
 
 impl SseEncode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_i64::<NativeEndian>(self).unwrap();}
 }
 
 impl SseEncode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <String>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<crate::doc::document_types::BlockActionDoc> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::doc::document_types::BlockActionDoc>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::BlockActionDoc>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<crate::doc::document_types::BlockDoc> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::BlockDoc>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<crate::doc::document_types::BlockStats> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::BlockStats>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<crate::doc::document_types::CommentDoc> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::CommentDoc>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<Vec<u8>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Vec<u8>>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <Vec<u8>>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<crate::doc::document_types::MetaOp> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::MetaOp>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<crate::doc::document_types::OrderedBlock> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::OrderedBlock>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u32>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <u32>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <u8>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<(String, crate::doc::document_types::BlockDoc)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::doc::document_types::BlockDoc)>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <(String, crate::doc::document_types::BlockDoc)>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<(String, Vec<String>)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, Vec<String>)>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <(String, Vec<String>)>::sse_encode(item, serializer); }}
 }
 
 impl SseEncode for Vec<(String, String)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, String)>::sse_encode(item, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <(String, String)>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<(u64, u32)> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <(u64, u32)>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for Vec<crate::doc::document_types::TextMatch> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer); for item in self { <crate::doc::document_types::TextMatch>::sse_encode(item, serializer); }}
+}
+
+impl SseEncode for crate::doc::document_types::MetaOp {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self { crate::doc::document_types::MetaOp::SetString { key, value } => { <i32>::sse_encode(0, serializer); <String>::sse_encode(key, serializer);<String>::sse_encode(value, serializer);}crate::doc::document_types::MetaOp::SetInt { key, value } => { <i32>::sse_encode(1, serializer); <String>::sse_encode(key, serializer);<i64>::sse_encode(value, serializer);}crate::doc::document_types::MetaOp::SetBool { key, value } => { <i32>::sse_encode(2, serializer); <String>::sse_encode(key, serializer);<bool>::sse_encode(value, serializer);}crate::doc::document_types::MetaOp::SetStringArray { key, values } => { <i32>::sse_encode(3, serializer); <String>::sse_encode(key, serializer);<Vec<String>>::sse_encode(values, serializer);}crate::doc::document_types::MetaOp::Remove { key } => { <i32>::sse_encode(4, serializer); <String>::sse_encode(key, serializer);}crate::doc::document_types::MetaOp::PushArrayItem { key, value } => { <i32>::sse_encode(5, serializer); <String>::sse_encode(key, serializer);<String>::sse_encode(value, serializer);}crate::doc::document_types::MetaOp::RemoveArrayItem { key, value } => { <i32>::sse_encode(6, serializer); <String>::sse_encode(key, serializer);<String>::sse_encode(value, serializer);} _ => { unimplemented!(""); } }}
 }
 
 impl SseEncode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <String>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<crate::doc::document_types::BlockDoc> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <crate::doc::document_types::BlockDoc>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<bool> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <bool>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<i64> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <i64>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<u32> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <u32>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<u64> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <u64>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<Vec<String>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <Vec<String>>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for Option<Vec<crate::doc::document_types::BlockStats>> {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <Vec<crate::doc::document_types::BlockStats>>::sse_encode(value, serializer); }}
 }
 
 impl SseEncode for Option<Vec<u32>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<u32>>::sse_encode(value, serializer);
-        }
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer); if let Some(value) = self { <Vec<u32>>::sse_encode(value, serializer); }}
+}
+
+impl SseEncode for crate::doc::document_types::OrderedBlock {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::doc::document_types::BlockDoc>::sse_encode(self.block, serializer); <i32>::sse_encode(self.depth, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::OriginUpdate {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<u8>>::sse_encode(self.update, serializer); <Option<String>>::sse_encode(self.origin, serializer);}
 }
 
 impl SseEncode for (String, crate::doc::document_types::BlockDoc) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.0, serializer);
-        <crate::doc::document_types::BlockDoc>::sse_encode(self.1, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.0, serializer); <crate::doc::document_types::BlockDoc>::sse_encode(self.1, serializer);}
 }
 
 impl SseEncode for (String, Vec<String>) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.0, serializer);
-        <Vec<String>>::sse_encode(self.1, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.0, serializer); <Vec<String>>::sse_encode(self.1, serializer);}
 }
 
 impl SseEncode for (String, String) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.0, serializer);
-        <String>::sse_encode(self.1, serializer);
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.0, serializer); <String>::sse_encode(self.1, serializer);}
+}
+
+impl SseEncode for (u64, u32) {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.0, serializer); <u32>::sse_encode(self.1, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::RelativePos {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.block_id, serializer); <u32>::sse_encode(self.offset, serializer);}
+}
+
+impl SseEncode for crate::doc::document_types::TextMatch {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.block_id, serializer); <u32>::sse_encode(self.offset, serializer); <u32>::sse_encode(self.length, serializer);}
 }
 
 impl SseEncode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u32::<NativeEndian>(self).unwrap();}
+}
+
+impl SseEncode for u64 {
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u64::<NativeEndian>(self).unwrap();}
 }
 
 impl SseEncode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u8(self).unwrap();}
 }
 
 impl SseEncode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
 impl SseEncode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
-    }
+// Codec=Sse (Serialization based), see doc to use other codecs
+fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u64::<NativeEndian>(self as _).unwrap();}
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -1948,10 +3526,9 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::doc::document_registry::*;
     use crate::doc::document_service::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
+    use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
     use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
     use flutter_rust_bridge::{Handler, IntoIntoDart};
 
@@ -1959,6 +3536,20 @@ mod io {
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_appflowy_editor_sync_plugin_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_appflowy_editor_sync_plugin_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_appflowy_editor_sync_plugin_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
         ptr: *const std::ffi::c_void,
@@ -1985,10 +3576,9 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::doc::document_registry::*;
     use crate::doc::document_service::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
+    use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
     use flutter_rust_bridge::for_generated::wasm_bindgen;
     use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
     use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
@@ -1998,6 +3588,20 @@ mod web {
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentRegistry(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentRegistry>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
         ptr: *const std::ffi::c_void,